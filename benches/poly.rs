@@ -1,6 +1,10 @@
 use concrete_ntt::prime64::Plan;
-use criterion::{Criterion, criterion_group, criterion_main};
+use criterion::measurement::WallTime;
+use criterion::{
+    BenchmarkGroup, BenchmarkId, Criterion, criterion_group, criterion_main,
+};
 use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::plantard::NttContext32;
 use rust_ntt::*;
 use std::hint::black_box;
 use std::sync::Arc;
@@ -95,11 +99,11 @@ fn bench_ntt_forward_shoup(c: &mut Criterion) {
     let mut ax = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
     ax.ntt_forward(); // Start with NTT-transformed data
 
+    let mut out = NttPolynomial::zero(Arc::clone(&ctx));
     c.bench_function("ntt forward shoup", |b| {
         b.iter(|| {
-            let mut poly = ax.clone();
-            poly.ntt_forward_shoup();
-            black_box(poly);
+            ax.ntt_forward_to(&mut out);
+            black_box(&out);
         })
     });
 }
@@ -112,15 +116,149 @@ fn bench_ntt_inverse_shoup(c: &mut Criterion) {
     let mut ax = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
     ax.ntt_forward(); // Start with NTT-transformed data
 
+    let mut out = NttPolynomial::zero(Arc::clone(&ctx));
     c.bench_function("ntt inverse shoup", |b| {
+        b.iter(|| {
+            ax.ntt_inverse_to(&mut out);
+            black_box(&out);
+        })
+    });
+}
+
+fn bench_ntt_small_shoup(c: &mut Criterion) {
+    const M: usize = 16;
+    let q: u64 = find_first_prime_down(58, M);
+    let mut rng = StdRng::seed_from_u64(42); // Deterministic seed
+    let ctx = NttContext::<M>::new(q);
+
+    let ax = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+    c.bench_function("ntt forward+inverse shoup n=16", |b| {
         b.iter(|| {
             let mut poly = ax.clone();
+            poly.ntt_forward_shoup();
             poly.ntt_inverse_shoup();
             black_box(poly);
         })
     });
 }
 
+fn bench_ntt_forward_lazy(c: &mut Criterion) {
+    let q: u64 = find_first_prime_down(58, N);
+    let mut rng = StdRng::seed_from_u64(42); // Deterministic seed
+    let ctx = NttContext::<N>::new(q);
+
+    let ax = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+    c.bench_function("ntt forward harvey", |b| {
+        b.iter(|| {
+            let mut poly = ax.clone();
+            poly.ntt_forward_lazy();
+            black_box(poly);
+        })
+    });
+}
+
+fn bench_ntt_neg_conv_montgomery(c: &mut Criterion) {
+    let q: u64 = find_first_prime_down(58, N);
+    let mut rng = StdRng::seed_from_u64(42); // Deterministic seed
+    let ctx = NttContext::<N>::new(q);
+
+    let ax = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+    let bx = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+    c.bench_function("ntt convolution montgomery", |b| {
+        b.iter(|| {
+            ax.negacyclic_convolution_montgomery(black_box(&bx));
+        })
+    });
+}
+
+fn bench_ntt_forward_montgomery(c: &mut Criterion) {
+    let q: u64 = find_first_prime_down(58, N);
+    let mut rng = StdRng::seed_from_u64(42); // Deterministic seed
+    let ctx = NttContext::<N>::new(q);
+
+    let ax = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+    c.bench_function("ntt forward montgomery", |b| {
+        b.iter(|| {
+            let mut poly = ax.clone();
+            poly.ntt_forward_montgomery();
+            black_box(poly);
+        })
+    });
+}
+
+fn bench_ntt_inverse_montgomery(c: &mut Criterion) {
+    let q: u64 = find_first_prime_down(58, N);
+    let mut rng = StdRng::seed_from_u64(42); // Deterministic seed
+    let ctx = NttContext::<N>::new(q);
+
+    let mut ax = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+    ax.ntt_forward_montgomery(); // Start with NTT-transformed data
+
+    c.bench_function("ntt inverse montgomery", |b| {
+        b.iter(|| {
+            let mut poly = ax.clone();
+            poly.ntt_inverse_montgomery();
+            black_box(poly);
+        })
+    });
+}
+
+fn bench_ntt_forward_plantard(c: &mut Criterion) {
+    let q: u64 = find_first_prime_up(30, N);
+    let mut rng = StdRng::seed_from_u64(42); // Deterministic seed
+    let ctx = NttContext::<N>::new(q);
+    let plantard = NttContext32::<N>::new(q as u32);
+
+    let sample_poly = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+    let ax = sample_poly.coeffs().map(|c| c as u32);
+
+    c.bench_function("ntt forward plantard", |b| {
+        b.iter(|| {
+            let mut poly = ax;
+            plantard.ntt_forward(&mut poly);
+            black_box(poly);
+        })
+    });
+}
+
+fn bench_four_step_crossover(c: &mut Criterion) {
+    fn sizes<const M: usize>(group: &mut BenchmarkGroup<'_, WallTime>) {
+        let q: u64 = find_first_prime_down(58, M);
+        let mut rng = StdRng::seed_from_u64(42); // Deterministic seed
+        let ctx = NttContext::<M>::new(q);
+
+        let ax = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+        // Build the lazy tables outside the timed loop
+        ax.clone().ntt_forward_four_step();
+
+        group.bench_function(BenchmarkId::new("shoup", M), |b| {
+            b.iter(|| {
+                let mut poly = ax.clone();
+                poly.ntt_forward_shoup();
+                black_box(poly);
+            })
+        });
+        group.bench_function(BenchmarkId::new("four-step", M), |b| {
+            b.iter(|| {
+                let mut poly = ax.clone();
+                poly.ntt_forward_four_step();
+                black_box(poly);
+            })
+        });
+    }
+
+    let mut group = c.benchmark_group("four-step crossover");
+    sizes::<{ 1 << 12 }>(&mut group);
+    sizes::<{ 1 << 14 }>(&mut group);
+    sizes::<{ 1 << 16 }>(&mut group);
+    sizes::<{ 1 << 18 }>(&mut group);
+    group.finish();
+}
+
 fn bench_concrete_forward(c: &mut Criterion) {
     let q: u64 = find_first_prime_down(58, N);
     let mut rng = StdRng::seed_from_u64(42); // Deterministic seed
@@ -162,6 +300,13 @@ criterion_group!(
     bench_ntt_inverse,
     bench_ntt_forward_shoup,
     bench_ntt_inverse_shoup,
+    bench_ntt_small_shoup,
+    bench_ntt_forward_lazy,
+    bench_ntt_neg_conv_montgomery,
+    bench_ntt_forward_montgomery,
+    bench_ntt_inverse_montgomery,
+    bench_ntt_forward_plantard,
+    bench_four_step_crossover,
     bench_concrete_forward,
     bench_concrete_inverse,
 );
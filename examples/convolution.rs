@@ -19,18 +19,18 @@ fn main() {
     let bx = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
 
     println!("📊 Input polynomials:");
-    println!("a(x) = {:?}", ax.coeffs());
-    println!("b(x) = {:?}", bx.coeffs());
+    println!("a(x) = {}", ax.to_sage_string());
+    println!("b(x) = {}", bx.to_sage_string());
 
     // Naive O(N^2) convolution for reference
     let cx_naive = ax.naive_negacyclic_convolution(&bx);
     println!("\n🧮 Naive convolution result:");
-    println!("c(x) = a(x) * b(x) = {:?}", cx_naive.coeffs());
+    println!("c(x) = a(x) * b(x) = {}", cx_naive.to_sage_string());
 
     // ⚡ Fast NTT-based convolution
     let cx_ntt = ax.negacyclic_convolution(&bx);
     println!("\n🚀 NTT convolution result:");
-    println!("c(x) = a(x) * b(x) = {:?}", cx_ntt.coeffs());
+    println!("c(x) = a(x) * b(x) = {}", cx_ntt.to_sage_string());
 
     // Verify both methods give same result
     let results_match = cx_naive.coeffs() == cx_ntt.coeffs();
@@ -41,7 +41,8 @@ fn main() {
 }
 
 /*
-Sage math example, can copy paste!
+Sage math example, can copy paste! The a(x) and b(x) lines above are
+printed as Sage lists, ready to drop into a_coeffs and b_coeffs.
 
 q = 1033
 N = 4
@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_ntt::fuzz::PolyPair;
+
+fuzz_target!(|pair: PolyPair<32>| {
+    let PolyPair(a, b) = pair;
+    let q = a.context().modulus();
+
+    let naive = a.naive_negacyclic_convolution(&b);
+    let fast = a.negacyclic_convolution(&b);
+    let shoup = a.negacyclic_convolution_shoup(&b);
+
+    for ((&x, &y), &z) in naive.coeffs().iter().zip(fast.coeffs()).zip(shoup.coeffs()) {
+        assert_eq!(x % q, y % q);
+        assert_eq!(x % q, z % q);
+    }
+});
@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_ntt::NttPolynomial;
+
+fuzz_target!(|poly: NttPolynomial<64>| {
+    let q = poly.context().modulus();
+
+    let mut plain = poly.clone();
+    plain.ntt_forward();
+    plain.ntt_inverse();
+
+    let mut shoup = poly.clone();
+    shoup.ntt_forward_shoup();
+    shoup.ntt_inverse_shoup();
+
+    for ((&a, &b), &c) in poly.coeffs().iter().zip(plain.coeffs()).zip(shoup.coeffs()) {
+        assert_eq!(a % q, b % q);
+        assert_eq!(a % q, c % q);
+    }
+});
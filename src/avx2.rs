@@ -0,0 +1,341 @@
+//! AVX2 Shoup transforms, four coefficients per vector.
+//!
+//! AVX2 has no 64×64-bit multiplication, so [`mul_hi`] and [`mul_lo`]
+//! assemble the high and low words of a product from the 32×32 → 64-bit
+//! `vpmuludq`. Everything else is a lane-wise copy of the scalar
+//! butterflies, using `q < 2^63`: a difference that should be reduced has
+//! its sign bit clear and one that wrapped has it set, so reductions are
+//! one blend on that bit.
+//!
+//! Stages with `t ≥ 4` run the butterflies on contiguous vectors. The two
+//! stages with `t = 2` and `t = 1` interleave two blocks of four, or four
+//! blocks of two, across a pair of vectors first. Slot order and values
+//! are those of [`forward_shoup_kernel`](crate::ntt::forward_shoup_kernel)
+//! and [`inverse_shoup_kernel`](crate::ntt::inverse_shoup_kernel), which
+//! call into this module when `is_x86_feature_detected!("avx2")` holds.
+
+// The intrinsics are only safe to call inside `target_feature` functions
+// from Rust 1.87 on; the unsafe blocks keep older compilers happy
+#![allow(unused_unsafe)]
+
+use crate::congruence::CongruenceClass;
+use std::arch::x86_64::*;
+
+/// Smallest degree with a full pair of vectors in the `t = 1` stage
+pub(crate) const MIN_DEGREE: usize = 8;
+
+/// Forward Shoup NTT in place, as [`forward_shoup_kernel`](crate::ntt::forward_shoup_kernel)
+///
+/// # Safety
+/// The CPU must support AVX2.
+///
+/// # Panics
+/// * If `coeffs` is shorter than [`MIN_DEGREE`] or the tables differ from
+///   it in length
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn forward_shoup(
+    class: &CongruenceClass,
+    tf: &[u64],
+    tf_shoup: &[u64],
+    coeffs: &mut [u64],
+) {
+    let degree = coeffs.len();
+    assert!(degree >= MIN_DEGREE && degree.is_power_of_two());
+    assert!(tf.len() == degree && tf_shoup.len() == degree);
+
+    unsafe {
+        let q = _mm256_set1_epi64x(class.q() as i64);
+        let c = coeffs.as_mut_ptr();
+        let mut t = degree >> 1;
+        let mut n = 1;
+
+        while t >= 4 {
+            for i in 0..n {
+                let w = _mm256_set1_epi64x(tf[n + i] as i64);
+                let w_shoup = _mm256_set1_epi64x(tf_shoup[n + i] as i64);
+                let j1 = 2 * i * t;
+
+                for j in (j1..j1 + t).step_by(4) {
+                    let (x, y) =
+                        ct(load(c.add(j)), load(c.add(j + t)), w, w_shoup, q);
+                    store(c.add(j), x);
+                    store(c.add(j + t), y);
+                }
+            }
+
+            n <<= 1;
+            t >>= 1;
+        }
+
+        // t = 2: blocks i and i + 1 as (c0, c1, c4, c5) and (c2, c3, c6, c7)
+        for i in (0..n).step_by(2) {
+            let j = 4 * i;
+            let (a, b) = (load(c.add(j)), load(c.add(j + 4)));
+            let (x, y) = (
+                _mm256_permute2x128_si256::<0x20>(a, b),
+                _mm256_permute2x128_si256::<0x31>(a, b),
+            );
+            let (w, w_shoup) = (pairs(&tf[n + i..]), pairs(&tf_shoup[n + i..]));
+
+            let (x, y) = ct(x, y, w, w_shoup, q);
+            store(c.add(j), _mm256_permute2x128_si256::<0x20>(x, y));
+            store(c.add(j + 4), _mm256_permute2x128_si256::<0x31>(x, y));
+        }
+        n <<= 1;
+
+        // t = 1: blocks i .. i + 3 as (c0, c4, c2, c6) and (c1, c5, c3, c7)
+        for i in (0..n).step_by(4) {
+            let j = 2 * i;
+            let (a, b) = (load(c.add(j)), load(c.add(j + 4)));
+            let (x, y) = (_mm256_unpacklo_epi64(a, b), _mm256_unpackhi_epi64(a, b));
+            let (w, w_shoup) = (quads(&tf[n + i..]), quads(&tf_shoup[n + i..]));
+
+            let (x, y) = ct(x, y, w, w_shoup, q);
+            store(c.add(j), _mm256_unpacklo_epi64(x, y));
+            store(c.add(j + 4), _mm256_unpackhi_epi64(x, y));
+        }
+    }
+}
+
+/// Inverse Shoup NTT in place with the scaling of
+/// [`inverse_shoup_kernel`](crate::ntt::inverse_shoup_kernel)
+///
+/// # Safety
+/// The CPU must support AVX2.
+///
+/// # Panics
+/// * If `evals` is shorter than [`MIN_DEGREE`] or the tables differ from it
+///   in length
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn inverse_shoup(
+    class: &CongruenceClass,
+    itf: &[u64],
+    itf_shoup: &[u64],
+    (inv_n, inv_n_shoup): (u64, u64),
+    (inv_n_itf, inv_n_itf_shoup): (u64, u64),
+    evals: &mut [u64],
+) {
+    let degree = evals.len();
+    assert!(degree >= MIN_DEGREE && degree.is_power_of_two());
+    assert!(itf.len() == degree && itf_shoup.len() == degree);
+
+    unsafe {
+        let q = _mm256_set1_epi64x(class.q() as i64);
+        let c = evals.as_mut_ptr();
+        let mut h = degree >> 1;
+
+        // t = 1, lanes as in the last forward stage
+        for i in (0..h).step_by(4) {
+            let j = 2 * i;
+            let (a, b) = (load(c.add(j)), load(c.add(j + 4)));
+            let (x, y) = (_mm256_unpacklo_epi64(a, b), _mm256_unpackhi_epi64(a, b));
+            let (w, w_shoup) = (quads(&itf[h + i..]), quads(&itf_shoup[h + i..]));
+
+            let (x, y) = gs(x, y, w, w_shoup, q);
+            store(c.add(j), _mm256_unpacklo_epi64(x, y));
+            store(c.add(j + 4), _mm256_unpackhi_epi64(x, y));
+        }
+        h >>= 1;
+
+        // t = 2; h ≥ 2 since degree ≥ 8, so this is never the last stage
+        for i in (0..h).step_by(2) {
+            let j = 4 * i;
+            let (a, b) = (load(c.add(j)), load(c.add(j + 4)));
+            let (x, y) = (
+                _mm256_permute2x128_si256::<0x20>(a, b),
+                _mm256_permute2x128_si256::<0x31>(a, b),
+            );
+            let (w, w_shoup) = (pairs(&itf[h + i..]), pairs(&itf_shoup[h + i..]));
+
+            let (x, y) = gs(x, y, w, w_shoup, q);
+            store(c.add(j), _mm256_permute2x128_si256::<0x20>(x, y));
+            store(c.add(j + 4), _mm256_permute2x128_si256::<0x31>(x, y));
+        }
+        h >>= 1;
+        let mut t = 4;
+
+        while h > 1 {
+            for i in 0..h {
+                let w = _mm256_set1_epi64x(itf[h + i] as i64);
+                let w_shoup = _mm256_set1_epi64x(itf_shoup[h + i] as i64);
+                let j1 = 2 * i * t;
+
+                for j in (j1..j1 + t).step_by(4) {
+                    let (x, y) =
+                        gs(load(c.add(j)), load(c.add(j + t)), w, w_shoup, q);
+                    store(c.add(j), x);
+                    store(c.add(j + t), y);
+                }
+            }
+
+            h >>= 1;
+            t <<= 1;
+        }
+
+        // Last stage, t = N/2, scaling sums by N^(-1) and differences by
+        // itf[1]·N^(-1)
+        let (s, s_shoup) = (
+            _mm256_set1_epi64x(inv_n as i64),
+            _mm256_set1_epi64x(inv_n_shoup as i64),
+        );
+        let (d, d_shoup) = (
+            _mm256_set1_epi64x(inv_n_itf as i64),
+            _mm256_set1_epi64x(inv_n_itf_shoup as i64),
+        );
+        for j in (0..t).step_by(4) {
+            let (u, v) = (load(c.add(j)), load(c.add(j + t)));
+            store(c.add(j), mul_shoup(add(u, v, q), s, s_shoup, q));
+            store(c.add(j + t), mul_shoup(sub(u, v, q), d, d_shoup, q));
+        }
+    }
+}
+
+/// Cooley-Tukey butterfly `(x + w·y, x - w·y)`
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn ct(
+    x: __m256i,
+    y: __m256i,
+    w: __m256i,
+    w_shoup: __m256i,
+    q: __m256i,
+) -> (__m256i, __m256i) {
+    unsafe {
+        let v = mul_shoup(y, w, w_shoup, q);
+        (add(x, v, q), sub(x, v, q))
+    }
+}
+
+/// Gentleman-Sande butterfly `(x + y, w·(x - y))`
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn gs(
+    x: __m256i,
+    y: __m256i,
+    w: __m256i,
+    w_shoup: __m256i,
+    q: __m256i,
+) -> (__m256i, __m256i) {
+    unsafe { (add(x, y, q), mul_shoup(sub(x, y, q), w, w_shoup, q)) }
+}
+
+/// `a·w mod q` for `a < 2^64`, with `w_shoup = ⌊w·2^64/q⌋`
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn mul_shoup(
+    a: __m256i,
+    w: __m256i,
+    w_shoup: __m256i,
+    q: __m256i,
+) -> __m256i {
+    unsafe {
+        let quotient = mul_hi(a, w_shoup);
+        // In [0, 2q) as in CongruenceClass::modmul_shoup
+        let r = _mm256_sub_epi64(mul_lo(a, w), mul_lo(quotient, q));
+        let d = _mm256_sub_epi64(r, q);
+        select_on_sign(d, d, r)
+    }
+}
+
+/// `(a + b) mod q` for `a, b < q`
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn add(a: __m256i, b: __m256i, q: __m256i) -> __m256i {
+    unsafe {
+        let s = _mm256_add_epi64(a, b);
+        let d = _mm256_sub_epi64(s, q);
+        select_on_sign(d, d, s)
+    }
+}
+
+/// `(a - b) mod q` for `a, b < q`
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn sub(a: __m256i, b: __m256i, q: __m256i) -> __m256i {
+    unsafe {
+        let d = _mm256_sub_epi64(a, b);
+        select_on_sign(d, d, _mm256_add_epi64(d, q))
+    }
+}
+
+/// Lane-wise `if mask < 0 { negative } else { positive }`
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn select_on_sign(
+    mask: __m256i,
+    positive: __m256i,
+    negative: __m256i,
+) -> __m256i {
+    unsafe {
+        _mm256_castpd_si256(_mm256_blendv_pd(
+            _mm256_castsi256_pd(positive),
+            _mm256_castsi256_pd(negative),
+            _mm256_castsi256_pd(mask),
+        ))
+    }
+}
+
+/// High words of the lane-wise 128-bit products `a·b`
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn mul_hi(a: __m256i, b: __m256i) -> __m256i {
+    unsafe {
+        let low = _mm256_set1_epi64x(0xffff_ffff);
+        let (a_hi, b_hi) = (_mm256_srli_epi64::<32>(a), _mm256_srli_epi64::<32>(b));
+
+        let lo_lo = _mm256_mul_epu32(a, b);
+        let hi_lo = _mm256_mul_epu32(a_hi, b);
+        let lo_hi = _mm256_mul_epu32(a, b_hi);
+        let hi_hi = _mm256_mul_epu32(a_hi, b_hi);
+
+        // Neither sum overflows: (2^32 - 1)^2 + 2^32 - 1 < 2^64
+        let mid = _mm256_add_epi64(hi_lo, _mm256_srli_epi64::<32>(lo_lo));
+        let mid_lo = _mm256_add_epi64(lo_hi, _mm256_and_si256(mid, low));
+        _mm256_add_epi64(
+            hi_hi,
+            _mm256_add_epi64(
+                _mm256_srli_epi64::<32>(mid),
+                _mm256_srli_epi64::<32>(mid_lo),
+            ),
+        )
+    }
+}
+
+/// Low words of the lane-wise products `a·b`
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn mul_lo(a: __m256i, b: __m256i) -> __m256i {
+    unsafe {
+        let cross = _mm256_add_epi64(
+            _mm256_mul_epu32(_mm256_srli_epi64::<32>(a), b),
+            _mm256_mul_epu32(a, _mm256_srli_epi64::<32>(b)),
+        );
+        _mm256_add_epi64(_mm256_mul_epu32(a, b), _mm256_slli_epi64::<32>(cross))
+    }
+}
+
+/// `(w[0], w[0], w[1], w[1])`, the lanes of the `t = 2` stage
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn pairs(w: &[u64]) -> __m256i {
+    unsafe { _mm256_set_epi64x(w[1] as i64, w[1] as i64, w[0] as i64, w[0] as i64) }
+}
+
+/// `(w[0], w[2], w[1], w[3])`, the lanes of the `t = 1` stage
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn quads(w: &[u64]) -> __m256i {
+    unsafe { _mm256_set_epi64x(w[3] as i64, w[1] as i64, w[2] as i64, w[0] as i64) }
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn load(p: *const u64) -> __m256i {
+    unsafe { _mm256_loadu_si256(p.cast()) }
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn store(p: *mut u64, value: __m256i) {
+    unsafe { _mm256_storeu_si256(p.cast(), value) }
+}
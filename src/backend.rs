@@ -0,0 +1,119 @@
+//! Interchangeable NTT backends behind one transform trait.
+//!
+//! [`NttBackend`] captures what a negacyclic transform provides: a forward
+//! map into some evaluation representation, its inverse, and the resulting
+//! fast ring product. [`NttContext`] is the native implementation. With the
+//! `concrete-ntt` feature, `ConcreteNtt` wraps `concrete_ntt::prime64::Plan`
+//! so the two can be A/B-tested, and its AVX kernels used, through the same
+//! API. To change how `NttContext` itself transforms, inject an
+//! [`NttKernel`](crate::kernel::NttKernel) instead.
+//!
+//! Backends may order (and even choose) their evaluation points differently,
+//! so only round trips and products are comparable across backends, not the
+//! forward outputs themselves.
+use crate::context::NttContext;
+
+/// A negacyclic transform of fixed length over `Z_q`.
+pub trait NttBackend<const DEGREE: usize> {
+    /// Modulus `q` of the ring `Z_q[x]/(x^DEGREE + 1)`
+    fn modulus(&self) -> u64;
+
+    /// Forward transform in place, from coefficients to evaluations
+    fn forward(&self, coeffs: &mut [u64; DEGREE]);
+
+    /// Inverse transform in place, including the `DEGREE^(-1)` scaling
+    fn inverse(&self, evals: &mut [u64; DEGREE]);
+
+    /// Slot-wise product of two forward-transformed operands, into `lhs`
+    fn pointwise_mul(&self, lhs: &mut [u64; DEGREE], rhs: &[u64; DEGREE]) {
+        let q = self.modulus() as u128;
+        for (x, &y) in lhs.iter_mut().zip(rhs) {
+            *x = ((*x as u128 * y as u128) % q) as u64;
+        }
+    }
+
+    /// Product `a·b` in `Z_q[x]/(x^DEGREE + 1)`
+    fn negacyclic_mul(
+        &self,
+        a: &[u64; DEGREE],
+        b: &[u64; DEGREE],
+    ) -> [u64; DEGREE] {
+        let (mut a, mut b) = (*a, *b);
+        self.forward(&mut a);
+        self.forward(&mut b);
+        self.pointwise_mul(&mut a, &b);
+        self.inverse(&mut a);
+        a
+    }
+}
+
+impl<const DEGREE: usize> NttBackend<DEGREE> for NttContext<DEGREE> {
+    fn modulus(&self) -> u64 {
+        self.class.q()
+    }
+
+    fn forward(&self, coeffs: &mut [u64; DEGREE]) {
+        self.forward_shoup_in_place(coeffs);
+    }
+
+    fn inverse(&self, evals: &mut [u64; DEGREE]) {
+        self.inverse_shoup_in_place(evals);
+    }
+
+    fn pointwise_mul(&self, lhs: &mut [u64; DEGREE], rhs: &[u64; DEGREE]) {
+        self.kernel().pointwise(self, lhs, rhs);
+    }
+}
+
+/// [`NttBackend`] on top of `concrete_ntt::prime64::Plan`.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::backend::{ConcreteNtt, NttBackend};
+/// use rust_ntt::{NttContext, find_first_prime_up};
+///
+/// const N: usize = 64;
+/// let q = find_first_prime_up(40, N);
+/// let native = NttContext::<N>::new(q);
+/// let concrete = ConcreteNtt::<N>::new(q).unwrap();
+///
+/// let a = std::array::from_fn(|i| i as u64);
+/// let b = std::array::from_fn(|i| (i * i) as u64);
+/// assert_eq!(native.negacyclic_mul(&a, &b), concrete.negacyclic_mul(&a, &b));
+/// ```
+#[cfg(feature = "concrete-ntt")]
+#[derive(Debug, Clone)]
+pub struct ConcreteNtt<const DEGREE: usize> {
+    plan: concrete_ntt::prime64::Plan,
+}
+
+#[cfg(feature = "concrete-ntt")]
+impl<const DEGREE: usize> ConcreteNtt<DEGREE> {
+    /// Plan a transform for the prime `q`, or `None` if concrete-ntt does
+    /// not support this size and modulus.
+    pub fn new(q: u64) -> Option<Self> {
+        concrete_ntt::prime64::Plan::try_new(DEGREE, q).map(|plan| Self { plan })
+    }
+
+    /// The underlying concrete-ntt plan
+    pub fn plan(&self) -> &concrete_ntt::prime64::Plan {
+        &self.plan
+    }
+}
+
+#[cfg(feature = "concrete-ntt")]
+impl<const DEGREE: usize> NttBackend<DEGREE> for ConcreteNtt<DEGREE> {
+    fn modulus(&self) -> u64 {
+        self.plan.modulus()
+    }
+
+    fn forward(&self, coeffs: &mut [u64; DEGREE]) {
+        self.plan.fwd(coeffs);
+    }
+
+    fn inverse(&self, evals: &mut [u64; DEGREE]) {
+        // concrete-ntt leaves the DEGREE^(-1) factor to the caller
+        self.plan.inv(evals);
+        self.plan.normalize(evals);
+    }
+}
@@ -0,0 +1,258 @@
+//! Batches of polynomials in column-major ("transposed") layout.
+//!
+//! A [`Vec<NttPolynomial>`] stores each polynomial contiguously. A
+//! [`ColumnBatch`] instead stores coefficient `i` of every polynomial next to
+//! each other, so every butterfly of a transform becomes one operation over
+//! a contiguous run of `len` values with a single shared twiddle, which is
+//! the layout SIMD lanes and GPU threads prefer.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::*;
+//! use rust_ntt::batch::ColumnBatch;
+//! use std::sync::Arc;
+//!
+//! let ctx = NttContext::<4>::new(find_first_prime_up(20, 4));
+//! let a = NttPolynomial::from_coeffs([1, 2, 3, 4], Arc::clone(&ctx));
+//! let b = NttPolynomial::from_coeffs([5, 6, 7, 8], Arc::clone(&ctx));
+//!
+//! let mut batch = ColumnBatch::from_polys(&[a.clone(), b]);
+//! assert_eq!(batch.coefficient(0), &[1, 5]);
+//!
+//! batch.ntt_forward();
+//! let mut expected = a;
+//! expected.ntt_forward_shoup();
+//! assert_eq!(batch.poly(0).coeffs(), expected.coeffs());
+//! ```
+use crate::context::{NttContext, boxed_from_fn};
+use crate::counters::Op;
+use crate::ntt::NttPolynomial;
+use std::sync::Arc;
+
+/// `len` polynomials stored coefficient-major: entry `(i, k)`, coefficient
+/// `i` of polynomial `k`, lives at index `i·len + k`.
+#[derive(Debug, Clone)]
+pub struct ColumnBatch<const DEGREE: usize> {
+    data: Vec<u64>,
+    len: usize,
+    context: Arc<NttContext<DEGREE>>,
+}
+
+impl<const DEGREE: usize> ColumnBatch<DEGREE> {
+    /// Create batch of `len` zero polynomials
+    pub fn zero(len: usize, context: Arc<NttContext<DEGREE>>) -> Self {
+        Self {
+            data: vec![0; DEGREE * len],
+            len,
+            context,
+        }
+    }
+
+    /// Create batch from data already in column-major order
+    ///
+    /// # Panics
+    /// * If `data.len()` is not a multiple of `DEGREE`
+    pub fn from_columns(data: Vec<u64>, context: Arc<NttContext<DEGREE>>) -> Self {
+        assert_eq!(
+            data.len() % DEGREE,
+            0,
+            "batch data must hold a multiple of {DEGREE} coefficients"
+        );
+        let len = data.len() / DEGREE;
+        Self { data, len, context }
+    }
+
+    /// Transpose row-major data, polynomial `k` at `rows[k·N..(k+1)·N]`
+    ///
+    /// # Panics
+    /// * If `rows.len()` is not a multiple of `DEGREE`
+    pub fn from_row_major(rows: &[u64], context: Arc<NttContext<DEGREE>>) -> Self {
+        assert_eq!(
+            rows.len() % DEGREE,
+            0,
+            "batch data must hold a multiple of {DEGREE} coefficients"
+        );
+        let len = rows.len() / DEGREE;
+        Self {
+            data: transpose(rows, len, DEGREE),
+            len,
+            context,
+        }
+    }
+
+    /// Gather polynomials into one batch
+    ///
+    /// # Panics
+    /// * If `polys` is empty or the polynomials have different moduli
+    pub fn from_polys(polys: &[NttPolynomial<DEGREE>]) -> Self {
+        let context = Arc::clone(polys.first().expect("empty batch").context());
+        let mut batch = Self::zero(polys.len(), context);
+
+        for (k, poly) in polys.iter().enumerate() {
+            if let Err(e) = batch.context.check_compatible(poly.context()) {
+                panic!("Cannot batch polynomials: {e}");
+            }
+            for (i, &c) in poly.coeffs().iter().enumerate() {
+                batch.data[i * batch.len + k] = c;
+            }
+        }
+
+        batch
+    }
+
+    /// Row-major copy, polynomial `k` at `[k·N..(k+1)·N]`
+    pub fn to_row_major(&self) -> Vec<u64> {
+        transpose(&self.data, DEGREE, self.len)
+    }
+
+    /// Copy out polynomial `k`
+    ///
+    /// # Panics
+    /// * If `k >= self.len()`
+    pub fn poly(&self, k: usize) -> NttPolynomial<DEGREE> {
+        assert!(k < self.len, "index {k} out of batch of {}", self.len);
+        let coeffs = boxed_from_fn(|i| self.data[i * self.len + k]);
+        NttPolynomial::from_boxed_coeffs(coeffs, Arc::clone(&self.context))
+    }
+
+    /// Split back into separate polynomials
+    pub fn to_polys(&self) -> Vec<NttPolynomial<DEGREE>> {
+        (0..self.len).map(|k| self.poly(k)).collect()
+    }
+
+    /// Number of polynomials
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the batch has no polynomials
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Coefficient `i` of every polynomial, contiguous
+    pub fn coefficient(&self, i: usize) -> &[u64] {
+        &self.data[i * self.len..(i + 1) * self.len]
+    }
+
+    /// Column-major data
+    pub fn as_slice(&self) -> &[u64] {
+        &self.data
+    }
+
+    /// Column-major data, for kernels that write the batch directly
+    pub fn as_mut_slice(&mut self) -> &mut [u64] {
+        &mut self.data
+    }
+
+    pub fn context(&self) -> &Arc<NttContext<DEGREE>> {
+        &self.context
+    }
+
+    /// Forward NTT of every polynomial, with Shoup multiplication.
+    ///
+    /// Same butterflies as [`NttPolynomial::ntt_forward_shoup`], each applied
+    /// to two whole coefficient rows at once.
+    pub fn ntt_forward(&mut self) {
+        for _ in 0..self.len {
+            self.context.count_transform(Op::Forward);
+        }
+        let (ctx, len) = (&self.context, self.len);
+        let class = &ctx.class;
+
+        let mut t = DEGREE >> 1;
+        let mut n = 1;
+
+        while n < DEGREE {
+            for i in 0..n {
+                let (s, s_shoup) = (ctx.tf[n + i], ctx.tf_shoup[n + i]);
+                let block = &mut self.data[2 * i * t * len..2 * (i + 1) * t * len];
+                let (lo, hi) = block.split_at_mut(t * len);
+
+                for (x, y) in lo.iter_mut().zip(hi.iter_mut()) {
+                    let v = class.modmul_shoup(*y, s, s_shoup);
+                    *y = class.modsub(*x, v);
+                    class.modadd_eq(x, v);
+                }
+            }
+
+            n <<= 1;
+            t >>= 1;
+        }
+    }
+
+    /// Inverse NTT of every polynomial, with Shoup multiplication and the
+    /// `N^(-1)` scaling folded into the last stage
+    pub fn ntt_inverse(&mut self) {
+        for _ in 0..self.len {
+            self.context.count_transform(Op::Inverse);
+        }
+        let (ctx, len) = (&self.context, self.len);
+        let class = &ctx.class;
+
+        let mut t = 1;
+        let mut h = DEGREE >> 1;
+
+        while h > 1 {
+            for i in 0..h {
+                let (s, s_shoup) = (ctx.itf[h + i], ctx.itf_shoup[h + i]);
+                let block = &mut self.data[2 * i * t * len..2 * (i + 1) * t * len];
+                let (lo, hi) = block.split_at_mut(t * len);
+
+                for (x, y) in lo.iter_mut().zip(hi.iter_mut()) {
+                    let (u, v) = (*x, *y);
+                    *x = class.modadd(u, v);
+                    *y = class.modmul_shoup(class.modsub(u, v), s, s_shoup);
+                }
+            }
+
+            h >>= 1;
+            t <<= 1;
+        }
+
+        if DEGREE > 1 {
+            let (lo, hi) = self.data.split_at_mut(t * len);
+            for (x, y) in lo.iter_mut().zip(hi.iter_mut()) {
+                let (u, v) = (*x, *y);
+                *x = class.modmul_shoup(
+                    class.modadd(u, v),
+                    ctx.inv_n,
+                    ctx.inv_n_shoup,
+                );
+                *y = class.modmul_shoup(
+                    class.modsub(u, v),
+                    ctx.inv_n_itf,
+                    ctx.inv_n_itf_shoup,
+                );
+            }
+        }
+    }
+
+    /// Multiply by `rhs` slot-wise, for batches in the evaluation domain
+    ///
+    /// # Panics
+    /// * If the batches differ in length or modulus
+    pub fn pointwise_mul_assign(&mut self, rhs: &Self) {
+        if let Err(e) = self.context.check_compatible(&rhs.context) {
+            panic!("Cannot multiply batches: {e}");
+        }
+        assert_eq!(self.len, rhs.len, "batch lengths differ");
+
+        let class = &self.context.class;
+        self.context.count(Op::ModMul, self.data.len() as u64);
+        for (x, &y) in self.data.iter_mut().zip(&rhs.data) {
+            class.modmul_eq(x, y);
+        }
+    }
+}
+
+/// Transpose a `rows × cols` row-major matrix
+fn transpose(data: &[u64], rows: usize, cols: usize) -> Vec<u64> {
+    let mut out = vec![0; data.len()];
+    for r in 0..rows {
+        for c in 0..cols {
+            out[c * rows + r] = data[r * cols + c];
+        }
+    }
+    out
+}
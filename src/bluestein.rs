@@ -0,0 +1,156 @@
+//! Convolutions and transforms of lengths that are not powers of two.
+//!
+//! The NTT contexts require a power-of-two degree. Both functions here
+//! embed an arbitrary length `n` into a negacyclic transform of the next
+//! power of two `N ≥ 2n - 1`, large enough that the products they need
+//! never wrap around `x^N + 1`:
+//!
+//! * [`convolution_arbitrary_len`] forms the full product and folds it
+//!   modulo `x^n - 1`, which needs no `n`-th root of unity in `Z_q`.
+//! * [`bluestein_dft`] evaluates at the powers of any `ω` with Bluestein's
+//!   chirp-z identity `jk = T(j + k) - T(j) - T(k)`, `T(m) = m(m - 1)/2`,
+//!   which turns the transform into one correlation against the chirp
+//!   `ω^T(m)`. With `ω` of order `n` it is the length-`n` DFT.
+//!
+//! Either way `q` must be an NTT prime for the embedding, `q ≡ 1 (mod 2N)`.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::bluestein::{bluestein_dft, convolution_arbitrary_len};
+//!
+//! // q = 97 has 2N = 16 | q - 1 for the length-8 embedding of n = 3
+//! let c = convolution_arbitrary_len(&[1, 2, 3], &[4, 5, 6], 97);
+//! assert_eq!(c, [4 + 12 + 15, 5 + 8 + 18, 6 + 10 + 12]);
+//!
+//! // 35 has order 3 modulo 97, so this is the length-3 DFT
+//! let evals = bluestein_dft(&[1, 1, 1], 35, 97);
+//! assert_eq!(evals, [3, 0, 0]);
+//! ```
+use crate::congruence::CongruenceClass;
+use crate::context::NttError;
+use crate::dynamic::NttPlan;
+
+/// Cyclic convolution `a·b mod (x^n - 1)` over `Z_q` for any length
+/// `n = a.len()`; inputs may be unreduced.
+///
+/// # Panics
+/// * If `a` and `b` differ in length
+/// * If `q` admits no negacyclic NTT of length `N`, the next power of two
+///   at or above `2n - 1`; see [`try_convolution_arbitrary_len`]
+pub fn convolution_arbitrary_len(a: &[u64], b: &[u64], q: u64) -> Vec<u64> {
+    try_convolution_arbitrary_len(a, b, q).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// [`convolution_arbitrary_len`], or why `q` admits no embedding
+///
+/// # Panics
+/// * If `a` and `b` differ in length
+pub fn try_convolution_arbitrary_len(
+    a: &[u64],
+    b: &[u64],
+    q: u64,
+) -> Result<Vec<u64>, NttError> {
+    assert_eq!(a.len(), b.len(), "Operands must have the same length");
+    let n = a.len();
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+
+    let plan = embedding(q, n)?;
+    let class = CongruenceClass::new(q);
+    let product = padded_product(&plan, &class, a, b);
+
+    // Degree 2n - 2 < N, so nothing wrapped and the fold is exact
+    let mut result = product[..n].to_vec();
+    for (c, &high) in result.iter_mut().zip(&product[n..2 * n - 1]) {
+        class.modadd_eq(c, high);
+    }
+    Ok(result)
+}
+
+/// Evaluations `a(ω^k)` for `k < n = a.len()`, by Bluestein's algorithm;
+/// inputs may be unreduced.
+///
+/// # Panics
+/// * If `omega ≡ 0 (mod q)` with `n > 1`
+/// * If `q` admits no negacyclic NTT of length `N`, the next power of two
+///   at or above `2n - 1`; see [`try_bluestein_dft`]
+pub fn bluestein_dft(a: &[u64], omega: u64, q: u64) -> Vec<u64> {
+    try_bluestein_dft(a, omega, q).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// [`bluestein_dft`], or why `q` admits no embedding
+///
+/// # Panics
+/// * If `omega ≡ 0 (mod q)` with `n > 1`
+pub fn try_bluestein_dft(
+    a: &[u64],
+    omega: u64,
+    q: u64,
+) -> Result<Vec<u64>, NttError> {
+    let n = a.len();
+    if n <= 1 {
+        return Ok(a.iter().map(|&x| x % q).collect());
+    }
+
+    let plan = embedding(q, n)?;
+    let class = CongruenceClass::new(q);
+    let omega = class.reduce(omega);
+    assert_ne!(omega, 0, "Cannot evaluate at powers of zero");
+
+    // ω^T(m) for m < 2n - 1, and ω^(-T(m)) for m < n
+    let chirp_up = chirp(&class, omega, 2 * n - 1);
+    let chirp_down = chirp(&class, class.modinv(omega), n);
+
+    // X_k = ω^(-T(k))·Σ_j u_j·ω^T(j+k) with u_j = a_j·ω^(-T(j)); reversing u
+    // turns the correlation into entries n - 1 .. 2n - 2 of a product
+    let reversed: Vec<u64> = a
+        .iter()
+        .zip(&chirp_down)
+        .rev()
+        .map(|(&x, &w)| class.modmul(class.reduce(x), w))
+        .collect();
+    let product = padded_product(&plan, &class, &reversed, &chirp_up);
+
+    Ok(product[n - 1..2 * n - 1]
+        .iter()
+        .zip(&chirp_down)
+        .map(|(&c, &w)| class.modmul(c, w))
+        .collect())
+}
+
+/// Negacyclic plan of the smallest power-of-two length `N ≥ 2n - 1`
+fn embedding(q: u64, n: usize) -> Result<NttPlan, NttError> {
+    NttPlan::try_new(q, (2 * n - 1).next_power_of_two())
+}
+
+/// `a·b mod (x^N + 1)`, zero-padding both operands to `N` coefficients
+fn padded_product(
+    plan: &NttPlan,
+    class: &CongruenceClass,
+    a: &[u64],
+    b: &[u64],
+) -> Vec<u64> {
+    let padded = |values: &[u64]| {
+        let mut out: Vec<u64> = values.iter().map(|&x| class.reduce(x)).collect();
+        out.resize(plan.ntt_size(), 0);
+        out
+    };
+
+    let mut product = padded(a);
+    plan.negacyclic_mul(&mut product, &padded(b));
+    product
+}
+
+/// `ω^T(m)` for `m < len`, stepping `T(m + 1) = T(m) + m`
+fn chirp(class: &CongruenceClass, omega: u64, len: usize) -> Vec<u64> {
+    let (mut power, mut step) = (1, 1);
+    (0..len)
+        .map(|_| {
+            let value = power;
+            power = class.modmul(power, step);
+            step = class.modmul(step, omega);
+            value
+        })
+        .collect()
+}
@@ -0,0 +1,207 @@
+//! Negacyclic NTT that computes its twiddles during the transform.
+//!
+//! An [`NttContext`](crate::NttContext) keeps `tf`, `itf` and their Shoup
+//! tables, `32·N` bytes, plus the coset tables. With dozens of RNS moduli
+//! at `N = 2^16` that is tens of megabytes of tables alone. A
+//! [`CompactNttContext`] stores only the `log2(N)` repeated squares of `ψ`
+//! and of `ψ^(-1)`.
+//!
+//! The stage with `n` blocks uses the twiddles `ψ^bitrev(n + i)`. Visiting
+//! its blocks in the order `i = bitrev(r)` turns them into the geometric
+//! sequence `ψ^(N/2n)·ψ^(r·N/n)`, so one multiplication per block produces
+//! the next twiddle. Twiddles are used once per block and get no Shoup
+//! constant, so the butterflies use Barrett products: the transforms are
+//! slower than the table-driven ones, but produce the same evaluations in
+//! the same order.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::*;
+//! use rust_ntt::compact::CompactNttContext;
+//! use std::sync::Arc;
+//!
+//! let q = find_first_prime_up(40, 1024);
+//! let compact = CompactNttContext::<1024>::new(q);
+//! let full = NttContext::<1024>::new(q);
+//! assert!(compact.memory_footprint().total() < full.memory_footprint().total() / 100);
+//!
+//! let a: [u64; 1024] = std::array::from_fn(|i| i as u64);
+//! let mut evals = a;
+//! compact.ntt_forward(&mut evals);
+//!
+//! let mut expected = NttPolynomial::from_coeffs(a, full);
+//! expected.ntt_forward();
+//! assert_eq!(&evals, expected.coeffs());
+//! ```
+use crate::congruence::CongruenceClass;
+use crate::context::{NttError, validate_parameters};
+use crate::footprint::MemoryFootprint;
+use crate::math::find_generator;
+use std::sync::Arc;
+
+/// Negacyclic NTT of length `DEGREE` whose tables take `O(log N)` words.
+#[derive(Debug, Clone)]
+pub struct CompactNttContext<const DEGREE: usize> {
+    class: CongruenceClass,
+    /// `ψ^(2^k)` for `k < log2(N)`
+    psi_powers: Vec<u64>,
+    /// `ψ^(-2^k)` for `k < log2(N)`
+    inv_psi_powers: Vec<u64>,
+    /// `N^(-1) mod q`
+    inv_n: u64,
+}
+
+impl<const DEGREE: usize> CompactNttContext<DEGREE> {
+    /// Create a context for the prime `q ≡ 1 (mod 2·DEGREE)`, with the same
+    /// `ψ` as [`NttContext::new`](crate::NttContext::new).
+    ///
+    /// # Panics
+    /// * If `q` and `DEGREE` admit no negacyclic NTT; use
+    ///   [`try_new`](Self::try_new) for untrusted parameters
+    pub fn new(q: u64) -> Arc<Self> {
+        Self::try_new(q).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// [`new`](Self::new), or why `q` and `DEGREE` admit no negacyclic NTT
+    pub fn try_new(q: u64) -> Result<Arc<Self>, NttError> {
+        validate_parameters(q, DEGREE)?;
+
+        let class = CongruenceClass::new(q);
+        let psi = find_generator(q, DEGREE);
+        let squares = |root: u64| -> Vec<u64> {
+            let mut power = root;
+            (0..DEGREE.trailing_zeros())
+                .map(|_| {
+                    let value = power;
+                    power = class.modsquare(power);
+                    value
+                })
+                .collect()
+        };
+
+        Ok(Arc::new(Self {
+            class,
+            psi_powers: squares(psi),
+            inv_psi_powers: squares(class.modinv(psi)),
+            inv_n: class.modinv(DEGREE as u64),
+        }))
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.class.q()
+    }
+
+    pub fn class(&self) -> &CongruenceClass {
+        &self.class
+    }
+
+    /// The primitive `2N`-th root of unity the transforms are built on
+    pub fn psi(&self) -> u64 {
+        self.psi_powers
+            .first()
+            .copied()
+            .unwrap_or(self.class.q() - 1)
+    }
+
+    /// Bytes used by this context, in the layout of
+    /// [`NttContext::memory_footprint`](crate::NttContext::memory_footprint)
+    pub fn memory_footprint(&self) -> MemoryFootprint {
+        let bytes = |table: &Vec<u64>| table.capacity() * size_of::<u64>();
+        MemoryFootprint {
+            modulus: self.modulus(),
+            degree: DEGREE,
+            tables: vec![
+                ("psi_powers", bytes(&self.psi_powers)),
+                ("inv_psi_powers", bytes(&self.inv_psi_powers)),
+            ],
+            overhead: size_of::<Self>(),
+        }
+    }
+
+    /// Forward negacyclic NTT of residues in `[0, q)`, in place
+    pub fn ntt_forward(&self, coeffs: &mut [u64; DEGREE]) {
+        let class = &self.class;
+        let log_n = self.psi_powers.len();
+        let mut t = DEGREE >> 1;
+
+        for stage in 0..log_n {
+            // n = 2^stage blocks, twiddles ψ^(N/2n)·(ψ^(N/n))^r
+            let base = self.psi_powers[log_n - 1 - stage];
+            let step = class.modsquare(base);
+            let mut w = base;
+
+            for r in 0..1 << stage {
+                let j1 = 2 * reverse(r, stage) * t;
+                for j in j1..j1 + t {
+                    let v = class.modmul(coeffs[j + t], w);
+                    coeffs[j + t] = class.modsub(coeffs[j], v);
+                    class.modadd_eq(&mut coeffs[j], v);
+                }
+                w = class.modmul(w, step);
+            }
+
+            t >>= 1;
+        }
+    }
+
+    /// Inverse negacyclic NTT in place, including the `N^(-1)` scaling
+    pub fn ntt_inverse(&self, evals: &mut [u64; DEGREE]) {
+        let class = &self.class;
+        let log_n = self.inv_psi_powers.len();
+        let mut t = 1;
+
+        for stage in (1..log_n).rev() {
+            let base = self.inv_psi_powers[log_n - 1 - stage];
+            let step = class.modsquare(base);
+            let mut w = base;
+
+            for r in 0..1 << stage {
+                let j1 = 2 * reverse(r, stage) * t;
+                for j in j1..j1 + t {
+                    let (u, v) = (evals[j], evals[j + t]);
+                    evals[j] = class.modadd(u, v);
+                    evals[j + t] = class.modmul(class.modsub(u, v), w);
+                }
+                w = class.modmul(w, step);
+            }
+
+            t <<= 1;
+        }
+
+        // Last stage with one block and twiddle ψ^(-N/2), carrying N^(-1)
+        if DEGREE > 1 {
+            let w = class.modmul(self.inv_psi_powers[log_n - 1], self.inv_n);
+            let (lo, hi) = evals.split_at_mut(t);
+            for (x, y) in lo.iter_mut().zip(hi) {
+                let (u, v) = (*x, *y);
+                *x = class.modmul(class.modadd(u, v), self.inv_n);
+                *y = class.modmul(class.modsub(u, v), w);
+            }
+        }
+    }
+
+    /// Product of `a` and `b` in `Z_q[x]/(x^DEGREE + 1)`, for residues in
+    /// `[0, q)`
+    pub fn negacyclic_convolution(
+        &self,
+        a: &[u64; DEGREE],
+        b: &[u64; DEGREE],
+    ) -> [u64; DEGREE] {
+        let (mut a, mut b) = (*a, *b);
+        self.ntt_forward(&mut a);
+        self.ntt_forward(&mut b);
+        for (x, &y) in a.iter_mut().zip(&b) {
+            self.class.modmul_eq(x, y);
+        }
+        self.ntt_inverse(&mut a);
+        a
+    }
+}
+
+/// `r` with its low `bits` bits reversed
+#[inline]
+fn reverse(r: usize, bits: usize) -> usize {
+    r.reverse_bits()
+        .checked_shr(usize::BITS - bits as u32)
+        .unwrap_or(0)
+}
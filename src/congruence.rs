@@ -12,6 +12,20 @@
 /// 3. Compute remainder: `a*b - estimated_quotient * q`
 /// 4. Apply final correction if needed
 ///
+/// # Canonical Form
+///
+/// Given operands in `[0, q)`, every operation returns its result in
+/// `[0, q)`; in particular `modadd(a, q - a)` and `modneg(0)` are `0`, never
+/// `q`. The rest of the crate relies on this, so coefficients can be
+/// compared directly.
+///
+/// # Montgomery Form
+///
+/// For odd q the class also carries `-q^(-1) mod 2^64` and `R² mod q` with
+/// `R = 2^64`, for Montgomery multiplication on values kept as `a·R mod q`.
+/// See [`mont_mul`](Self::mont_mul) and [`crate::montgomery`] for an NTT
+/// built on it.
+///
 /// # Examples
 ///
 /// ```rust
@@ -24,10 +38,64 @@
 /// assert_eq!(result, (15 * 23) % 97);
 /// ```
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct CongruenceClass {
-    mu: u64,   // Barrett parameter μ = ⌊2^(2*logq) / q⌋
-    q: u64,    // Prime modulus
-    logq: u64, // Bit length of q (⌈log₂(q)⌉)
+    mu: u64,             // Barrett parameter μ = ⌊2^(2*logq) / q⌋
+    q: u64,              // Prime modulus
+    logq: u64,           // Bit length of q (⌈log₂(q)⌉)
+    q_neg_inv: u64,      // Montgomery parameter -q^(-1) mod 2^64 (0 for even q)
+    r2: u64, // R² mod q with R = 2^64, for conversion into Montgomery form
+    shape: ModulusShape, // Special form of q that replaces Barrett, if any
+}
+
+/// Special forms of a modulus with reduction by shifts and adds.
+///
+/// For `q = 2^a - c` the identity `2^a ≡ c (mod q)` folds the bits of a
+/// product above position `a` back onto the low ones. With `c < 2^(a/2)`
+/// two folds and two conditional subtractions reduce any product of
+/// residues, replacing the two wide multiplications of Barrett reduction
+/// by two narrow ones; when `c = 2^b - 1` even those become a shift and a
+/// subtraction. [`CongruenceClass::new`] detects both forms, and
+/// [`CongruenceClass::modmul`] and everything built on it, including the
+/// Barrett NTT and pointwise products, use them automatically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub enum ModulusShape {
+    /// No usable structure; products are reduced with Barrett's method
+    Generic,
+    /// Solinas prime `q = 2^a - 2^b + 1` with `b ≤ a/2`
+    Solinas { a: u32, b: u32 },
+    /// Pseudo-Mersenne prime `q = 2^a - c` with `c < 2^(a/2)`
+    PseudoMersenne { a: u32, c: u64 },
+}
+
+impl ModulusShape {
+    /// Shape of `q`, preferring the Solinas form when both apply
+    pub const fn detect(q: u64) -> Self {
+        let a = 64 - q.leading_zeros();
+        // Below 8 bits the tail bound leaves nothing worth specializing
+        if a < 8 || a > 63 {
+            return Self::Generic;
+        }
+
+        let c = (1u64 << a) - q;
+        if c >= 1u64 << (a / 2) {
+            Self::Generic
+        } else if (c + 1).is_power_of_two() {
+            Self::Solinas {
+                a,
+                b: (c + 1).trailing_zeros(),
+            }
+        } else {
+            Self::PseudoMersenne { a, c }
+        }
+    }
 }
 
 // Here are getters
@@ -38,6 +106,14 @@ impl CongruenceClass {
     }
 }
 
+#[cfg(feature = "rkyv")]
+impl ArchivedCongruenceClass {
+    #[inline]
+    pub fn q(&self) -> u64 {
+        self.q.to_native()
+    }
+}
+
 impl CongruenceClass {
     /// Creates a new modular arithmetic context for the given modulus.
     ///
@@ -48,7 +124,7 @@ impl CongruenceClass {
     ///
     /// # Panics
     /// * If q < 2 or q ≥ 2^63
-    pub fn new(q: u64) -> Self {
+    pub const fn new(q: u64) -> Self {
         assert!(q >= 2, "modulus must be ≥ 2");
         assert!(q < (1u64 << 63), "modulus must be < 2^63");
 
@@ -57,7 +133,37 @@ impl CongruenceClass {
         let logq: u64 = 64 - (q.leading_zeros() as u64);
         let mu: u64 = ((1u128 << (2 * logq)) / (q as u128)) as u64;
 
-        Self { q, mu, logq }
+        // Newton iteration for q^(-1) mod 2^64: q·q ≡ 1 (mod 8) for odd q,
+        // and each step doubles the number of correct low bits
+        let q_neg_inv = if q & 1 == 1 {
+            let mut inv = q;
+            let mut i = 0;
+            while i < 5 {
+                inv = inv.wrapping_mul(2u64.wrapping_sub(q.wrapping_mul(inv)));
+                i += 1;
+            }
+            inv.wrapping_neg()
+        } else {
+            0
+        };
+        let r = ((u64::MAX % q) + 1) % q;
+        let r2 = ((r as u128 * r as u128) % (q as u128)) as u64;
+
+        Self {
+            q,
+            mu,
+            logq,
+            q_neg_inv,
+            r2,
+            shape: ModulusShape::detect(q),
+        }
+    }
+
+    /// Special form of q used by [`modmul`](Self::modmul), see
+    /// [`ModulusShape`]
+    #[inline]
+    pub fn shape(&self) -> ModulusShape {
+        self.shape
     }
     // mu = (2^126 / q)
 
@@ -153,6 +259,17 @@ impl CongruenceClass {
         };
     }
 
+    /// Shoup product without the final correction, in `[0, 2q)`.
+    ///
+    /// Valid for any `a < 2^64`, so operands of lazy butterflies need no
+    /// reduction first. The result is computed modulo 2^64, where
+    /// `a·b - ⌊a·b_prec/2^64⌋·q` is known to lie below `2q`.
+    #[inline]
+    pub fn modmul_shoup_lazy(&self, a: u64, b: u64, b_prec: u64) -> u64 {
+        let quot = (((a as u128) * (b_prec as u128)) >> 64) as u64;
+        a.wrapping_mul(b).wrapping_sub(quot.wrapping_mul(self.q))
+    }
+
     /// Fast modular multiplication: (a * b) mod q.
     ///
     /// Uses Barrett reduction to avoid expensive division operations.
@@ -165,110 +282,145 @@ impl CongruenceClass {
     /// `(a * b) mod q`
     #[inline]
     pub fn modmul(&self, a: u64, b: u64) -> u64 {
-        let mul = (a as u128) * (b as u128);
-
-        let tmp1 = mul >> (self.logq - 2); // (ab / 2^62)
-        let tmp2 = (tmp1 * (self.mu as u128)) >> (self.logq + 2);
-        // (ab / 2^62) * (2^126 / q) / 2^64 = (ab 2^64 / q) / 2^64 = floor(ab/q)
-
-        let r = (mul.wrapping_sub(tmp2 * (self.q as u128))) as u64;
-        // ab - floor(ab/q) * q = ab mod q
-
-        // return if r < self.q { r } else { r.wrapping_sub(self.q) };
-        if r < self.q {
-            r
-        } else {
-            r.wrapping_sub(self.q)
-        }
+        self.reduce_wide((a as u128) * (b as u128))
     }
 
     #[inline]
     pub fn modsquare(&self, a: u64) -> u64 {
-        let mul = (a as u128) * (a as u128);
+        self.reduce_wide((a as u128) * (a as u128))
+    }
 
-        let tmp1 = mul >> (self.logq - 2); // (ab / 2^62)
-        let tmp2 = (tmp1 * (self.mu as u128)) >> (self.logq + 2);
-        // (ab / 2^62) * (2^126 / q) / 2^64 = (ab 2^64 / q) / 2^64 = floor(ab/q)
+    #[inline]
+    pub fn modmul_eq(&self, a: &mut u64, b: u64) {
+        *a = self.reduce_wide((*a as u128) * (b as u128));
+    }
 
-        let r = (mul.wrapping_sub(tmp2 * (self.q as u128))) as u64;
-        // ab - floor(ab/q) * q = ab mod q
+    #[inline]
+    pub fn modsquare_eq(&self, a: &mut u64) {
+        *a = self.reduce_wide((*a as u128) * (*a as u128));
+    }
 
-        // return if r < self.q { r } else { r.wrapping_sub(self.q) };
-        if r < self.q {
-            r
+    /// `a mod q` for any `a`, not only a product of residues
+    #[inline]
+    pub fn reduce(&self, a: u64) -> u64 {
+        // Barrett needs the input below 2^(2·logq)
+        if 2 * self.logq >= 64 {
+            self.reduce_wide(a as u128)
         } else {
-            r.wrapping_sub(self.q)
+            a % self.q
         }
     }
 
-    #[inline]
-    pub fn modmul_eq(&self, a: &mut u64, b: u64) {
-        let mul = (*a as u128) * (b as u128);
-
-        let tmp1 = mul >> (self.logq - 2); // (ab / 2^62)
-        let tmp2 = (tmp1 * (self.mu as u128)) >> (self.logq + 2);
+    /// Reduce every value into `[0, q)` in one pass, for input from
+    /// external sources that may hold arbitrary `u64` values.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::CongruenceClass;
+    ///
+    /// let class = CongruenceClass::new(97);
+    /// let mut values = [5, 97, 200, u64::MAX];
+    /// class.reduce_slice(&mut values);
+    /// assert_eq!(values, [5, 0, 6, u64::MAX % 97]);
+    /// ```
+    pub fn reduce_slice(&self, values: &mut [u64]) {
+        for value in values {
+            *value = self.reduce(*value);
+        }
+    }
 
-        let r = (mul.wrapping_sub(tmp2 * (self.q as u128))) as u64;
+    /// Reduce a product of two residues modulo q, by folding for a
+    /// special modulus and with Barrett's method otherwise. The shape is
+    /// fixed per class, so the branch is perfectly predicted.
+    #[inline]
+    fn reduce_wide(&self, mul: u128) -> u64 {
+        match self.shape {
+            ModulusShape::Generic => self.barrett_reduce(mul),
+            ModulusShape::Solinas { a, b } => {
+                // h·(2^b - 1) as a shift and a subtraction
+                self.fold_reduce(mul, a, |h| (h << b) - h)
+            }
+            ModulusShape::PseudoMersenne { a, c } => {
+                self.fold_reduce(mul, a, |h| h * c as u128)
+            }
+        }
+    }
 
-        *a = if r < self.q {
-            r
-        } else {
-            r.wrapping_sub(self.q)
-        };
+    /// Reduce `mul < 2^(2a)` for `q = 2^a - c`, with `times_c(h) = h·c`.
+    ///
+    /// The first fold leaves less than `(c + 1)·2^a`, so the second sees a
+    /// high part of at most `c` and leaves less than `c² + 2^a < 2^(a+1)`,
+    /// which is below `3q` for `a ≥ 4`.
+    #[inline]
+    fn fold_reduce(
+        &self,
+        mul: u128,
+        a: u32,
+        times_c: impl Fn(u128) -> u128,
+    ) -> u64 {
+        let mask = (1u128 << a) - 1;
+        let x = times_c(mul >> a) + (mul & mask);
+        let mut r = (times_c(x >> a) + (x & mask)) as u64;
+
+        if r >= self.q {
+            r -= self.q;
+        }
+        if r >= self.q {
+            r -= self.q;
+        }
+        r
     }
 
+    /// Reduce a product of two residues modulo q.
+    ///
+    /// The truncated shifts make the quotient estimate up to two short, so
+    /// the remainder lies in [0, 3q) and needs up to two corrections. It
+    /// can exceed 2^64 for q close to 2^63, hence the corrections in u128.
     #[inline]
-    pub fn modsquare_eq(&self, a: &mut u64) {
-        let mul = (*a as u128) * (*a as u128);
+    fn barrett_reduce(&self, mul: u128) -> u64 {
+        let q = self.q as u128;
 
         let tmp1 = mul >> (self.logq - 2); // (ab / 2^62)
         let tmp2 = (tmp1 * (self.mu as u128)) >> (self.logq + 2);
+        // (ab / 2^62) * (2^126 / q) / 2^64 = (ab 2^64 / q) / 2^64 = floor(ab/q)
 
-        let r = (mul.wrapping_sub(tmp2 * (self.q as u128))) as u64;
+        let mut r = mul.wrapping_sub(tmp2 * q);
+        // ab - floor(ab/q) * q = ab mod q, up to two more multiples of q
 
-        *a = if r < self.q {
-            r
-        } else {
-            r.wrapping_sub(self.q)
-        };
+        if r >= q {
+            r -= q;
+        }
+        if r >= q {
+            r -= q;
+        }
+        r as u64
     }
 
+    /// `(a + b) mod q` for `b ≤ q`.
+    ///
+    /// Compares `a` with `q - b` instead of forming `a + b`, which would
+    /// wrap for unreduced operands once q approaches 2^63.
     #[inline]
     pub fn modadd(&self, a: u64, b: u64) -> u64 {
-        let t = a + b;
-        if t <= self.q {
-            t
-        } else {
-            t.wrapping_sub(self.q)
-        }
+        let d = self.q - b;
+        if a >= d { a - d } else { a + b }
     }
 
     #[inline]
     pub fn modadd_eq(&self, a: &mut u64, b: u64) {
-        let t = *a + b;
-        *a = if t <= self.q {
-            t
-        } else {
-            t.wrapping_sub(self.q)
-        };
+        *a = self.modadd(*a, b);
     }
 
+    /// `(a - b) mod q` for `b ≤ q`; as in [`modadd`](Self::modadd) no
+    /// intermediate exceeds `max(a, q)`.
     #[inline]
     pub fn modsub(&self, a: u64, b: u64) -> u64 {
-        if a >= b {
-            a.wrapping_sub(b)
-        } else {
-            (self.q + a).wrapping_sub(b)
-        }
+        if a >= b { a - b } else { (self.q - b) + a }
     }
 
     #[inline]
     pub fn modsub_eq(&self, a: &mut u64, b: u64) {
-        *a = if *a >= b {
-            (*a).wrapping_sub(b)
-        } else {
-            (self.q + *a).wrapping_sub(b)
-        };
+        *a = self.modsub(*a, b);
     }
 
     #[inline]
@@ -279,7 +431,7 @@ impl CongruenceClass {
 
     #[inline]
     pub fn modneg_eq(&self, a: &mut u64) {
-        (*a) = self.q.wrapping_sub(*a);
+        *a = self.modneg(*a);
     }
 
     #[inline]
@@ -315,11 +467,122 @@ impl CongruenceClass {
         }
     }
 
+    /// Inverse of `a` modulo q by the extended Euclidean algorithm.
+    ///
+    /// Unlike Fermat inversion this does not assume q is prime, so it also
+    /// serves prime-power (Galois ring) moduli.
+    ///
+    /// # Panics
+    /// * If `a` is not a unit modulo q (including `a ≡ 0`)
     pub fn modinv(&self, a: u64) -> u64 {
-        self.modexp(a, self.q - 2)
+        let (mut r0, mut r1) = (self.q as i128, (a % self.q) as i128);
+        let (mut t0, mut t1) = (0i128, 1i128);
+
+        while r1 != 0 {
+            let quot = r0 / r1;
+            (r0, r1) = (r1, r0 - quot * r1);
+            (t0, t1) = (t1, t0 - quot * t1);
+        }
+
+        assert_eq!(r0, 1, "{a} is not invertible modulo {}", self.q);
+        t0.rem_euclid(self.q as i128) as u64
     }
 
     pub fn modinv_eq(&self, a: &mut u64) {
-        self.modexp_eq(&mut *a, self.q - 2);
+        *a = self.modinv(*a);
     }
 }
+
+// Montgomery arithmetic
+impl CongruenceClass {
+    /// Montgomery reduction (REDC): `x·R^(-1) mod q` with `R = 2^64`.
+    ///
+    /// Adding `m·q` with `m = x·(-q^(-1)) mod R` clears the low word of `x`,
+    /// so the shift is exact. For `x < q·R` the quotient lies in `[0, 2q)`
+    /// and one correction brings it into `[0, q)`; `q < 2^63` keeps the sum
+    /// below 2^128.
+    ///
+    /// # Panics
+    /// * In debug builds, if q is even, as R is then not invertible modulo q
+    #[inline]
+    pub fn redc(&self, x: u128) -> u64 {
+        debug_assert!(self.q & 1 == 1, "Montgomery form needs an odd modulus");
+        let m = (x as u64).wrapping_mul(self.q_neg_inv);
+        let t = ((x + (m as u128) * (self.q as u128)) >> 64) as u64;
+
+        if t < self.q { t } else { t - self.q }
+    }
+
+    /// Montgomery product `a·b·R^(-1) mod q`.
+    ///
+    /// For `a`, `b` in Montgomery form this is the Montgomery form of their
+    /// product; with only `b` in Montgomery form it is the plain product.
+    /// `a` may be any `u64` as long as `b < q`.
+    #[inline]
+    pub fn mont_mul(&self, a: u64, b: u64) -> u64 {
+        self.redc((a as u128) * (b as u128))
+    }
+
+    #[inline]
+    pub fn mont_mul_eq(&self, a: &mut u64, b: u64) {
+        *a = self.mont_mul(*a, b);
+    }
+
+    /// Montgomery form `a·R mod q` of any `a`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::CongruenceClass;
+    ///
+    /// let class = CongruenceClass::new(97);
+    /// let (a, b) = (class.to_mont(15), class.to_mont(23));
+    /// assert_eq!(class.from_mont(class.mont_mul(a, b)), (15 * 23) % 97);
+    /// ```
+    #[inline]
+    pub fn to_mont(&self, a: u64) -> u64 {
+        self.mont_mul(a, self.r2)
+    }
+
+    /// Plain residue `a·R^(-1) mod q` of a value in Montgomery form
+    #[inline]
+    pub fn from_mont(&self, a: u64) -> u64 {
+        self.redc(a as u128)
+    }
+}
+
+// Constant-time comparison
+impl CongruenceClass {
+    /// Reduce `a < 2q` to `[0, q)` without branching on `a`
+    #[inline]
+    pub(crate) fn ct_reduce(&self, a: u64) -> u64 {
+        let (r, borrow) = a.overflowing_sub(self.q);
+        r.wrapping_add(self.q & (borrow as u64).wrapping_neg())
+    }
+
+    /// Whether `a ≡ b (mod q)`, in time independent of the values.
+    ///
+    /// Accepts partially reduced inputs in `[0, 2q)`, such as Shoup
+    /// products, so `0` and `q` compare equal.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::CongruenceClass;
+    ///
+    /// let class = CongruenceClass::new(97);
+    /// assert!(class.ct_eq(5, 5));
+    /// assert!(class.ct_eq(0, 97));
+    /// assert!(!class.ct_eq(5, 6));
+    /// ```
+    #[inline]
+    pub fn ct_eq(&self, a: u64, b: u64) -> bool {
+        ct_is_zero(self.ct_reduce(a) ^ self.ct_reduce(b))
+    }
+}
+
+/// `d == 0` without a data-dependent branch; `black_box` keeps the compiler
+/// from turning accumulated differences back into an early exit
+#[inline]
+pub(crate) fn ct_is_zero(d: u64) -> bool {
+    let d = std::hint::black_box(d);
+    (d | d.wrapping_neg()) >> 63 == 0
+}
@@ -1,6 +1,19 @@
-use crate::congruence::CongruenceClass;
-use crate::math::find_generator;
-use std::sync::Arc;
+use crate::congruence::{CongruenceClass, ModulusShape};
+use crate::counters::Op;
+#[cfg(feature = "counters")]
+use crate::counters::{OpCounters, OpStats};
+use crate::fourstep::FourStepPlan;
+use crate::galois::{
+    AutomorphismTable, GaloisCache, conjugation_element, galois_element,
+};
+use crate::kernel::{KernelSlot, NttKernel};
+use crate::math::{find_generator, find_primitive_root};
+use crate::montgomery::MontgomeryTables;
+use crate::polyalg::{CyclicPlan, TwistedPlan};
+use crate::reducer::{Reducer, ReducerTables, Shoup};
+use primal::is_prime;
+use std::fmt;
+use std::sync::{Arc, OnceLock};
 
 /// Shared NTT context containing precomputed values for a specific degree and modulus.
 ///
@@ -13,30 +26,105 @@ use std::sync::Arc;
 /// # Modulus Requirements
 ///
 /// The modulus `q` must satisfy:
-/// - `q` is prime
+/// - `q` is prime, or a prime power `q = p^k` built through
+///   [`NttContext::new_prime_power`], which puts the next condition on `p`
 /// - `q ≡ 1 (mod 2*DEGREE)` (ensures primitive 2n-th root of unity exists)
 /// - `q < 2^63` (fits in signed 64-bit for safe arithmetic)
 ///
 /// Use utility functions like `find_first_prime_up(logq, DEGREE)` to find suitable moduli.
+///
+/// # Small degrees
+///
+/// `DEGREE = 1` and `DEGREE = 2` are supported like any other power of two,
+/// so code generic over the degree needs no special cases. For `N = 1` the
+/// ring `Z_q[x]/(x + 1)` is just `Z_q`: every transform is the identity
+/// (the single evaluation point is `ψ = -1` and `N^(-1) = 1`), and there are
+/// no slots to rotate. For `N = 2` each transform is a single butterfly.
+///
+/// # Ordering
+///
+/// Coefficients are in natural order. Forward transforms (plain, Shoup,
+/// twisted, coset and batched) produce evaluations in bit-reversed order and
+/// inverse transforms expect them so; [`to_bitrev_order`] and
+/// [`from_bitrev_order`] convert for libraries using natural order.
+///
+/// # Reduction
+///
+/// `R` is the [reduction strategy](crate::reducer) the transforms and
+/// products run on: [`Shoup`] by default, or another one through
+/// [`NttContext::with_reducer`] and [`NttContext::into_reducer`].
 #[derive(Debug, Clone)]
-pub struct NttContext<const DEGREE: usize> {
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct NttContext<const DEGREE: usize, R: Reducer = Shoup> {
     /// Modular arithmetic context with Barrett reduction parameters
     pub(crate) class: CongruenceClass,
     /// Inverse of DEGREE modulo q, for NTT normalization
     pub(crate) inv_n: u64,
     /// Shoup precomputed value for inv_n
     pub(crate) inv_n_shoup: u64,
+    /// itf[1]·inv_n, the last inverse twiddle with normalization folded in
+    pub(crate) inv_n_itf: u64,
+    /// Shoup precomputed value for inv_n_itf
+    pub(crate) inv_n_itf_shoup: u64,
     /// Forward twiddle factors for NTT (bit-reversed order)
-    pub(crate) tf: [u64; DEGREE],
+    pub(crate) tf: Box<[u64; DEGREE]>,
     /// Shoup precomputed values for forward twiddle factors
-    pub(crate) tf_shoup: [u64; DEGREE],
+    pub(crate) tf_shoup: Box<[u64; DEGREE]>,
     /// Inverse twiddle factors for INTT (bit-reversed order)
-    pub(crate) itf: [u64; DEGREE],
+    pub(crate) itf: Box<[u64; DEGREE]>,
     /// Shoup precomputed values for inverse twiddle factors
-    pub(crate) itf_shoup: [u64; DEGREE],
+    pub(crate) itf_shoup: Box<[u64; DEGREE]>,
+    /// Coset shift g (a primitive root of q, never in the evaluation domain)
+    pub(crate) coset_shift: u64,
+    /// Powers g^i of the coset shift, for pre-weighting coset transforms
+    pub(crate) coset_powers: Box<[u64; DEGREE]>,
+    /// Powers g^(-i) of the coset shift, for post-weighting inverse transforms
+    pub(crate) coset_inv_powers: Box<[u64; DEGREE]>,
+    /// Cached index maps for automorphisms x -> x^k
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Skip))]
+    pub(crate) galois: GaloisCache,
+    /// Cyclic transform of length 2·DEGREE for linear convolution, built on
+    /// first use
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Skip))]
+    pub(crate) linear: OnceLock<CyclicPlan>,
+    /// Cyclic transform of length DEGREE for convolution modulo `x^N - 1`,
+    /// built on first use
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Skip))]
+    pub(crate) cyclic: OnceLock<CyclicPlan>,
+    /// ψ-weighted cyclic transform of length DEGREE, built on first use of
+    /// the twisted NTT
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Skip))]
+    pub(crate) twisted: OnceLock<TwistedPlan>,
+    /// Twiddles in Montgomery form, built on first use of the Montgomery NTT
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Skip))]
+    pub(crate) montgomery: OnceLock<MontgomeryTables>,
+    /// Weights and twiddles for the four-step NTT, built on first use
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Skip))]
+    pub(crate) four_step: OnceLock<FourStepPlan>,
+    /// Validate coefficients in `NttPolynomial::from_coeffs`, see
+    /// [`NttContext::new_strict`]
+    pub(crate) strict: bool,
+    /// Transform kernel, see [`crate::kernel`]
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Skip))]
+    pub(crate) kernel: KernelSlot<DEGREE, R>,
+    /// Reduction strategy, see [`crate::reducer`]; rebuilt from the
+    /// modulus on first use after deserialization
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Skip))]
+    pub(crate) reducer: OnceLock<R>,
+    /// Twiddles in the precomputed form of `R`, built on first use of a
+    /// transform that needs them
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Skip))]
+    pub(crate) reducer_tables: OnceLock<ReducerTables<DEGREE>>,
+    /// Operation counts, see [`crate::counters`]
+    #[cfg(feature = "counters")]
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Skip))]
+    pub(crate) counters: OpCounters,
 }
 
-impl<const DEGREE: usize> NttContext<DEGREE> {
+impl<const DEGREE: usize, R: Reducer> NttContext<DEGREE, R> {
     pub fn tf(&self) -> &[u64; DEGREE] {
         &self.tf
     }
@@ -45,9 +133,167 @@ impl<const DEGREE: usize> NttContext<DEGREE> {
         &self.itf
     }
 
+    pub fn tf_shoup(&self) -> &[u64; DEGREE] {
+        &self.tf_shoup
+    }
+
+    pub fn itf_shoup(&self) -> &[u64; DEGREE] {
+        &self.itf_shoup
+    }
+
+    /// `N^(-1) mod q`, the scaling of the inverse transform
+    pub fn inv_n(&self) -> u64 {
+        self.inv_n
+    }
+
     pub fn class(&self) -> &CongruenceClass {
         &self.class
     }
+
+    /// Special form of the modulus that the Barrett transforms and
+    /// pointwise products exploit, see [`ModulusShape`]
+    pub fn modulus_shape(&self) -> ModulusShape {
+        self.class.shape()
+    }
+}
+
+/// Operands belong to rings with different moduli.
+///
+/// Returned by the `checked_*` operations of
+/// [`NttPolynomial`](crate::NttPolynomial); the operators panic with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContextMismatch {
+    pub lhs_modulus: u64,
+    pub rhs_modulus: u64,
+}
+
+impl fmt::Display for ContextMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "moduli differ, {} vs {}",
+            self.lhs_modulus, self.rhs_modulus
+        )
+    }
+}
+
+impl std::error::Error for ContextMismatch {}
+
+/// Parameters that admit no negacyclic NTT.
+///
+/// Returned by [`NttContext::try_new`]; [`NttContext::new`] panics with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NttError {
+    DegreeNotPowerOfTwo {
+        degree: usize,
+    },
+    /// `q ≥ 2^63`, too large for the lazy additions of the butterflies
+    ModulusTooLarge {
+        modulus: u64,
+    },
+    NotPrime {
+        modulus: u64,
+    },
+    /// `q ≢ 1 (mod 2N)`, so there is no primitive `2N`-th root of unity
+    WrongCongruence {
+        modulus: u64,
+        degree: usize,
+    },
+}
+
+impl fmt::Display for NttError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::DegreeNotPowerOfTwo { degree } => {
+                write!(f, "DEGREE must be a power of 2, got {degree}")
+            }
+            Self::ModulusTooLarge { modulus } => {
+                write!(f, "Modulus must be < 2^63, got {modulus}")
+            }
+            Self::NotPrime { modulus } => {
+                write!(f, "Modulus {modulus} is not prime")
+            }
+            Self::WrongCongruence { modulus, degree } => write!(
+                f,
+                "Modulus {modulus} must satisfy q ≡ 1 (mod 2*DEGREE={})",
+                2 * degree
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NttError {}
+
+/// Twiddle tables rejected by [`NttContext::try_with_tables`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwiddleError {
+    /// `q` and `DEGREE` admit no negacyclic NTT at all
+    Parameters(NttError),
+    /// `ψ = tf[N/2]` does not satisfy `ψ^N ≡ -1`
+    NotPrimitiveRoot { psi: u64 },
+    /// `tf[index] ≠ ψ^bitrev(index)`
+    NotBitReversedPowers { index: usize },
+    /// `tf[index]·itf[index] ≢ 1`, or `itf[index] ≥ q`
+    NotInverse { index: usize },
+}
+
+impl From<NttError> for TwiddleError {
+    fn from(e: NttError) -> Self {
+        Self::Parameters(e)
+    }
+}
+
+impl fmt::Display for TwiddleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Parameters(e) => e.fmt(f),
+            Self::NotPrimitiveRoot { psi } => {
+                write!(f, "Twiddle root {psi} does not satisfy psi^N = -1")
+            }
+            Self::NotBitReversedPowers { index } => write!(
+                f,
+                "tf[{index}] is not psi^bitrev({index}) for psi = tf[N/2]"
+            ),
+            Self::NotInverse { index } => {
+                write!(f, "itf[{index}] is not the inverse of tf[{index}]")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TwiddleError {}
+
+/// Check that `q` and `degree` admit a negacyclic NTT, cheapest test first
+pub(crate) fn validate_parameters(q: u64, degree: usize) -> Result<(), NttError> {
+    if !degree.is_power_of_two() {
+        return Err(NttError::DegreeNotPowerOfTwo { degree });
+    }
+    if q >= 1u64 << 63 {
+        return Err(NttError::ModulusTooLarge { modulus: q });
+    }
+    if !is_prime(q) {
+        return Err(NttError::NotPrime { modulus: q });
+    }
+    // 2·degree is a power of two, so the remainder is a mask
+    if (q - 1) & (2 * degree as u64 - 1) != 0 {
+        return Err(NttError::WrongCongruence { modulus: q, degree });
+    }
+    Ok(())
+}
+
+impl<const DEGREE: usize, R: Reducer> NttContext<DEGREE, R> {
+    /// Whether polynomials over `self` and `other` may be combined. Degrees
+    /// already agree by type, so this is a single modulus comparison.
+    pub fn check_compatible(&self, other: &Self) -> Result<(), ContextMismatch> {
+        if self.modulus() == other.modulus() {
+            Ok(())
+        } else {
+            Err(ContextMismatch {
+                lhs_modulus: self.modulus(),
+                rhs_modulus: other.modulus(),
+            })
+        }
+    }
 }
 
 impl<const DEGREE: usize> NttContext<DEGREE> {
@@ -58,7 +304,8 @@ impl<const DEGREE: usize> NttContext<DEGREE> {
     ///
     /// # Panics
     /// * If DEGREE is not a power of 2
-    /// * If q doesn't satisfy the modulus requirements
+    /// * If q doesn't satisfy the modulus requirements; use
+    ///   [`try_new`](Self::try_new) for untrusted parameters
     ///
     /// # Examples
     /// ```
@@ -69,55 +316,367 @@ impl<const DEGREE: usize> NttContext<DEGREE> {
     /// let ctx = NttContext::<N>::new(q);
     /// ```
     pub fn new(q: u64) -> Arc<Self> {
-        // Validate that DEGREE is a power of 2
+        Self::try_new(q).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Create a new NTT context, or report why `q` and `DEGREE` admit no
+    /// negacyclic NTT.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_ntt::{NttContext, NttError};
+    ///
+    /// assert!(NttContext::<4>::try_new(17).is_ok());
+    /// assert_eq!(
+    ///     NttContext::<4>::try_new(19).unwrap_err(),
+    ///     NttError::WrongCongruence { modulus: 19, degree: 4 }
+    /// );
+    /// assert_eq!(
+    ///     NttContext::<4>::try_new(25).unwrap_err(),
+    ///     NttError::NotPrime { modulus: 25 }
+    /// );
+    /// ```
+    pub fn try_new(q: u64) -> Result<Arc<Self>, NttError> {
+        validate_parameters(q, DEGREE)?;
+
+        // Find generator (primitive 2n-th root of unity)
+        let g = find_generator(q, DEGREE);
+
+        // Coset shift: a primitive root lies outside every proper subgroup,
+        // in particular outside the 2n-th roots of unity
+        let coset_shift = find_primitive_root(q);
+
+        Ok(Self::from_root(q, g, coset_shift))
+    }
+
+    /// Create a context like [`new`](Self::new) that rejects out-of-range
+    /// coefficients.
+    ///
+    /// Barrett and Shoup reduction assume operands below `q`; larger ones
+    /// give wrong results without any error. Polynomials over a strict
+    /// context check every coefficient on construction: `from_coeffs`
+    /// panics on a value `≥ q`, and `try_from_coeffs` returns the error.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_ntt::{NttContext, NttPolynomial};
+    ///
+    /// let ctx = NttContext::<4>::new_strict(17);
+    /// let err = NttPolynomial::try_from_coeffs([1, 2, 17, 3], ctx).unwrap_err();
+    /// assert_eq!((err.index, err.value), (2, 17));
+    /// ```
+    pub fn new_strict(q: u64) -> Arc<Self> {
+        let mut ctx = Self::new(q);
+        Arc::get_mut(&mut ctx)
+            .expect("context was just created")
+            .strict = true;
+        ctx
+    }
+
+    /// Create a context whose transforms and pointwise products run on
+    /// `kernel` instead of the scalar defaults, see [`crate::kernel`]
+    ///
+    /// # Panics
+    /// * Under the same conditions as [`NttContext::new`]
+    pub fn with_kernel(q: u64, kernel: Arc<dyn NttKernel<DEGREE>>) -> Arc<Self> {
+        Self::new(q).into_kernel(kernel)
+    }
+
+    /// Create a context for the prime-power modulus `q = p^k` (Galois ring).
+    ///
+    /// `Z_{p^k}` is not a field, but the negacyclic NTT only needs a
+    /// primitive 2n-th root `ψ` with `ψ^n = -1` and invertible differences
+    /// `ψ^i - ψ^j`. A root modulo `p` is Hensel-lifted to `p^k`; the lifted
+    /// root reduces to a unit modulo `p`, so all required inverses exist.
+    ///
+    /// # Arguments
+    /// * `p` - Prime satisfying p ≡ 1 (mod 2*DEGREE)
+    /// * `k` - Exponent, with `p^k < 2^63`
+    ///
+    /// # Panics
+    /// * If DEGREE is not a power of 2
+    /// * If `p` is not a suitable prime or `p^k` overflows 63 bits
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_ntt::{NttContext, find_first_prime_up};
+    ///
+    /// const N: usize = 16;
+    /// let p = find_first_prime_up(15, N);
+    /// let ctx = NttContext::<N>::new_prime_power(p, 3);
+    /// assert_eq!(ctx.modulus(), p * p * p);
+    /// ```
+    pub fn new_prime_power(p: u64, k: u32) -> Arc<Self> {
         assert!(
             DEGREE.is_power_of_two() && DEGREE > 0,
             "DEGREE must be a power of 2, got {DEGREE}"
         );
-
-        // Validate modulus requirements
-        assert!(q >= 3, "Modulus must be at least 3, got {q}");
-        assert!(q < (1u64 << 63), "Modulus must be < 2^63, got {q}");
+        assert!(k >= 1, "exponent must be at least 1");
+        assert!(
+            is_prime(p),
+            "base {p} of a prime-power modulus must be prime"
+        );
         assert_eq!(
-            (q - 1) % (2 * DEGREE as u64),
+            (p - 1) % (2 * DEGREE as u64),
             0,
-            "Modulus {q} must satisfy q ≡ 1 (mod 2*DEGREE={})",
+            "Prime {p} must satisfy p ≡ 1 (mod 2*DEGREE={})",
             2 * DEGREE
         );
 
+        let q = p
+            .checked_pow(k)
+            .filter(|&q| q < (1u64 << 63))
+            .unwrap_or_else(|| panic!("Modulus {p}^{k} must be < 2^63"));
+
         let class = CongruenceClass::new(q);
 
-        // Find generator (primitive 2n-th root of unity)
-        let g = find_generator(q, DEGREE);
+        // Newton iteration on f(x) = x^n + 1 starting from a root mod p;
+        // f'(x) = n·x^(n-1) is a unit, and each step doubles the p-adic
+        // precision
+        let mut psi = find_generator(p, DEGREE);
+        let n = DEGREE as u64 % q;
+        let mut precision = 1;
+        while precision < k {
+            let f = class.modadd(class.modexp(psi, DEGREE as u64), 1);
+            let df = class.modmul(n, class.modexp(psi, DEGREE as u64 - 1));
+            psi = class.modsub(psi, class.modmul(f, class.modinv(df)));
+            precision *= 2;
+        }
+
+        Self::from_root(q, psi, find_primitive_root(p))
+    }
+
+    /// Create a context from twiddle tables computed elsewhere, for example
+    /// to reproduce another library's choice of `ψ` bit for bit.
+    ///
+    /// `tf` must hold `ψ^bitrev(i)` and `itf` the inverses `ψ^(-bitrev(i))`,
+    /// the layout of [`tf`](Self::tf) and [`itf`](Self::itf), for a `ψ`
+    /// with `ψ^N ≡ -1`; `ψ` itself is `tf[N/2]`. Shoup values and the coset
+    /// tables are derived as in [`new`](Self::new).
+    ///
+    /// # Panics
+    /// * If the parameters or tables are invalid; use
+    ///   [`try_with_tables`](Self::try_with_tables) for untrusted input
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_ntt::*;
+    ///
+    /// let q = find_first_prime_up(30, 8);
+    /// let ctx = NttContext::<8>::new(q);
+    ///
+    /// // Another root of x^8 + 1: ψ^3 instead of ψ
+    /// let psi3 = ctx.class().modexp(ctx.psi(), 3);
+    /// let mut tf = [0; 8];
+    /// let mut itf = [0; 8];
+    /// for (i, (w, iw)) in tf.iter_mut().zip(&mut itf).enumerate() {
+    ///     let e = context::bit_reverse(i, 3) as u64;
+    ///     *w = ctx.class().modexp(psi3, e);
+    ///     *iw = ctx.class().modinv(*w);
+    /// }
+    ///
+    /// let other = NttContext::<8>::with_tables(q, &tf, &itf);
+    /// assert_eq!(other.psi(), psi3);
+    /// ```
+    pub fn with_tables(
+        q: u64,
+        tf: &[u64; DEGREE],
+        itf: &[u64; DEGREE],
+    ) -> Arc<Self> {
+        Self::try_with_tables(q, tf, itf).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// [`with_tables`](Self::with_tables), or why the parameters or tables
+    /// are invalid
+    pub fn try_with_tables(
+        q: u64,
+        tf: &[u64; DEGREE],
+        itf: &[u64; DEGREE],
+    ) -> Result<Arc<Self>, TwiddleError> {
+        validate_parameters(q, DEGREE)?;
+        let class = CongruenceClass::new(q);
+
+        let psi = if DEGREE > 1 { tf[DEGREE >> 1] } else { q - 1 };
+        if psi >= q || class.modexp(psi, DEGREE as u64) != q - 1 {
+            return Err(TwiddleError::NotPrimitiveRoot { psi });
+        }
+        // Every other entry is fixed by ψ, so one stray value is caught too
+        let expected = compute_twiddle_factors::<DEGREE>(&class, psi, false);
+        if let Some(index) = (0..DEGREE).find(|&i| tf[i] != expected[i]) {
+            return Err(TwiddleError::NotBitReversedPowers { index });
+        }
+        if let Some(index) =
+            (0..DEGREE).find(|&i| itf[i] >= q || class.modmul(tf[i], itf[i]) != 1)
+        {
+            return Err(TwiddleError::NotInverse { index });
+        }
+
+        let tf = boxed_from_fn(|i| tf[i]);
+        let itf = boxed_from_fn(|i| itf[i]);
+        let tf_shoup = boxed_from_fn(|i| class.precompute_shoup(tf[i]));
+        let itf_shoup = boxed_from_fn(|i| class.precompute_shoup(itf[i]));
+
+        let coset_shift = find_primitive_root(q);
+        let coset_powers = compute_powers::<DEGREE>(&class, coset_shift);
+        let coset_inv_powers =
+            compute_powers::<DEGREE>(&class, class.modinv(coset_shift));
+
+        Ok(Arc::new(Self::from_tables(
+            class,
+            [tf, tf_shoup, itf, itf_shoup],
+            coset_shift,
+            [coset_powers, coset_inv_powers],
+        )))
+    }
+
+    /// Build the context from a primitive 2n-th root `g` and a coset shift.
+    fn from_root(q: u64, g: u64, coset_shift: u64) -> Arc<Self> {
+        let class = CongruenceClass::new(q);
 
         // Compute twiddle factors
         let tf = compute_twiddle_factors::<DEGREE>(&class, g, false);
         let itf = compute_twiddle_factors::<DEGREE>(&class, g, true);
 
         // Precompute Shoup values for twiddle factors
-        let mut tf_shoup = [0u64; DEGREE];
-        for (i, &twiddle) in tf.iter().enumerate() {
-            tf_shoup[i] = class.precompute_shoup(twiddle);
-        }
+        let tf_shoup = boxed_from_fn(|i| class.precompute_shoup(tf[i]));
+        let itf_shoup = boxed_from_fn(|i| class.precompute_shoup(itf[i]));
 
-        let mut itf_shoup = [0u64; DEGREE];
-        for (i, &twiddle) in itf.iter().enumerate() {
-            itf_shoup[i] = class.precompute_shoup(twiddle);
-        }
+        let coset_powers = compute_powers::<DEGREE>(&class, coset_shift);
+        let coset_inv_powers =
+            compute_powers::<DEGREE>(&class, class.modinv(coset_shift));
+
+        Arc::new(Self::from_tables(
+            class,
+            [tf, tf_shoup, itf, itf_shoup],
+            coset_shift,
+            [coset_powers, coset_inv_powers],
+        ))
+    }
+}
+
+impl<const DEGREE: usize, R: Reducer> NttContext<DEGREE, R> {
+    /// This context with its transforms and pointwise products on
+    /// `kernel`, e.g. to give a kernel to a context built by another
+    /// constructor or moved onto a [reducer](crate::reducer)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use rust_ntt::kernel::ScalarKernel;
+    /// use rust_ntt::reducer::Montgomery;
+    /// use std::sync::Arc;
+    ///
+    /// let ctx = NttContext::<8>::new_strict(find_first_prime_up(20, 8))
+    ///     .into_reducer::<Montgomery>()
+    ///     .into_kernel(Arc::new(ScalarKernel));
+    /// assert!(ctx.is_strict());
+    /// ```
+    pub fn into_kernel(
+        self: Arc<Self>,
+        kernel: Arc<dyn NttKernel<DEGREE, R>>,
+    ) -> Arc<Self> {
+        let mut ctx = Arc::unwrap_or_clone(self);
+        ctx.kernel = KernelSlot(kernel);
+        Arc::new(ctx)
+    }
+
+    /// Kernel the transforms dispatch to
+    pub fn kernel(&self) -> &dyn NttKernel<DEGREE, R> {
+        &*self.kernel.0
+    }
 
+    /// Whether polynomials over this context validate their coefficients
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Assemble a context from its twiddle tables `[tf, tf_shoup, itf,
+    /// itf_shoup]` and coset powers `[g^i, g^(-i)]`.
+    fn from_tables(
+        class: CongruenceClass,
+        [tf, tf_shoup, itf, itf_shoup]: [Box<[u64; DEGREE]>; 4],
+        coset_shift: u64,
+        [coset_powers, coset_inv_powers]: [Box<[u64; DEGREE]>; 2],
+    ) -> Self {
         // Compute normalization factor (inverse of DEGREE)
         let inv_n = class.modinv(DEGREE as u64);
         let inv_n_shoup = class.precompute_shoup(inv_n);
+        let inv_n_itf = if DEGREE > 1 {
+            class.modmul(itf[1], inv_n)
+        } else {
+            inv_n
+        };
+        let inv_n_itf_shoup = class.precompute_shoup(inv_n_itf);
 
-        Arc::new(Self {
+        Self {
             class,
             inv_n,
             inv_n_shoup,
+            inv_n_itf,
+            inv_n_itf_shoup,
             tf,
             tf_shoup,
             itf,
             itf_shoup,
-        })
+            coset_shift,
+            coset_powers,
+            coset_inv_powers,
+            galois: GaloisCache::default(),
+            linear: OnceLock::new(),
+            cyclic: OnceLock::new(),
+            twisted: OnceLock::new(),
+            montgomery: OnceLock::new(),
+            four_step: OnceLock::new(),
+            strict: false,
+            kernel: KernelSlot::default(),
+            reducer: OnceLock::new(),
+            reducer_tables: OnceLock::new(),
+            #[cfg(feature = "counters")]
+            counters: OpCounters::default(),
+        }
+    }
+
+    /// Context of half the degree over the same modulus, with root `ψ²`,
+    /// derived without another generator search.
+    ///
+    /// Since `tf[i] = ψ^bitrev(i)` and reversing `log N` bits of `i < N/2`
+    /// gives twice its reversal over `log N - 1` bits, the first half of
+    /// every twiddle table is exactly the table for `ψ²`; the coset powers
+    /// are likewise prefixes. The result keeps strictness and the reducer
+    /// but uses the scalar kernel. `HALF` is given explicitly because
+    /// `DEGREE / 2` cannot appear in a type on stable Rust.
+    ///
+    /// # Panics
+    /// * If `2·HALF != DEGREE`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let ctx = NttContext::<16>::new(find_first_prime_up(20, 16));
+    /// let half = ctx.derive_half::<8>();
+    /// let quarter = half.derive_half::<4>();
+    /// assert_eq!(quarter.modulus(), ctx.modulus());
+    /// assert_eq!(half.psi(), ctx.class().modmul(ctx.psi(), ctx.psi()));
+    /// ```
+    pub fn derive_half<const HALF: usize>(&self) -> Arc<NttContext<HALF, R>> {
+        assert!(
+            2 * HALF == DEGREE,
+            "HALF ({HALF}) must be half of DEGREE ({DEGREE})"
+        );
+        let prefix = |table: &[u64; DEGREE]| -> Box<[u64; HALF]> {
+            boxed_from_fn(|i| table[i])
+        };
+
+        let mut ctx = NttContext::<HALF, R>::from_tables(
+            self.class,
+            [&*self.tf, &self.tf_shoup, &self.itf, &self.itf_shoup].map(prefix),
+            self.coset_shift,
+            [&*self.coset_powers, &self.coset_inv_powers].map(prefix),
+        );
+        ctx.strict = self.strict;
+        ctx.reducer = self.reducer().clone().into();
+        Arc::new(ctx)
     }
 
     /// Get the modulus for this context
@@ -130,18 +689,140 @@ impl<const DEGREE: usize> NttContext<DEGREE> {
         DEGREE
     }
 
-    /// Get the generator used for this context (for debugging/verification)
+    /// Get the generator used for this context (for debugging/verification):
+    /// the primitive 2N-th root [`psi`](Self::psi) behind the twiddle
+    /// factors, whichever constructor chose it
     pub fn generator(&self) -> u64 {
-        // Reconstruct generator from first non-trivial twiddle factor
-        // tf[1] = g^(bit_reverse(1)) = g^(2^(log2(DEGREE)-1)) for DEGREE > 1
+        self.psi()
+    }
+}
+
+impl<const DEGREE: usize, R: Reducer> NttContext<DEGREE, R> {
+    /// Operation counts accumulated since creation or the last reset
+    #[cfg(feature = "counters")]
+    pub fn stats(&self) -> OpStats {
+        self.counters.snapshot()
+    }
+
+    /// Set all operation counts back to zero
+    #[cfg(feature = "counters")]
+    pub fn reset_stats(&self) {
+        self.counters.reset();
+    }
+
+    /// Record `n` operations of kind `op`; a no-op without `counters`
+    #[inline]
+    pub(crate) fn count(&self, op: Op, n: u64) {
+        #[cfg(feature = "counters")]
+        self.counters.add(op, n);
+        #[cfg(not(feature = "counters"))]
+        let _ = (op, n);
+    }
+
+    /// Record one transform: `(N/2)·log N` butterflies, plus the `N^(-1)`
+    /// scaling for inverse ones
+    pub(crate) fn count_transform(&self, op: Op) {
+        let n = DEGREE as u64;
+        let butterflies = n / 2 * DEGREE.trailing_zeros() as u64;
+        let scaling = if op == Op::Inverse { n } else { 0 };
+
+        self.count(op, 1);
+        self.count(Op::ModMul, butterflies + scaling);
+        self.count(Op::ModAdd, 2 * butterflies);
+    }
+}
+
+impl<const DEGREE: usize, R: Reducer> NttContext<DEGREE, R> {
+    /// Points at which the forward NTT evaluates a polynomial.
+    ///
+    /// Slot `i` of a forward-transformed polynomial holds `a(ψ^(2·bitrev(i)+1))`,
+    /// where ψ is the primitive 2N-th root of unity of this context, i.e. the
+    /// transform evaluates at the odd powers of ψ in bit-reversed order.
+    /// The points are returned on the heap, like the coefficients of a
+    /// polynomial.
+    pub fn evaluation_points(&self) -> Box<[u64; DEGREE]> {
+        let psi = self.psi();
+        let psi_sq = self.class.modmul(psi, psi);
+        let log_degree = DEGREE.trailing_zeros() as usize;
+
+        let mut odd_powers = boxed_zeros::<DEGREE>();
+        let mut power = psi;
+        for elem in odd_powers.iter_mut() {
+            *elem = power;
+            power = self.class.modmul(power, psi_sq);
+        }
+
+        boxed_from_fn(|i| odd_powers[bit_reverse(i, log_degree)])
+    }
+
+    /// Primitive 2N-th root of unity ψ underlying the twiddle factors
+    pub fn psi(&self) -> u64 {
+        // tf[i] = ψ^bitrev(i), so ψ sits at the index whose reversal is 1
         if DEGREE > 1 {
-            // We'd need to compute discrete log to get g, but for debugging
-            // we can verify the generator property instead
-            find_generator(self.class.q(), DEGREE)
+            self.tf[DEGREE >> 1]
         } else {
-            1 // Trivial case
+            self.class.q() - 1
         }
     }
+
+    /// Cyclic plan of length 2N with ψ as its root, for linear convolution
+    pub(crate) fn linear_plan(&self) -> &CyclicPlan {
+        self.linear.get_or_init(|| {
+            CyclicPlan::with_root(&self.class, 2 * DEGREE, self.psi())
+        })
+    }
+
+    /// Cyclic plan of length N with root `ω = ψ²`, a primitive N-th root
+    /// of unity, for convolution modulo `x^N - 1`
+    pub(crate) fn cyclic_plan(&self) -> &CyclicPlan {
+        self.cyclic.get_or_init(|| {
+            let omega = self.class.modsquare(self.psi());
+            CyclicPlan::with_root(&self.class, DEGREE, omega)
+        })
+    }
+
+    /// Twisted plan with the same ψ, so its evaluations match the main NTT
+    pub(crate) fn twisted_plan(&self) -> &TwistedPlan {
+        self.twisted
+            .get_or_init(|| TwistedPlan::new(&self.class, DEGREE, self.psi()))
+    }
+
+    /// Shift element g of the coset domain used by the coset transforms
+    pub fn coset_shift(&self) -> u64 {
+        self.coset_shift
+    }
+
+    /// Points of the coset domain g·H, in the same order as
+    /// [`evaluation_points`](Self::evaluation_points).
+    pub fn coset_evaluation_points(&self) -> Box<[u64; DEGREE]> {
+        let mut points = self.evaluation_points();
+        for x in points.iter_mut() {
+            *x = self.class.modmul(*x, self.coset_shift);
+        }
+        points
+    }
+
+    /// Index map for the automorphism `x -> x^k`, computed once per `k` and
+    /// cached in the context.
+    ///
+    /// Coefficient `i` moves to `table[i].0` and is negated if `table[i].1`.
+    pub fn automorphism_table(&self, k: usize) -> AutomorphismTable {
+        self.galois.get_or_insert(k, DEGREE)
+    }
+
+    /// Precompute index maps for the given slot rotations and for conjugation,
+    /// e.g. for the rotations a set of Galois keys was generated for.
+    pub fn precompute_rotations(&self, rotations: &[isize]) {
+        for &r in rotations {
+            self.automorphism_table(galois_element(r, DEGREE));
+        }
+        self.automorphism_table(conjugation_element(DEGREE));
+    }
+
+    /// Number of automorphism index maps currently cached
+    pub fn cached_automorphisms(&self) -> usize {
+        self.galois.len()
+    }
 }
 
 /// Compute twiddle factors for NTT/INTT in bit-reversed order.
@@ -160,19 +841,32 @@ fn compute_twiddle_factors<const DEGREE: usize>(
     class: &CongruenceClass,
     g: u64,
     is_inverse: bool,
-) -> [u64; DEGREE] {
-    let mut tf = [0u64; DEGREE];
-    let mut tf_direct = [0u64; DEGREE];
+) -> Box<[u64; DEGREE]> {
+    twiddle_table(class, g, is_inverse, DEGREE)
+        .into_boxed_slice()
+        .try_into()
+        .expect("table of length DEGREE")
+}
+
+/// [`compute_twiddle_factors`] for a degree known only at run time
+pub(crate) fn twiddle_table(
+    class: &CongruenceClass,
+    g: u64,
+    is_inverse: bool,
+    degree: usize,
+) -> Vec<u64> {
+    let mut tf = vec![0u64; degree];
+    let mut tf_direct = vec![0u64; degree];
 
-    // Calculate bit-reversal length (log2 of DEGREE)
-    let log_degree = (DEGREE.trailing_zeros()) as usize;
+    // Calculate bit-reversal length (log2 of degree)
+    let log_degree = (degree.trailing_zeros()) as usize;
 
     // Use g or g^(-1) depending on direction
     let base = if is_inverse { class.modinv(g) } else { g };
 
     // Compute powers of base: base^0, base^1, base^2, ...
     tf_direct[0] = 1;
-    for i in 1..DEGREE {
+    for i in 1..degree {
         tf_direct[i] = class.modmul(tf_direct[i - 1], base);
     }
 
@@ -185,6 +879,37 @@ fn compute_twiddle_factors<const DEGREE: usize>(
     tf
 }
 
+/// Compute powers base^0, base^1, ..., base^(DEGREE-1) in natural order.
+fn compute_powers<const DEGREE: usize>(
+    class: &CongruenceClass,
+    base: u64,
+) -> Box<[u64; DEGREE]> {
+    let mut powers = boxed_zeros::<DEGREE>();
+    powers[0] = 1;
+    for i in 1..DEGREE {
+        powers[i] = class.modmul(powers[i - 1], base);
+    }
+    powers
+}
+
+/// Zero-filled array allocated directly on the heap.
+///
+/// Tables and coefficient arrays of FHE-sized degrees (`2^15` and up) take
+/// hundreds of kilobytes each; `Box::new([0; N])` would build them on the
+/// stack first and overflow it, at least in unoptimized builds.
+pub(crate) fn boxed_zeros<const N: usize>() -> Box<[u64; N]> {
+    let boxed = vec![0u64; N].into_boxed_slice();
+    boxed.try_into().expect("vector of length N")
+}
+
+/// Heap-allocated `[f(0), ..., f(N-1)]`, see [`boxed_zeros`].
+pub(crate) fn boxed_from_fn<const N: usize>(
+    f: impl FnMut(usize) -> u64,
+) -> Box<[u64; N]> {
+    let boxed: Box<[u64]> = (0..N).map(f).collect();
+    boxed.try_into().expect("iterator of length N")
+}
+
 /// Compute bit-reversal of a number within specified bit length.
 ///
 /// Used to reorder twiddle factors for efficient NTT memory access.
@@ -210,6 +935,101 @@ pub fn bit_reverse(number: usize, bit_length: usize) -> usize {
     reversed
 }
 
+/// Permute `values` from natural into bit-reversed order in place, moving
+/// entry `i` to index `bitrev(i)`.
+///
+/// Forward transforms in this crate leave evaluations in bit-reversed
+/// order: slot `i` holds `a(ψ^(2·bitrev(i)+1))`, see
+/// [`NttContext::evaluation_points`]. Libraries that instead store
+/// `a(ψ^(2i+1))` at slot `i` exchange data with this crate through this
+/// permutation. Coefficients are always in natural order, and the twiddle
+/// tables [`NttContext::tf`] and [`NttContext::itf`] hold `ψ^bitrev(i)`.
+///
+/// The permutation is an involution, so this is the same map as
+/// [`from_bitrev_order`].
+///
+/// # Panics
+/// * If `values.len()` is not a power of two
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::*;
+///
+/// let ctx = NttContext::<4>::new(17);
+/// let mut points = ctx.evaluation_points();
+/// to_bitrev_order(&mut points[..]);
+///
+/// // Natural order: ψ, ψ^3, ψ^5, ψ^7
+/// let psi = ctx.psi();
+/// let power = |e| (0..e).fold(1, |acc, _| acc * psi % 17);
+/// assert_eq!(*points, [power(1), power(3), power(5), power(7)]);
+/// ```
+pub fn to_bitrev_order<T>(values: &mut [T]) {
+    let len = values.len();
+    assert!(
+        len.is_power_of_two(),
+        "length must be a power of two, got {len}"
+    );
+    if len <= 2 {
+        return;
+    }
+    // Reversing the whole word and shifting keeps the index arithmetic to
+    // one instruction on most targets, unlike bit_reverse's loop
+    let shift = usize::BITS - len.trailing_zeros();
+
+    for i in 1..len - 1 {
+        let j = i.reverse_bits() >> shift;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
+
+/// Permute `values` from bit-reversed back into natural order in place,
+/// undoing [`to_bitrev_order`].
+///
+/// # Panics
+/// * If `values.len()` is not a power of two
+pub fn from_bitrev_order<T>(values: &mut [T]) {
+    to_bitrev_order(values);
+}
+
+/// Order of the evaluations a transform produces or consumes.
+///
+/// The crate's own order is [`BitReversed`](Self::BitReversed): slot `i`
+/// holds `a(ψ^(2·bitrev(i)+1))`, which is what the butterflies produce and
+/// what slot-wise products and the inverse transforms expect.
+/// [`Natural`](Self::Natural) puts `a(ψ^(2i+1))` at slot `i`, at the cost
+/// of one [`to_bitrev_order`] pass. Coefficients are always in natural
+/// order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Ordering {
+    /// Slot `i` holds the evaluation at `ψ^(2i+1)`
+    Natural,
+    /// Slot `i` holds the evaluation at `ψ^(2·bitrev(i)+1)`
+    #[default]
+    BitReversed,
+}
+
+/// Read-only access to an archived context, without deserializing it.
+///
+/// Twiddle tables are stored little-endian, so they can be memory-mapped
+/// straight from an archive.
+#[cfg(feature = "rkyv")]
+impl<const DEGREE: usize> ArchivedNttContext<DEGREE> {
+    pub fn modulus(&self) -> u64 {
+        self.class.q()
+    }
+
+    pub fn tf(&self) -> &[rkyv::Archived<u64>; DEGREE] {
+        &self.tf
+    }
+
+    pub fn itf(&self) -> &[rkyv::Archived<u64>; DEGREE] {
+        &self.itf
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,101 @@
+//! Per-context operation counters.
+//!
+//! With the `counters` feature every [`NttContext`](crate::NttContext) tallies
+//! the work done on its behalf: modular multiplications and additions (the
+//! latter including subtractions), forward and inverse transforms, and
+//! polynomials created over it. Counts are exact for the ring operations of
+//! [`NttPolynomial`](crate::NttPolynomial) and are kept in relaxed atomics, so
+//! contexts stay shareable across threads. Without the feature the hooks
+//! compile to nothing.
+//!
+//! # Examples
+//! ```rust
+//! # #[cfg(feature = "counters")] {
+//! use rust_ntt::*;
+//! use std::sync::Arc;
+//!
+//! const N: usize = 8;
+//! let ctx = NttContext::<N>::new(find_first_prime_up(20, N));
+//! let a = NttPolynomial::zero(Arc::clone(&ctx));
+//! let _ = a.negacyclic_convolution_shoup(&a);
+//!
+//! let stats = ctx.stats();
+//! assert_eq!((stats.forward, stats.inverse), (2, 1));
+//! // 3 transforms of (N/2)·log N butterflies, N scalings, N pointwise
+//! assert_eq!(stats.modmul, 3 * 12 + 8 + 8);
+//! # }
+//! ```
+
+#[cfg(feature = "counters")]
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Kind of operation being counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Op {
+    ModMul,
+    ModAdd,
+    Forward,
+    Inverse,
+    Allocation,
+}
+
+/// Snapshot of a context's counters.
+#[cfg(feature = "counters")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OpStats {
+    /// Modular multiplications, Barrett and Shoup alike
+    pub modmul: u64,
+    /// Modular additions and subtractions
+    pub modadd: u64,
+    /// Forward transforms
+    pub forward: u64,
+    /// Inverse transforms
+    pub inverse: u64,
+    /// Polynomials created over the context
+    pub allocations: u64,
+}
+
+#[cfg(feature = "counters")]
+#[derive(Debug, Default)]
+pub(crate) struct OpCounters {
+    counts: [AtomicU64; 5],
+}
+
+#[cfg(feature = "counters")]
+impl OpCounters {
+    pub(crate) fn add(&self, op: Op, n: u64) {
+        self.counts[op as usize].fetch_add(n, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> OpStats {
+        let get = |op: Op| self.counts[op as usize].load(Ordering::Relaxed);
+        OpStats {
+            modmul: get(Op::ModMul),
+            modadd: get(Op::ModAdd),
+            forward: get(Op::Forward),
+            inverse: get(Op::Inverse),
+            allocations: get(Op::Allocation),
+        }
+    }
+
+    pub(crate) fn reset(&self) {
+        for count in &self.counts {
+            count.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+/// A cloned context starts from the counts of the original
+#[cfg(feature = "counters")]
+impl Clone for OpCounters {
+    fn clone(&self) -> Self {
+        let stats = self.snapshot();
+        let counters = Self::default();
+        counters.add(Op::ModMul, stats.modmul);
+        counters.add(Op::ModAdd, stats.modadd);
+        counters.add(Op::Forward, stats.forward);
+        counters.add(Op::Inverse, stats.inverse);
+        counters.add(Op::Allocation, stats.allocations);
+        counters
+    }
+}
@@ -0,0 +1,191 @@
+//! Verification of transform-based products against independent arithmetic.
+//!
+//! With the `cross-check` feature every NTT convolution of
+//! [`NttPolynomial`](crate::NttPolynomial) is checked before it returns:
+//! `negacyclic_convolution` (and `*`), `negacyclic_convolution_shoup`,
+//! `convolve_short` and `linear_convolution`. Up to [`NAIVE_MAX_DEGREE`]
+//! the product is compared with the O(N²) schoolbook result. Beyond it a
+//! reference comes from the context's length-2N cyclic transform, which
+//! shares no code with the kernels, and is itself verified by evaluating it
+//! and both operands at [`EVALUATION_CHECKS`] random points of `Z_q`; a
+//! wrong reference slips through with probability below `(2N/q)^8`.
+//! A mismatch panics with the differing coefficients or values.
+//!
+//! The checks cost far more than the transforms themselves and are meant
+//! as a safety net while kernels are being optimized, not for production.
+//!
+//! # Examples
+//! ```rust,should_panic
+//! use rust_ntt::*;
+//! use rust_ntt::kernel::NttKernel;
+//! use std::sync::Arc;
+//!
+//! /// Pointwise kernel with an off-by-one bug
+//! #[derive(Debug)]
+//! struct Broken;
+//!
+//! impl<const N: usize> NttKernel<N> for Broken {
+//!     fn pointwise(&self, ctx: &NttContext<N>, lhs: &mut [u64; N], rhs: &[u64; N]) {
+//!         for (x, &y) in lhs.iter_mut().zip(rhs) {
+//!             *x = ctx.class().modmul(*x, y) + 1;
+//!         }
+//!     }
+//! }
+//!
+//! let ctx = NttContext::<8>::with_kernel(find_first_prime_up(20, 8), Arc::new(Broken));
+//! let a = NttPolynomial::from_coeffs([1; 8], ctx);
+//! let _ = a.negacyclic_convolution_shoup(&a); // panics with the diff
+//! ```
+use crate::congruence::CongruenceClass;
+use crate::context::NttContext;
+use crate::polyalg;
+use crate::reducer::Reducer;
+use rand::Rng;
+use std::fmt::Write;
+
+/// Largest degree checked against the schoolbook product
+pub const NAIVE_MAX_DEGREE: usize = 1024;
+
+/// Random evaluation points per product above [`NAIVE_MAX_DEGREE`]
+pub const EVALUATION_CHECKS: usize = 8;
+
+/// Differing coefficients listed in a panic message
+const MAX_REPORTED: usize = 8;
+
+/// Check `product = a·b mod (x^N + 1)`; `a` and `b` may be shorter than `N`
+/// and are then zero-padded.
+pub(crate) fn check_negacyclic<const DEGREE: usize, R: Reducer>(
+    what: &str,
+    ctx: &NttContext<DEGREE, R>,
+    a: &[u64],
+    b: &[u64],
+    product: &[u64; DEGREE],
+) {
+    let class = ctx.class();
+
+    if DEGREE <= NAIVE_MAX_DEGREE {
+        let mut expected = [0u64; DEGREE];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                let term = class.modmul(x, y);
+                let k = i + j;
+                if k < DEGREE {
+                    class.modadd_eq(&mut expected[k], term);
+                } else {
+                    class.modsub_eq(&mut expected[k - DEGREE], term);
+                }
+            }
+        }
+        compare_coeffs(what, class, product, &expected);
+    } else {
+        // The length-2N cyclic plan is separate code from the kernels; its
+        // linear product is itself verified at random points, then folded
+        // modulo x^N + 1 for a full comparison
+        let linear = ctx.linear_plan().convolve(a, b);
+        let reference = format!("cyclic reference for {what}");
+        check_at_random_points(&reference, class, a, b, &linear);
+
+        let mut expected = [0u64; DEGREE];
+        for (k, &c) in linear.iter().enumerate() {
+            if k < DEGREE {
+                class.modadd_eq(&mut expected[k], c);
+            } else {
+                class.modsub_eq(&mut expected[k - DEGREE], c);
+            }
+        }
+        compare_coeffs(what, class, product, &expected);
+    }
+}
+
+/// Check `product = a·b` over `Z_q[x]`, without reduction
+pub(crate) fn check_linear<const DEGREE: usize, R: Reducer>(
+    what: &str,
+    ctx: &NttContext<DEGREE, R>,
+    a: &[u64],
+    b: &[u64],
+    product: &[u64],
+) {
+    let class = ctx.class();
+
+    if DEGREE <= NAIVE_MAX_DEGREE {
+        let mut expected = vec![0u64; product.len()];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                class.modadd_eq(&mut expected[i + j], class.modmul(x, y));
+            }
+        }
+        compare_coeffs(what, class, product, &expected);
+    } else {
+        check_at_random_points(what, class, a, b, product);
+    }
+}
+
+/// Schwartz–Zippel test of `product = a·b` over `Z_q[x]`: a wrong product
+/// of degree below `d` passes one point with probability at most `d/q`
+fn check_at_random_points(
+    what: &str,
+    class: &CongruenceClass,
+    a: &[u64],
+    b: &[u64],
+    product: &[u64],
+) {
+    let mut rng = rand::rng();
+    for _ in 0..EVALUATION_CHECKS {
+        let x = rng.random_range(0..class.q());
+        compare_at(what, class, x, a, b, product);
+    }
+}
+
+fn compare_coeffs(
+    what: &str,
+    class: &CongruenceClass,
+    got: &[u64],
+    expected: &[u64],
+) {
+    let q = class.q();
+    let diffs: Vec<usize> = (0..got.len())
+        .filter(|&i| got[i] % q != expected[i])
+        .collect();
+    if diffs.is_empty() {
+        return;
+    }
+
+    let mut message = format!(
+        "{what} mismatch in {} of {} coefficients mod {q}:",
+        diffs.len(),
+        got.len()
+    );
+    for &i in diffs.iter().take(MAX_REPORTED) {
+        let _ = write!(
+            message,
+            "\n  [{i}] got {}, expected {}",
+            got[i], expected[i]
+        );
+    }
+    if diffs.len() > MAX_REPORTED {
+        message.push_str("\n  ...");
+    }
+    panic!("{message}");
+}
+
+fn compare_at(
+    what: &str,
+    class: &CongruenceClass,
+    x: u64,
+    a: &[u64],
+    b: &[u64],
+    product: &[u64],
+) {
+    let (a_x, b_x) = (
+        polyalg::evaluate(a, x, class),
+        polyalg::evaluate(b, x, class),
+    );
+    let expected = class.modmul(a_x, b_x);
+    let got = polyalg::evaluate(product, x, class);
+    assert!(
+        got == expected,
+        "{what} mismatch mod {}: at x = {x} the product evaluates to {got}, \
+         the operands to {a_x}·{b_x} = {expected}",
+        class.q()
+    );
+}
@@ -0,0 +1,122 @@
+//! `rand` distributions over `Z_q[x]/(x^N + 1)`.
+//!
+//! Each sampler holds its context and implements
+//! `rand::distr::Distribution<NttPolynomial<N>>`, so polynomials can be drawn
+//! with `rng.sample(&dist)`, collected from `dist.sample_iter(rng)`, or mapped
+//! with `dist.map(..)` like any other `rand` distribution.
+//!
+//! Small distributions encode negative coefficients as `q - |c|`.
+use crate::context::{NttContext, boxed_from_fn, boxed_zeros};
+use crate::ntt::NttPolynomial;
+use rand::Rng;
+use rand::distr::Distribution;
+use std::sync::Arc;
+
+/// Coefficients drawn independently and uniformly from `[0, q)`.
+///
+/// # Examples
+/// ```rust
+/// use rand::{Rng, SeedableRng, rngs::StdRng};
+/// use rust_ntt::*;
+/// use rust_ntt::distr::UniformPoly;
+///
+/// const N: usize = 16;
+/// let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+/// let uniform = UniformPoly::new(ctx);
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let a: NttPolynomial<N> = rng.sample(&uniform);
+/// let batch: Vec<NttPolynomial<N>> = (&mut rng).sample_iter(&uniform).take(4).collect();
+/// assert_eq!(batch.len(), 4);
+/// # let _ = a;
+/// ```
+#[derive(Debug, Clone)]
+pub struct UniformPoly<const DEGREE: usize> {
+    context: Arc<NttContext<DEGREE>>,
+}
+
+impl<const DEGREE: usize> UniformPoly<DEGREE> {
+    pub fn new(context: Arc<NttContext<DEGREE>>) -> Self {
+        Self { context }
+    }
+
+    pub fn context(&self) -> &Arc<NttContext<DEGREE>> {
+        &self.context
+    }
+}
+
+impl<const DEGREE: usize> Distribution<NttPolynomial<DEGREE>>
+    for UniformPoly<DEGREE>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NttPolynomial<DEGREE> {
+        let q = self.context.modulus();
+        let coeffs = boxed_from_fn(|_| rng.random_range(0..q));
+        NttPolynomial::from_boxed_coeffs(coeffs, Arc::clone(&self.context))
+    }
+}
+
+/// Coefficients drawn from `{-1, 0, 1}`.
+///
+/// By default each coefficient is uniform over the three values. With
+/// [`TernaryPoly::with_hamming_weight`] exactly `h` coefficients are nonzero,
+/// each `±1` with equal probability, as used for sparse secrets.
+#[derive(Debug, Clone)]
+pub struct TernaryPoly<const DEGREE: usize> {
+    context: Arc<NttContext<DEGREE>>,
+    hamming_weight: Option<usize>,
+}
+
+impl<const DEGREE: usize> TernaryPoly<DEGREE> {
+    pub fn new(context: Arc<NttContext<DEGREE>>) -> Self {
+        Self {
+            context,
+            hamming_weight: None,
+        }
+    }
+
+    /// # Panics
+    /// * If `h > DEGREE`
+    pub fn with_hamming_weight(context: Arc<NttContext<DEGREE>>, h: usize) -> Self {
+        assert!(h <= DEGREE, "hamming weight {h} exceeds degree {DEGREE}");
+        Self {
+            context,
+            hamming_weight: Some(h),
+        }
+    }
+
+    pub fn context(&self) -> &Arc<NttContext<DEGREE>> {
+        &self.context
+    }
+
+    pub fn hamming_weight(&self) -> Option<usize> {
+        self.hamming_weight
+    }
+}
+
+impl<const DEGREE: usize> Distribution<NttPolynomial<DEGREE>>
+    for TernaryPoly<DEGREE>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NttPolynomial<DEGREE> {
+        let q = self.context.modulus();
+        let mut coeffs = boxed_zeros::<DEGREE>();
+        match self.hamming_weight {
+            None => {
+                for coeff in coeffs.iter_mut() {
+                    *coeff = match rng.random_range(0..3u8) {
+                        0 => 0,
+                        1 => 1,
+                        _ => q - 1,
+                    };
+                }
+            }
+            Some(h) => {
+                let positions = rand::seq::index::sample(rng, DEGREE, h).into_vec();
+                for i in positions {
+                    coeffs[i] = if rng.random_bool(0.5) { 1 } else { q - 1 };
+                }
+            }
+        }
+
+        NttPolynomial::from_boxed_coeffs(coeffs, Arc::clone(&self.context))
+    }
+}
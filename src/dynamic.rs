@@ -0,0 +1,370 @@
+//! Negacyclic NTT with the degree chosen at run time.
+//!
+//! [`NttContext`](crate::NttContext) and [`NttPolynomial`](crate::NttPolynomial)
+//! fix `N` as a const generic, which rules out reading it from a
+//! configuration file or command line. [`DynNttContext`] and
+//! [`DynNttPolynomial`] hold their tables and coefficients in `Vec`s
+//! instead, and run the same Shoup butterflies as the const-generic types:
+//! for equal `q` and `N` the twiddle tables, evaluations and products are
+//! identical.
+//!
+//! Only the core operations are mirrored: forward and inverse transforms
+//! and the negacyclic product. Code that needs the rest of the API can
+//! dispatch once on the runtime degree to a const-generic implementation.
+//!
+//! Callers that manage their own buffers, or exchange them with other
+//! crates, can skip the polynomial type: an [`NttPlan`] runs the same
+//! transforms on plain `&mut [u64]` slices.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::dynamic::{DynNttContext, DynNttPolynomial};
+//! use rust_ntt::find_first_prime_up;
+//! use std::sync::Arc;
+//!
+//! let n: usize = "8".parse().unwrap();
+//! let ctx = DynNttContext::new(find_first_prime_up(20, n), n);
+//!
+//! let a = DynNttPolynomial::from_coeffs(vec![1, 2, 0, 0, 0, 0, 0, 0], Arc::clone(&ctx));
+//! let b = DynNttPolynomial::from_coeffs(vec![3, 1, 0, 0, 0, 0, 0, 0], ctx);
+//! assert_eq!((&a * &b).coeffs(), &[3, 7, 2, 0, 0, 0, 0, 0]);
+//! ```
+use crate::congruence::CongruenceClass;
+use crate::context::{NttError, twiddle_table, validate_parameters};
+use crate::math::find_generator;
+use crate::ntt::{LengthMismatch, forward_shoup_kernel, inverse_shoup_kernel};
+use std::ops::Mul;
+use std::sync::Arc;
+
+/// Precomputed tables for the negacyclic NTT of a runtime degree.
+#[derive(Debug, Clone)]
+pub struct DynNttContext {
+    class: CongruenceClass,
+    degree: usize,
+    inv_n: u64,
+    inv_n_shoup: u64,
+    inv_n_itf: u64,
+    inv_n_itf_shoup: u64,
+    tf: Vec<u64>,
+    tf_shoup: Vec<u64>,
+    itf: Vec<u64>,
+    itf_shoup: Vec<u64>,
+}
+
+impl DynNttContext {
+    /// Create a context for polynomials of `degree` coefficients modulo `q`.
+    ///
+    /// # Panics
+    /// * Under the conditions of [`NttContext::new`](crate::NttContext::new),
+    ///   with `degree` in place of `DEGREE`
+    pub fn new(q: u64, degree: usize) -> Arc<Self> {
+        Self::try_new(q, degree).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Create a context, or report why `q` and `degree` admit no negacyclic
+    /// NTT, as [`NttContext::try_new`](crate::NttContext::try_new) does
+    pub fn try_new(q: u64, degree: usize) -> Result<Arc<Self>, NttError> {
+        validate_parameters(q, degree)?;
+
+        let class = CongruenceClass::new(q);
+        let g = find_generator(q, degree);
+        let tf = twiddle_table(&class, g, false, degree);
+        let itf = twiddle_table(&class, g, true, degree);
+        let shoup = |table: &[u64]| -> Vec<u64> {
+            table.iter().map(|&w| class.precompute_shoup(w)).collect()
+        };
+
+        let inv_n = class.modinv(degree as u64);
+        let inv_n_itf = if degree > 1 {
+            class.modmul(itf[1], inv_n)
+        } else {
+            inv_n
+        };
+
+        Ok(Arc::new(Self {
+            class,
+            degree,
+            inv_n,
+            inv_n_shoup: class.precompute_shoup(inv_n),
+            inv_n_itf,
+            inv_n_itf_shoup: class.precompute_shoup(inv_n_itf),
+            tf_shoup: shoup(&tf),
+            itf_shoup: shoup(&itf),
+            tf,
+            itf,
+        }))
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.class.q()
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn class(&self) -> &CongruenceClass {
+        &self.class
+    }
+
+    /// Forward twiddle factors, in bit-reversed order
+    pub fn tf(&self) -> &[u64] {
+        &self.tf
+    }
+
+    /// Inverse twiddle factors, in bit-reversed order
+    pub fn itf(&self) -> &[u64] {
+        &self.itf
+    }
+
+    fn forward_in_place(&self, values: &mut [u64]) {
+        self.expect_len(values.len());
+        forward_shoup_kernel(&self.class, &self.tf, &self.tf_shoup, values);
+    }
+
+    fn inverse_in_place(&self, values: &mut [u64]) {
+        self.expect_len(values.len());
+        inverse_shoup_kernel(
+            &self.class,
+            &self.itf,
+            &self.itf_shoup,
+            (self.inv_n, self.inv_n_shoup),
+            (self.inv_n_itf, self.inv_n_itf_shoup),
+            values,
+        );
+    }
+
+    fn pointwise_in_place(&self, lhs: &mut [u64], rhs: &[u64]) {
+        self.expect_len(lhs.len());
+        self.expect_len(rhs.len());
+        for (x, &y) in lhs.iter_mut().zip(rhs) {
+            self.class.modmul_eq(x, y);
+        }
+    }
+
+    fn expect_len(&self, len: usize) {
+        assert!(
+            len == self.degree,
+            "expected {} coefficients, got {len}",
+            self.degree
+        );
+    }
+}
+
+/// Negacyclic NTT of a runtime degree over plain slices, in the style of
+/// concrete-ntt's plans.
+///
+/// Every slice must hold exactly `n` values below `q`. Evaluations are in
+/// the bit-reversed order of [`NttContext`](crate::NttContext), and unlike
+/// concrete-ntt, [`inv`](Self::inv) includes the `n^(-1)` scaling, so
+/// `fwd` followed by `inv` is the identity.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::dynamic::NttPlan;
+/// use rust_ntt::find_first_prime_up;
+///
+/// let plan = NttPlan::new(find_first_prime_up(30, 4), 4);
+///
+/// // (1 + 2x)(3 + x) = 3 + 7x + 2x^2
+/// let mut a = vec![1, 2, 0, 0];
+/// plan.negacyclic_mul(&mut a, &[3, 1, 0, 0]);
+/// assert_eq!(a, [3, 7, 2, 0]);
+///
+/// let mut evals = a.clone();
+/// plan.fwd(&mut evals);
+/// plan.inv(&mut evals);
+/// assert_eq!(evals, a);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NttPlan {
+    context: Arc<DynNttContext>,
+}
+
+impl NttPlan {
+    /// Plan for `n` coefficients modulo `q`
+    ///
+    /// # Panics
+    /// * Under the conditions of [`DynNttContext::new`]
+    pub fn new(q: u64, n: usize) -> Self {
+        Self::from(DynNttContext::new(q, n))
+    }
+
+    /// Plan for `n` coefficients modulo `q`, or why none exists
+    pub fn try_new(q: u64, n: usize) -> Result<Self, NttError> {
+        DynNttContext::try_new(q, n).map(Self::from)
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.context.modulus()
+    }
+
+    pub fn ntt_size(&self) -> usize {
+        self.context.degree
+    }
+
+    /// Tables of the plan, to build [`DynNttPolynomial`]s sharing them
+    pub fn context(&self) -> &Arc<DynNttContext> {
+        &self.context
+    }
+
+    /// Forward negacyclic NTT in place
+    ///
+    /// # Panics
+    /// * If `values.len() != n`
+    pub fn fwd(&self, values: &mut [u64]) {
+        self.context.forward_in_place(values);
+    }
+
+    /// Inverse negacyclic NTT in place, including the `n^(-1)` scaling
+    ///
+    /// # Panics
+    /// * If `values.len() != n`
+    pub fn inv(&self, values: &mut [u64]) {
+        self.context.inverse_in_place(values);
+    }
+
+    /// Slot-wise product of evaluations, into `lhs`
+    ///
+    /// # Panics
+    /// * If either slice does not have `n` values
+    pub fn pointwise_mul(&self, lhs: &mut [u64], rhs: &[u64]) {
+        self.context.pointwise_in_place(lhs, rhs);
+    }
+
+    /// `lhs = lhs·rhs mod (x^n + 1)` on coefficients; `rhs` is copied for
+    /// its transform
+    ///
+    /// # Panics
+    /// * If either slice does not have `n` values
+    pub fn negacyclic_mul(&self, lhs: &mut [u64], rhs: &[u64]) {
+        let mut rhs_evals = rhs.to_vec();
+        self.fwd(&mut rhs_evals);
+        self.fwd(lhs);
+        self.pointwise_mul(lhs, &rhs_evals);
+        self.inv(lhs);
+    }
+}
+
+impl From<Arc<DynNttContext>> for NttPlan {
+    fn from(context: Arc<DynNttContext>) -> Self {
+        Self { context }
+    }
+}
+
+/// Polynomial in `Z_q[x]/(x^N + 1)` for the runtime degree `N` of its
+/// context.
+#[derive(Debug, Clone)]
+pub struct DynNttPolynomial {
+    coeffs: Vec<u64>,
+    context: Arc<DynNttContext>,
+}
+
+impl DynNttPolynomial {
+    /// Create polynomial from exactly `context.degree()` coefficients
+    ///
+    /// # Panics
+    /// * If the number of coefficients differs from the degree
+    pub fn from_coeffs(coeffs: Vec<u64>, context: Arc<DynNttContext>) -> Self {
+        Self::try_from_coeffs(coeffs, context).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Create polynomial from coefficients, failing if their number differs
+    /// from the degree
+    pub fn try_from_coeffs(
+        coeffs: Vec<u64>,
+        context: Arc<DynNttContext>,
+    ) -> Result<Self, LengthMismatch> {
+        let expected = context.degree;
+        if coeffs.len() < expected {
+            return Err(LengthMismatch::TooFew {
+                expected,
+                found: coeffs.len(),
+            });
+        }
+        if coeffs.len() > expected {
+            return Err(LengthMismatch::TooMany { expected });
+        }
+        Ok(Self { coeffs, context })
+    }
+
+    /// Create zero polynomial
+    pub fn zero(context: Arc<DynNttContext>) -> Self {
+        Self {
+            coeffs: vec![0; context.degree],
+            context,
+        }
+    }
+
+    pub fn coeffs(&self) -> &[u64] {
+        &self.coeffs
+    }
+
+    pub fn coeffs_mut(&mut self) -> &mut [u64] {
+        &mut self.coeffs
+    }
+
+    pub fn context(&self) -> &Arc<DynNttContext> {
+        &self.context
+    }
+
+    pub fn into_coeffs(self) -> Vec<u64> {
+        self.coeffs
+    }
+
+    /// Forward negacyclic NTT in place, evaluations in bit-reversed order
+    /// as for [`NttPolynomial::ntt_forward_shoup`](crate::NttPolynomial::ntt_forward_shoup)
+    pub fn ntt_forward(&mut self) {
+        self.context.forward_in_place(&mut self.coeffs);
+    }
+
+    /// Inverse negacyclic NTT in place, including the `N^(-1)` scaling
+    pub fn ntt_inverse(&mut self) {
+        self.context.inverse_in_place(&mut self.coeffs);
+    }
+
+    /// Product modulo `x^N + 1` through forward transforms, a pointwise
+    /// product and an inverse transform
+    ///
+    /// # Panics
+    /// * If the degrees or moduli of the contexts differ
+    pub fn negacyclic_convolution(&self, other: &Self) -> Self {
+        self.expect_compatible(other, "convolve");
+
+        let mut result = self.clone();
+        let mut other_evals = other.clone();
+        result.ntt_forward();
+        other_evals.ntt_forward();
+
+        self.context
+            .pointwise_in_place(&mut result.coeffs, &other_evals.coeffs);
+
+        result.ntt_inverse();
+        result
+    }
+
+    fn expect_compatible(&self, other: &Self, verb: &str) {
+        let (lhs, rhs) = (&self.context, &other.context);
+        if lhs.degree != rhs.degree {
+            panic!(
+                "Cannot {verb} polynomials: degrees differ, {} vs {}",
+                lhs.degree, rhs.degree
+            );
+        }
+        if lhs.modulus() != rhs.modulus() {
+            panic!(
+                "Cannot {verb} polynomials: moduli differ, {} vs {}",
+                lhs.modulus(),
+                rhs.modulus()
+            );
+        }
+    }
+}
+
+impl Mul for &DynNttPolynomial {
+    type Output = DynNttPolynomial;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.negacyclic_convolution(rhs)
+    }
+}
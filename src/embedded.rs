@@ -0,0 +1,344 @@
+//! Heap-free NTT contexts with tables fixed at build time.
+//!
+//! [`StaticNttContext`] stores its twiddle and Shoup tables inline, so it can
+//! live in a `static` and transform `[u64; N]` arrays without allocating or
+//! precomputing anything at run time. The tables are produced ahead of time
+//! by [`render_static_context`], typically from a build script:
+//!
+//! ```rust,ignore
+//! // build.rs, with rust_ntt as a build-dependency
+//! use rust_ntt::{NttContext, embedded::render_static_context};
+//!
+//! fn main() {
+//!     let ctx = NttContext::<1024>::new(132120577);
+//!     let out = std::env::var("OUT_DIR").unwrap();
+//!     let code = render_static_context("NTT_1024", &ctx);
+//!     std::fs::write(format!("{out}/ntt_tables.rs"), code).unwrap();
+//! }
+//!
+//! // src/lib.rs
+//! include!(concat!(env!("OUT_DIR"), "/ntt_tables.rs"));
+//! ```
+//!
+//! The generated tables are identical to those of the [`NttContext`] they
+//! were rendered from, so both produce the same evaluations.
+//!
+//! Without a build script, [`ntt_context!`](crate::ntt_context) computes the
+//! tables during constant evaluation instead.
+use crate::backend::NttBackend;
+use crate::congruence::CongruenceClass;
+use crate::context::NttContext;
+use crate::unrolled;
+use std::fmt::Write;
+
+/// NTT context whose tables are plain arrays, suitable for a `static`.
+#[derive(Debug, Clone)]
+pub struct StaticNttContext<const DEGREE: usize> {
+    class: CongruenceClass,
+    inv_n: u64,
+    inv_n_shoup: u64,
+    inv_n_itf: u64,
+    inv_n_itf_shoup: u64,
+    tf: [u64; DEGREE],
+    tf_shoup: [u64; DEGREE],
+    itf: [u64; DEGREE],
+    itf_shoup: [u64; DEGREE],
+}
+
+impl<const DEGREE: usize> StaticNttContext<DEGREE> {
+    /// Assemble a context from precomputed tables.
+    ///
+    /// The tables are trusted as given; use the output of
+    /// [`render_static_context`] rather than writing them by hand.
+    #[allow(clippy::too_many_arguments)]
+    pub const fn from_raw_parts(
+        q: u64,
+        inv_n: u64,
+        inv_n_shoup: u64,
+        tf: [u64; DEGREE],
+        tf_shoup: [u64; DEGREE],
+        itf: [u64; DEGREE],
+        itf_shoup: [u64; DEGREE],
+    ) -> Self {
+        // Normalization folded into the last inverse stage, as in NttContext
+        let inv_n_itf = if DEGREE > 1 {
+            const_mulmod(itf[1], inv_n, q)
+        } else {
+            inv_n
+        };
+        Self {
+            class: CongruenceClass::new(q),
+            inv_n,
+            inv_n_shoup,
+            inv_n_itf,
+            inv_n_itf_shoup: const_shoup(inv_n_itf, q),
+            tf,
+            tf_shoup,
+            itf,
+            itf_shoup,
+        }
+    }
+
+    /// Compute all tables during constant evaluation.
+    ///
+    /// The root of unity is derived from the smallest quadratic non-residue
+    /// rather than the smallest primitive root, so the evaluation order may
+    /// differ from `NttContext::new(q)`; round trips and products agree.
+    /// Prefer [`ntt_context!`](crate::ntt_context), which forces evaluation
+    /// at compile time.
+    ///
+    /// # Panics
+    /// * If `DEGREE` is not a power of two, `q` is not a prime below `2^63`,
+    ///   or `q ≢ 1 (mod 2·DEGREE)`
+    pub const fn new(q: u64) -> Self {
+        assert!(DEGREE.is_power_of_two(), "DEGREE must be a power of two");
+        assert!(q >= 3 && q < (1u64 << 63), "modulus must be in [3, 2^63)");
+        assert!(
+            (q - 1) & (2 * DEGREE as u64 - 1) == 0,
+            "modulus must satisfy q ≡ 1 (mod 2·DEGREE)"
+        );
+        assert!(const_is_prime(q), "modulus must be prime");
+
+        // ψ = x^((q-1)/2N) for a non-residue x has ψ^N = -1, hence order 2N
+        let mut x = 2;
+        while const_powmod(x, (q - 1) / 2, q) != q - 1 {
+            x += 1;
+        }
+        let psi = const_powmod(x, (q - 1) / (2 * DEGREE as u64), q);
+
+        let tf = const_twiddles::<DEGREE>(psi, q);
+        let itf = const_twiddles::<DEGREE>(const_invmod(psi, q), q);
+        let inv_n = const_invmod(DEGREE as u64, q);
+
+        Self::from_raw_parts(
+            q,
+            inv_n,
+            const_shoup(inv_n, q),
+            tf,
+            const_shoup_table(&tf, q),
+            itf,
+            const_shoup_table(&itf, q),
+        )
+    }
+
+    pub fn class(&self) -> &CongruenceClass {
+        &self.class
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.class.q()
+    }
+
+    pub fn tf(&self) -> &[u64; DEGREE] {
+        &self.tf
+    }
+
+    pub fn itf(&self) -> &[u64; DEGREE] {
+        &self.itf
+    }
+
+    /// Forward negacyclic NTT in place
+    pub fn ntt_forward(&self, coeffs: &mut [u64; DEGREE]) {
+        unrolled::forward_shoup(&self.class, &self.tf, &self.tf_shoup, coeffs);
+    }
+
+    /// Inverse negacyclic NTT in place, including the `N^(-1)` scaling
+    pub fn ntt_inverse(&self, evals: &mut [u64; DEGREE]) {
+        unrolled::inverse_shoup(
+            &self.class,
+            &self.itf,
+            &self.itf_shoup,
+            (self.inv_n, self.inv_n_shoup),
+            (self.inv_n_itf, self.inv_n_itf_shoup),
+            evals,
+        );
+    }
+}
+
+impl<const DEGREE: usize> NttBackend<DEGREE> for StaticNttContext<DEGREE> {
+    fn modulus(&self) -> u64 {
+        self.class.q()
+    }
+
+    fn forward(&self, coeffs: &mut [u64; DEGREE]) {
+        self.ntt_forward(coeffs);
+    }
+
+    fn inverse(&self, evals: &mut [u64; DEGREE]) {
+        self.ntt_inverse(evals);
+    }
+
+    fn pointwise_mul(&self, lhs: &mut [u64; DEGREE], rhs: &[u64; DEGREE]) {
+        for (x, &y) in lhs.iter_mut().zip(rhs) {
+            self.class.modmul_eq(x, y);
+        }
+    }
+}
+
+/// Rust source declaring `pub static {name}: StaticNttContext<DEGREE>` with
+/// the tables of `ctx`.
+///
+/// The output refers to the crate as `::rust_ntt`, and is meant to be
+/// written to `OUT_DIR` and pulled in with `include!`.
+pub fn render_static_context<const DEGREE: usize>(
+    name: &str,
+    ctx: &NttContext<DEGREE>,
+) -> String {
+    let mut out = String::new();
+    let path = "::rust_ntt::embedded::StaticNttContext";
+
+    writeln!(
+        out,
+        "// Generated by rust_ntt::embedded::render_static_context"
+    )
+    .unwrap();
+    writeln!(out, "// q = {}, N = {DEGREE}", ctx.modulus()).unwrap();
+    writeln!(
+        out,
+        "pub static {name}: {path}<{DEGREE}> = {path}::from_raw_parts("
+    )
+    .unwrap();
+    writeln!(out, "    {},", ctx.modulus()).unwrap();
+    writeln!(out, "    {},", ctx.inv_n).unwrap();
+    writeln!(out, "    {},", ctx.inv_n_shoup).unwrap();
+    for table in [&ctx.tf, &ctx.tf_shoup, &ctx.itf, &ctx.itf_shoup] {
+        render_array(&mut out, &table[..]);
+    }
+    writeln!(out, ");").unwrap();
+
+    out
+}
+
+fn render_array(out: &mut String, table: &[u64]) {
+    writeln!(out, "    [").unwrap();
+    for row in table.chunks(4) {
+        let row: Vec<String> = row.iter().map(|v| format!("{v},")).collect();
+        writeln!(out, "        {}", row.join(" ")).unwrap();
+    }
+    writeln!(out, "    ],").unwrap();
+}
+
+/// Fully precomputed [`StaticNttContext`] built at compile time.
+///
+/// Expands to an inline `const` block, so an invalid modulus or degree is a
+/// compile error rather than a run-time panic, and the result can initialize
+/// a `const` or `static`.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::embedded::StaticNttContext;
+/// use rust_ntt::ntt_context;
+///
+/// static CTX: StaticNttContext<256> = ntt_context!(q = 7681, n = 256);
+///
+/// let coeffs = std::array::from_fn(|i| i as u64);
+/// let mut evals = coeffs;
+/// CTX.ntt_forward(&mut evals);
+/// CTX.ntt_inverse(&mut evals);
+/// assert_eq!(evals, coeffs);
+/// ```
+///
+/// ```rust,compile_fail
+/// // 7681 ≢ 1 (mod 2·1024)
+/// let ctx = rust_ntt::ntt_context!(q = 7681, n = 1024);
+/// ```
+#[macro_export]
+macro_rules! ntt_context {
+    (q = $q:expr, n = $n:expr $(,)?) => {
+        const { $crate::embedded::StaticNttContext::<$n>::new($q) }
+    };
+}
+
+const fn const_mulmod(a: u64, b: u64, q: u64) -> u64 {
+    ((a as u128 * b as u128) % q as u128) as u64
+}
+
+const fn const_powmod(mut base: u64, mut e: u64, q: u64) -> u64 {
+    let mut acc = 1;
+    base %= q;
+    while e > 0 {
+        if e & 1 == 1 {
+            acc = const_mulmod(acc, base, q);
+        }
+        base = const_mulmod(base, base, q);
+        e >>= 1;
+    }
+    acc
+}
+
+/// Inverse of a unit modulo the prime `q`, by Fermat's little theorem
+const fn const_invmod(a: u64, q: u64) -> u64 {
+    const_powmod(a, q - 2, q)
+}
+
+const fn const_shoup(b: u64, q: u64) -> u64 {
+    (((b as u128) << 64) / q as u128) as u64
+}
+
+const fn const_shoup_table<const DEGREE: usize>(
+    table: &[u64; DEGREE],
+    q: u64,
+) -> [u64; DEGREE] {
+    let mut out = [0u64; DEGREE];
+    let mut i = 0;
+    while i < DEGREE {
+        out[i] = const_shoup(table[i], q);
+        i += 1;
+    }
+    out
+}
+
+/// Powers of `base` in bit-reversed order, as in the runtime context
+const fn const_twiddles<const DEGREE: usize>(base: u64, q: u64) -> [u64; DEGREE] {
+    let log_degree = DEGREE.trailing_zeros();
+    let mut out = [0u64; DEGREE];
+    let mut power = 1;
+    let mut i = 0;
+    while i < DEGREE {
+        let rev = if log_degree == 0 {
+            0
+        } else {
+            i.reverse_bits() >> (usize::BITS - log_degree)
+        };
+        out[rev] = power;
+        power = const_mulmod(power, base, q);
+        i += 1;
+    }
+    out
+}
+
+/// Deterministic Miller-Rabin; these bases are exact for all `u64`
+const fn const_is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < BASES.len() {
+        let rem = n % BASES[i];
+        if rem == 0 {
+            return n == BASES[i];
+        }
+        i += 1;
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let mut i = 0;
+    while i < BASES.len() {
+        let mut x = const_powmod(BASES[i], d, n);
+        let mut round = 1;
+        let mut witness = x != 1 && x != n - 1;
+        while witness && round < s {
+            x = const_mulmod(x, x, n);
+            witness = x != n - 1;
+            round += 1;
+        }
+        if witness {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
@@ -0,0 +1,223 @@
+//! Compact binary encoding of polynomials.
+//!
+//! [`NttPolynomial::to_packed_bytes`] stores each coefficient in
+//! `⌈log2 q⌉` bits instead of a full word, after a 14-byte header:
+//!
+//! | offset | size | field                              |
+//! |--------|------|------------------------------------|
+//! | 0      | 1    | format version, currently 1        |
+//! | 1      | 1    | bits per coefficient `⌈log2 q⌉`    |
+//! | 2      | 4    | degree `N`, little endian          |
+//! | 6      | 8    | modulus `q`, little endian         |
+//! | 14     | ..   | coefficients, LSB-first bit stream |
+//!
+//! The body takes `⌈N·⌈log2 q⌉ / 8⌉` bytes, with unused high bits of the
+//! last byte zero. The header lets the decoder reject data produced for a
+//! different ring instead of silently misreading it.
+use crate::context::{NttContext, boxed_zeros};
+use crate::ntt::NttPolynomial;
+use std::fmt;
+use std::sync::Arc;
+
+const VERSION: u8 = 1;
+const HEADER_LEN: usize = 14;
+
+/// Reason packed bytes could not be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Input shorter than the header or than the body it announces
+    Truncated {
+        expected: usize,
+        found: usize,
+    },
+    /// Input longer than header plus body
+    TrailingBytes {
+        expected: usize,
+        found: usize,
+    },
+    UnsupportedVersion(u8),
+    /// Header describes a different ring than the target context
+    RingMismatch {
+        modulus: u64,
+        degree: usize,
+        expected_modulus: u64,
+        expected_degree: usize,
+    },
+    /// Header bit width is not `⌈log2 q⌉`
+    InvalidBitWidth(u8),
+    /// A decoded coefficient is not below `q`
+    CoefficientOutOfRange {
+        index: usize,
+        value: u64,
+    },
+    /// Unused bits of the last byte are not zero
+    NonZeroPadding,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated { expected, found } => {
+                write!(f, "expected {expected} bytes, found only {found}")
+            }
+            Self::TrailingBytes { expected, found } => {
+                write!(f, "expected {expected} bytes, found {found}")
+            }
+            Self::UnsupportedVersion(v) => {
+                write!(f, "unsupported packed format version {v}")
+            }
+            Self::RingMismatch {
+                modulus,
+                degree,
+                expected_modulus,
+                expected_degree,
+            } => write!(
+                f,
+                "data is for q = {modulus}, N = {degree}, \
+                 context has q = {expected_modulus}, N = {expected_degree}"
+            ),
+            Self::InvalidBitWidth(bits) => {
+                write!(f, "invalid bit width {bits} for the modulus")
+            }
+            Self::CoefficientOutOfRange { index, value } => {
+                write!(f, "coefficient {index} = {value} is not reduced")
+            }
+            Self::NonZeroPadding => write!(f, "padding bits are not zero"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Bits needed for residues modulo `q`, `⌈log2 q⌉`
+fn coefficient_bits(q: u64) -> u32 {
+    64 - (q - 1).leading_zeros()
+}
+
+impl<const DEGREE: usize> NttPolynomial<DEGREE> {
+    /// Length in bytes of [`to_packed_bytes`](Self::to_packed_bytes) output
+    /// for this ring.
+    pub fn packed_len(&self) -> usize {
+        let bits = coefficient_bits(self.context().modulus()) as usize;
+        HEADER_LEN + (DEGREE * bits).div_ceil(8)
+    }
+
+    /// Encode with `⌈log2 q⌉` bits per coefficient, see [`crate::encoding`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use std::sync::Arc;
+    ///
+    /// const N: usize = 256;
+    /// let ctx = NttContext::<N>::new(find_first_prime_up(23, N));
+    /// let a = NttPolynomial::from_coeffs(std::array::from_fn(|i| i as u64), Arc::clone(&ctx));
+    ///
+    /// let bytes = a.to_packed_bytes();
+    /// assert_eq!(bytes.len(), 14 + N * 24 / 8); // vs. 8·N = 2048 bytes
+    ///
+    /// let b = NttPolynomial::from_packed_bytes(&bytes, ctx).unwrap();
+    /// assert_eq!(b.coeffs(), a.coeffs());
+    /// ```
+    pub fn to_packed_bytes(&self) -> Vec<u8> {
+        let q = self.context().modulus();
+        let bits = coefficient_bits(q);
+
+        let mut out = Vec::with_capacity(self.packed_len());
+        out.push(VERSION);
+        out.push(bits as u8);
+        out.extend_from_slice(&(DEGREE as u32).to_le_bytes());
+        out.extend_from_slice(&q.to_le_bytes());
+
+        let mut acc: u128 = 0;
+        let mut pending = 0;
+        for &c in self.coeffs() {
+            acc |= ((c % q) as u128) << pending;
+            pending += bits;
+            while pending >= 8 {
+                out.push(acc as u8);
+                acc >>= 8;
+                pending -= 8;
+            }
+        }
+        if pending > 0 {
+            out.push(acc as u8);
+        }
+
+        out
+    }
+
+    /// Decode [`to_packed_bytes`](Self::to_packed_bytes) output, checking
+    /// that it was produced for the ring of `context`.
+    pub fn from_packed_bytes(
+        bytes: &[u8],
+        context: Arc<NttContext<DEGREE>>,
+    ) -> Result<Self, DecodeError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(DecodeError::Truncated {
+                expected: HEADER_LEN,
+                found: bytes.len(),
+            });
+        }
+        if bytes[0] != VERSION {
+            return Err(DecodeError::UnsupportedVersion(bytes[0]));
+        }
+
+        let degree = u32::from_le_bytes(bytes[2..6].try_into().unwrap()) as usize;
+        let modulus = u64::from_le_bytes(bytes[6..14].try_into().unwrap());
+        let q = context.modulus();
+        if degree != DEGREE || modulus != q {
+            return Err(DecodeError::RingMismatch {
+                modulus,
+                degree,
+                expected_modulus: q,
+                expected_degree: DEGREE,
+            });
+        }
+
+        let bits = coefficient_bits(q);
+        if bytes[1] as u32 != bits {
+            return Err(DecodeError::InvalidBitWidth(bytes[1]));
+        }
+
+        let expected = HEADER_LEN + (DEGREE * bits as usize).div_ceil(8);
+        if bytes.len() < expected {
+            return Err(DecodeError::Truncated {
+                expected,
+                found: bytes.len(),
+            });
+        }
+        if bytes.len() > expected {
+            return Err(DecodeError::TrailingBytes {
+                expected,
+                found: bytes.len(),
+            });
+        }
+
+        let mask = (1u128 << bits) - 1;
+        let mut body = bytes[HEADER_LEN..].iter();
+        let mut acc: u128 = 0;
+        let mut available = 0;
+        let mut coeffs = boxed_zeros::<DEGREE>();
+
+        for (index, coeff) in coeffs.iter_mut().enumerate() {
+            while available < bits {
+                acc |= (*body.next().unwrap() as u128) << available;
+                available += 8;
+            }
+            let value = (acc & mask) as u64;
+            acc >>= bits;
+            available -= bits;
+
+            if value >= q {
+                return Err(DecodeError::CoefficientOutOfRange { index, value });
+            }
+            *coeff = value;
+        }
+        if acc != 0 {
+            return Err(DecodeError::NonZeroPadding);
+        }
+
+        Ok(Self::from_boxed_coeffs(coeffs, context))
+    }
+}
@@ -0,0 +1,259 @@
+//! Exact integer convolution over several NTT primes.
+//!
+//! A single context computes products modulo `q < 2^63`, so integer
+//! convolutions whose coefficients grow past `q` wrap around. An
+//! [`ExactConvolver`] runs the same linear convolution modulo two or three
+//! primes and recombines the residues with the Chinese remainder theorem
+//! (Garner's algorithm), which recovers every coefficient exactly as long
+//! as it stays below the bound the convolver was built for.
+//!
+//! For short-integer sequences of any length, [`convolve_u8`] and
+//! [`convolve_u16`] pick a single prime large enough for the exact result
+//! and a transform length to match, so no context has to be set up.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::exact::ExactConvolver;
+//!
+//! // Products of 60-bit values need about 120 bits
+//! let conv = ExactConvolver::<8>::new(124);
+//! let big = 1u64 << 60;
+//! assert_eq!(
+//!     conv.convolve_u64(&[big, 1], &[big, 2]),
+//!     vec![1u128 << 120, 3 << 60, 2]
+//! );
+//! assert_eq!(conv.convolve_i64(&[-3, 1], &[2]), vec![-6, 2]);
+//! ```
+use crate::congruence::CongruenceClass;
+use crate::context::NttContext;
+use crate::math::{
+    find_first_prime_down, find_first_prime_up, find_next_prime_down,
+};
+use crate::ntt::NttPolynomial;
+use crate::polyalg;
+use std::sync::Arc;
+
+/// Linear convolution of integer vectors of length up to `DEGREE` with
+/// exact results below `2^bits`.
+#[derive(Debug, Clone)]
+pub struct ExactConvolver<const DEGREE: usize> {
+    bits: u32,
+    contexts: Vec<Arc<NttContext<DEGREE>>>,
+    /// `garner[i]` is `(q_0·…·q_(i-1))^(-1) mod q_i`, with `garner[0]` unused
+    garner: Vec<u64>,
+}
+
+impl<const DEGREE: usize> ExactConvolver<DEGREE> {
+    /// Largest supported bound, so the product of the moduli fits a `u128`
+    pub const MAX_BITS: u32 = 124;
+
+    /// Convolver for results with absolute value below `2^bits`.
+    ///
+    /// Uses one 62-bit prime up to 60 bits, two primes up to 122 bits and
+    /// three 42-bit primes beyond, so that the product `M` of the moduli
+    /// exceeds `2^(bits+1)` and signed results can be centered.
+    ///
+    /// # Panics
+    /// * If `bits` is not in `1..=MAX_BITS`
+    pub fn new(bits: u32) -> Self {
+        assert!(
+            (1..=Self::MAX_BITS).contains(&bits),
+            "bits must be in 1..={}, got {bits}",
+            Self::MAX_BITS
+        );
+
+        let count = (bits + 2).div_ceil(62);
+        let prime_bits = if count == 1 {
+            62
+        } else {
+            (bits + 2).div_ceil(count)
+        };
+
+        let mut moduli = vec![find_first_prime_down(prime_bits as usize, DEGREE)];
+        while moduli.len() < count as usize {
+            let last = *moduli.last().unwrap();
+            moduli.push(find_next_prime_down(last, DEGREE));
+        }
+
+        let contexts: Vec<_> = moduli
+            .iter()
+            .map(|&q| NttContext::<DEGREE>::new(q))
+            .collect();
+
+        let mut garner = vec![0];
+        for i in 1..moduli.len() {
+            let class = contexts[i].class();
+            let prefix = moduli[..i]
+                .iter()
+                .fold(1, |acc, &m| class.modmul(acc, m % moduli[i]));
+            garner.push(class.modinv(prefix));
+        }
+
+        Self {
+            bits,
+            contexts,
+            garner,
+        }
+    }
+
+    /// Bound the convolver was built for
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Moduli of the underlying contexts
+    pub fn moduli(&self) -> Vec<u64> {
+        self.contexts.iter().map(|ctx| ctx.modulus()).collect()
+    }
+
+    /// Exact product of two non-negative integer polynomials, of length
+    /// `a.len() + b.len() - 1` (empty if either input is).
+    ///
+    /// # Panics
+    /// * If an input is longer than `DEGREE`, or the coefficient bound
+    ///   `max|a|·max|b|·min(len)` reaches `2^bits`
+    pub fn convolve_u64(&self, a: &[u64], b: &[u64]) -> Vec<u128> {
+        self.check_bound(a, b, |&x| x);
+        let residues = |v: &[u64], q: u64| v.iter().map(|&x| x % q).collect();
+        self.convolve_residues(a.len(), b.len(), |q| {
+            (residues(a, q), residues(b, q))
+        })
+    }
+
+    /// Exact product of two signed integer polynomials, see
+    /// [`convolve_u64`](Self::convolve_u64).
+    pub fn convolve_i64(&self, a: &[i64], b: &[i64]) -> Vec<i128> {
+        self.check_bound(a, b, |x| x.unsigned_abs());
+        let residues = |v: &[i64], q: u64| -> Vec<u64> {
+            v.iter()
+                .map(|&x| {
+                    let r = x.unsigned_abs() % q;
+                    if x < 0 && r != 0 { q - r } else { r }
+                })
+                .collect()
+        };
+
+        let modulus: u128 = self
+            .contexts
+            .iter()
+            .map(|ctx| ctx.modulus() as u128)
+            .product();
+        self.convolve_residues(a.len(), b.len(), |q| {
+            (residues(a, q), residues(b, q))
+        })
+        .into_iter()
+        .map(|x| {
+            if x > modulus / 2 {
+                -((modulus - x) as i128)
+            } else {
+                x as i128
+            }
+        })
+        .collect()
+    }
+
+    fn check_bound<T>(&self, a: &[T], b: &[T], magnitude: impl Fn(&T) -> u64) {
+        assert!(
+            a.len() <= DEGREE && b.len() <= DEGREE,
+            "inputs must have at most {DEGREE} coefficients"
+        );
+        let max = |v: &[T]| v.iter().map(&magnitude).max().unwrap_or(0) as u128;
+        let bound = max(a)
+            .checked_mul(max(b))
+            .and_then(|p| p.checked_mul(a.len().min(b.len()) as u128));
+        assert!(
+            bound.is_some_and(|bound| bound < 1 << self.bits),
+            "product coefficients may reach 2^{}",
+            self.bits
+        );
+    }
+
+    /// Linear convolution modulo every prime, recombined into `[0, M)`;
+    /// `reduce(q)` gives both operands modulo `q`
+    fn convolve_residues(
+        &self,
+        a_len: usize,
+        b_len: usize,
+        reduce: impl Fn(u64) -> (Vec<u64>, Vec<u64>),
+    ) -> Vec<u128> {
+        if a_len == 0 || b_len == 0 {
+            return Vec::new();
+        }
+        let len = a_len + b_len - 1;
+
+        let residues: Vec<Vec<u64>> = self
+            .contexts
+            .iter()
+            .map(|ctx| {
+                let (a, b) = reduce(ctx.modulus());
+                let a = NttPolynomial::from_short_coeffs(&a, Arc::clone(ctx));
+                let b = NttPolynomial::from_short_coeffs(&b, Arc::clone(ctx));
+                a.linear_convolution(&b)
+            })
+            .collect();
+
+        (0..len).map(|j| self.garner(&residues, j)).collect()
+    }
+
+    /// Garner's mixed-radix CRT of coefficient `j`
+    fn garner(&self, residues: &[Vec<u64>], j: usize) -> u128 {
+        let mut x = residues[0][j] as u128;
+        let mut prefix = self.contexts[0].modulus() as u128;
+
+        for (i, ctx) in self.contexts.iter().enumerate().skip(1) {
+            let (class, q) = (ctx.class(), ctx.modulus());
+            let diff = class.modsub(residues[i][j], (x % q as u128) as u64);
+            let t = class.modmul(diff, self.garner[i]);
+            x += prefix * t as u128;
+            prefix *= q as u128;
+        }
+
+        x
+    }
+}
+
+/// Exact linear convolution of two byte sequences, of length
+/// `a.len() + b.len() - 1` (empty if either input is).
+///
+/// Typical uses are digit products of big numbers and, with one input
+/// reversed, sliding-window sums such as wildcard pattern matching.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::exact::convolve_u8;
+///
+/// // Little-endian digits: 12 · 34 = 8 + 10·10 + 3·100 = 408
+/// assert_eq!(convolve_u8(&[2, 1], &[4, 3]), vec![8, 10, 3]);
+/// ```
+pub fn convolve_u8(a: &[u8], b: &[u8]) -> Vec<u64> {
+    convolve_bounded(&widen(a), &widen(b), u8::MAX as u64)
+}
+
+/// Exact linear convolution of two `u16` sequences, see [`convolve_u8`].
+pub fn convolve_u16(a: &[u16], b: &[u16]) -> Vec<u64> {
+    convolve_bounded(&widen(a), &widen(b), u16::MAX as u64)
+}
+
+fn widen<T: Copy + Into<u64>>(v: &[T]) -> Vec<u64> {
+    v.iter().map(|&x| x.into()).collect()
+}
+
+/// Convolution of inputs bounded by `max`, modulo one prime that exceeds
+/// every possible result coefficient and supports the transform length
+fn convolve_bounded(a: &[u64], b: &[u64], max: u64) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    let len = (a.len() + b.len() - 1).next_power_of_two();
+    let bound = (max as u128 * max as u128) * a.len().min(b.len()) as u128;
+    let bound_bits = 128 - bound.leading_zeros();
+    let logq = bound_bits.max(len.trailing_zeros() + 1) as usize;
+    assert!(
+        logq <= 61,
+        "sequences too long for an exact result below 2^62"
+    );
+
+    let class = CongruenceClass::new(find_first_prime_up(logq, len));
+    polyalg::mul(a, b, &class)
+}
@@ -0,0 +1,223 @@
+//! Quadratic extension fields `F_{q^2} = F_q[α]/(α^2 - β)`.
+//!
+//! Elements are written `c0 + c1·α` with `β` a quadratic non-residue modulo
+//! the odd prime `q`, so `α^2 - β` is irreducible. The multiplicative group
+//! of `F_{q^2}` has order `q^2 - 1 = (q - 1)(q + 1)`, which supplies roots of
+//! unity (in particular power-of-two ones) that `F_q` itself lacks when
+//! `q - 1` has too few factors.
+use crate::congruence::CongruenceClass;
+
+/// Element `c0 + c1·α` of a quadratic extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Fq2Element {
+    pub c0: u64,
+    pub c1: u64,
+}
+
+impl Fq2Element {
+    pub const fn new(c0: u64, c1: u64) -> Self {
+        Self { c0, c1 }
+    }
+}
+
+/// Arithmetic context for `F_{q^2}`, in the style of [`CongruenceClass`].
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::extension::{Fq2, Fq2Element};
+///
+/// // 7 ≡ 3 mod 4, so -1 is a non-residue and α = i
+/// let field = Fq2::with_non_residue(7, 6);
+/// let i = Fq2Element::new(0, 1);
+/// assert_eq!(field.mul(i, i), Fq2Element::new(6, 0));
+///
+/// let z = Fq2Element::new(3, 5);
+/// assert_eq!(field.mul(z, field.inv(z)), field.one());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Fq2 {
+    class: CongruenceClass,
+    non_residue: u64, // β = α^2
+}
+
+impl Fq2 {
+    /// Build `F_{q^2}` using the smallest quadratic non-residue modulo `q`.
+    ///
+    /// # Panics
+    /// * If `q` is not an odd prime (checked through Euler's criterion only)
+    pub fn new(q: u64) -> Self {
+        assert!(
+            q >= 3 && q % 2 == 1,
+            "modulus must be an odd prime, got {q}"
+        );
+
+        let class = CongruenceClass::new(q);
+        let non_residue = (2..q)
+            .find(|&x| class.modexp(x, (q - 1) / 2) == q - 1)
+            .expect("every odd prime has a quadratic non-residue");
+
+        Self { class, non_residue }
+    }
+
+    /// Build `F_{q^2}` with a caller-chosen non-residue `β`.
+    ///
+    /// # Panics
+    /// * If `β` is a quadratic residue modulo `q`
+    pub fn with_non_residue(q: u64, non_residue: u64) -> Self {
+        assert!(
+            q >= 3 && q % 2 == 1,
+            "modulus must be an odd prime, got {q}"
+        );
+
+        let class = CongruenceClass::new(q);
+        assert_eq!(
+            class.modexp(non_residue % q, (q - 1) / 2),
+            q - 1,
+            "{non_residue} is not a quadratic non-residue modulo {q}"
+        );
+
+        Self {
+            class,
+            non_residue: non_residue % q,
+        }
+    }
+
+    pub fn class(&self) -> &CongruenceClass {
+        &self.class
+    }
+
+    pub fn non_residue(&self) -> u64 {
+        self.non_residue
+    }
+
+    pub fn zero(&self) -> Fq2Element {
+        Fq2Element::new(0, 0)
+    }
+
+    pub fn one(&self) -> Fq2Element {
+        Fq2Element::new(1, 0)
+    }
+
+    /// Embed a base-field element
+    pub fn from_base(&self, c0: u64) -> Fq2Element {
+        Fq2Element::new(c0, 0)
+    }
+
+    pub fn add(&self, x: Fq2Element, y: Fq2Element) -> Fq2Element {
+        let c = &self.class;
+        Fq2Element::new(c.modadd(x.c0, y.c0), c.modadd(x.c1, y.c1))
+    }
+
+    pub fn sub(&self, x: Fq2Element, y: Fq2Element) -> Fq2Element {
+        let c = &self.class;
+        Fq2Element::new(c.modsub(x.c0, y.c0), c.modsub(x.c1, y.c1))
+    }
+
+    pub fn neg(&self, x: Fq2Element) -> Fq2Element {
+        let c = &self.class;
+        Fq2Element::new(c.modneg(x.c0), c.modneg(x.c1))
+    }
+
+    /// Karatsuba product: three base-field multiplications plus one by β
+    pub fn mul(&self, x: Fq2Element, y: Fq2Element) -> Fq2Element {
+        let c = &self.class;
+
+        let v0 = c.modmul(x.c0, y.c0);
+        let v1 = c.modmul(x.c1, y.c1);
+        let cross = c.modmul(c.modadd(x.c0, x.c1), c.modadd(y.c0, y.c1));
+
+        Fq2Element::new(
+            c.modadd(v0, c.modmul(self.non_residue, v1)),
+            c.modsub(c.modsub(cross, v0), v1),
+        )
+    }
+
+    pub fn square(&self, x: Fq2Element) -> Fq2Element {
+        self.mul(x, x)
+    }
+
+    /// Multiply by a base-field scalar
+    pub fn scale(&self, x: Fq2Element, s: u64) -> Fq2Element {
+        let c = &self.class;
+        Fq2Element::new(c.modmul(x.c0, s), c.modmul(x.c1, s))
+    }
+
+    /// Conjugate `c0 - c1·α`
+    pub fn conjugate(&self, x: Fq2Element) -> Fq2Element {
+        Fq2Element::new(x.c0, self.class.modneg(x.c1))
+    }
+
+    /// Frobenius map `x ↦ x^q`.
+    ///
+    /// Since `α^q = α·β^((q-1)/2) = -α`, this is the conjugation.
+    pub fn frobenius(&self, x: Fq2Element) -> Fq2Element {
+        self.conjugate(x)
+    }
+
+    /// Norm `x·x^q = c0^2 - β·c1^2`, an element of `F_q`
+    pub fn norm(&self, x: Fq2Element) -> u64 {
+        let c = &self.class;
+        c.modsub(
+            c.modmul(x.c0, x.c0),
+            c.modmul(self.non_residue, c.modmul(x.c1, x.c1)),
+        )
+    }
+
+    /// Multiplicative inverse `conj(x) / norm(x)`.
+    ///
+    /// # Panics
+    /// * If `x` is zero
+    pub fn inv(&self, x: Fq2Element) -> Fq2Element {
+        let norm = self.norm(x);
+        assert_ne!(norm, 0, "zero has no inverse");
+        self.scale(self.conjugate(x), self.class.modinv(norm))
+    }
+
+    /// `x^e`; the exponent is a `u128` since the group order is `q^2 - 1`
+    pub fn pow(&self, x: Fq2Element, mut e: u128) -> Fq2Element {
+        let mut base = x;
+        let mut acc = self.one();
+
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = self.mul(acc, base);
+            }
+            base = self.square(base);
+            e >>= 1;
+        }
+
+        acc
+    }
+
+    /// Order `q^2 - 1` of the multiplicative group
+    pub fn group_order(&self) -> u128 {
+        let q = self.class.q() as u128;
+        q * q - 1
+    }
+
+    /// Primitive `order`-th root of unity for a power of two `order`.
+    ///
+    /// The 2-part of `q^2 - 1` is at least twice that of `q - 1`, so this
+    /// at least doubles the transform length available over `F_q`.
+    ///
+    /// # Panics
+    /// * If `order` is not a power of two dividing `q^2 - 1`
+    pub fn two_adic_root(&self, order: u64) -> Fq2Element {
+        let group = self.group_order();
+        assert!(
+            order.is_power_of_two()
+                && group.trailing_zeros() >= order.trailing_zeros(),
+            "no root of unity of order {order} in F_q^2"
+        );
+
+        // A non-square carries the full 2-part of the group order
+        let half = group / 2;
+        let minus_one = self.neg(self.one());
+        let non_square = (0..self.class.q())
+            .flat_map(|c0| (1..3).map(move |c1| Fq2Element::new(c0, c1)))
+            .find(|&x| self.pow(x, half) == minus_one)
+            .expect("F_q^2 has non-squares");
+
+        self.pow(non_square, group / order as u128)
+    }
+}
@@ -0,0 +1,123 @@
+//! Sample extraction and repacking.
+//!
+//! The `i`-th coefficient of a product `a·s` in `Z_q[x]/(x^N + 1)` is a
+//! linear form in the coefficients of `s`. [`NttPolynomial::extract_coefficient`]
+//! returns that form, which turns an RLWE sample `(a, b = a·s + e)` into an
+//! LWE sample `(extract(a, i), b_i)` under the same key coefficients.
+//! [`NttPolynomial::mul_monomial`] and [`NttPolynomial::repack`] go the
+//! other way, moving selected coefficients into place in a ring element.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::*;
+//! use std::sync::Arc;
+//!
+//! let ctx = NttContext::<4>::new(17);
+//! let a = NttPolynomial::from_coeffs([1, 2, 3, 4], Arc::clone(&ctx));
+//! let s = NttPolynomial::from_coeffs([1, 0, 1, 0], ctx);
+//!
+//! // Coefficient 1 of a·s is ⟨extract(a, 1), s⟩
+//! let v = a.extract_coefficient(1);
+//! let dot = v.iter().zip(s.coeffs()).map(|(x, y)| x * y).sum::<u64>() % 17;
+//! assert_eq!(dot, (&a * &s).coeffs()[1]);
+//! ```
+use crate::context::boxed_zeros;
+use crate::counters::Op;
+use crate::ntt::NttPolynomial;
+use std::sync::Arc;
+
+impl<const DEGREE: usize> NttPolynomial<DEGREE> {
+    /// Vector `v` with `(self·s)_i = Σ_j v_j·s_j` for every `s`: `v_j` is
+    /// `a_(i-j)` for `j ≤ i` and `-a_(N+i-j)` past it, the wrapped terms
+    /// picking up the sign of `x^N = -1`.
+    ///
+    /// # Panics
+    /// * If `i >= DEGREE`
+    pub fn extract_coefficient(&self, i: usize) -> [u64; DEGREE] {
+        assert!(
+            i < DEGREE,
+            "coefficient {i} out of range for degree {DEGREE}"
+        );
+        let class = self.context().class();
+        let a = self.coeffs();
+
+        std::array::from_fn(|j| {
+            if j <= i {
+                a[i - j]
+            } else {
+                class.modneg(a[DEGREE + i - j])
+            }
+        })
+    }
+
+    /// `x^k·self`, a negacyclic rotation: coefficients move up by `k`
+    /// (down for negative `k`), changing sign each time they wrap past
+    /// `x^N`. Costs no multiplications.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let a = NttPolynomial::from_coeffs([1, 2, 3, 4], NttContext::<4>::new(17));
+    /// assert_eq!(a.mul_monomial(1).coeffs(), &[13, 1, 2, 3]);
+    /// assert_eq!(a.mul_monomial(-1).coeffs(), &[2, 3, 4, 16]);
+    /// ```
+    pub fn mul_monomial(&self, k: isize) -> Self {
+        let class = self.context().class();
+        // x^(2N) = 1, and x^N = -1 flips every sign
+        let k = k.rem_euclid(2 * DEGREE as isize) as usize;
+        let (negate, shift) = (k >= DEGREE, k % DEGREE);
+
+        self.context().count(Op::ModAdd, DEGREE as u64);
+        let mut coeffs = boxed_zeros::<DEGREE>();
+        for (j, &c) in self.coeffs().iter().enumerate() {
+            let wraps = j + shift >= DEGREE;
+            coeffs[(j + shift) % DEGREE] =
+                if negate != wraps { class.modneg(c) } else { c };
+        }
+
+        NttPolynomial::from_boxed_coeffs(coeffs, Arc::clone(self.context()))
+    }
+
+    /// Ring element `Σ_k p_k[i_k]·x^k` holding coefficient `i_k` of the
+    /// `k`-th entry `(p_k, i_k)` at position `k`, e.g. to gather the
+    /// constant terms of several products into one polynomial.
+    ///
+    /// # Panics
+    /// * If `entries` is empty or longer than `DEGREE`, an index is out of
+    ///   range, or the entries have different moduli
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use std::sync::Arc;
+    ///
+    /// let ctx = NttContext::<4>::new(17);
+    /// let a = NttPolynomial::from_coeffs([1, 2, 3, 4], Arc::clone(&ctx));
+    /// let b = NttPolynomial::from_coeffs([5, 6, 7, 8], ctx);
+    /// let packed = NttPolynomial::repack(&[(&a, 3), (&b, 0), (&a, 0)]);
+    /// assert_eq!(packed.coeffs(), &[4, 5, 1, 0]);
+    /// ```
+    pub fn repack(entries: &[(&Self, usize)]) -> Self {
+        let (first, _) = entries.first().expect("nothing to repack");
+        assert!(
+            entries.len() <= DEGREE,
+            "cannot repack {} coefficients into degree {DEGREE}",
+            entries.len()
+        );
+
+        let mut coeffs = boxed_zeros::<DEGREE>();
+        for (k, &(poly, i)) in entries.iter().enumerate() {
+            if let Err(e) = first.context().check_compatible(poly.context()) {
+                panic!("Cannot repack polynomials: {e}");
+            }
+            assert!(
+                i < DEGREE,
+                "coefficient {i} out of range for degree {DEGREE}"
+            );
+            coeffs[k] = poly.coeffs()[i];
+        }
+
+        NttPolynomial::from_boxed_coeffs(coeffs, Arc::clone(first.context()))
+    }
+}
@@ -0,0 +1,151 @@
+//! Memory used by precomputed contexts.
+//!
+//! An [`NttContext`] keeps its twiddle, Shoup and coset tables in boxed
+//! arrays, each `8·N` bytes, and grows further as automorphism maps, the linear
+//! convolution, cyclic, twisted and four-step plans and the Montgomery tables
+//! are built on first use. [`NttContext::memory_footprint`]
+//! reports each of these separately, and [`chain_footprint`] sums them over
+//! the contexts of an RNS chain, for budgeting deployments that hold many
+//! contexts or run on small devices.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::footprint::chain_footprint;
+//! use rust_ntt::params::FHE_4096;
+//!
+//! let chain = FHE_4096.contexts();
+//! let report = chain_footprint(&chain);
+//! // Six tables of 4096 words per context, plus a few scalars
+//! assert!(report.total() > 3 * 6 * 4096 * 8);
+//! println!("{report}");
+//! ```
+use crate::context::NttContext;
+use std::fmt;
+use std::sync::Arc;
+
+/// Bytes used by one context, per table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryFootprint {
+    pub modulus: u64,
+    pub degree: usize,
+    /// Size of each table, named after the context field that holds it;
+    /// lazily built tables count zero until first use
+    pub tables: Vec<(&'static str, usize)>,
+    /// Rest of the context: reduction constants, cache and counter headers
+    pub overhead: usize,
+}
+
+impl MemoryFootprint {
+    /// Bytes used by the context, tables included
+    pub fn total(&self) -> usize {
+        self.overhead + self.tables.iter().map(|&(_, bytes)| bytes).sum::<usize>()
+    }
+}
+
+impl fmt::Display for MemoryFootprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "q = {}, N = {}", self.modulus, self.degree)?;
+        for (name, bytes) in &self.tables {
+            writeln!(f, "  {name:<18} {bytes:>10} B")?;
+        }
+        writeln!(f, "  {:<18} {:>10} B", "overhead", self.overhead)?;
+        write!(f, "  {:<18} {:>10} B", "total", self.total())
+    }
+}
+
+/// Bytes used by every context of an RNS chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainFootprint {
+    pub contexts: Vec<MemoryFootprint>,
+}
+
+impl ChainFootprint {
+    pub fn total(&self) -> usize {
+        self.contexts.iter().map(MemoryFootprint::total).sum()
+    }
+}
+
+impl fmt::Display for ChainFootprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for context in &self.contexts {
+            writeln!(f, "{context}")?;
+        }
+        write!(
+            f,
+            "{} contexts, {} B in total",
+            self.contexts.len(),
+            self.total()
+        )
+    }
+}
+
+impl<const DEGREE: usize> NttContext<DEGREE> {
+    /// Bytes used by this context and each of its precomputed tables.
+    ///
+    /// The automorphism maps, the linear, cyclic, twisted and four-step plans
+    /// and the Montgomery tables are counted as currently built, so the figure grows after
+    /// their first use.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let ctx = NttContext::<1024>::new(find_first_prime_up(30, 1024));
+    /// let footprint = ctx.memory_footprint();
+    /// assert!(footprint.tables.contains(&("tf_shoup", 8 * 1024)));
+    /// assert_eq!(footprint.total(), size_of::<NttContext<1024>>() + 6 * 8 * 1024);
+    /// ```
+    pub fn memory_footprint(&self) -> MemoryFootprint {
+        let table = size_of::<[u64; DEGREE]>();
+        let boxed = [
+            "tf",
+            "itf",
+            "tf_shoup",
+            "itf_shoup",
+            "coset_powers",
+            "coset_inv_powers",
+        ];
+
+        let mut tables: Vec<_> = boxed.iter().map(|&name| (name, table)).collect();
+        tables.push(("galois", self.galois.heap_bytes()));
+        tables.push((
+            "linear",
+            self.linear.get().map_or(0, |plan| plan.heap_bytes()),
+        ));
+        tables.push((
+            "cyclic",
+            self.cyclic.get().map_or(0, |plan| plan.heap_bytes()),
+        ));
+        tables.push((
+            "twisted",
+            self.twisted.get().map_or(0, |plan| plan.heap_bytes()),
+        ));
+        tables.push((
+            "montgomery",
+            self.montgomery
+                .get()
+                .map_or(0, |tables| tables.heap_bytes()),
+        ));
+        tables.push((
+            "four_step",
+            self.four_step.get().map_or(0, |plan| plan.heap_bytes()),
+        ));
+
+        MemoryFootprint {
+            modulus: self.modulus(),
+            degree: DEGREE,
+            tables,
+            overhead: size_of::<Self>(),
+        }
+    }
+}
+
+/// Footprint of each context of an RNS chain, as built by
+/// [`FheProfile::contexts`](crate::params::FheProfile::contexts)
+pub fn chain_footprint<const DEGREE: usize>(
+    contexts: &[Arc<NttContext<DEGREE>>],
+) -> ChainFootprint {
+    ChainFootprint {
+        contexts: contexts.iter().map(|ctx| ctx.memory_footprint()).collect(),
+    }
+}
@@ -0,0 +1,220 @@
+//! Four-step NTT for large degrees.
+//!
+//! Each stage of the radix-2 transforms sweeps the whole coefficient array,
+//! and once `N` words no longer fit in cache every stage with a large
+//! butterfly span misses on most accesses. The four-step decomposition
+//! instead views the coefficients as an `N1 × N2` matrix and performs
+//!
+//! 1. `N2` transforms of length `N1`, one down each column,
+//! 2. a multiplication by the twiddles `ω^(n2·k1)`,
+//! 3. `N1` transforms of length `N2`, one along each row,
+//!
+//! with tiled transposes in between so that every small transform runs on
+//! contiguous, cache-resident memory. The negacyclic weighting by `ψ^i` is
+//! applied up front, as in the twisted NTT, and the bit-reversed outputs of
+//! the small transforms line up so that the result matches
+//! [`NttPolynomial::ntt_forward`] slot for slot, with no final permutation.
+//!
+//! The radix-2 transforms remain faster while the context fits in cache;
+//! the `four-step crossover` benchmark in `benches/poly.rs` compares both
+//! from `N = 2^12` to `2^18`. The tables, `4·N` words, are built on first
+//! use.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::*;
+//! use std::sync::Arc;
+//!
+//! let ctx = NttContext::<64>::new(find_first_prime_up(30, 64));
+//! let a = NttPolynomial::from_coeffs(std::array::from_fn(|i| i as u64), ctx);
+//!
+//! let mut expected = a.clone();
+//! expected.ntt_forward();
+//! let mut evals = a.clone();
+//! evals.ntt_forward_four_step();
+//! assert_eq!(evals.coeffs(), expected.coeffs());
+//!
+//! evals.ntt_inverse_four_step();
+//! assert_eq!(evals.coeffs(), a.coeffs());
+//! ```
+use crate::congruence::CongruenceClass;
+use crate::context::{NttContext, bit_reverse};
+use crate::counters::Op;
+use crate::ntt::NttPolynomial;
+use crate::polyalg::CyclicPlan;
+use std::sync::Arc;
+
+/// Side of the square tiles moved at a time by the transposes
+const TILE: usize = 16;
+
+/// Tables for the four-step transform of one length
+#[derive(Debug, Clone)]
+pub(crate) struct FourStepPlan {
+    class: CongruenceClass,
+    /// Rows `N1`, the largest power of two not above `√N`
+    rows: usize,
+    /// Columns `N2 = N / N1`
+    cols: usize,
+    /// Length-`N1` transform down the columns, with root `ω^N2`
+    column: CyclicPlan,
+    /// Length-`N2` transform along the rows, with root `ω^N1`
+    row: CyclicPlan,
+    /// `ψ^i` for `i < N`
+    twist: Vec<u64>,
+    /// `ψ^(-i)` for `i < N`
+    untwist: Vec<u64>,
+    /// `ω^(n2·k1)` at `n2·N1 + p`, where column slot `p` holds `k1 = bitrev(p)`
+    twiddles: Vec<u64>,
+    /// `ω^(-n2·k1)` in the same layout
+    inv_twiddles: Vec<u64>,
+}
+
+impl FourStepPlan {
+    /// Plan a negacyclic transform of length `len` from a primitive
+    /// `2·len`-th root of unity `psi`
+    fn new(class: &CongruenceClass, len: usize, psi: u64) -> Self {
+        let log_rows = len.trailing_zeros() as usize / 2;
+        let (rows, cols) = (1 << log_rows, len >> log_rows);
+        let omega = class.modsquare(psi);
+
+        let powers = |base: u64, count: usize| {
+            let mut acc = 1u64;
+            (0..count).map(move |_| {
+                let power = acc;
+                acc = class.modmul(acc, base);
+                power
+            })
+        };
+        let twiddles = |root: u64| {
+            let mut table = vec![0; len];
+            for (n2, base) in powers(root, cols).enumerate() {
+                for (k1, w) in powers(base, rows).enumerate() {
+                    table[n2 * rows + bit_reverse(k1, log_rows)] = w;
+                }
+            }
+            table
+        };
+        let omega_inv = class.modinv(omega);
+
+        Self {
+            class: *class,
+            rows,
+            cols,
+            column: CyclicPlan::with_root(
+                class,
+                rows,
+                class.modexp(omega, cols as u64),
+            ),
+            row: CyclicPlan::with_root(
+                class,
+                cols,
+                class.modexp(omega, rows as u64),
+            ),
+            twist: powers(psi, len).collect(),
+            untwist: powers(class.modinv(psi), len).collect(),
+            twiddles: twiddles(omega),
+            inv_twiddles: twiddles(omega_inv),
+        }
+    }
+
+    /// Bytes held by the weight, twiddle and root tables
+    pub(crate) fn heap_bytes(&self) -> usize {
+        let tables = self.twist.len()
+            + self.untwist.len()
+            + self.twiddles.len()
+            + self.inv_twiddles.len();
+        tables * size_of::<u64>() + self.column.heap_bytes() + self.row.heap_bytes()
+    }
+
+    /// Forward transform in place, in the order of the radix-2 NTT
+    fn forward(&self, coeffs: &mut [u64]) {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut scratch = vec![0; coeffs.len()];
+
+        for (x, &w) in coeffs.iter_mut().zip(&self.twist) {
+            self.class.modmul_eq(x, w);
+        }
+        transpose(coeffs, &mut scratch, rows, cols);
+
+        let columns = scratch.chunks_exact_mut(rows);
+        for (column, twiddles) in columns.zip(self.twiddles.chunks_exact(rows)) {
+            self.column.forward(column);
+            for (x, &w) in column.iter_mut().zip(twiddles) {
+                self.class.modmul_eq(x, w);
+            }
+        }
+        transpose(&scratch, coeffs, cols, rows);
+
+        for row in coeffs.chunks_exact_mut(cols) {
+            self.row.forward(row);
+        }
+    }
+
+    /// Inverse of [`forward`](Self::forward), the same steps undone in
+    /// reverse order; the small inverses scale by `1/N2` and `1/N1`
+    fn inverse(&self, coeffs: &mut [u64]) {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut scratch = vec![0; coeffs.len()];
+
+        for row in coeffs.chunks_exact_mut(cols) {
+            self.row.inverse(row);
+        }
+        transpose(coeffs, &mut scratch, rows, cols);
+
+        let columns = scratch.chunks_exact_mut(rows);
+        for (column, twiddles) in columns.zip(self.inv_twiddles.chunks_exact(rows))
+        {
+            for (x, &w) in column.iter_mut().zip(twiddles) {
+                self.class.modmul_eq(x, w);
+            }
+            self.column.inverse(column);
+        }
+        transpose(&scratch, coeffs, cols, rows);
+
+        for (x, &w) in coeffs.iter_mut().zip(&self.untwist) {
+            self.class.modmul_eq(x, w);
+        }
+    }
+}
+
+/// Write the transpose of `src`, a `rows × cols` matrix in row-major
+/// order, to `dst`, one tile at a time so both sides stay in cache
+fn transpose(src: &[u64], dst: &mut [u64], rows: usize, cols: usize) {
+    debug_assert!(src.len() == rows * cols && dst.len() == rows * cols);
+
+    for r0 in (0..rows).step_by(TILE) {
+        for c0 in (0..cols).step_by(TILE) {
+            for r in r0..(r0 + TILE).min(rows) {
+                for c in c0..(c0 + TILE).min(cols) {
+                    dst[c * rows + r] = src[r * cols + c];
+                }
+            }
+        }
+    }
+}
+
+impl<const DEGREE: usize> NttContext<DEGREE> {
+    /// Four-step plan with the context's ψ, built on first use
+    pub(crate) fn four_step_plan(&self) -> &FourStepPlan {
+        self.four_step
+            .get_or_init(|| FourStepPlan::new(&self.class, DEGREE, self.psi()))
+    }
+}
+
+impl<const DEGREE: usize> NttPolynomial<DEGREE> {
+    /// Forward NTT by the four-step method, giving the same evaluations as
+    /// [`ntt_forward`](Self::ntt_forward) with fewer cache misses for large
+    /// degrees
+    pub fn ntt_forward_four_step(&mut self) {
+        let context = Arc::clone(self.context());
+        context.count_transform(Op::Forward);
+        context.four_step_plan().forward(&mut self.coeffs_mut()[..]);
+    }
+
+    /// Inverse of [`ntt_forward_four_step`](Self::ntt_forward_four_step)
+    pub fn ntt_inverse_four_step(&mut self) {
+        let context = Arc::clone(self.context());
+        context.count_transform(Op::Inverse);
+        context.four_step_plan().inverse(&mut self.coeffs_mut()[..]);
+    }
+}
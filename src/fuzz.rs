@@ -0,0 +1,98 @@
+//! `arbitrary::Arbitrary` inputs for structured fuzzing.
+//!
+//! Fuzzers hand out raw bytes; these types turn them into inputs that satisfy
+//! the crate's preconditions, so every run exercises the transforms rather
+//! than tripping a constructor assertion:
+//! - [`SmallContext`] builds a context for an NTT-friendly prime between
+//!   2^17 and 2^60, chosen among the first few primes above a random bit size.
+//! - `NttPolynomial<N>` draws its own context and coefficients in `[0, q)`.
+//! - [`PolyPair`] draws two polynomials over one shared context, which is
+//!   what the convolution kernels need.
+//!
+//! `DEGREE` must be a power of two, as for [`NttContext::new`].
+use crate::context::NttContext;
+use crate::math::{find_first_prime_up, find_next_prime_up};
+use crate::ntt::NttPolynomial;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use std::sync::Arc;
+
+/// Smallest and largest bit sizes for fuzzed moduli
+const MIN_BITS: usize = 17;
+const MAX_BITS: usize = 60;
+
+/// How many primes past the first one a fuzzed modulus may skip
+const MAX_PRIME_SKIP: usize = 3;
+
+/// Valid context with a fuzzer-chosen modulus.
+#[derive(Debug, Clone)]
+pub struct SmallContext<const DEGREE: usize>(pub Arc<NttContext<DEGREE>>);
+
+impl<'a, const DEGREE: usize> Arbitrary<'a> for SmallContext<DEGREE> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // q ≡ 1 (mod 2N) needs 2^bits > 2N
+        let min_bits = MIN_BITS.max(DEGREE.trailing_zeros() as usize + 2);
+        let bits = u.int_in_range(min_bits..=MAX_BITS)?;
+        let skip = u.int_in_range(0..=MAX_PRIME_SKIP)?;
+
+        let mut q = find_first_prime_up(bits, DEGREE);
+        for _ in 0..skip {
+            q = find_next_prime_up(q, DEGREE);
+        }
+
+        Ok(Self(NttContext::new(q)))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (2, Some(2))
+    }
+}
+
+fn arbitrary_coeffs<const DEGREE: usize>(
+    u: &mut Unstructured<'_>,
+    q: u64,
+) -> Result<[u64; DEGREE]> {
+    let mut coeffs = [0u64; DEGREE];
+    for coeff in &mut coeffs {
+        *coeff = u.int_in_range(0..=q - 1)?;
+    }
+    Ok(coeffs)
+}
+
+impl<'a, const DEGREE: usize> Arbitrary<'a> for NttPolynomial<DEGREE> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let SmallContext(context) = SmallContext::arbitrary(u)?;
+        let coeffs = arbitrary_coeffs(u, context.modulus())?;
+        Ok(NttPolynomial::from_coeffs(coeffs, context))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        let (lo, _) = SmallContext::<DEGREE>::size_hint(depth);
+        (lo + DEGREE, Some(lo + 8 * DEGREE))
+    }
+}
+
+/// Two polynomials over the same fuzzer-chosen context.
+#[derive(Debug, Clone)]
+pub struct PolyPair<const DEGREE: usize>(
+    pub NttPolynomial<DEGREE>,
+    pub NttPolynomial<DEGREE>,
+);
+
+impl<'a, const DEGREE: usize> Arbitrary<'a> for PolyPair<DEGREE> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let SmallContext(context) = SmallContext::arbitrary(u)?;
+        let q = context.modulus();
+        let a = arbitrary_coeffs(u, q)?;
+        let b = arbitrary_coeffs(u, q)?;
+
+        Ok(Self(
+            NttPolynomial::from_coeffs(a, Arc::clone(&context)),
+            NttPolynomial::from_coeffs(b, context),
+        ))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        let (lo, _) = SmallContext::<DEGREE>::size_hint(depth);
+        (lo + 2 * DEGREE, Some(lo + 16 * DEGREE))
+    }
+}
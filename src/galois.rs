@@ -0,0 +1,126 @@
+//! Galois elements for slot rotations and conjugation.
+//!
+//! The automorphisms of `Z_q[x]/(x^N + 1)` are `x -> x^k` for odd `k`. The
+//! group `Z_2N^*` is generated by 5 (order N/2) together with -1, so slot
+//! rotations by `r` correspond to `k = 5^r mod 2N` and complex conjugation
+//! of the slots to `k = 2N - 1`.
+//!
+//! Index maps for the automorphisms are cached in the [`NttContext`], see
+//! `NttContext::automorphism_table`.
+//!
+//! [`NttContext`]: crate::context::NttContext
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Shared index map for an automorphism, see [`automorphism_index_map`]
+pub type AutomorphismTable = Arc<[(usize, bool)]>;
+
+/// Generator of the rotation subgroup of `Z_2N^*`
+pub const ROTATION_GENERATOR: usize = 5;
+
+/// Galois element `5^r mod 2N` for a rotation by `rotation` slots.
+///
+/// Negative rotations rotate in the opposite direction; rotations are taken
+/// modulo the number of slots `N/2`. For `N = 1` there are no slots and
+/// every rotation is the identity, `k = 1`.
+///
+/// # Examples
+/// ```
+/// use rust_ntt::galois::galois_element;
+///
+/// assert_eq!(galois_element(0, 8), 1);
+/// assert_eq!(galois_element(1, 8), 5);
+/// assert_eq!(galois_element(2, 8), 9); // 25 mod 16
+/// assert_eq!(galois_element(-1, 8), 13); // 5 * 13 = 65 ≡ 1 (mod 16)
+/// ```
+pub fn galois_element(rotation: isize, degree: usize) -> usize {
+    assert!(
+        degree.is_power_of_two(),
+        "degree must be a power of 2, got {degree}"
+    );
+    if degree == 1 {
+        return 1;
+    }
+
+    let two_n = 2 * degree;
+    let slots = (degree / 2) as isize;
+    let steps = rotation.rem_euclid(slots.max(1)) as usize;
+
+    let mut k = 1usize;
+    for _ in 0..steps {
+        k = (k * ROTATION_GENERATOR) % two_n;
+    }
+    k
+}
+
+/// Galois element `2N - 1` mapping `x -> x^(-1)`, i.e. slot conjugation.
+pub fn conjugation_element(degree: usize) -> usize {
+    2 * degree - 1
+}
+
+/// Index map for `x -> x^k`: coefficient `i` moves to `table[i].0`, negated
+/// when `table[i].1` is set.
+pub fn automorphism_index_map(k: usize, degree: usize) -> Vec<(usize, bool)> {
+    assert!(k % 2 == 1, "automorphism exponent must be odd, got {k}");
+
+    let two_n = 2 * degree;
+    (0..degree)
+        .map(|i| {
+            let idx = (i * k) % two_n;
+            if idx < degree {
+                (idx, false)
+            } else {
+                (idx - degree, true)
+            }
+        })
+        .collect()
+}
+
+/// Per-context cache of automorphism index maps, keyed by `k mod 2N`.
+#[derive(Debug, Default)]
+pub(crate) struct GaloisCache {
+    tables: RwLock<HashMap<usize, AutomorphismTable>>,
+}
+
+impl GaloisCache {
+    pub(crate) fn get_or_insert(
+        &self,
+        k: usize,
+        degree: usize,
+    ) -> AutomorphismTable {
+        let k = k % (2 * degree);
+
+        if let Some(table) = self.tables.read().unwrap().get(&k) {
+            return Arc::clone(table);
+        }
+
+        let table: AutomorphismTable = automorphism_index_map(k, degree).into();
+        self.tables
+            .write()
+            .unwrap()
+            .entry(k)
+            .or_insert(table)
+            .clone()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.tables.read().unwrap().len()
+    }
+
+    /// Bytes held by the cached index maps
+    pub(crate) fn heap_bytes(&self) -> usize {
+        let tables = self.tables.read().unwrap();
+        tables
+            .values()
+            .map(|table| std::mem::size_of_val::<[(usize, bool)]>(table))
+            .sum()
+    }
+}
+
+impl Clone for GaloisCache {
+    fn clone(&self) -> Self {
+        Self {
+            tables: RwLock::new(self.tables.read().unwrap().clone()),
+        }
+    }
+}
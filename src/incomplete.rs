@@ -0,0 +1,240 @@
+//! Incomplete negacyclic NTT with base multiplication in small blocks.
+//!
+//! A complete NTT of length `N` needs a primitive `2N`-th root of unity, so
+//! `q ≡ 1 (mod 2N)`. Some moduli only go part of the way: Kyber's
+//! `q = 3329` has `q - 1 = 2^8·13`, enough for 7 of the 8 levels of a
+//! length-256 transform. Stopping after `L` levels splits
+//! `Z_q[x]/(x^N + 1)` into `2^L` rings `Z_q[x]/(x^B - γ_i)` of
+//! `B = N / 2^L` coefficients, with `γ_i = ζ^(2·bitrev(i)+1)` for a
+//! primitive `2^(L+1)`-th root `ζ`. Products are then computed block by
+//! block with [`IncompleteNttContext::basemul`]; for `L = log2(N)` the
+//! blocks are single slots and everything reduces to the usual NTT.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::NttContext;
+//!
+//! // Kyber: 7 levels, pairs of coefficients multiplied modulo x^2 - γ_i
+//! let ctx = NttContext::<256>::new_incomplete(3329, 7);
+//! assert_eq!(ctx.block_len(), 2);
+//!
+//! let mut a = [0u64; 256];
+//! let mut b = [0u64; 256];
+//! a[255] = 1;
+//! b[1] = 1;
+//! // x^255 · x = x^256 = -1
+//! let c = ctx.negacyclic_convolution(&a, &b);
+//! assert_eq!(c[0], 3328);
+//! assert!(c[1..].iter().all(|&x| x == 0));
+//! ```
+use crate::congruence::CongruenceClass;
+use crate::context::{NttContext, NttError, twiddle_table, validate_parameters};
+use crate::math::find_generator;
+use std::sync::Arc;
+
+/// Tables for a negacyclic NTT of length `DEGREE` stopped after a given
+/// number of levels.
+///
+/// Built by [`NttContext::new_incomplete`]. It is a type of its own since
+/// the modulus lacks the roots that the complete tables of an
+/// [`NttContext`] are made of.
+#[derive(Debug, Clone)]
+pub struct IncompleteNttContext<const DEGREE: usize> {
+    class: CongruenceClass,
+    levels: u32,
+    /// `ζ^bitrev(k)` for `k < 2^L` and their Shoup values
+    tf: Vec<u64>,
+    tf_shoup: Vec<u64>,
+    /// `ζ^(-bitrev(k))` for `k < 2^L` and their Shoup values
+    itf: Vec<u64>,
+    itf_shoup: Vec<u64>,
+    /// `γ_i = ζ^(2·bitrev(i)+1)`, the constant of block `i`
+    gammas: Vec<u64>,
+    /// `2^(-L)` and its Shoup value, scaling the inverse
+    inv_scale: (u64, u64),
+}
+
+impl<const DEGREE: usize> NttContext<DEGREE> {
+    /// Context for the NTT of length `DEGREE` stopped after `levels`
+    /// levels, needing only `q ≡ 1 (mod 2^(levels+1))`.
+    ///
+    /// # Panics
+    /// * If `levels > log2(DEGREE)`
+    /// * If the parameters admit no such transform; use
+    ///   [`try_new_incomplete`](Self::try_new_incomplete) for untrusted ones
+    pub fn new_incomplete(
+        q: u64,
+        levels: u32,
+    ) -> Arc<IncompleteNttContext<DEGREE>> {
+        Self::try_new_incomplete(q, levels).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// [`new_incomplete`](Self::new_incomplete), or why the parameters admit
+    /// no incomplete NTT. A missing root is reported as
+    /// [`NttError::WrongCongruence`] with `degree = 2^levels`.
+    ///
+    /// # Panics
+    /// * If `levels > log2(DEGREE)`
+    pub fn try_new_incomplete(
+        q: u64,
+        levels: u32,
+    ) -> Result<Arc<IncompleteNttContext<DEGREE>>, NttError> {
+        if !DEGREE.is_power_of_two() {
+            return Err(NttError::DegreeNotPowerOfTwo { degree: DEGREE });
+        }
+        assert!(
+            levels <= DEGREE.trailing_zeros(),
+            "Cannot run {levels} levels of a length-{DEGREE} NTT"
+        );
+        let splits = 1usize << levels;
+        validate_parameters(q, splits)?;
+
+        let class = CongruenceClass::new(q);
+        let zeta = find_generator(q, splits);
+        let shoup = |table: &[u64]| -> Vec<u64> {
+            table.iter().map(|&w| class.precompute_shoup(w)).collect()
+        };
+
+        let tf = twiddle_table(&class, zeta, false, splits);
+        let itf = twiddle_table(&class, zeta, true, splits);
+        // bitrev(i) over L bits is tf's exponent at i, so γ_i = ζ·tf[i]^2
+        let gammas = tf
+            .iter()
+            .map(|&w| class.modmul(zeta, class.modsquare(w)))
+            .collect();
+        let inv_scale = class.modinv(splits as u64);
+
+        Ok(Arc::new(IncompleteNttContext {
+            class,
+            levels,
+            tf_shoup: shoup(&tf),
+            tf,
+            itf_shoup: shoup(&itf),
+            itf,
+            gammas,
+            inv_scale: (inv_scale, class.precompute_shoup(inv_scale)),
+        }))
+    }
+}
+
+impl<const DEGREE: usize> IncompleteNttContext<DEGREE> {
+    pub fn modulus(&self) -> u64 {
+        self.class.q()
+    }
+
+    pub fn class(&self) -> &CongruenceClass {
+        &self.class
+    }
+
+    /// Number of butterfly levels `L`
+    pub fn levels(&self) -> u32 {
+        self.levels
+    }
+
+    /// Coefficients per block, `B = DEGREE / 2^L`
+    pub fn block_len(&self) -> usize {
+        DEGREE >> self.levels
+    }
+
+    /// Block constants `γ_i`: block `i` of a transform holds a residue
+    /// modulo `x^B - γ_i`
+    pub fn gammas(&self) -> &[u64] {
+        &self.gammas
+    }
+
+    /// Forward transform of residues in `[0, q)`, in place: the first `L`
+    /// Cooley-Tukey levels of [`NttPolynomial::ntt_forward`](crate::NttPolynomial::ntt_forward)
+    pub fn ntt_forward(&self, coeffs: &mut [u64; DEGREE]) {
+        let class = &self.class;
+        let splits = 1 << self.levels;
+        let mut t = DEGREE >> 1;
+        let mut n = 1;
+
+        while n < splits {
+            for i in 0..n {
+                let j1 = 2 * i * t;
+                let (s, s_shoup) = (self.tf[n + i], self.tf_shoup[n + i]);
+
+                for j in j1..j1 + t {
+                    let v = class.modmul_shoup(coeffs[j + t], s, s_shoup);
+                    coeffs[j + t] = class.modsub(coeffs[j], v);
+                    class.modadd_eq(&mut coeffs[j], v);
+                }
+            }
+
+            n <<= 1;
+            t >>= 1;
+        }
+    }
+
+    /// Inverse of [`ntt_forward`](Self::ntt_forward), including the
+    /// `2^(-L)` scaling
+    pub fn ntt_inverse(&self, coeffs: &mut [u64; DEGREE]) {
+        let class = &self.class;
+        let mut t = self.block_len();
+        let mut h = 1 << self.levels >> 1;
+
+        while h > 0 {
+            for i in 0..h {
+                let j1 = 2 * i * t;
+                let (s, s_shoup) = (self.itf[h + i], self.itf_shoup[h + i]);
+
+                for j in j1..j1 + t {
+                    let (u, v) = (coeffs[j], coeffs[j + t]);
+                    coeffs[j] = class.modadd(u, v);
+                    coeffs[j + t] =
+                        class.modmul_shoup(class.modsub(u, v), s, s_shoup);
+                }
+            }
+
+            h >>= 1;
+            t <<= 1;
+        }
+
+        let (scale, scale_shoup) = self.inv_scale;
+        for c in coeffs.iter_mut() {
+            *c = class.modmul_shoup(*c, scale, scale_shoup);
+        }
+    }
+
+    /// Block-wise product of two transforms: block `i` of the result is
+    /// the product of the blocks of `a` and `b` modulo `x^B - γ_i`
+    pub fn basemul(&self, a: &[u64; DEGREE], b: &[u64; DEGREE]) -> [u64; DEGREE] {
+        let class = &self.class;
+        let block = self.block_len();
+        let mut out = [0u64; DEGREE];
+
+        let blocks = out
+            .chunks_exact_mut(block)
+            .zip(a.chunks_exact(block).zip(b.chunks_exact(block)));
+        for ((c, (x, y)), &gamma) in blocks.zip(&self.gammas) {
+            // Schoolbook product, terms of degree ≥ B folding in as γ·x^(k-B)
+            for (i, &xi) in x.iter().enumerate() {
+                for (j, &yj) in y.iter().enumerate() {
+                    let mut p = class.modmul(xi, yj);
+                    if i + j >= block {
+                        p = class.modmul(p, gamma);
+                    }
+                    let k = (i + j) % block;
+                    c[k] = class.modadd(c[k], p);
+                }
+            }
+        }
+        out
+    }
+
+    /// Product of `a` and `b` in `Z_q[x]/(x^DEGREE + 1)`, for residues in
+    /// `[0, q)`
+    pub fn negacyclic_convolution(
+        &self,
+        a: &[u64; DEGREE],
+        b: &[u64; DEGREE],
+    ) -> [u64; DEGREE] {
+        let (mut a, mut b) = (*a, *b);
+        self.ntt_forward(&mut a);
+        self.ntt_forward(&mut b);
+        let mut product = self.basemul(&a, &b);
+        self.ntt_inverse(&mut product);
+        product
+    }
+}
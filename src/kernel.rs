@@ -0,0 +1,97 @@
+//! Pluggable transform kernels behind [`NttContext`].
+//!
+//! Every [`NttContext`] holds an [`NttKernel`] and routes its Shoup
+//! transforms and pointwise products through it: `ntt_forward_shoup`,
+//! `ntt_inverse_shoup` and what is built on them (Shoup convolution, views,
+//! vector and matrix products), and the
+//! [`NttBackend`](crate::backend::NttBackend) implementation. The default methods are the scalar kernels of
+//! the context's [reducer](crate::reducer), so a custom kernel only
+//! overrides what it accelerates, for example an AVX-512 forward
+//! transform, and inherits the rest. A kernel generic over the reducer
+//! works on every context; one written for `NttKernel<N>` serves the
+//! default, Shoup contexts.
+//!
+//! Unlike an `NttBackend`, which is a whole
+//! transform that may choose its own evaluation order, a kernel computes
+//! over the context's own tables and must produce exactly the slot order of
+//! the scalar code, since automorphisms, coset and evaluation-domain
+//! operations rely on it.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::*;
+//! use rust_ntt::kernel::{NttKernel, ScalarKernel};
+//! use std::sync::Arc;
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! /// Scalar kernel that counts forward transforms
+//! #[derive(Debug, Default)]
+//! struct Counting(AtomicUsize);
+//!
+//! impl<const N: usize> NttKernel<N> for Counting {
+//!     fn forward(&self, ctx: &NttContext<N>, coeffs: &mut [u64; N]) {
+//!         self.0.fetch_add(1, Ordering::Relaxed);
+//!         ScalarKernel.forward(ctx, coeffs);
+//!     }
+//! }
+//!
+//! let kernel = Arc::new(Counting::default());
+//! let ctx = NttContext::<8>::with_kernel(find_first_prime_up(20, 8), kernel.clone());
+//! let a = NttPolynomial::from_coeffs([1; 8], Arc::clone(&ctx));
+//! let _ = a.negacyclic_convolution_shoup(&a);
+//! assert_eq!(kernel.0.load(Ordering::Relaxed), 2);
+//! ```
+use crate::context::NttContext;
+use crate::reducer::{Reducer, Shoup};
+use std::fmt;
+use std::sync::Arc;
+
+/// Forward, inverse and pointwise steps of the negacyclic NTT over the
+/// tables of a context whose arithmetic is the [reducer](crate::reducer)
+/// `R`.
+pub trait NttKernel<const DEGREE: usize, R: Reducer = Shoup>:
+    fmt::Debug + Send + Sync
+{
+    /// Forward transform in place, bit-reversed evaluations out
+    fn forward(&self, ctx: &NttContext<DEGREE, R>, coeffs: &mut [u64; DEGREE]) {
+        R::forward(ctx, coeffs);
+    }
+
+    /// Inverse transform in place, including the `N^(-1)` scaling
+    fn inverse(&self, ctx: &NttContext<DEGREE, R>, evals: &mut [u64; DEGREE]) {
+        R::inverse(ctx, evals);
+    }
+
+    /// Slot-wise product into `lhs`
+    fn pointwise(
+        &self,
+        ctx: &NttContext<DEGREE, R>,
+        lhs: &mut [u64; DEGREE],
+        rhs: &[u64; DEGREE],
+    ) {
+        let r = ctx.reducer();
+        for (x, &y) in lhs.iter_mut().zip(rhs) {
+            *x = r.modmul(*x, y);
+        }
+    }
+}
+
+/// The crate's scalar kernels, used unless a context is given another one
+/// with [`NttContext::with_kernel`] or [`NttContext::into_kernel`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ScalarKernel;
+
+impl<const DEGREE: usize, R: Reducer> NttKernel<DEGREE, R> for ScalarKernel {}
+
+/// Kernel held by a context; defaults to [`ScalarKernel`], which also lets
+/// archived contexts skip the field
+#[derive(Debug, Clone)]
+pub(crate) struct KernelSlot<const DEGREE: usize, R: Reducer = Shoup>(
+    pub(crate) Arc<dyn NttKernel<DEGREE, R>>,
+);
+
+impl<const DEGREE: usize, R: Reducer> Default for KernelSlot<DEGREE, R> {
+    fn default() -> Self {
+        Self(Arc::new(ScalarKernel))
+    }
+}
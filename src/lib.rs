@@ -1,14 +1,78 @@
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
+
+#[cfg(target_arch = "x86_64")]
+mod avx2;
+pub mod backend;
+pub mod batch;
+pub mod bluestein;
+pub mod compact;
 pub mod congruence;
 pub mod context;
+pub mod counters;
+#[cfg(feature = "cross-check")]
+pub mod crosscheck;
+pub mod distr;
+pub mod dynamic;
+pub mod embedded;
+pub mod encoding;
+pub mod exact;
+pub mod extension;
+pub mod extract;
+pub mod footprint;
+pub mod fourstep;
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
+pub mod galois;
+pub mod incomplete;
+pub mod kernel;
+pub mod linalg;
 pub mod math;
+pub mod matrix;
+pub mod montgomery;
+pub mod monty31;
+pub mod noise;
 pub mod ntt;
+#[cfg(feature = "async")]
+pub mod offload;
+pub mod params;
+pub mod plaintext;
+pub mod plantard;
+pub mod polyalg;
+pub mod reducer;
+#[cfg(feature = "reference")]
+pub mod reference;
+pub mod rns;
+pub mod rs;
+pub mod sampling;
+pub mod selftest;
+pub mod shamir;
+pub mod shoup;
+#[cfg(feature = "portable-simd")]
+pub mod simd;
+pub mod stats;
+pub mod storage;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod text;
+mod unrolled;
+pub mod vector;
+pub mod verify;
+pub mod view;
+pub mod wide;
 
-pub use congruence::CongruenceClass;
-pub use context::NttContext;
+pub use congruence::{CongruenceClass, ModulusShape};
+pub use context::{
+    ContextMismatch, NttContext, NttError, Ordering, TwiddleError,
+    from_bitrev_order, to_bitrev_order,
+};
 pub use math::{
     barrett_precompute, barrett_precompute_old, find_first_prime_down,
     find_first_prime_up, find_generator, find_next_prime_up, find_primitive_root,
     modadd, modadd_naive, modmul_barrett, modmul_barrett_eq, modmul_barrett_old,
     modmul_barrett_old_eq, modmul_naive, modsub,
 };
-pub use ntt::NttPolynomial;
+pub use ntt::{
+    CoefficientOutOfRange, InvalidCoefficients, LengthMismatch, Lift, NttPolynomial,
+};
+pub use selftest::selftest;
+pub use storage::{Heap, Inline};
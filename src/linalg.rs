@@ -0,0 +1,337 @@
+//! Structured matrices over `Z_q` with transform-based products.
+//!
+//! A [`Toeplitz`] matrix is constant along its diagonals, so its
+//! matrix-vector product is a slice of a polynomial product and costs one
+//! transform pair instead of `rows·cols` multiplications.
+//!
+//! [`NegaCirculant`] and [`Circulant`] are the square special cases whose
+//! products are exactly ring multiplications in `Z_q[x]/(x^N + 1)` and
+//! `Z_q[x]/(x^N - 1)`. They are stored in NTT form, so applying one to a
+//! vector costs a transform pair and composing two is a pointwise product.
+use crate::congruence::CongruenceClass;
+use crate::context::NttContext;
+use crate::ntt::NttPolynomial;
+use crate::polyalg::middle_product;
+use std::sync::Arc;
+
+/// `rows × cols` Toeplitz matrix `T[i][j] = t_{i-j}`.
+///
+/// The `rows + cols - 1` diagonal values are stored as
+/// `diagonals[i - j + cols - 1]`: the top-right corner first, the
+/// bottom-left corner last.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::CongruenceClass;
+/// use rust_ntt::linalg::Toeplitz;
+///
+/// let class = CongruenceClass::new(97);
+/// // [[2, 1],
+/// //  [3, 2],
+/// //  [4, 3]]
+/// let t = Toeplitz::from_col_row(&[2, 3, 4], &[2, 1]);
+/// assert_eq!(t.get(2, 1), 3);
+/// assert_eq!(t.matvec(&[1, 10], &class), vec![12, 23, 34]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Toeplitz {
+    rows: usize,
+    cols: usize,
+    diagonals: Vec<u64>,
+}
+
+impl Toeplitz {
+    /// Create a Toeplitz matrix from its `rows + cols - 1` diagonal values.
+    ///
+    /// # Panics
+    /// * If either dimension is zero or the diagonal count is wrong
+    pub fn new(rows: usize, cols: usize, diagonals: Vec<u64>) -> Self {
+        assert!(rows > 0 && cols > 0, "matrix dimensions must be non-zero");
+        assert_eq!(
+            diagonals.len(),
+            rows + cols - 1,
+            "a {rows}x{cols} Toeplitz matrix has {} diagonals, got {}",
+            rows + cols - 1,
+            diagonals.len()
+        );
+
+        Self {
+            rows,
+            cols,
+            diagonals,
+        }
+    }
+
+    /// Create a Toeplitz matrix from its first column and first row.
+    ///
+    /// # Panics
+    /// * If either slice is empty or they disagree on the corner `T[0][0]`
+    pub fn from_col_row(col: &[u64], row: &[u64]) -> Self {
+        assert!(
+            !col.is_empty() && !row.is_empty(),
+            "matrix dimensions must be non-zero"
+        );
+        assert_eq!(col[0], row[0], "first column and row disagree on T[0][0]");
+
+        let diagonals = row.iter().rev().chain(&col[1..]).copied().collect();
+        Self::new(col.len(), row.len(), diagonals)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn diagonals(&self) -> &[u64] {
+        &self.diagonals
+    }
+
+    /// Entry `T[i][j]`
+    pub fn get(&self, i: usize, j: usize) -> u64 {
+        assert!(
+            i < self.rows && j < self.cols,
+            "index ({i}, {j}) out of range"
+        );
+        self.diagonals[i + self.cols - 1 - j]
+    }
+
+    /// Matrix-vector product `T·v`.
+    ///
+    /// `(T·v)_i = Σ_j t_{i-j}·v_j` is exactly coefficient `cols - 1 + i` of
+    /// `diagonals(x)·v(x)`, i.e. the middle product of the two, which
+    /// [`middle_product`] computes with a single cyclic transform pair of
+    /// length at least `rows + cols - 1`; the wrap-around lands in the
+    /// discarded low coefficients.
+    ///
+    /// # Panics
+    /// * If `v.len() != cols`
+    pub fn matvec(&self, v: &[u64], class: &CongruenceClass) -> Vec<u64> {
+        assert_eq!(
+            v.len(),
+            self.cols,
+            "vector length {} does not match {} columns",
+            v.len(),
+            self.cols
+        );
+
+        middle_product(&self.diagonals, v, class)
+    }
+
+    /// Schoolbook `T·v` in `O(rows·cols)`, for testing
+    pub fn matvec_naive(&self, v: &[u64], class: &CongruenceClass) -> Vec<u64> {
+        assert_eq!(
+            v.len(),
+            self.cols,
+            "vector length {} does not match {} columns",
+            v.len(),
+            self.cols
+        );
+
+        (0..self.rows)
+            .map(|i| {
+                (0..self.cols).fold(0, |acc, j| {
+                    class.modadd(acc, class.modmul(self.get(i, j), v[j]))
+                })
+            })
+            .collect()
+    }
+}
+
+/// `N × N` negacirculant matrix: the matrix of multiplication by a fixed
+/// element `a` of `Z_q[x]/(x^N + 1)`.
+///
+/// Row `i` is the first row shifted right by `i`, with entries that wrap
+/// around negated: `M[i][j] = a_{i-j}` for `i ≥ j` and `-a_{N+i-j}`
+/// otherwise.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::*;
+/// use rust_ntt::linalg::NegaCirculant;
+///
+/// let q = find_first_prime_up(20, 4);
+/// let ctx = NttContext::<4>::new(q);
+///
+/// // Multiplication by x shifts and negates the wrapped coefficient
+/// let shift = NegaCirculant::from_first_row([0, 0, 0, q - 1], ctx);
+/// assert_eq!(shift.apply(&[1, 2, 3, 4]), [q - 4, 1, 2, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NegaCirculant<const DEGREE: usize> {
+    /// NTT of the first column `a`
+    evals: NttPolynomial<DEGREE>,
+}
+
+impl<const DEGREE: usize> NegaCirculant<DEGREE> {
+    /// Create the matrix from its first row.
+    pub fn from_first_row(
+        row: [u64; DEGREE],
+        context: Arc<NttContext<DEGREE>>,
+    ) -> Self {
+        // a_0 = r_0 and a_k = -r_{N-k}
+        let class = *context.class();
+        let column = std::array::from_fn(|k| {
+            if k == 0 {
+                row[0]
+            } else {
+                class.modneg(row[DEGREE - k])
+            }
+        });
+
+        Self::from_polynomial(&NttPolynomial::from_coeffs(column, context))
+    }
+
+    /// Matrix of multiplication by the coefficient-form polynomial `a`.
+    pub fn from_polynomial(a: &NttPolynomial<DEGREE>) -> Self {
+        let mut evals = a.clone();
+        evals.ntt_forward_shoup();
+        Self { evals }
+    }
+
+    pub fn context(&self) -> &Arc<NttContext<DEGREE>> {
+        self.evals.context()
+    }
+
+    /// Polynomial `a` whose multiplication this matrix represents
+    pub fn polynomial(&self) -> NttPolynomial<DEGREE> {
+        let mut a = self.evals.clone();
+        a.ntt_inverse_shoup();
+        a
+    }
+
+    /// First row `(a_0, -a_{N-1}, ..., -a_1)`
+    pub fn first_row(&self) -> [u64; DEGREE] {
+        let a = self.polynomial();
+        let class = self.context().class();
+        std::array::from_fn(|j| {
+            if j == 0 {
+                a.coeffs()[0]
+            } else {
+                class.modneg(a.coeffs()[DEGREE - j])
+            }
+        })
+    }
+
+    /// Matrix-vector product `M·v`, i.e. the ring product `a·v`
+    pub fn apply(&self, v: &[u64; DEGREE]) -> [u64; DEGREE] {
+        let mut v = NttPolynomial::from_coeffs(*v, Arc::clone(self.context()));
+        v.ntt_forward_shoup();
+        v.pointwise_mul_assign(&self.evals);
+        v.ntt_inverse_shoup();
+        *v.coeffs()
+    }
+
+    /// Matrix product `self·other`, as a pointwise product of NTT forms
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            evals: self.evals.pointwise_mul(&other.evals),
+        }
+    }
+}
+
+/// `N × N` circulant matrix: the matrix of multiplication by a fixed
+/// element `a` of `Z_q[x]/(x^N - 1)`.
+///
+/// Row `i` is the first row cyclically shifted right by `i`:
+/// `M[i][j] = r_{(j-i) mod N}`. Cyclic products reuse the negacyclic NTT
+/// through the twist `x ↦ ψ·x`, which maps `x^N - 1` to `-(x^N + 1)`.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::*;
+/// use rust_ntt::linalg::Circulant;
+///
+/// let q = find_first_prime_up(20, 4);
+/// let ctx = NttContext::<4>::new(q);
+///
+/// // Multiplication by x is a cyclic shift
+/// let shift = Circulant::from_first_row([0, 0, 0, 1], ctx);
+/// assert_eq!(shift.apply(&[1, 2, 3, 4]), [4, 1, 2, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Circulant<const DEGREE: usize> {
+    /// NTT of the twisted first column `a_k·ψ^k`
+    evals: NttPolynomial<DEGREE>,
+    /// ψ^k, for twisting inputs
+    twist: Arc<[u64; DEGREE]>,
+    /// ψ^(-k), for untwisting outputs
+    untwist: Arc<[u64; DEGREE]>,
+}
+
+impl<const DEGREE: usize> Circulant<DEGREE> {
+    /// Create the matrix from its first row.
+    pub fn from_first_row(
+        row: [u64; DEGREE],
+        context: Arc<NttContext<DEGREE>>,
+    ) -> Self {
+        // a_k = r_{-k mod N}
+        let column = std::array::from_fn(|k| row[(DEGREE - k) % DEGREE]);
+        Self::from_polynomial(&NttPolynomial::from_coeffs(column, context))
+    }
+
+    /// Matrix of cyclic multiplication by the coefficient-form polynomial `a`.
+    pub fn from_polynomial(a: &NttPolynomial<DEGREE>) -> Self {
+        let context = a.context();
+        let class = context.class();
+
+        let psi = context.psi();
+        let psi_inv = class.modinv(psi);
+
+        let mut twist = [1u64; DEGREE];
+        let mut untwist = [1u64; DEGREE];
+        for k in 1..DEGREE {
+            twist[k] = class.modmul(twist[k - 1], psi);
+            untwist[k] = class.modmul(untwist[k - 1], psi_inv);
+        }
+
+        let mut evals = a.scale_argument_by_powers(&twist);
+        evals.ntt_forward_shoup();
+
+        Self {
+            evals,
+            twist: Arc::new(twist),
+            untwist: Arc::new(untwist),
+        }
+    }
+
+    pub fn context(&self) -> &Arc<NttContext<DEGREE>> {
+        self.evals.context()
+    }
+
+    /// Polynomial `a` whose cyclic multiplication this matrix represents
+    pub fn polynomial(&self) -> NttPolynomial<DEGREE> {
+        let mut a = self.evals.clone();
+        a.ntt_inverse_shoup();
+        a.scale_argument_by_powers_eq(&self.untwist);
+        a
+    }
+
+    /// First row `(a_0, a_{N-1}, ..., a_1)`
+    pub fn first_row(&self) -> [u64; DEGREE] {
+        let a = self.polynomial();
+        std::array::from_fn(|j| a.coeffs()[(DEGREE - j) % DEGREE])
+    }
+
+    /// Matrix-vector product `M·v`, i.e. the cyclic product `a·v`
+    pub fn apply(&self, v: &[u64; DEGREE]) -> [u64; DEGREE] {
+        let mut v = NttPolynomial::from_coeffs(*v, Arc::clone(self.context()));
+        v.scale_argument_by_powers_eq(&self.twist);
+        v.ntt_forward_shoup();
+        v.pointwise_mul_assign(&self.evals);
+        v.ntt_inverse_shoup();
+        v.scale_argument_by_powers_eq(&self.untwist);
+        *v.coeffs()
+    }
+
+    /// Matrix product `self·other`, as a pointwise product of NTT forms
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            evals: self.evals.pointwise_mul(&other.evals),
+            twist: Arc::clone(&self.twist),
+            untwist: Arc::clone(&self.untwist),
+        }
+    }
+}
@@ -7,7 +7,7 @@ use primal::{Sieve, is_prime};
 /** naive version to test performance **/
 #[inline]
 pub fn modadd_naive(a: u64, b: u64, q: u64) -> u64 {
-    (a + b) % q
+    ((a as u128 + b as u128) % q as u128) as u64
 }
 
 #[inline]
@@ -19,22 +19,19 @@ pub fn modmul_naive(a: u64, b: u64, q: u64) -> u64 {
 /** efficient section **/
 #[inline]
 pub fn modnegate(a: u64, q: u64) -> u64 {
-    q.wrapping_sub(a) // q - a
+    if a == 0 { 0 } else { q - a }
 }
 
 #[inline]
 pub fn modadd(a: u64, b: u64, q: u64) -> u64 {
-    let t = a + b;
-    if t < q { t } else { t.wrapping_sub(q) }
+    // a + b itself may not fit for q near 2^63, compare against q - b instead
+    let d = q - b;
+    if a >= d { a - d } else { a + b }
 }
 
 #[inline]
 pub fn modsub(a: u64, b: u64, q: u64) -> u64 {
-    if a >= b {
-        a.wrapping_sub(b)
-    } else {
-        (q + a).wrapping_sub(b)
-    }
+    if a >= b { a - b } else { (q - b) + a }
 }
 
 // возможна 2ая версия когда mu принимает значение в 128 бит диапазоне, а logq фиксируется в 63
@@ -0,0 +1,278 @@
+//! NTT with Montgomery butterflies.
+//!
+//! The default transforms multiply by twiddles with Shoup's method, which
+//! needs a second table of `⌊w·2^64/q⌋` values. Montgomery multiplication
+//! by `w·R mod q` (`R = 2^64`) needs only the one table and a REDC per
+//! product, and for many moduli its butterflies are the faster of the two.
+//! The tables are built from the context's twiddles on first use.
+//!
+//! Multiplying by a twiddle in Montgomery form returns the plain product,
+//! so the transforms are linear in the representation: coefficients in
+//! Montgomery form stay in it, and plain ones stay plain. A computation can
+//! thus convert once with [`NttPolynomial::convert_to_montgomery`], run any number
+//! of transforms and [`NttPolynomial::pointwise_mul_montgomery`] products,
+//! and convert back at the end. [`NttPolynomial::negacyclic_convolution_montgomery`]
+//! instead takes and returns plain coefficients, folding the `R` factors
+//! into the inverse scaling.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::*;
+//! use std::sync::Arc;
+//!
+//! let ctx = NttContext::<4>::new(find_first_prime_up(20, 4));
+//! let a = NttPolynomial::from_coeffs([1, 2, 3, 4], Arc::clone(&ctx));
+//! let b = NttPolynomial::from_coeffs([5, 6, 7, 8], ctx);
+//!
+//! // Plain in, plain out
+//! let expected = &a * &b;
+//! assert_eq!(a.negacyclic_convolution_montgomery(&b).coeffs(), expected.coeffs());
+//!
+//! // Or stay in Montgomery form throughout
+//! let (mut am, mut bm) = (a.clone(), b.clone());
+//! am.convert_to_montgomery();
+//! bm.convert_to_montgomery();
+//! am.ntt_forward_montgomery();
+//! bm.ntt_forward_montgomery();
+//! let mut product = am.pointwise_mul_montgomery(&bm);
+//! product.ntt_inverse_montgomery();
+//! product.convert_from_montgomery();
+//! assert_eq!(product.coeffs(), expected.coeffs());
+//! ```
+use crate::congruence::CongruenceClass;
+use crate::context::NttContext;
+use crate::counters::Op;
+#[cfg(feature = "cross-check")]
+use crate::crosscheck;
+use crate::ntt::NttPolynomial;
+use std::sync::Arc;
+
+/// Twiddles and inverse scaling in Montgomery form
+#[derive(Debug, Clone)]
+pub(crate) struct MontgomeryTables {
+    /// `tf[i]·R mod q`
+    tf: Vec<u64>,
+    /// `itf[i]·R mod q`
+    itf: Vec<u64>,
+    /// `(N^(-1)·R, itf[1]·N^(-1)·R)`, scaling the last inverse stage
+    inv_n: (u64, u64),
+    /// The same constants times another `R`, cancelling the `R^(-1)` a
+    /// Montgomery product of plain operands leaves behind
+    inv_n_r2: (u64, u64),
+}
+
+impl MontgomeryTables {
+    fn new<const DEGREE: usize>(context: &NttContext<DEGREE>) -> Self {
+        let class = context.class();
+        let inv_n = (
+            class.to_mont(context.inv_n),
+            class.to_mont(context.inv_n_itf),
+        );
+
+        Self {
+            tf: context.tf.iter().map(|&w| class.to_mont(w)).collect(),
+            itf: context.itf.iter().map(|&w| class.to_mont(w)).collect(),
+            inv_n,
+            inv_n_r2: (class.to_mont(inv_n.0), class.to_mont(inv_n.1)),
+        }
+    }
+
+    /// Bytes held by the twiddle tables
+    pub(crate) fn heap_bytes(&self) -> usize {
+        (self.tf.capacity() + self.itf.capacity()) * size_of::<u64>()
+    }
+}
+
+impl<const DEGREE: usize> NttContext<DEGREE> {
+    /// Montgomery twiddle tables, built on first use
+    pub(crate) fn montgomery_tables(&self) -> &MontgomeryTables {
+        self.montgomery.get_or_init(|| MontgomeryTables::new(self))
+    }
+
+    fn forward_montgomery_in_place(&self, coeffs: &mut [u64; DEGREE]) {
+        self.count_transform(Op::Forward);
+        let tables = self.montgomery_tables();
+        forward_montgomery_kernel(&self.class, &tables.tf, coeffs);
+    }
+
+    /// Inverse transform, scaling by `R` on top of `N^(-1)` if `rescale`
+    fn inverse_montgomery_in_place(
+        &self,
+        coeffs: &mut [u64; DEGREE],
+        rescale: bool,
+    ) {
+        self.count_transform(Op::Inverse);
+        let tables = self.montgomery_tables();
+        let scaling = if rescale {
+            tables.inv_n_r2
+        } else {
+            tables.inv_n
+        };
+        inverse_montgomery_kernel(&self.class, &tables.itf, scaling, coeffs);
+    }
+}
+
+/// Cooley-Tukey butterflies as in the Shoup kernel, with twiddles in
+/// Montgomery form
+#[inline]
+fn forward_montgomery_kernel(
+    class: &CongruenceClass,
+    tf: &[u64],
+    coeffs: &mut [u64],
+) {
+    let degree = coeffs.len();
+    debug_assert_eq!(tf.len(), degree);
+
+    let mut t = degree >> 1;
+    let mut n = 1;
+
+    while n < degree {
+        for i in 0..n {
+            let j1 = 2 * i * t;
+            let s = tf[n + i];
+
+            for j in j1..j1 + t {
+                let v = class.mont_mul(coeffs[j + t], s);
+
+                coeffs[j + t] = class.modsub(coeffs[j], v);
+                class.modadd_eq(&mut coeffs[j], v);
+            }
+        }
+
+        n <<= 1;
+        t >>= 1;
+    }
+}
+
+/// Gentleman-Sande butterflies with twiddles in Montgomery form and the
+/// scaling `(inv_n, inv_n_itf)` folded into the last stage
+#[inline]
+fn inverse_montgomery_kernel(
+    class: &CongruenceClass,
+    itf: &[u64],
+    (inv_n, inv_n_itf): (u64, u64),
+    coeffs: &mut [u64],
+) {
+    let degree = coeffs.len();
+    debug_assert_eq!(itf.len(), degree);
+
+    let mut t = 1;
+    let mut h = degree >> 1;
+
+    while h > 1 {
+        let mut j1 = 0;
+
+        for i in 0..h {
+            let s = itf[h + i];
+
+            for j in j1..j1 + t {
+                let (u, v) = (coeffs[j], coeffs[j + t]);
+
+                coeffs[j] = class.modadd(u, v);
+                coeffs[j + t] = class.mont_mul(class.modsub(u, v), s);
+            }
+
+            j1 += t << 1;
+        }
+
+        h >>= 1;
+        t <<= 1;
+    }
+
+    if degree > 1 {
+        let (lo, hi) = coeffs.split_at_mut(t);
+        for (x, y) in lo.iter_mut().zip(hi) {
+            let (u, v) = (*x, *y);
+            *x = class.mont_mul(class.modadd(u, v), inv_n);
+            *y = class.mont_mul(class.modsub(u, v), inv_n_itf);
+        }
+    } else {
+        // N^(-1) = 1, but a rescaling inverse still owes a factor R
+        class.mont_mul_eq(&mut coeffs[0], inv_n);
+    }
+}
+
+impl<const DEGREE: usize> NttPolynomial<DEGREE> {
+    /// Convert every coefficient to Montgomery form `c·R mod q`
+    pub fn convert_to_montgomery(&mut self) {
+        let context = Arc::clone(self.context());
+        let class = context.class();
+
+        context.count(Op::ModMul, DEGREE as u64);
+        for c in self.coeffs_mut().iter_mut() {
+            *c = class.to_mont(*c);
+        }
+    }
+
+    /// Convert every coefficient back from Montgomery form
+    pub fn convert_from_montgomery(&mut self) {
+        let context = Arc::clone(self.context());
+        let class = context.class();
+
+        context.count(Op::ModMul, DEGREE as u64);
+        for c in self.coeffs_mut().iter_mut() {
+            *c = class.from_mont(*c);
+        }
+    }
+
+    /// Forward NTT with Montgomery butterflies, giving the same evaluations
+    /// as [`ntt_forward`](Self::ntt_forward) in the representation of the
+    /// input
+    pub fn ntt_forward_montgomery(&mut self) {
+        let context = Arc::clone(self.context());
+        context.forward_montgomery_in_place(self.coeffs_mut());
+    }
+
+    /// Inverse of [`ntt_forward_montgomery`](Self::ntt_forward_montgomery),
+    /// again keeping the representation
+    pub fn ntt_inverse_montgomery(&mut self) {
+        let context = Arc::clone(self.context());
+        context.inverse_montgomery_in_place(self.coeffs_mut(), false);
+    }
+
+    /// Slot-wise Montgomery product `self∘rhs·R^(-1)`: the Montgomery form
+    /// of the product when both operands are in Montgomery form
+    ///
+    /// # Panics
+    /// * If the contexts have different moduli
+    pub fn pointwise_mul_montgomery(&self, rhs: &Self) -> Self {
+        if let Err(e) = self.context().check_compatible(rhs.context()) {
+            panic!("Cannot multiply polynomials: {e}");
+        }
+        let mut result = self.clone();
+        let class = self.context().class();
+
+        self.context().count(Op::ModMul, DEGREE as u64);
+        for (x, &y) in result.coeffs_mut().iter_mut().zip(rhs.coeffs()) {
+            class.mont_mul_eq(x, y);
+        }
+        result
+    }
+
+    /// Negacyclic convolution through the Montgomery NTT, on plain
+    /// coefficients; the result equals [`negacyclic_convolution`](Self::negacyclic_convolution)
+    ///
+    /// # Panics
+    /// * If the contexts have different moduli
+    pub fn negacyclic_convolution_montgomery(&self, other: &Self) -> Self {
+        if let Err(e) = self.context().check_compatible(other.context()) {
+            panic!("Cannot convolve polynomials: {e}");
+        }
+        let context = Arc::clone(self.context());
+        let (mut a, mut b) = (self.clone(), other.clone());
+
+        context.forward_montgomery_in_place(a.coeffs_mut());
+        context.forward_montgomery_in_place(b.coeffs_mut());
+        let mut result = a.pointwise_mul_montgomery(&b);
+        context.inverse_montgomery_in_place(result.coeffs_mut(), true);
+        #[cfg(feature = "cross-check")]
+        crosscheck::check_negacyclic(
+            "negacyclic_convolution_montgomery",
+            &context,
+            &self.coeffs()[..],
+            &other.coeffs()[..],
+            result.coeffs(),
+        );
+        result
+    }
+}
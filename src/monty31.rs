@@ -0,0 +1,316 @@
+//! 31-bit STARK fields with Montgomery ("Monty-31") arithmetic.
+//!
+//! Proving systems favour primes of the form `p = 2^31 - 2^k + 1`: elements
+//! fit in a `u32`, products fit in a `u64`, and `p - 1` has a large power of
+//! two, giving deep two-adic root tables. This module provides
+//! [`BABY_BEAR`] (`2^31 - 2^27 + 1`) and [`KOALA_BEAR`] (`2^31 - 2^24 + 1`)
+//! together with a negacyclic NTT specialized to them.
+//!
+//! Montgomery reduction with `R = 2^32` replaces the Barrett step of
+//! [`CongruenceClass`](crate::CongruenceClass): a product `a·b` reduces to
+//! `a·b·R^-1 mod p` with two 32-bit multiplications and no division. The
+//! transform stores its twiddles in Montgomery form, so canonical inputs
+//! produce canonical outputs without an explicit conversion pass.
+use crate::context::bit_reverse;
+use std::sync::Arc;
+
+/// Parameters of a 31-bit prime field in Montgomery representation.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::monty31::BABY_BEAR;
+///
+/// let a = BABY_BEAR.to_monty(123_456);
+/// let b = BABY_BEAR.to_monty(789_012);
+/// let c = BABY_BEAR.from_monty(BABY_BEAR.monty_mul(a, b));
+/// assert_eq!(c as u64, 123_456u64 * 789_012 % BABY_BEAR.p() as u64);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Monty31Field {
+    p: u32,           // Prime modulus, p < 2^31
+    p_neg_inv: u32,   // -p^(-1) mod 2^32
+    r2: u32,          // R^2 mod p, for conversion into Montgomery form
+    generator: u32,   // Generator of the multiplicative group
+    two_adicity: u32, // Largest k with 2^k | p - 1
+}
+
+/// BabyBear field, `p = 2^31 - 2^27 + 1 = 15·2^27 + 1`
+pub const BABY_BEAR: Monty31Field = Monty31Field::new(0x7800_0001, 31);
+
+/// KoalaBear field, `p = 2^31 - 2^24 + 1 = 127·2^24 + 1`
+pub const KOALA_BEAR: Monty31Field = Monty31Field::new(0x7f00_0001, 3);
+
+impl Monty31Field {
+    /// Precompute Montgomery parameters for the prime `p`.
+    ///
+    /// `generator` must generate the multiplicative group of `Z_p`; it is
+    /// only used to derive two-adic roots of unity.
+    ///
+    /// # Panics
+    /// * If `p` is even or `p ≥ 2^31`
+    pub const fn new(p: u32, generator: u32) -> Self {
+        assert!(p % 2 == 1, "modulus must be odd");
+        assert!(p < (1 << 31), "modulus must be < 2^31");
+
+        // Newton iteration for p^(-1) mod 2^32; each step doubles the
+        // number of correct low bits, starting from 3 (p·p ≡ 1 mod 8)
+        let mut inv = p;
+        let mut i = 0;
+        while i < 4 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub(p.wrapping_mul(inv)));
+            i += 1;
+        }
+
+        let r = ((1u64 << 32) % p as u64) as u128;
+        let r2 = (r * r % p as u128) as u32;
+
+        Self {
+            p,
+            p_neg_inv: inv.wrapping_neg(),
+            r2,
+            generator,
+            two_adicity: (p - 1).trailing_zeros(),
+        }
+    }
+
+    #[inline]
+    pub fn p(&self) -> u32 {
+        self.p
+    }
+
+    #[inline]
+    pub fn generator(&self) -> u32 {
+        self.generator
+    }
+
+    #[inline]
+    pub fn two_adicity(&self) -> u32 {
+        self.two_adicity
+    }
+
+    /// Montgomery reduction: `x·R^(-1) mod p` for `x < p·2^32`
+    #[inline]
+    pub fn reduce(&self, x: u64) -> u32 {
+        let m = (x as u32).wrapping_mul(self.p_neg_inv);
+        // x + m·p < 2·p·2^32 < 2^64 since p < 2^31
+        let t = ((x + m as u64 * self.p as u64) >> 32) as u32;
+        if t >= self.p { t - self.p } else { t }
+    }
+
+    /// Montgomery product `a·b·R^(-1) mod p`
+    #[inline]
+    pub fn monty_mul(&self, a: u32, b: u32) -> u32 {
+        self.reduce(a as u64 * b as u64)
+    }
+
+    /// Convert a canonical residue into Montgomery form `a·R mod p`
+    #[inline]
+    pub fn to_monty(&self, a: u32) -> u32 {
+        self.monty_mul(a, self.r2)
+    }
+
+    /// Convert from Montgomery form back to a canonical residue
+    #[inline]
+    pub fn from_monty(&self, a: u32) -> u32 {
+        self.reduce(a as u64)
+    }
+
+    /// Modular addition; valid for both canonical and Montgomery form
+    #[inline]
+    pub fn add(&self, a: u32, b: u32) -> u32 {
+        let t = a + b;
+        if t >= self.p { t - self.p } else { t }
+    }
+
+    /// Modular subtraction; valid for both canonical and Montgomery form
+    #[inline]
+    pub fn sub(&self, a: u32, b: u32) -> u32 {
+        if a >= b { a - b } else { a + self.p - b }
+    }
+
+    /// Product of two canonical residues
+    #[inline]
+    pub fn mul(&self, a: u32, b: u32) -> u32 {
+        self.monty_mul(self.monty_mul(a, b), self.r2)
+    }
+
+    /// `a^e mod p` for a canonical residue `a`
+    pub fn pow(&self, a: u32, mut e: u64) -> u32 {
+        let mut base = self.to_monty(a);
+        let mut acc = self.to_monty(1);
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = self.monty_mul(acc, base);
+            }
+            base = self.monty_mul(base, base);
+            e >>= 1;
+        }
+        self.from_monty(acc)
+    }
+
+    /// Multiplicative inverse of a non-zero canonical residue
+    pub fn inv(&self, a: u32) -> u32 {
+        assert_ne!(a % self.p, 0, "zero has no inverse");
+        self.pow(a, self.p as u64 - 2)
+    }
+
+    /// Canonical primitive `2^bits`-th root of unity, `g^((p-1) / 2^bits)`.
+    ///
+    /// # Panics
+    /// * If `bits` exceeds the two-adicity of the field
+    pub fn two_adic_root(&self, bits: u32) -> u32 {
+        assert!(
+            bits <= self.two_adicity,
+            "field has no root of unity of order 2^{bits} (two-adicity {})",
+            self.two_adicity
+        );
+        self.pow(self.generator, ((self.p - 1) >> bits) as u64)
+    }
+}
+
+/// Negacyclic NTT context over a [`Monty31Field`].
+///
+/// Follows the layout of [`NttContext`](crate::NttContext): twiddles are
+/// powers of a primitive `2·DEGREE`-th root `ψ` in bit-reversed order, so
+/// the forward transform leaves `a(ψ^(2·bitrev(i)+1))` in slot `i`.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::monty31::{KOALA_BEAR, Monty31Context};
+///
+/// let ctx = Monty31Context::<8>::new(KOALA_BEAR);
+/// let a = [1, 2, 3, 4, 5, 6, 7, 8];
+///
+/// let mut evals = a;
+/// ctx.ntt_forward(&mut evals);
+/// ctx.ntt_inverse(&mut evals);
+/// assert_eq!(evals, a);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Monty31Context<const DEGREE: usize> {
+    field: Monty31Field,
+    /// Forward twiddles in Montgomery form (bit-reversed order)
+    tf: [u32; DEGREE],
+    /// Inverse twiddles in Montgomery form (bit-reversed order)
+    itf: [u32; DEGREE],
+    /// DEGREE^(-1) in Montgomery form
+    inv_n: u32,
+}
+
+impl<const DEGREE: usize> Monty31Context<DEGREE> {
+    /// Create a transform context of size `DEGREE` over `field`.
+    ///
+    /// # Panics
+    /// * If DEGREE is not a power of 2
+    /// * If `2·DEGREE` exceeds the two-adic subgroup of the field
+    pub fn new(field: Monty31Field) -> Arc<Self> {
+        assert!(
+            DEGREE.is_power_of_two() && DEGREE > 0,
+            "DEGREE must be a power of 2, got {DEGREE}"
+        );
+
+        let log_degree = DEGREE.trailing_zeros() as usize;
+        let psi = field.two_adic_root(log_degree as u32 + 1);
+        let psi_inv = field.inv(psi);
+
+        let mut tf = [0u32; DEGREE];
+        let mut itf = [0u32; DEGREE];
+        let (mut power, mut inv_power) = (1u32, 1u32);
+        for i in 0..DEGREE {
+            let j = bit_reverse(i, log_degree);
+            tf[j] = field.to_monty(power);
+            itf[j] = field.to_monty(inv_power);
+            power = field.mul(power, psi);
+            inv_power = field.mul(inv_power, psi_inv);
+        }
+
+        let inv_n = field.to_monty(field.inv(DEGREE as u32));
+
+        Arc::new(Self {
+            field,
+            tf,
+            itf,
+            inv_n,
+        })
+    }
+
+    pub fn field(&self) -> &Monty31Field {
+        &self.field
+    }
+
+    /// Forward negacyclic NTT of canonical residues, in place
+    pub fn ntt_forward(&self, coeffs: &mut [u32; DEGREE]) {
+        let f = &self.field;
+        let mut t = DEGREE >> 1;
+        let mut n = 1;
+
+        while n < DEGREE {
+            for i in 0..n {
+                let j1 = 2 * i * t;
+                let s = self.tf[n + i];
+
+                for j in j1..j1 + t {
+                    let u = coeffs[j];
+                    let v = f.monty_mul(coeffs[j + t], s);
+
+                    coeffs[j] = f.add(u, v);
+                    coeffs[j + t] = f.sub(u, v);
+                }
+            }
+
+            n <<= 1;
+            t >>= 1;
+        }
+    }
+
+    /// Inverse negacyclic NTT, in place; undoes [`ntt_forward`](Self::ntt_forward)
+    pub fn ntt_inverse(&self, coeffs: &mut [u32; DEGREE]) {
+        let f = &self.field;
+        let mut t = 1;
+        let mut h = DEGREE >> 1;
+
+        while h > 0 {
+            let mut j1 = 0;
+
+            for i in 0..h {
+                let s = self.itf[h + i];
+
+                for j in j1..j1 + t {
+                    let u = coeffs[j];
+                    let v = coeffs[j + t];
+
+                    coeffs[j] = f.add(u, v);
+                    coeffs[j + t] = f.monty_mul(f.sub(u, v), s);
+                }
+
+                j1 += t << 1;
+            }
+
+            h >>= 1;
+            t <<= 1;
+        }
+
+        for coeff in coeffs.iter_mut() {
+            *coeff = f.monty_mul(*coeff, self.inv_n);
+        }
+    }
+
+    /// Product of `a` and `b` in `Z_p[x]/(x^DEGREE + 1)`
+    pub fn negacyclic_convolution(
+        &self,
+        a: &[u32; DEGREE],
+        b: &[u32; DEGREE],
+    ) -> [u32; DEGREE] {
+        let (mut a, mut b) = (*a, *b);
+        self.ntt_forward(&mut a);
+        self.ntt_forward(&mut b);
+
+        // Pointwise products in canonical form need one extra R factor
+        for (x, &y) in a.iter_mut().zip(&b) {
+            *x = self.field.mul(*x, y);
+        }
+
+        self.ntt_inverse(&mut a);
+        a
+    }
+}
@@ -0,0 +1,215 @@
+//! Noise growth tracking for prototyping lattice schemes.
+//!
+//! `TrackedPoly` carries an upper bound on the size of a polynomial's centered
+//! coefficients and updates it through every operation, so parameter choices
+//! can be checked against the modulus while a scheme is being designed.
+//!
+//! Two heuristics are available:
+//! - [`NoiseHeuristic::WorstCase`] tracks the infinity norm with the worst-case
+//!   expansion factor `N` for products, so bounds are always rigorous.
+//! - [`NoiseHeuristic::CanonicalEmbedding`] tracks the canonical embedding norm,
+//!   which is submultiplicative and bounds the infinity norm. Fresh values use
+//!   the usual six-sigma estimate `6·sqrt(N)·σ`. Products then grow by about
+//!   `6·sqrt(N)·σ` rather than `N`, so along chains of products at large `N`
+//!   the (only heuristic) bounds are much tighter for random polynomials.
+use crate::ntt::NttPolynomial;
+use std::ops::{Add, Mul, Sub};
+
+/// Rule used to propagate noise bounds through ring operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoiseHeuristic {
+    /// Rigorous infinity-norm bounds, products grow by a factor of `N`
+    WorstCase,
+    /// Canonical embedding norm bounds, products grow multiplicatively
+    CanonicalEmbedding,
+}
+
+/// Map a residue in [0, q) to its centered representative in (-q/2, q/2].
+#[inline]
+pub fn centered(c: u64, q: u64) -> i64 {
+    if c > q / 2 {
+        -((q - c) as i64)
+    } else {
+        c as i64
+    }
+}
+
+/// Largest absolute value among the centered coefficients.
+pub fn infinity_norm<const DEGREE: usize>(poly: &NttPolynomial<DEGREE>) -> u64 {
+    let q = poly.context().modulus();
+    poly.coeffs()
+        .iter()
+        .map(|&c| centered(c, q).unsigned_abs())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Polynomial together with an upper bound on its noise.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::*;
+/// use rust_ntt::noise::{NoiseHeuristic, TrackedPoly};
+/// use std::sync::Arc;
+///
+/// const N: usize = 8;
+/// let q = find_first_prime_up(40, N);
+/// let ctx = NttContext::<N>::new(q);
+///
+/// let e = NttPolynomial::from_coeffs([1, q - 1, 0, 1, 1, 0, q - 1, 1], ctx);
+/// let e = TrackedPoly::fresh(e, NoiseHeuristic::WorstCase);
+///
+/// let e2 = &e * &e;
+/// assert_eq!(e2.bound(), 8.0); // N * 1 * 1
+/// assert!(e2.is_within_bound());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TrackedPoly<const DEGREE: usize> {
+    poly: NttPolynomial<DEGREE>,
+    bound: f64,
+    heuristic: NoiseHeuristic,
+}
+
+impl<const DEGREE: usize> TrackedPoly<DEGREE> {
+    /// Wrap a polynomial with an explicitly known bound
+    pub fn new(
+        poly: NttPolynomial<DEGREE>,
+        bound: f64,
+        heuristic: NoiseHeuristic,
+    ) -> Self {
+        Self {
+            poly,
+            bound,
+            heuristic,
+        }
+    }
+
+    /// Wrap a freshly sampled polynomial, estimating its bound from the
+    /// coefficients according to the heuristic.
+    pub fn fresh(poly: NttPolynomial<DEGREE>, heuristic: NoiseHeuristic) -> Self {
+        let bound = match heuristic {
+            NoiseHeuristic::WorstCase => infinity_norm(&poly) as f64,
+            NoiseHeuristic::CanonicalEmbedding => {
+                let q = poly.context().modulus();
+                let sum_sq: f64 = poly
+                    .coeffs()
+                    .iter()
+                    .map(|&c| (centered(c, q) as f64).powi(2))
+                    .sum();
+                let sigma = (sum_sq / DEGREE as f64).sqrt();
+                6.0 * (DEGREE as f64).sqrt() * sigma
+            }
+        };
+
+        Self::new(poly, bound, heuristic)
+    }
+
+    /// Get the tracked polynomial
+    pub fn poly(&self) -> &NttPolynomial<DEGREE> {
+        &self.poly
+    }
+
+    /// Unwrap the tracked polynomial
+    pub fn into_inner(self) -> NttPolynomial<DEGREE> {
+        self.poly
+    }
+
+    /// Current noise bound
+    pub fn bound(&self) -> f64 {
+        self.bound
+    }
+
+    /// Heuristic used to propagate the bound
+    pub fn heuristic(&self) -> NoiseHeuristic {
+        self.heuristic
+    }
+
+    /// Actual infinity norm of the centered coefficients
+    pub fn measured_norm(&self) -> u64 {
+        infinity_norm(&self.poly)
+    }
+
+    /// Returns true if the measured norm does not exceed the tracked bound
+    pub fn is_within_bound(&self) -> bool {
+        self.measured_norm() as f64 <= self.bound
+    }
+
+    /// Remaining noise budget in bits, `log2(q / (2 * bound))`.
+    ///
+    /// Decryption-style rounding stays correct while this is positive.
+    pub fn noise_budget_bits(&self) -> f64 {
+        let q = self.poly.context().modulus() as f64;
+        (q / (2.0 * self.bound.max(1.0))).log2()
+    }
+
+    /// Apply the automorphism `x -> x^k`, which leaves both norms unchanged
+    pub fn automorphism(&self, k: usize) -> Self {
+        Self::new(self.poly.automorphism(k), self.bound, self.heuristic)
+    }
+
+    /// Multiply by the scalar `c`, scaling the bound by its centered size
+    pub fn mul_scalar(&self, c: u64) -> Self {
+        let q = self.poly.context().modulus();
+        let poly = self.poly.mul_scalar(c);
+        let factor = centered(c % q, q).unsigned_abs() as f64;
+        Self::new(poly, self.bound * factor, self.heuristic)
+    }
+
+    fn product_bound(&self, rhs: &Self) -> f64 {
+        match self.heuristic {
+            NoiseHeuristic::WorstCase => DEGREE as f64 * self.bound * rhs.bound,
+            NoiseHeuristic::CanonicalEmbedding => self.bound * rhs.bound,
+        }
+    }
+}
+
+impl<const DEGREE: usize> Add for &TrackedPoly<DEGREE> {
+    type Output = TrackedPoly<DEGREE>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        debug_assert_eq!(
+            self.heuristic, rhs.heuristic,
+            "Cannot combine bounds tracked with different heuristics"
+        );
+
+        TrackedPoly::new(
+            &self.poly + &rhs.poly,
+            self.bound + rhs.bound,
+            self.heuristic,
+        )
+    }
+}
+
+impl<const DEGREE: usize> Sub for &TrackedPoly<DEGREE> {
+    type Output = TrackedPoly<DEGREE>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        debug_assert_eq!(
+            self.heuristic, rhs.heuristic,
+            "Cannot combine bounds tracked with different heuristics"
+        );
+
+        TrackedPoly::new(
+            &self.poly - &rhs.poly,
+            self.bound + rhs.bound,
+            self.heuristic,
+        )
+    }
+}
+
+impl<const DEGREE: usize> Mul for &TrackedPoly<DEGREE> {
+    type Output = TrackedPoly<DEGREE>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        debug_assert_eq!(
+            self.heuristic, rhs.heuristic,
+            "Cannot combine bounds tracked with different heuristics"
+        );
+
+        TrackedPoly::new(
+            &self.poly * &rhs.poly,
+            self.product_bound(rhs),
+            self.heuristic,
+        )
+    }
+}
@@ -6,7 +6,23 @@
 //! The polynomial operations (addition, multiplication) are implemented as standard
 //! Rust operators, making the API intuitive while providing O(N log N) performance
 //! for multiplication through NTT-based convolution.
-use crate::context::NttContext;
+#[cfg(target_arch = "x86_64")]
+use crate::avx2;
+use crate::congruence::{CongruenceClass, ct_is_zero};
+#[cfg(feature = "rkyv")]
+use crate::context::ArchivedNttContext;
+use crate::context::{
+    ContextMismatch, NttContext, Ordering, boxed_zeros, to_bitrev_order,
+};
+use crate::counters::Op;
+#[cfg(feature = "cross-check")]
+use crate::crosscheck;
+use crate::galois::{conjugation_element, galois_element};
+use crate::noise::centered;
+use crate::polyalg;
+use crate::reducer::{Reducer, Shoup};
+use crate::storage::{Heap, Storage};
+use std::fmt;
 use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use std::sync::Arc;
 
@@ -16,8 +32,13 @@ use std::sync::Arc;
 /// fast negacyclic convolution where `x^N = -1`. All arithmetic operations
 /// maintain this negacyclic property automatically.
 ///
+/// Every operation keeps coefficients canonical, in `[0, q)`, provided the
+/// coefficients it was constructed from are.
+///
 /// # Type Parameters
 /// * `DEGREE` - Polynomial degree (must be power of 2), fixed at compile time
+/// * `S` - Coefficient [storage](crate::storage): [`Heap`] by default, or
+///   [`Inline`](crate::Inline) to skip the allocation at small degrees
 ///
 /// # Examples
 /// ```rust
@@ -32,29 +53,554 @@ use std::sync::Arc;
 /// let b = NttPolynomial::from_coeffs([2, 1, 0, 1], Arc::clone(&ctx));
 /// let c = &a * &b; // Fast negacyclic convolution
 /// ```
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct NttPolynomial<const DEGREE: usize, S: Storage = Heap, R: Reducer = Shoup>
+{
+    coeffs: S::Array<DEGREE>,
+    context: Arc<NttContext<DEGREE, R>>,
+}
+
+/// A coefficient is not a canonical residue modulo `q`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoefficientOutOfRange {
+    pub index: usize,
+    pub value: u64,
+    pub modulus: u64,
+}
+
+impl fmt::Display for CoefficientOutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "coefficient {} = {} is not below q = {}",
+            self.index, self.value, self.modulus
+        )
+    }
+}
+
+impl std::error::Error for CoefficientOutOfRange {}
+
+/// An iterator or slice did not provide exactly `DEGREE` coefficients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthMismatch {
+    /// The iterator ended after `found` coefficients
+    TooFew { expected: usize, found: usize },
+    /// The iterator had a coefficient left after `expected`
+    TooMany { expected: usize },
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooFew { expected, found } => {
+                write!(f, "expected {expected} coefficients, got {found}")
+            }
+            Self::TooMany { expected } => {
+                write!(f, "expected {expected} coefficients, got more")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LengthMismatch {}
+
+/// Coefficients rejected by [`NttPolynomial::try_from_slice`] and the
+/// `TryFrom` conversions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidCoefficients {
+    /// Not exactly `DEGREE` coefficients
+    Length(LengthMismatch),
+    /// A coefficient is not below `q`
+    OutOfRange(CoefficientOutOfRange),
+}
+
+impl fmt::Display for InvalidCoefficients {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length(e) => write!(f, "invalid length: {e}"),
+            Self::OutOfRange(e) => write!(f, "invalid coefficient: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for InvalidCoefficients {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Length(e) => Some(e),
+            Self::OutOfRange(e) => Some(e),
+        }
+    }
+}
+
+impl From<LengthMismatch> for InvalidCoefficients {
+    fn from(e: LengthMismatch) -> Self {
+        Self::Length(e)
+    }
+}
+
+impl From<CoefficientOutOfRange> for InvalidCoefficients {
+    fn from(e: CoefficientOutOfRange) -> Self {
+        Self::OutOfRange(e)
+    }
+}
+
+/// Incremental construction of a polynomial from coefficient chunks, e.g.
+/// as a decoder produces them.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::*;
+///
+/// let mut builder = NttPolynomial::builder(NttContext::<4>::new(17));
+/// builder.extend([1, 2]);
+/// builder.push(3);
+/// builder.extend(std::iter::once(4));
+/// assert_eq!(builder.finish().unwrap().coeffs(), &[1, 2, 3, 4]);
+/// ```
 #[derive(Debug, Clone)]
-pub struct NttPolynomial<const DEGREE: usize> {
-    coeffs: [u64; DEGREE],
-    context: Arc<NttContext<DEGREE>>,
+pub struct PolyBuilder<const DEGREE: usize, R: Reducer = Shoup> {
+    coeffs: Box<[u64; DEGREE]>,
+    len: usize,
+    /// Set once more than `DEGREE` coefficients were pushed
+    overflow: bool,
+    context: Arc<NttContext<DEGREE, R>>,
+}
+
+impl<const DEGREE: usize, R: Reducer> PolyBuilder<DEGREE, R> {
+    /// Append one coefficient
+    pub fn push(&mut self, coeff: u64) {
+        if self.len < DEGREE {
+            self.coeffs[self.len] = coeff;
+            self.len += 1;
+        } else {
+            self.overflow = true;
+        }
+    }
+
+    /// Number of coefficients pushed so far, at most `DEGREE`
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if nothing was pushed yet
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The polynomial, if exactly `DEGREE` coefficients were pushed
+    ///
+    /// # Panics
+    /// * If the context is [strict](NttContext::new_strict) and a
+    ///   coefficient is not below `q`
+    pub fn finish(self) -> Result<NttPolynomial<DEGREE, Heap, R>, LengthMismatch> {
+        if self.overflow {
+            Err(LengthMismatch::TooMany { expected: DEGREE })
+        } else if self.len < DEGREE {
+            Err(LengthMismatch::TooFew {
+                expected: DEGREE,
+                found: self.len,
+            })
+        } else {
+            Ok(NttPolynomial::from_boxed_coeffs(self.coeffs, self.context))
+        }
+    }
+}
+
+impl<const DEGREE: usize, R: Reducer> Extend<u64> for PolyBuilder<DEGREE, R> {
+    fn extend<I: IntoIterator<Item = u64>>(&mut self, iter: I) {
+        for coeff in iter {
+            self.push(coeff);
+        }
+    }
+}
+
+/// Zero-pad up to `DEGREE` coefficients
+fn pad_coeffs<const DEGREE: usize>(coeffs: &[u64]) -> Box<[u64; DEGREE]> {
+    assert!(
+        coeffs.len() <= DEGREE,
+        "expected at most {DEGREE} coefficients, got {}",
+        coeffs.len()
+    );
+    let mut padded = boxed_zeros::<DEGREE>();
+    padded[..coeffs.len()].copy_from_slice(coeffs);
+    padded
+}
+
+fn check_canonical(coeffs: &[u64], q: u64) -> Result<(), CoefficientOutOfRange> {
+    match coeffs.iter().position(|&c| c >= q) {
+        Some(index) => Err(CoefficientOutOfRange {
+            index,
+            value: coeffs[index],
+            modulus: q,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// How [`NttPolynomial::convert_to`] reads coefficients modulo the source
+/// modulus `q`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lift {
+    /// As integers in `[0, q)`
+    Plain,
+    /// As signed integers in `(-q/2, q/2]`
+    Centered,
 }
 
-impl<const DEGREE: usize> NttPolynomial<DEGREE> {
+impl<const DEGREE: usize, R: Reducer> NttPolynomial<DEGREE, Heap, R> {
     /// Create polynomial from coefficients
+    ///
+    /// # Panics
+    /// * If the context is [strict](NttContext::new_strict) and a
+    ///   coefficient is not below `q`
     pub fn from_coeffs(
         coeffs: [u64; DEGREE],
-        context: Arc<NttContext<DEGREE>>,
+        context: Arc<NttContext<DEGREE, R>>,
     ) -> Self {
-        Self { coeffs, context }
+        Self::from_coeffs_in(coeffs, context, Heap)
     }
 
-    /// Create zero polynomial
-    pub fn zero(context: Arc<NttContext<DEGREE>>) -> Self {
-        Self {
-            coeffs: [0u64; DEGREE],
+    /// Create polynomial from coefficients that are already on the heap.
+    ///
+    /// With [`Heap`] storage this takes ownership without copying. For
+    /// large degrees it also avoids the stack: an array of `2^16`
+    /// coefficients is half a megabyte, which
+    /// [`from_coeffs`](Self::from_coeffs) would first have to receive by
+    /// value.
+    ///
+    /// # Panics
+    /// * If the context is [strict](NttContext::new_strict) and a
+    ///   coefficient is not below `q`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// const N: usize = 1 << 16;
+    /// let ctx = NttContext::<N>::new(find_first_prime_up(50, N));
+    /// let coeffs: Box<[u64]> = (0..N as u64).collect();
+    /// let a = NttPolynomial::from_boxed_coeffs(coeffs.try_into().unwrap(), ctx);
+    /// assert_eq!(a.coeffs()[N - 1], N as u64 - 1);
+    /// ```
+    pub fn from_boxed_coeffs(
+        coeffs: Box<[u64; DEGREE]>,
+        context: Arc<NttContext<DEGREE, R>>,
+    ) -> Self {
+        Self::from_storage(coeffs, context)
+    }
+
+    /// Create polynomial from coefficients, failing if any is not below `q`
+    pub fn try_from_coeffs(
+        coeffs: [u64; DEGREE],
+        context: Arc<NttContext<DEGREE, R>>,
+    ) -> Result<Self, CoefficientOutOfRange> {
+        check_canonical(&coeffs, context.modulus())?;
+        Ok(Self::from_coeffs(coeffs, context))
+    }
+
+    /// Create polynomial from a slice of exactly `DEGREE` canonical
+    /// coefficients, the checked counterpart of
+    /// [`from_coeffs`](Self::from_coeffs) for data of unknown shape.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let ctx = NttContext::<4>::new(17);
+    /// let a = NttPolynomial::try_from_slice(&[1, 2, 3, 4], ctx.clone()).unwrap();
+    /// assert_eq!(a.coeffs(), &[1, 2, 3, 4]);
+    ///
+    /// let err = NttPolynomial::try_from_slice(&[1, 2, 3], ctx.clone()).unwrap_err();
+    /// assert!(matches!(err, InvalidCoefficients::Length(_)));
+    /// let err = NttPolynomial::try_from_slice(&[1, 2, 3, 17], ctx).unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid coefficient: coefficient 3 = 17 is not below q = 17");
+    /// ```
+    pub fn try_from_slice(
+        coeffs: &[u64],
+        context: Arc<NttContext<DEGREE, R>>,
+    ) -> Result<Self, InvalidCoefficients> {
+        if coeffs.len() != DEGREE {
+            return Err(if coeffs.len() < DEGREE {
+                LengthMismatch::TooFew {
+                    expected: DEGREE,
+                    found: coeffs.len(),
+                }
+            } else {
+                LengthMismatch::TooMany { expected: DEGREE }
+            }
+            .into());
+        }
+        check_canonical(coeffs, context.modulus())?;
+        Ok(Self::from_boxed_coeffs(pad_coeffs(coeffs), context))
+    }
+
+    /// Create polynomial from its first `coeffs.len()` coefficients, the
+    /// rest being zero
+    ///
+    /// # Panics
+    /// * If there are more than `DEGREE` coefficients, or the context is
+    ///   [strict](NttContext::new_strict) and a coefficient is not below `q`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let ctx = NttContext::<8>::new(find_first_prime_up(20, 8));
+    /// let a = NttPolynomial::from_short_coeffs(&[1, 2, 3], ctx);
+    /// assert_eq!(a.coeffs(), &[1, 2, 3, 0, 0, 0, 0, 0]);
+    /// ```
+    pub fn from_short_coeffs(
+        coeffs: &[u64],
+        context: Arc<NttContext<DEGREE, R>>,
+    ) -> Self {
+        Self::from_boxed_coeffs(pad_coeffs(coeffs), context)
+    }
+
+    /// Create polynomial from arbitrary `u64` values, reduced modulo `q`,
+    /// the coefficients past `coeffs.len()` being zero.
+    ///
+    /// Unlike [`from_coeffs`](Self::from_coeffs) this accepts any input, so
+    /// data from files, the network or another modulus can be ingested
+    /// without a separate validation pass, even in strict contexts.
+    ///
+    /// # Panics
+    /// * If there are more than `DEGREE` coefficients
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let ctx = NttContext::<4>::new_strict(17);
+    /// let a = NttPolynomial::from_unreduced(&[17, 20, u64::MAX], ctx);
+    /// assert_eq!(a.coeffs(), &[0, 3, u64::MAX % 17, 0]);
+    /// ```
+    pub fn from_unreduced(
+        coeffs: &[u64],
+        context: Arc<NttContext<DEGREE, R>>,
+    ) -> Self {
+        let mut coeffs = pad_coeffs(coeffs);
+        context.class.reduce_slice(&mut coeffs[..]);
+        Self::from_boxed_coeffs(coeffs, context)
+    }
+
+    /// Create polynomial from exactly `DEGREE` coefficients of an iterator,
+    /// without an intermediate collection.
+    ///
+    /// Stops reading at the first coefficient past `DEGREE`, so the
+    /// iterator may be unbounded.
+    ///
+    /// # Panics
+    /// * If the context is [strict](NttContext::new_strict) and a
+    ///   coefficient is not below `q`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let ctx = NttContext::<4>::new(17);
+    /// let text = "1 2 3 4";
+    /// let a = NttPolynomial::try_from_iter(
+    ///     text.split(' ').map(|t| t.parse::<u64>().unwrap()),
+    ///     ctx,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(a.coeffs(), &[1, 2, 3, 4]);
+    /// ```
+    pub fn try_from_iter(
+        coeffs: impl IntoIterator<Item = u64>,
+        context: Arc<NttContext<DEGREE, R>>,
+    ) -> Result<Self, LengthMismatch> {
+        let mut builder = Self::builder(context);
+        builder.extend(coeffs.into_iter().take(DEGREE + 1));
+        builder.finish()
+    }
+
+    /// Empty [`PolyBuilder`] over `context`, filled through [`Extend`]
+    pub fn builder(context: Arc<NttContext<DEGREE, R>>) -> PolyBuilder<DEGREE, R> {
+        PolyBuilder {
+            coeffs: boxed_zeros(),
+            len: 0,
+            overflow: false,
             context,
         }
     }
 
+    /// Create zero polynomial
+    pub fn zero(context: Arc<NttContext<DEGREE, R>>) -> Self {
+        Self::zero_in(context, Heap)
+    }
+
+    /// Generate random polynomial with coefficients in [1, q).
+    pub fn sample_random<G: rand::Rng>(
+        context: Arc<NttContext<DEGREE, R>>,
+        rng: &mut G,
+    ) -> Self {
+        Self::sample_random_in(context, rng, Heap)
+    }
+
+    /// Generate polynomial with exactly `nonzero_count` nonzero
+    /// coefficients, at uniformly random distinct positions and uniform in
+    /// `[1, q)`; the rest are zero.
+    ///
+    /// # Panics
+    /// * If `nonzero_count > DEGREE`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use rand::{SeedableRng, rngs::StdRng};
+    ///
+    /// let ctx = NttContext::<64>::new(find_first_prime_up(30, 64));
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let a = NttPolynomial::sample_sparse_uniform(ctx, 5, &mut rng);
+    /// assert_eq!(a.coeffs().iter().filter(|&&c| c != 0).count(), 5);
+    /// ```
+    pub fn sample_sparse_uniform<G: rand::Rng>(
+        context: Arc<NttContext<DEGREE, R>>,
+        nonzero_count: usize,
+        rng: &mut G,
+    ) -> Self {
+        assert!(
+            nonzero_count <= DEGREE,
+            "nonzero count {nonzero_count} exceeds degree {DEGREE}"
+        );
+        let mut coeffs = boxed_zeros::<DEGREE>();
+
+        for i in rand::seq::index::sample(rng, DEGREE, nonzero_count) {
+            coeffs[i] = rng.random_range(1..context.modulus());
+        }
+
+        Self::from_storage(coeffs, context)
+    }
+
+    /// Negacyclic product of two polynomials given by their first
+    /// coefficients, zero-padded to `DEGREE`.
+    ///
+    /// The forward transforms skip the stages that only move zeros, see
+    /// [`ntt_forward_padded`](Self::ntt_forward_padded), which saves most
+    /// when the inputs are much shorter than `DEGREE`.
+    ///
+    /// # Panics
+    /// * If either input has more than `DEGREE` coefficients
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let ctx = NttContext::<8>::new(find_first_prime_up(20, 8));
+    /// // (1 + x)(1 + 2x + x^2) = 1 + 3x + 3x^2 + x^3
+    /// let c = NttPolynomial::convolve_short(&[1, 1], &[1, 2, 1], ctx);
+    /// assert_eq!(c.coeffs(), &[1, 3, 3, 1, 0, 0, 0, 0]);
+    /// ```
+    pub fn convolve_short(
+        a: &[u64],
+        b: &[u64],
+        context: Arc<NttContext<DEGREE, R>>,
+    ) -> Self {
+        let mut result = Self::from_short_coeffs(a, Arc::clone(&context));
+        let mut other = Self::from_short_coeffs(b, context);
+
+        result.ntt_forward_padded(a.len());
+        other.ntt_forward_padded(b.len());
+        let context = Arc::clone(&result.context);
+        context.pointwise_mul_assign(&mut result.coeffs, &other.coeffs);
+
+        result.ntt_inverse_shoup();
+        #[cfg(feature = "cross-check")]
+        crosscheck::check_negacyclic(
+            "convolve_short",
+            &result.context,
+            a,
+            b,
+            &result.coeffs,
+        );
+        result
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer> NttPolynomial<DEGREE, S, R> {
+    /// [`from_coeffs`](NttPolynomial::from_coeffs) into the given storage
+    ///
+    /// # Panics
+    /// * If the context is [strict](NttContext::new_strict) and a
+    ///   coefficient is not below `q`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let ctx = NttContext::<4>::new(17);
+    /// let a = NttPolynomial::from_coeffs_in([1, 2, 3, 4], ctx.clone(), Inline);
+    /// let b = NttPolynomial::from_coeffs_in([4, 3, 2, 1], ctx, Inline);
+    /// assert_eq!((&a + &b).coeffs(), &[5; 4]);
+    /// ```
+    pub fn from_coeffs_in(
+        coeffs: [u64; DEGREE],
+        context: Arc<NttContext<DEGREE, R>>,
+        _storage: S,
+    ) -> Self {
+        Self::from_storage(S::from_array(coeffs), context)
+    }
+
+    /// [`zero`](NttPolynomial::zero) in the given storage
+    pub fn zero_in(context: Arc<NttContext<DEGREE, R>>, _storage: S) -> Self {
+        Self::from_storage(S::zeros(), context)
+    }
+
+    /// [`sample_random`](NttPolynomial::sample_random) into the given
+    /// storage
+    pub fn sample_random_in<G: rand::Rng>(
+        context: Arc<NttContext<DEGREE, R>>,
+        rng: &mut G,
+        _storage: S,
+    ) -> Self {
+        let q = context.modulus();
+        Self::from_fn(context, |_| rng.random_range(1..q))
+    }
+
+    /// Create polynomial from `[f(0), ..., f(N-1)]`, built in place in the
+    /// storage
+    fn from_fn(
+        context: Arc<NttContext<DEGREE, R>>,
+        f: impl FnMut(usize) -> u64,
+    ) -> Self {
+        Self::from_storage(S::from_fn(f), context)
+    }
+
+    /// Wrap `coeffs`, checking them in strict contexts
+    fn from_storage(
+        coeffs: S::Array<DEGREE>,
+        context: Arc<NttContext<DEGREE, R>>,
+    ) -> Self {
+        if context.strict {
+            check_canonical(&coeffs[..], context.modulus()).unwrap_or_else(|e| {
+                panic!("{e}, use try_from_coeffs to handle this")
+            });
+        }
+        context.count(Op::Allocation, 1);
+        Self { coeffs, context }
+    }
+
+    /// Take the coefficients out, without copying
+    pub fn into_boxed_coeffs(self) -> Box<[u64; DEGREE]> {
+        S::into_boxed(self.coeffs)
+    }
+
+    /// Whether every coefficient lies in `[0, q)`
+    pub fn is_canonical(&self) -> bool {
+        check_canonical(&self.coeffs[..], self.context.modulus()).is_ok()
+    }
+
+    /// Reduce every coefficient into `[0, q)`, e.g. after writing through
+    /// [`coeffs_mut`](Self::coeffs_mut)
+    pub fn canonicalize(&mut self) {
+        self.context.class.reduce_slice(&mut self.coeffs[..]);
+    }
+
     /// Get coefficients
     pub fn coeffs(&self) -> &[u64; DEGREE] {
         &self.coeffs
@@ -66,14 +612,23 @@ impl<const DEGREE: usize> NttPolynomial<DEGREE> {
     }
 
     /// Get context
-    pub fn context(&self) -> &Arc<NttContext<DEGREE>> {
+    pub fn context(&self) -> &Arc<NttContext<DEGREE, R>> {
         &self.context
     }
 
+    /// Coefficients and context borrowed together, for views
+    pub(crate) fn parts_mut(
+        &mut self,
+    ) -> (&mut [u64; DEGREE], &NttContext<DEGREE, R>) {
+        (&mut self.coeffs, &self.context)
+    }
+
     // NTT operations
     pub fn ntt_forward(&mut self) {
         // Cooley-Tukey forward negacyclic NTT
         // using algorithm from https://eprint.iacr.org/2016/504.pdf
+        self.context.count_transform(Op::Forward);
+        let r = self.context.reducer();
 
         let mut t = DEGREE >> 1;
         let mut n = 1;
@@ -86,10 +641,10 @@ impl<const DEGREE: usize> NttPolynomial<DEGREE> {
 
                 for j in j1..=j2 {
                     let u = self.coeffs[j];
-                    let v = self.context.class.modmul(self.coeffs[j + t], s);
+                    let v = r.modmul(self.coeffs[j + t], s);
 
-                    self.coeffs[j] = self.context.class.modadd(u, v);
-                    self.coeffs[j + t] = self.context.class.modsub(u, v);
+                    self.coeffs[j] = r.modadd(u, v);
+                    self.coeffs[j + t] = r.modsub(u, v);
                 }
             }
 
@@ -100,10 +655,12 @@ impl<const DEGREE: usize> NttPolynomial<DEGREE> {
 
     pub fn ntt_inverse(&mut self) {
         // Gentleman-Sande inverse negacyclic NTT
+        self.context.count_transform(Op::Inverse);
+        let r = self.context.reducer();
         let mut t = 1;
         let mut h = DEGREE >> 1;
 
-        while h > 0 {
+        while h > 1 {
             let mut j1 = 0;
 
             for i in 0..h {
@@ -114,10 +671,8 @@ impl<const DEGREE: usize> NttPolynomial<DEGREE> {
                     let u = self.coeffs[j];
                     let v = self.coeffs[j + t];
 
-                    self.coeffs[j] = self.context.class.modadd(u, v);
-                    self.coeffs[j + t] = self.context.class.modsub(u, v);
-                    self.coeffs[j + t] =
-                        self.context.class.modmul(self.coeffs[j + t], s);
+                    self.coeffs[j] = r.modadd(u, v);
+                    self.coeffs[j + t] = r.modmul(r.modsub(u, v), s);
                 }
 
                 j1 += t << 1;
@@ -127,99 +682,296 @@ impl<const DEGREE: usize> NttPolynomial<DEGREE> {
             t <<= 1;
         }
 
-        // Final normalization
-        for coeff in &mut self.coeffs {
-            *coeff = self.context.class.modmul(*coeff, self.context.inv_n);
+        // Last stage with the normalization by N^(-1) folded in
+        if DEGREE > 1 {
+            let (lo, hi) = self.coeffs.split_at_mut(t);
+            for (x, y) in lo.iter_mut().zip(hi) {
+                let (u, v) = (*x, *y);
+                *x = r.modmul(r.modadd(u, v), self.context.inv_n);
+                *y = r.modmul(r.modsub(u, v), self.context.inv_n_itf);
+            }
         }
     }
 
     pub fn ntt_forward_shoup(&mut self) {
-        // Cooley-Tukey forward negacyclic NTT with Shoup multiplication
-        let mut t = DEGREE >> 1;
-        let mut n = 1;
-
-        while n < DEGREE {
-            for i in 0..n {
-                let j1 = 2 * i * t;
-                let j2 = j1 + t - 1;
-                let s = self.context.tf[n + i];
-                let s_shoup = self.context.tf_shoup[n + i];
+        self.context.forward_shoup_in_place(&mut self.coeffs);
+    }
 
-                for j in j1..=j2 {
-                    let v = self.context.class.modmul_shoup(
-                        self.coeffs[j + t],
-                        s,
-                        s_shoup,
-                    );
+    pub fn ntt_inverse_shoup(&mut self) {
+        self.context.inverse_shoup_in_place(&mut self.coeffs);
+    }
 
-                    self.coeffs[j + t] =
-                        self.context.class.modsub(self.coeffs[j], v);
-                    self.context.class.modadd_eq(&mut self.coeffs[j], v);
-                }
-            }
+    /// Forward NTT with the evaluations left in `order`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let ctx = NttContext::<4>::new(17);
+    /// let mut a = NttPolynomial::from_coeffs([0, 1, 0, 0], ctx.clone());
+    /// a.ntt_forward_ordered(Ordering::Natural);
+    ///
+    /// // a(x) = x, so slot i holds ψ^(2i+1)
+    /// let mut points = ctx.evaluation_points();
+    /// to_bitrev_order(&mut points[..]);
+    /// assert_eq!(a.coeffs(), &*points);
+    ///
+    /// a.ntt_inverse_ordered(Ordering::Natural);
+    /// assert_eq!(a.coeffs(), &[0, 1, 0, 0]);
+    /// ```
+    pub fn ntt_forward_ordered(&mut self, order: Ordering) {
+        self.ntt_forward_shoup();
+        if order == Ordering::Natural {
+            to_bitrev_order(&mut self.coeffs[..]);
+        }
+    }
 
-            n <<= 1;
-            t >>= 1;
+    /// Inverse NTT of evaluations given in `order`
+    pub fn ntt_inverse_ordered(&mut self, order: Ordering) {
+        if order == Ordering::Natural {
+            to_bitrev_order(&mut self.coeffs[..]);
         }
+        self.ntt_inverse_shoup();
     }
 
-    pub fn ntt_inverse_shoup(&mut self) {
-        // Gentleman-Sande inverse negacyclic NTT with Shoup multiplication
-        let mut t = 1;
-        let mut h = DEGREE >> 1;
+    /// Forward NTT with Harvey's lazy reduction: the butterflies keep
+    /// values in `[0, 4q)` and a single pass at the end reduces them, which
+    /// saves most of the corrections of
+    /// [`ntt_forward_shoup`](Self::ntt_forward_shoup). The evaluations are
+    /// the same, canonical ones.
+    ///
+    /// Needs `4q < 2^64`; for larger moduli, and on a
+    /// [reducer](crate::reducer) other than [`Shoup`], this is
+    /// `ntt_forward_shoup`. Otherwise it runs the scalar code rather than
+    /// the context's [kernel](crate::kernel).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use std::sync::Arc;
+    ///
+    /// let ctx = NttContext::<8>::new(find_first_prime_up(60, 8));
+    /// let mut a = NttPolynomial::from_coeffs([1, 2, 3, 4, 5, 6, 7, 8], Arc::clone(&ctx));
+    /// let mut b = a.clone();
+    /// a.ntt_forward_lazy();
+    /// b.ntt_forward_shoup();
+    /// assert_eq!(a.coeffs(), b.coeffs());
+    /// ```
+    pub fn ntt_forward_lazy(&mut self) {
+        self.context.forward_lazy_in_place(&mut self.coeffs);
+    }
 
-        while h > 0 {
-            let mut j1 = 0;
+    /// Forward Shoup NTT of `self` written into `out`, leaving `self`
+    /// untouched.
+    ///
+    /// `out` is overwritten and takes the context of `self`; reusing one
+    /// output buffer avoids a clone per transform.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use std::sync::Arc;
+    ///
+    /// let ctx = NttContext::<4>::new(find_first_prime_up(20, 4));
+    /// let a = NttPolynomial::from_coeffs([1, 2, 3, 4], Arc::clone(&ctx));
+    /// let mut evals = NttPolynomial::zero(ctx);
+    ///
+    /// a.ntt_forward_to(&mut evals);
+    /// let mut back = a.clone();
+    /// evals.ntt_inverse_to(&mut back);
+    /// assert_eq!(back.coeffs(), a.coeffs());
+    /// ```
+    pub fn ntt_forward_to(&self, out: &mut Self) {
+        self.copy_into(out);
+        self.context.forward_shoup_in_place(&mut out.coeffs);
+    }
 
-            for i in 0..h {
-                let j2 = j1 + t - 1;
-                let s = self.context.itf[h + i];
-                let s_shoup = self.context.itf_shoup[h + i];
+    /// Inverse Shoup NTT of `self` written into `out`, see
+    /// [`ntt_forward_to`](Self::ntt_forward_to)
+    pub fn ntt_inverse_to(&self, out: &mut Self) {
+        self.copy_into(out);
+        self.context.inverse_shoup_in_place(&mut out.coeffs);
+    }
 
-                for j in j1..=j2 {
-                    let u = self.coeffs[j];
-                    let v = self.coeffs[j + t];
+    /// Forward Shoup NTT by value, for pipelines such as
+    /// `a.into_ntt().pointwise_mul(&b_evals).into_intt()`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let ctx = NttContext::<4>::new(find_first_prime_up(20, 4));
+    /// let a = NttPolynomial::from_coeffs([1, 2, 0, 0], ctx.clone());
+    /// let b = NttPolynomial::from_coeffs([3, 1, 0, 0], ctx);
+    ///
+    /// let product = a.to_ntt().pointwise_mul(&b.to_ntt()).into_intt();
+    /// assert_eq!(product.coeffs(), (&a * &b).coeffs());
+    /// ```
+    pub fn into_ntt(mut self) -> Self {
+        self.ntt_forward_shoup();
+        self
+    }
 
-                    self.coeffs[j] = self.context.class.modadd(u, v);
-                    self.coeffs[j + t] = self.context.class.modsub(u, v);
-                    self.context.class.modmul_shoup_eq(
-                        &mut self.coeffs[j + t],
-                        s,
-                        s_shoup,
-                    );
-                }
+    /// Forward Shoup NTT into a new polynomial, leaving `self` untouched
+    pub fn to_ntt(&self) -> Self {
+        self.clone().into_ntt()
+    }
 
-                j1 += t << 1;
-            }
+    /// Inverse Shoup NTT by value, see [`into_ntt`](Self::into_ntt)
+    pub fn into_intt(mut self) -> Self {
+        self.ntt_inverse_shoup();
+        self
+    }
 
-            h >>= 1;
-            t <<= 1;
+    /// Inverse Shoup NTT into a new polynomial, leaving `self` untouched
+    pub fn to_intt(&self) -> Self {
+        self.clone().into_intt()
+    }
+
+    /// Overwrite `out` with the coefficients and context of `self`, without
+    /// allocating
+    fn copy_into(&self, out: &mut Self) {
+        out.coeffs.copy_from_slice(&self.coeffs[..]);
+        if !Arc::ptr_eq(&out.context, &self.context) {
+            out.context = Arc::clone(&self.context);
         }
+    }
 
-        // Final normalization with Shoup
-        for coeff in &mut self.coeffs {
-            self.context.class.modmul_shoup_eq(
-                coeff,
-                self.context.inv_n,
-                self.context.inv_n_shoup,
-            );
+    /// [`ntt_forward_shoup`](Self::ntt_forward_shoup) for a polynomial whose
+    /// coefficients from `len` on are known to be zero.
+    ///
+    /// While a whole half of every butterfly block is zero, a stage only
+    /// copies the lower half into the upper one, so the first
+    /// `log2(N / len)` stages run without multiplications. The result is
+    /// undefined if a coefficient past `len` is not zero.
+    ///
+    /// # Panics
+    /// * If `len > DEGREE`
+    pub fn ntt_forward_padded(&mut self, len: usize) {
+        assert!(len <= DEGREE, "length {len} exceeds degree {DEGREE}");
+        debug_assert!(self.coeffs[len..].iter().all(|&c| c == 0));
+        self.context.forward_padded_in_place(&mut self.coeffs, len);
+    }
+
+    /// Substitute `x → c·x`, multiplying coefficient i by `c^i`.
+    ///
+    /// Evaluating the result at `x` equals evaluating `self` at `c·x`. This
+    /// is the twist behind coset transforms (`c = g`), Bluestein's chirp and
+    /// the cyclic/negacyclic correspondence (`c = ψ` maps `x^N - 1` to
+    /// `-(x^N + 1)`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let ctx = NttContext::<4>::new(find_first_prime_up(20, 4));
+    /// let a = NttPolynomial::from_coeffs([1, 1, 1, 1], ctx);
+    /// assert_eq!(a.scale_argument(2).coeffs(), &[1, 2, 4, 8]);
+    /// ```
+    pub fn scale_argument(&self, c: u64) -> Self {
+        let c = self.context.class.reduce(c);
+        let r = self.context.reducer();
+
+        let mut powers = boxed_zeros::<DEGREE>();
+        powers[0] = 1;
+        for i in 1..DEGREE {
+            powers[i] = r.modmul(powers[i - 1], c);
+        }
+
+        self.scale_argument_by_powers(&powers)
+    }
+
+    /// [`scale_argument`](Self::scale_argument) with precomputed powers
+    /// `powers[i] = c^i`, for callers that reuse one scale many times.
+    pub fn scale_argument_by_powers(&self, powers: &[u64; DEGREE]) -> Self {
+        let mut result = self.clone();
+        result.scale_argument_by_powers_eq(powers);
+        result
+    }
+
+    /// In-place [`scale_argument_by_powers`](Self::scale_argument_by_powers)
+    pub fn scale_argument_by_powers_eq(&mut self, powers: &[u64; DEGREE]) {
+        self.context.count(Op::ModMul, DEGREE as u64);
+        let r = self.context.reducer();
+        for (coeff, &power) in self.coeffs.iter_mut().zip(powers) {
+            *coeff = r.modmul(*coeff, power);
         }
     }
 
+    /// Forward NTT over the coset g·H of the evaluation domain.
+    ///
+    /// Pre-weights coefficient i by g^i, so slot i ends up holding
+    /// `a(g·ψ^(2·bitrev(i)+1))`. Since the coset avoids the roots of
+    /// `x^N + 1`, the vanishing polynomial of the standard domain is
+    /// invertible at every point.
+    pub fn ntt_forward_coset(&mut self) {
+        let context = Arc::clone(&self.context);
+        self.scale_argument_by_powers_eq(&context.coset_powers);
+        self.ntt_forward_shoup();
+    }
+
+    /// Inverse of [`ntt_forward_coset`](Self::ntt_forward_coset).
+    pub fn ntt_inverse_coset(&mut self) {
+        self.ntt_inverse_shoup();
+        let context = Arc::clone(&self.context);
+        self.scale_argument_by_powers_eq(&context.coset_inv_powers);
+    }
+
+    /// Forward NTT as a twisted cyclic transform.
+    ///
+    /// Weights coefficient i by `ψ^i`, which turns the negacyclic product
+    /// into a cyclic one, then runs a plain cyclic NTT with `ω = ψ^2`. The
+    /// slots come out in the same order as [`ntt_forward`](Self::ntt_forward),
+    /// so either inverse undoes either forward; this form matches libraries
+    /// built around a single cyclic core. The weighted coefficients on their
+    /// own are `self.scale_argument(ctx.psi())`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let ctx = NttContext::<8>::new(find_first_prime_up(20, 8));
+    /// let a = NttPolynomial::from_coeffs([1, 2, 3, 4, 5, 6, 7, 8], ctx);
+    ///
+    /// let mut twisted = a.clone();
+    /// twisted.ntt_forward_twisted();
+    /// let mut direct = a.clone();
+    /// direct.ntt_forward();
+    /// assert_eq!(twisted.coeffs(), direct.coeffs());
+    ///
+    /// twisted.ntt_inverse_twisted();
+    /// assert_eq!(twisted.coeffs(), a.coeffs());
+    /// ```
+    pub fn ntt_forward_twisted(&mut self) {
+        self.context.count_transform(Op::Forward);
+        self.context.count(Op::ModMul, DEGREE as u64);
+        self.context.twisted_plan().forward(&mut self.coeffs[..]);
+    }
+
+    /// Inverse of [`ntt_forward_twisted`](Self::ntt_forward_twisted): cyclic
+    /// inverse NTT, then removal of the `ψ^i` weights
+    pub fn ntt_inverse_twisted(&mut self) {
+        self.context.count_transform(Op::Inverse);
+        self.context.count(Op::ModMul, DEGREE as u64);
+        self.context.twisted_plan().inverse(&mut self.coeffs[..]);
+    }
+
     /// Naive O(n^2) negacyclic convolution for benchmarking/testing
     pub fn naive_negacyclic_convolution(&self, other: &Self) -> Self {
-        debug_assert_eq!(self.context.modulus(), other.context.modulus());
+        self.expect_compatible(other, "convolve");
 
-        let mut result_coeffs = [0u64; DEGREE];
+        let mut result_coeffs = S::zeros::<DEGREE>();
+        let products = (DEGREE * DEGREE) as u64;
+        self.context.count(Op::ModMul, products);
+        self.context.count(Op::ModAdd, products);
+        let r = self.context.reducer();
 
         for (i, result) in result_coeffs.iter_mut().enumerate() {
             // Sum products for j <= i
             for j in 0..=i {
                 self.context.class.modadd_eq(
                     result,
-                    self.context
-                        .class
-                        .modmul(self.coeffs[j], other.coeffs[i - j]),
+                    r.modmul(self.coeffs[j], other.coeffs[i - j]),
                 );
             }
 
@@ -227,27 +979,23 @@ impl<const DEGREE: usize> NttPolynomial<DEGREE> {
             for j in (i + 1)..DEGREE {
                 self.context.class.modsub_eq(
                     result,
-                    self.context
-                        .class
-                        .modmul(self.coeffs[j], other.coeffs[DEGREE + i - j]),
+                    r.modmul(self.coeffs[j], other.coeffs[DEGREE + i - j]),
                 );
             }
         }
 
-        NttPolynomial {
-            coeffs: result_coeffs,
-            context: Arc::clone(&self.context),
-        }
+        Self::from_storage(result_coeffs, Arc::clone(&self.context))
     }
 
     // Convolution methods
     pub fn negacyclic_convolution(&self, other: &Self) -> Self {
-        debug_assert_eq!(
-            self.context.modulus(),
-            other.context.modulus(),
-            "Cannot convolve polynomials with different moduli"
-        );
+        self.expect_compatible(other, "convolve");
+        self.mul_unchecked(other)
+    }
 
+    /// [`negacyclic_convolution`](Self::negacyclic_convolution) without the
+    /// modulus check; the result is meaningless if the contexts differ
+    pub fn mul_unchecked(&self, other: &Self) -> Self {
         let mut result = self.clone();
         let mut other_copy = other.clone();
 
@@ -255,206 +1003,1383 @@ impl<const DEGREE: usize> NttPolynomial<DEGREE> {
         other_copy.ntt_forward();
 
         // Pointwise multiplication in NTT domain
+        self.context.count(Op::ModMul, DEGREE as u64);
+        let r = self.context.reducer();
         for i in 0..DEGREE {
-            result.coeffs[i] = self
-                .context
-                .class
-                .modmul(result.coeffs[i], other_copy.coeffs[i]);
+            result.coeffs[i] = r.modmul(result.coeffs[i], other_copy.coeffs[i]);
         }
 
         result.ntt_inverse();
+        #[cfg(feature = "cross-check")]
+        crosscheck::check_negacyclic(
+            "negacyclic_convolution",
+            &self.context,
+            &self.coeffs[..],
+            &other.coeffs[..],
+            &result.coeffs,
+        );
         result
     }
 
+    /// Negacyclic product through the context's [kernel](crate::kernel),
+    /// by default Shoup transforms
     pub fn negacyclic_convolution_shoup(&self, other: &Self) -> Self {
-        debug_assert_eq!(
-            self.context.modulus(),
-            other.context.modulus(),
-            "Cannot convolve polynomials with different moduli"
+        self.expect_compatible(other, "convolve");
+
+        let mut result = self.clone();
+        let mut other_evals = S::from_fn::<DEGREE>(|i| other.coeffs[i]);
+
+        self.context.forward_shoup_in_place(&mut result.coeffs);
+        self.context.forward_shoup_in_place(&mut other_evals);
+        self.context
+            .pointwise_mul_assign(&mut result.coeffs, &other_evals);
+        self.context.inverse_shoup_in_place(&mut result.coeffs);
+        #[cfg(feature = "cross-check")]
+        crosscheck::check_negacyclic(
+            "negacyclic_convolution_shoup",
+            &self.context,
+            &self.coeffs[..],
+            &other.coeffs[..],
+            &result.coeffs,
+        );
+        result
+    }
+
+    /// `self = self·other mod (x^N + 1)`, computed in the buffer of `self`.
+    ///
+    /// Only `other` is copied, into a scratch array for its transform; when
+    /// the same `other` multiplies many polynomials, transform it once and
+    /// use [`negacyclic_convolution_assign_evals`](Self::negacyclic_convolution_assign_evals).
+    ///
+    /// # Panics
+    /// * If the contexts have different moduli
+    pub fn negacyclic_convolution_assign(&mut self, other: &Self) {
+        self.expect_compatible(other, "convolve");
+        #[cfg(feature = "cross-check")]
+        let lhs = self.coeffs.clone();
+
+        let mut other_evals = S::from_fn::<DEGREE>(|i| other.coeffs[i]);
+        self.context.forward_shoup_in_place(&mut other_evals);
+        self.convolve_evals_in_place(&other_evals);
+        #[cfg(feature = "cross-check")]
+        crosscheck::check_negacyclic(
+            "negacyclic_convolution_assign",
+            &self.context,
+            &lhs[..],
+            &other.coeffs[..],
+            &self.coeffs,
         );
+    }
+
+    /// `self·other` written into `out`, which is overwritten and takes the
+    /// context of `self`. Reusing one output buffer avoids the clone of
+    /// `self` made by [`negacyclic_convolution`](Self::negacyclic_convolution).
+    ///
+    /// # Panics
+    /// * If the contexts of `self` and `other` have different moduli
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use std::sync::Arc;
+    ///
+    /// let ctx = NttContext::<4>::new(find_first_prime_up(20, 4));
+    /// let a = NttPolynomial::from_coeffs([1, 2, 0, 0], Arc::clone(&ctx));
+    /// let b = NttPolynomial::from_coeffs([3, 1, 0, 0], Arc::clone(&ctx));
+    ///
+    /// let mut out = NttPolynomial::zero(ctx);
+    /// a.negacyclic_convolution_into(&b, &mut out);
+    /// assert_eq!(out.coeffs(), &[3, 7, 2, 0]);
+    /// ```
+    pub fn negacyclic_convolution_into(&self, other: &Self, out: &mut Self) {
+        self.expect_compatible(other, "convolve");
+        self.copy_into(out);
+        out.negacyclic_convolution_assign(other);
+    }
 
+    /// [`negacyclic_convolution_assign`](Self::negacyclic_convolution_assign)
+    /// with `other` already in NTT form, as produced by
+    /// [`ntt_forward_shoup`](Self::ntt_forward_shoup) or
+    /// [`ntt_forward_to`](Self::ntt_forward_to): one forward and one
+    /// inverse transform, and no allocation.
+    ///
+    /// # Panics
+    /// * If the contexts have different moduli
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use std::sync::Arc;
+    ///
+    /// let ctx = NttContext::<4>::new(find_first_prime_up(20, 4));
+    /// let mut key = NttPolynomial::from_coeffs([0, 1, 0, 0], Arc::clone(&ctx));
+    /// key.ntt_forward_shoup();
+    ///
+    /// // Multiplying by x shifts and negates the wrapped coefficient
+    /// let mut a = NttPolynomial::from_coeffs([1, 2, 3, 4], ctx);
+    /// a.negacyclic_convolution_assign_evals(&key);
+    /// assert_eq!(a.coeffs(), &[a.context().modulus() - 4, 1, 2, 3]);
+    /// ```
+    pub fn negacyclic_convolution_assign_evals(&mut self, other_evals: &Self) {
+        self.expect_compatible(other_evals, "convolve");
+        #[cfg(feature = "cross-check")]
+        let lhs = self.coeffs.clone();
+
+        self.convolve_evals_in_place(&other_evals.coeffs);
+        #[cfg(feature = "cross-check")]
+        {
+            // Straight to the kernel, keeping the check out of the counters
+            let mut rhs = other_evals.coeffs.clone();
+            self.context.kernel().inverse(&self.context, &mut rhs);
+            crosscheck::check_negacyclic(
+                "negacyclic_convolution_assign_evals",
+                &self.context,
+                &lhs[..],
+                &rhs[..],
+                &self.coeffs,
+            );
+        }
+    }
+
+    /// Forward transform of `self`, slot-wise product with `other_evals`
+    /// and inverse transform, through the context's kernel
+    fn convolve_evals_in_place(&mut self, other_evals: &[u64; DEGREE]) {
+        let context = &self.context;
+        context.forward_shoup_in_place(&mut self.coeffs);
+        context.pointwise_mul_assign(&mut self.coeffs, other_evals);
+        context.inverse_shoup_in_place(&mut self.coeffs);
+    }
+
+    /// Slot-wise product `self∘other` of two polynomials in NTT form, which
+    /// is the NTT form of their negacyclic product.
+    ///
+    /// Operands kept in the evaluation domain, such as RLWE key material,
+    /// can be combined with these pointwise operations and transformed back
+    /// once at the end.
+    ///
+    /// # Panics
+    /// * If the contexts have different moduli
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use std::sync::Arc;
+    ///
+    /// let ctx = NttContext::<4>::new(find_first_prime_up(20, 4));
+    /// let a = NttPolynomial::from_coeffs([1, 2, 0, 0], Arc::clone(&ctx));
+    /// let b = NttPolynomial::from_coeffs([3, 1, 0, 0], ctx);
+    ///
+    /// let (mut a_evals, mut b_evals) = (a.clone(), b.clone());
+    /// a_evals.ntt_forward_shoup();
+    /// b_evals.ntt_forward_shoup();
+    /// let mut product = a_evals.pointwise_mul(&b_evals);
+    /// product.ntt_inverse_shoup();
+    /// assert_eq!(product.coeffs(), (&a * &b).coeffs());
+    /// ```
+    pub fn pointwise_mul(&self, other: &Self) -> Self {
         let mut result = self.clone();
-        let mut other_copy = other.clone();
+        result.pointwise_mul_assign(other);
+        result
+    }
+
+    /// `self = self∘other`, see [`pointwise_mul`](Self::pointwise_mul)
+    ///
+    /// # Panics
+    /// * If the contexts have different moduli
+    pub fn pointwise_mul_assign(&mut self, other: &Self) {
+        self.expect_compatible(other, "multiply");
+        self.context
+            .pointwise_mul_assign(&mut self.coeffs, &other.coeffs);
+    }
+
+    /// `self += a∘b`, a fused multiply-accumulate for sums of NTT-form
+    /// products such as `Σ a_i·s_i`
+    ///
+    /// # Panics
+    /// * If the contexts have different moduli
+    pub fn pointwise_mul_add(&mut self, a: &Self, b: &Self) {
+        self.expect_compatible(a, "multiply");
+        self.expect_compatible(b, "multiply");
+        self.context
+            .pointwise_mul_add(&mut self.coeffs, &a.coeffs, &b.coeffs);
+    }
+
+    /// Full (acyclic) product of two polynomials, of length `2N - 1`.
+    ///
+    /// Both operands are zero-padded to `2N` coefficients and multiplied with
+    /// a cyclic transform of that length, which the context builds from ψ on
+    /// first use and caches, so no second context is needed. Since the
+    /// product has fewer than `2N` coefficients nothing wraps around.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use std::sync::Arc;
+    ///
+    /// let ctx = NttContext::<4>::new(find_first_prime_up(20, 4));
+    /// let a = NttPolynomial::from_coeffs([1, 1, 0, 1], Arc::clone(&ctx));
+    /// let b = NttPolynomial::from_coeffs([1, 0, 0, 1], ctx);
+    ///
+    /// // (1 + x + x^3)(1 + x^3) = 1 + x + 2x^3 + x^4 + x^6
+    /// assert_eq!(a.linear_convolution(&b), vec![1, 1, 0, 2, 1, 0, 1]);
+    /// ```
+    pub fn linear_convolution(&self, other: &Self) -> Vec<u64> {
+        self.expect_compatible(other, "convolve");
+
+        let mut product = self
+            .context
+            .linear_plan()
+            .convolve(&self.coeffs[..], &other.coeffs[..]);
+        product.truncate(2 * DEGREE - 1);
+        #[cfg(feature = "cross-check")]
+        crosscheck::check_linear(
+            "linear_convolution",
+            &self.context,
+            &self.coeffs[..],
+            &other.coeffs[..],
+            &product,
+        );
+        product
+    }
+
+    /// `self·other mod (x^N - 1)`: the cyclic convolution, where
+    /// coefficients wrapping past `x^N` keep their sign.
+    ///
+    /// Runs a cyclic NTT of length N with `ω = ψ²`, whose tables are built
+    /// on first use.
+    ///
+    /// # Panics
+    /// * If the contexts have different moduli
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use std::sync::Arc;
+    ///
+    /// let ctx = NttContext::<4>::new(17);
+    /// let a = NttPolynomial::from_coeffs([1, 2, 3, 4], Arc::clone(&ctx));
+    /// let x = NttPolynomial::from_coeffs([0, 1, 0, 0], ctx);
+    ///
+    /// // A pure rotation, unlike the negacyclic [13, 1, 2, 3]
+    /// assert_eq!(a.cyclic_convolution(&x).coeffs(), &[4, 1, 2, 3]);
+    /// ```
+    pub fn cyclic_convolution(&self, other: &Self) -> Self {
+        self.expect_compatible(other, "convolve");
+
+        let product = self
+            .context
+            .cyclic_plan()
+            .convolve(&self.coeffs[..], &other.coeffs[..]);
+        assert_eq!(product.len(), DEGREE, "plan of length DEGREE");
+        Self::from_fn(Arc::clone(&self.context), |i| product[i])
+    }
+
+    /// Cyclic forward NTT in place: slot `i` receives `a(ω^bitrev(i))` for
+    /// `ω = ψ²`, the evaluations over the N-th roots of unity, so
+    /// slot-wise products of two transforms are cyclic products
+    pub fn ntt_forward_cyclic(&mut self) {
+        self.context.cyclic_plan().forward(&mut self.coeffs[..]);
+    }
+
+    /// Inverse of [`ntt_forward_cyclic`](Self::ntt_forward_cyclic)
+    pub fn ntt_inverse_cyclic(&mut self) {
+        self.context.cyclic_plan().inverse(&mut self.coeffs[..]);
+    }
 
+    /// Low-degree extension onto a domain `BLOWN / DEGREE` times larger.
+    ///
+    /// Interprets `self` as evaluations over this context's domain (NTT form),
+    /// interpolates the unique polynomial of degree < DEGREE, zero-pads it to
+    /// BLOWN coefficients and evaluates over the larger domain of `target`.
+    /// The blowup factor is fixed by the target context's degree.
+    ///
+    /// # Panics
+    /// * If BLOWN is not a multiple of DEGREE
+    /// * If `target` uses a different modulus
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use std::sync::Arc;
+    ///
+    /// let q = find_first_prime_up(20, 16);
+    /// let small = NttContext::<4>::new(q);
+    /// let large = NttContext::<16>::new(q);
+    ///
+    /// let evals = NttPolynomial::from_coeffs([1, 2, 3, 4], small);
+    /// let extended = evals.lde(&large); // blowup factor 4
+    /// assert_eq!(extended.coeffs().len(), 16);
+    /// ```
+    pub fn lde<const BLOWN: usize>(
+        &self,
+        target: &Arc<NttContext<BLOWN>>,
+    ) -> NttPolynomial<BLOWN, S> {
+        assert_eq!(
+            BLOWN % DEGREE,
+            0,
+            "target degree {BLOWN} must be a multiple of {DEGREE}"
+        );
+        assert_eq!(
+            self.context.modulus(),
+            target.modulus(),
+            "Cannot extend into a context with a different modulus"
+        );
+
+        let mut interpolant = self.clone();
+        interpolant.ntt_inverse_shoup();
+
+        let mut coeffs = S::zeros::<BLOWN>();
+        coeffs[..DEGREE].copy_from_slice(&interpolant.coeffs[..]);
+
+        let mut result = NttPolynomial::from_storage(coeffs, Arc::clone(target));
         result.ntt_forward_shoup();
-        other_copy.ntt_forward_shoup();
+        result
+    }
 
-        // Pointwise multiplication in NTT domain
-        for i in 0..DEGREE {
-            result.coeffs[i] = self
-                .context
-                .class
-                .modmul(result.coeffs[i], other_copy.coeffs[i]);
+    /// Evaluate the polynomial at `x` (any `u64`, reduced mod `q`) using
+    /// Horner's rule.
+    pub fn evaluate(&self, x: u64) -> u64 {
+        let (class, r) = (&self.context.class, self.context.reducer());
+        let x = class.reduce(x);
+        self.context.count(Op::ModMul, DEGREE as u64);
+        self.context.count(Op::ModAdd, DEGREE as u64);
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(0, |acc, &c| class.modadd(r.modmul(acc, x), c))
+    }
+
+    /// Values at `1, c, c^2, …, c^(count-1)` in `O((N + count) log(N +
+    /// count))`, see [`polyalg::evaluate_at_powers`].
+    ///
+    /// The transforms need `q` to support length `(N + count)` rounded up
+    /// to a power of two; otherwise the product falls back to schoolbook.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let ctx = NttContext::<4>::new(find_first_prime_up(20, 4));
+    /// let a = NttPolynomial::from_coeffs([1, 1, 0, 1], ctx);
+    /// // 1 + x + x^3 at 1, 3, 9
+    /// assert_eq!(a.evaluate_at_powers(3, 3), vec![3, 31, 739]);
+    /// ```
+    pub fn evaluate_at_powers(&self, c: u64, count: usize) -> Vec<u64> {
+        let class = &self.context.class;
+        polyalg::evaluate_at_powers(&self.coeffs[..], class.reduce(c), count, class)
+    }
+
+    /// Apply the ring automorphism `x -> x^k` for odd `k`.
+    ///
+    /// Coefficient `i` moves to position `i*k mod 2N`, picking up a sign flip
+    /// when that position wraps past `N` (since `x^N = -1`). The index map is
+    /// cached in the context, so repeated calls with the same `k` are cheap.
+    pub fn automorphism(&self, k: usize) -> Self {
+        assert!(k % 2 == 1, "automorphism exponent must be odd, got {k}");
+
+        let table = self.context.automorphism_table(k);
+        let mut result_coeffs = S::zeros::<DEGREE>();
+
+        for (&coeff, &(idx, negate)) in self.coeffs.iter().zip(table.iter()) {
+            result_coeffs[idx] = if negate {
+                self.context.class.modneg(coeff)
+            } else {
+                coeff
+            };
         }
 
-        result.ntt_inverse_shoup();
-        result
+        Self::from_storage(result_coeffs, Arc::clone(&self.context))
     }
 
-    /// Generate random polynomial with coefficients in [1, q).
-    pub fn sample_random<R: rand::Rng>(
-        context: Arc<NttContext<DEGREE>>,
-        rng: &mut R,
+    /// Rotate the plaintext slots by `r` positions (automorphism `x -> x^(5^r)`)
+    pub fn rotate_slots(&self, r: isize) -> Self {
+        self.automorphism(galois_element(r, DEGREE))
+    }
+
+    /// Conjugate the plaintext slots (automorphism `x -> x^(-1)`)
+    pub fn conjugate(&self) -> Self {
+        self.automorphism(conjugation_element(DEGREE))
+    }
+
+    /// The same coefficients reduced into the ring of `target`, which may
+    /// have a different modulus.
+    ///
+    /// With [`Lift::Plain`] each coefficient `c ∈ [0, q)` becomes `c mod p`;
+    /// with [`Lift::Centered`] it is first read as its representative in
+    /// `(-q/2, q/2]`, which preserves small signed values such as noise or
+    /// ternary secrets.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let a = NttPolynomial::from_coeffs([1, 0, 0, 16], NttContext::<4>::new(17));
+    /// let target = NttContext::<4>::new(41);
+    ///
+    /// // 16 ≡ -1 (mod 17)
+    /// assert_eq!(a.convert_to(target.clone(), Lift::Plain).coeffs(), &[1, 0, 0, 16]);
+    /// assert_eq!(a.convert_to(target, Lift::Centered).coeffs(), &[1, 0, 0, 40]);
+    /// ```
+    pub fn convert_to(
+        &self,
+        target: Arc<NttContext<DEGREE, R>>,
+        lift: Lift,
     ) -> Self {
-        let mut coeffs = [0u64; DEGREE];
+        let (q, p) = (self.context.modulus(), target.modulus());
+
+        Self::from_fn(target, |i| {
+            let c = self.coeffs[i];
+            match lift {
+                Lift::Plain => c % p,
+                Lift::Centered => {
+                    let v = centered(c, q);
+                    let r = v.unsigned_abs() % p;
+                    if v < 0 && r != 0 { p - r } else { r }
+                }
+            }
+        })
+    }
 
-        for coeff in &mut coeffs {
-            *coeff = rng.random_range(1..context.modulus());
+    /// Whether both polynomials have congruent coefficients, comparing all
+    /// of them in time independent of their values.
+    ///
+    /// Use this instead of comparing [`coeffs`](Self::coeffs) when either
+    /// side is secret. Only the moduli, which are public, may end the
+    /// comparison early: polynomials over different rings are never equal.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use std::sync::Arc;
+    ///
+    /// let ctx = NttContext::<4>::new(17);
+    /// let a = NttPolynomial::from_coeffs([1, 2, 3, 0], Arc::clone(&ctx));
+    /// let b = NttPolynomial::from_coeffs([1, 2, 3, 17], Arc::clone(&ctx));
+    /// let c = NttPolynomial::from_coeffs([1, 2, 4, 0], ctx);
+    /// assert!(a.ct_eq(&b));
+    /// assert!(!a.ct_eq(&c));
+    /// ```
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        if self.context.modulus() != other.context.modulus() {
+            return false;
         }
 
-        Self { coeffs, context }
+        let class = &self.context.class;
+        let diff = self
+            .coeffs
+            .iter()
+            .zip(&other.coeffs[..])
+            .fold(0, |acc, (&a, &b)| {
+                acc | (class.ct_reduce(a) ^ class.ct_reduce(b))
+            });
+        ct_is_zero(diff)
+    }
+
+    /// Equality test by evaluating the difference at a few random points
+    /// of `Z_q`.
+    ///
+    /// Unequal polynomials differ by a nonzero polynomial of degree below
+    /// `N`, which vanishes at a random point with probability at most
+    /// `(N-1)/q`, so a wrong `true` has probability at most `((N-1)/q)^4`.
+    /// Parties holding large polynomials can likewise compare single
+    /// [`evaluate`](Self::evaluate) fingerprints at a jointly chosen random
+    /// point instead of exchanging all coefficients. Comparing random NTT
+    /// slots instead would be cheaper but is not a sound test, as a
+    /// difference may sit in a single slot.
+    ///
+    /// Polynomials over different moduli are never equal.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use rand::{SeedableRng, rngs::StdRng};
+    /// use std::sync::Arc;
+    ///
+    /// let ctx = NttContext::<4>::new(find_first_prime_up(30, 4));
+    /// let a = NttPolynomial::from_coeffs([1, 2, 3, 4], Arc::clone(&ctx));
+    /// let b = NttPolynomial::from_coeffs([1, 2, 3, 5], ctx);
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// assert!(a.probably_equal(&a.clone(), &mut rng));
+    /// assert!(!a.probably_equal(&b, &mut rng));
+    /// ```
+    pub fn probably_equal<G: rand::Rng>(&self, other: &Self, rng: &mut G) -> bool {
+        const POINTS: u64 = 4;
+        if self.context.check_compatible(&other.context).is_err() {
+            return false;
+        }
+
+        let (class, r) = (&self.context.class, self.context.reducer());
+        self.context.count(Op::ModMul, POINTS * DEGREE as u64);
+        self.context.count(Op::ModAdd, 2 * POINTS * DEGREE as u64);
+        (0..POINTS).all(|_| {
+            let x = rng.random_range(0..class.q());
+            let diff = self.coeffs.iter().zip(&other.coeffs[..]).rev().fold(
+                0,
+                |acc, (&a, &b)| {
+                    let acc = r.modmul(acc, x);
+                    class.modadd(acc, class.modsub(a, b))
+                },
+            );
+            diff == 0
+        })
     }
 }
 
 // Trait implementations - this is where the math logic lives
 
-impl<const DEGREE: usize> Add for &NttPolynomial<DEGREE> {
-    type Output = NttPolynomial<DEGREE>;
+impl<const DEGREE: usize, S: Storage, R: Reducer> Clone
+    for NttPolynomial<DEGREE, S, R>
+{
+    fn clone(&self) -> Self {
+        Self::from_fn(Arc::clone(&self.context), |i| self.coeffs[i])
+    }
+}
 
-    fn add(self, rhs: Self) -> Self::Output {
-        debug_assert_eq!(
-            self.context.modulus(),
-            rhs.context.modulus(),
-            "Cannot add polynomials with different moduli"
-        );
+impl<const DEGREE: usize, R: Reducer> NttContext<DEGREE, R> {
+    /// Forward Shoup NTT of a bare coefficient array, in place
+    pub(crate) fn forward_shoup_in_place(&self, coeffs: &mut [u64; DEGREE]) {
+        self.count_transform(Op::Forward);
+        self.kernel.0.forward(self, coeffs);
+    }
 
-        // let mut result_coeffs = [0u64; DEGREE];
-        // for i in 0..DEGREE {
-        //     result_coeffs[i] =
-        //         self.context.class.modadd(self.coeffs[i], rhs.coeffs[i]);
-        // }
+    /// Forward Shoup NTT of an array that is zero from `len` on, in place
+    pub(crate) fn forward_padded_in_place(
+        &self,
+        coeffs: &mut [u64; DEGREE],
+        len: usize,
+    ) {
+        let skipped = R::forward_padded(self, coeffs, len);
+
+        let stages = (DEGREE.trailing_zeros() - skipped) as u64;
+        let butterflies = DEGREE as u64 / 2 * stages;
+        self.count(Op::Forward, 1);
+        self.count(Op::ModMul, butterflies);
+        self.count(Op::ModAdd, 2 * butterflies);
+    }
 
-        let result_coeffs = std::array::from_fn(|i| {
-            self.context.class.modadd(self.coeffs[i], rhs.coeffs[i])
-        });
+    /// Forward NTT with Harvey's lazy butterflies, falling back to the
+    /// kernel for `q ≥ 2^62` and for reducers other than [`Shoup`]
+    pub(crate) fn forward_lazy_in_place(&self, coeffs: &mut [u64; DEGREE]) {
+        self.count_transform(Op::Forward);
+        R::forward_lazy(self, coeffs);
+    }
+
+    /// Inverse Shoup NTT of a bare evaluation array, in place
+    pub(crate) fn inverse_shoup_in_place(&self, coeffs: &mut [u64; DEGREE]) {
+        self.count_transform(Op::Inverse);
+        self.kernel.0.inverse(self, coeffs);
+    }
 
-        NttPolynomial {
-            coeffs: result_coeffs,
-            context: Arc::clone(&self.context),
+    /// Slot-wise product of bare evaluation arrays, into `lhs`, through the
+    /// context's [kernel](crate::kernel)
+    pub fn pointwise_mul_assign(
+        &self,
+        lhs: &mut [u64; DEGREE],
+        rhs: &[u64; DEGREE],
+    ) {
+        self.count(Op::ModMul, DEGREE as u64);
+        self.kernel.0.pointwise(self, lhs, rhs);
+    }
+
+    /// `acc += a∘b` slot-wise on bare evaluation arrays, e.g. to accumulate
+    /// an inner product of NTT-form vectors before one inverse transform
+    pub fn pointwise_mul_add(
+        &self,
+        acc: &mut [u64; DEGREE],
+        a: &[u64; DEGREE],
+        b: &[u64; DEGREE],
+    ) {
+        self.count(Op::ModMul, DEGREE as u64);
+        self.count(Op::ModAdd, DEGREE as u64);
+        let r = self.reducer();
+        for ((c, &x), &y) in acc.iter_mut().zip(a).zip(b) {
+            self.class.modadd_eq(c, r.modmul(x, y));
         }
     }
 }
 
-impl<const DEGREE: usize> Add<&NttPolynomial<DEGREE>> for NttPolynomial<DEGREE> {
-    type Output = NttPolynomial<DEGREE>;
+/// Cooley-Tukey butterflies over bit-reversed twiddles, shared by every
+/// context type that stores Shoup tables, whether the degree is a const
+/// generic or only known at run time. The degree is `coeffs.len()`. On
+/// `x86_64` CPUs with AVX2 the butterflies run four lanes at a time.
+#[inline]
+pub(crate) fn forward_shoup_kernel(
+    class: &CongruenceClass,
+    tf: &[u64],
+    tf_shoup: &[u64],
+    coeffs: &mut [u64],
+) {
+    let len = coeffs.len();
+    #[cfg(target_arch = "x86_64")]
+    if len >= avx2::MIN_DEGREE && std::arch::is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2 support was just detected
+        unsafe { avx2::forward_shoup(class, tf, tf_shoup, coeffs) };
+        return;
+    }
 
-    fn add(self, rhs: &NttPolynomial<DEGREE>) -> Self::Output {
-        &self + rhs
+    forward_shoup_scalar(class, tf, tf_shoup, coeffs, len >= RADIX4_MIN_DEGREE);
+}
+
+/// Scalar part of [`forward_shoup_kernel`], in radix-4 passes or radix-2
+/// stages as `radix4` says, whatever the CPU supports
+pub(crate) fn forward_shoup_scalar(
+    class: &CongruenceClass,
+    tf: &[u64],
+    tf_shoup: &[u64],
+    coeffs: &mut [u64],
+    radix4: bool,
+) {
+    if radix4 {
+        forward_shoup_radix4(class, tf, tf_shoup, coeffs);
+    } else {
+        let len = coeffs.len();
+        forward_shoup_kernel_padded(class, tf, tf_shoup, coeffs, len);
     }
 }
 
-impl<const DEGREE: usize> AddAssign<&NttPolynomial<DEGREE>>
-    for NttPolynomial<DEGREE>
-{
-    fn add_assign(&mut self, rhs: &NttPolynomial<DEGREE>) {
-        debug_assert_eq!(
-            self.context.modulus(),
-            rhs.context.modulus(),
-            "Cannot add polynomials with different moduli"
-        );
+/// Smallest degree from which the scalar Shoup kernels fuse pairs of
+/// stages into radix-4 passes. Below it the tables fit in L1 and the extra
+/// index arithmetic costs more than the saved passes over memory. On CPUs
+/// with AVX2 the vector kernels run instead, at every degree from
+/// `avx2::MIN_DEGREE` on.
+pub(crate) const RADIX4_MIN_DEGREE: usize = 1024;
+
+/// [`forward_shoup_kernel`] with stages `(n, t)` and `(2n, t/2)` fused:
+/// each pass loads four coefficients, applies the twiddle of their block
+/// and then those of its two halves, and stores them once. An odd number
+/// of stages starts with one radix-2 stage. Same twiddles, products and
+/// output order as the radix-2 loop.
+#[inline]
+fn forward_shoup_radix4(
+    class: &CongruenceClass,
+    tf: &[u64],
+    tf_shoup: &[u64],
+    coeffs: &mut [u64],
+) {
+    let degree = coeffs.len();
+    debug_assert!(tf.len() == degree && tf_shoup.len() == degree);
+
+    let mut t = degree >> 1;
+    let mut n = 1;
+
+    if degree.trailing_zeros() % 2 == 1 {
+        let (lo, hi) = coeffs.split_at_mut(t);
+        for (x, y) in lo.iter_mut().zip(hi) {
+            let v = class.modmul_shoup(*y, tf[1], tf_shoup[1]);
+            *y = class.modsub(*x, v);
+            class.modadd_eq(x, v);
+        }
 
-        for i in 0..DEGREE {
-            self.context
-                .class
-                .modadd_eq(&mut self.coeffs[i], rhs.coeffs[i]);
+        n <<= 1;
+        t >>= 1;
+    }
+
+    while n < degree {
+        let h = t >> 1;
+
+        for i in 0..n {
+            let j1 = 2 * i * t;
+            let (s1, s1_shoup) = (tf[n + i], tf_shoup[n + i]);
+            let (s2, s2_shoup) = (tf[2 * (n + i)], tf_shoup[2 * (n + i)]);
+            let (s3, s3_shoup) = (tf[2 * (n + i) + 1], tf_shoup[2 * (n + i) + 1]);
+
+            for j in j1..j1 + h {
+                let (x0, x1) = (coeffs[j], coeffs[j + h]);
+                let v2 = class.modmul_shoup(coeffs[j + t], s1, s1_shoup);
+                let v3 = class.modmul_shoup(coeffs[j + t + h], s1, s1_shoup);
+
+                let (y0, y2) = (class.modadd(x0, v2), class.modsub(x0, v2));
+                let (y1, y3) = (class.modadd(x1, v3), class.modsub(x1, v3));
+                let w1 = class.modmul_shoup(y1, s2, s2_shoup);
+                let w3 = class.modmul_shoup(y3, s3, s3_shoup);
+
+                coeffs[j] = class.modadd(y0, w1);
+                coeffs[j + h] = class.modsub(y0, w1);
+                coeffs[j + t] = class.modadd(y2, w3);
+                coeffs[j + t + h] = class.modsub(y2, w3);
+            }
         }
+
+        n <<= 2;
+        t >>= 2;
     }
 }
 
-impl<const DEGREE: usize> Sub for &NttPolynomial<DEGREE> {
-    type Output = NttPolynomial<DEGREE>;
+/// [`forward_shoup_kernel`] for input that is zero from `len` on. Returns
+/// the number of leading stages reduced to copies.
+#[inline]
+pub(crate) fn forward_shoup_kernel_padded(
+    class: &CongruenceClass,
+    tf: &[u64],
+    tf_shoup: &[u64],
+    coeffs: &mut [u64],
+    len: usize,
+) -> u32 {
+    let degree = coeffs.len();
+    debug_assert!(tf.len() == degree && tf_shoup.len() == degree);
+
+    // Cooley-Tukey forward negacyclic NTT with Shoup multiplication
+    let mut t = degree >> 1;
+    let mut n = 1;
+    let mut skipped = 0;
+
+    // While the nonzero prefix fits in the lower half of every block, each
+    // butterfly sees v = 0 and just duplicates u
+    while n < degree && len <= t {
+        for block in coeffs.chunks_exact_mut(t << 1) {
+            let (lo, hi) = block.split_at_mut(t);
+            hi.copy_from_slice(lo);
+        }
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        debug_assert_eq!(
-            self.context.modulus(),
-            rhs.context.modulus(),
-            "Cannot subtract polynomials with different moduli"
-        );
+        n <<= 1;
+        t >>= 1;
+        skipped += 1;
+    }
 
-        let result_coeffs = std::array::from_fn(|i| {
-            self.context.class.modsub(self.coeffs[i], rhs.coeffs[i])
-        });
+    while n < degree {
+        for i in 0..n {
+            let j1 = 2 * i * t;
+            let j2 = j1 + t - 1;
+            let s = tf[n + i];
+            let s_shoup = tf_shoup[n + i];
+
+            for j in j1..=j2 {
+                let v = class.modmul_shoup(coeffs[j + t], s, s_shoup);
 
-        NttPolynomial {
-            coeffs: result_coeffs,
-            context: Arc::clone(&self.context),
+                coeffs[j + t] = class.modsub(coeffs[j], v);
+                class.modadd_eq(&mut coeffs[j], v);
+            }
         }
+
+        n <<= 1;
+        t >>= 1;
     }
+
+    skipped
 }
 
-impl<const DEGREE: usize> Sub<&NttPolynomial<DEGREE>> for NttPolynomial<DEGREE> {
-    type Output = NttPolynomial<DEGREE>;
+/// Largest modulus (exclusive) for Harvey's lazy butterflies, whose
+/// values grow up to `4q`
+pub(crate) const HARVEY_MODULUS_BOUND: u64 = 1 << 62;
+
+/// Harvey's variant of [`forward_shoup_kernel`]: values stay in `[0, 4q)`
+/// between stages, each butterfly reducing only its first input into
+/// `[0, 2q)` and leaving the twiddle product in `[0, 2q)`, and one pass at
+/// the end brings everything back into `[0, q)`. Needs `q < 2^62`.
+#[inline]
+pub(crate) fn forward_harvey_kernel(
+    class: &CongruenceClass,
+    tf: &[u64],
+    tf_shoup: &[u64],
+    coeffs: &mut [u64],
+) {
+    let degree = coeffs.len();
+    let q = class.q();
+    let two_q = q << 1;
+    debug_assert!(tf.len() == degree && tf_shoup.len() == degree);
+    debug_assert!(q < HARVEY_MODULUS_BOUND);
+
+    let mut t = degree >> 1;
+    let mut n = 1;
+
+    while n < degree {
+        for i in 0..n {
+            let j1 = 2 * i * t;
+            let s = tf[n + i];
+            let s_shoup = tf_shoup[n + i];
+
+            for j in j1..j1 + t {
+                let mut u = coeffs[j];
+                if u >= two_q {
+                    u -= two_q;
+                }
+                let v = class.modmul_shoup_lazy(coeffs[j + t], s, s_shoup);
 
-    fn sub(self, rhs: &NttPolynomial<DEGREE>) -> Self::Output {
-        &self - rhs
+                coeffs[j] = u + v;
+                coeffs[j + t] = u + two_q - v;
+            }
+        }
+
+        n <<= 1;
+        t >>= 1;
+    }
+
+    for c in coeffs.iter_mut() {
+        if *c >= two_q {
+            *c -= two_q;
+        }
+        if *c >= q {
+            *c -= q;
+        }
     }
 }
 
-impl<const DEGREE: usize> SubAssign<&NttPolynomial<DEGREE>>
-    for NttPolynomial<DEGREE>
-{
-    fn sub_assign(&mut self, rhs: &NttPolynomial<DEGREE>) {
-        debug_assert_eq!(
-            self.context.modulus(),
-            rhs.context.modulus(),
-            "Cannot subtract polynomials with different moduli"
-        );
+/// Gentleman-Sande butterflies with the `N^(-1)` scaling folded into the
+/// last stage: `inv_n` scales the sums and `inv_n_itf = itf[1]·N^(-1)` the
+/// differences, each paired with its Shoup value. Vectorized with AVX2 as
+/// [`forward_shoup_kernel`].
+#[inline]
+pub(crate) fn inverse_shoup_kernel(
+    class: &CongruenceClass,
+    itf: &[u64],
+    itf_shoup: &[u64],
+    (inv_n, inv_n_shoup): (u64, u64),
+    (inv_n_itf, inv_n_itf_shoup): (u64, u64),
+    coeffs: &mut [u64],
+) {
+    let degree = coeffs.len();
+    debug_assert!(itf.len() == degree && itf_shoup.len() == degree);
+
+    #[cfg(target_arch = "x86_64")]
+    if degree >= avx2::MIN_DEGREE && std::arch::is_x86_feature_detected!("avx2") {
+        let (inv_n, inv_n_itf) =
+            ((inv_n, inv_n_shoup), (inv_n_itf, inv_n_itf_shoup));
+        // SAFETY: AVX2 support was just detected
+        unsafe {
+            avx2::inverse_shoup(class, itf, itf_shoup, inv_n, inv_n_itf, coeffs)
+        };
+        return;
+    }
+
+    inverse_shoup_scalar(
+        class,
+        itf,
+        itf_shoup,
+        (inv_n, inv_n_shoup),
+        (inv_n_itf, inv_n_itf_shoup),
+        coeffs,
+        degree >= RADIX4_MIN_DEGREE,
+    );
+}
+
+/// Scalar part of [`inverse_shoup_kernel`], see [`forward_shoup_scalar`]
+pub(crate) fn inverse_shoup_scalar(
+    class: &CongruenceClass,
+    itf: &[u64],
+    itf_shoup: &[u64],
+    (inv_n, inv_n_shoup): (u64, u64),
+    (inv_n_itf, inv_n_itf_shoup): (u64, u64),
+    coeffs: &mut [u64],
+    radix4: bool,
+) {
+    let degree = coeffs.len();
+
+    // Gentleman-Sande inverse negacyclic NTT with Shoup multiplication
+    let mut t = 1;
+    let mut h = degree >> 1;
+
+    // With radix4, fuse stages (h, t) and (h/2, 2t) as in
+    // forward_shoup_radix4; the radix-2 loop below then runs at most once
+    while radix4 && h >= 4 {
+        for k in 0..h >> 1 {
+            let j1 = 4 * k * t;
+            let (sa, sa_shoup) = (itf[h + 2 * k], itf_shoup[h + 2 * k]);
+            let (sb, sb_shoup) = (itf[h + 2 * k + 1], itf_shoup[h + 2 * k + 1]);
+            let (sc, sc_shoup) = (itf[(h >> 1) + k], itf_shoup[(h >> 1) + k]);
+
+            for j in j1..j1 + t {
+                let (x0, x1) = (coeffs[j], coeffs[j + t]);
+                let (x2, x3) = (coeffs[j + 2 * t], coeffs[j + 3 * t]);
+
+                let y0 = class.modadd(x0, x1);
+                let y1 = class.modmul_shoup(class.modsub(x0, x1), sa, sa_shoup);
+                let y2 = class.modadd(x2, x3);
+                let y3 = class.modmul_shoup(class.modsub(x2, x3), sb, sb_shoup);
+
+                coeffs[j] = class.modadd(y0, y2);
+                coeffs[j + t] = class.modadd(y1, y3);
+                coeffs[j + 2 * t] =
+                    class.modmul_shoup(class.modsub(y0, y2), sc, sc_shoup);
+                coeffs[j + 3 * t] =
+                    class.modmul_shoup(class.modsub(y1, y3), sc, sc_shoup);
+            }
+        }
+
+        h >>= 2;
+        t <<= 2;
+    }
+
+    while h > 1 {
+        let mut j1 = 0;
+
+        for i in 0..h {
+            let j2 = j1 + t - 1;
+            let s = itf[h + i];
+            let s_shoup = itf_shoup[h + i];
+
+            for j in j1..=j2 {
+                let u = coeffs[j];
+                let v = coeffs[j + t];
+
+                coeffs[j] = class.modadd(u, v);
+                coeffs[j + t] = class.modsub(u, v);
+                class.modmul_shoup_eq(&mut coeffs[j + t], s, s_shoup);
+            }
+
+            j1 += t << 1;
+        }
+
+        h >>= 1;
+        t <<= 1;
+    }
+
+    // Last stage (h = 1, t = N/2) with normalization; for N = 1 the
+    // transform is the identity and N^(-1) = 1
+    if degree > 1 {
+        let (lo, hi) = coeffs.split_at_mut(t);
+        for (x, y) in lo.iter_mut().zip(hi) {
+            let (u, v) = (*x, *y);
+            *x = class.modmul_shoup(class.modadd(u, v), inv_n, inv_n_shoup);
+            *y = class.modmul_shoup(class.modsub(u, v), inv_n_itf, inv_n_itf_shoup);
+        }
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer> NttPolynomial<DEGREE, S, R> {
+    /// Panic unless `other` is over the same ring; `verb` names the
+    /// operation in the message
+    fn expect_compatible(&self, other: &Self, verb: &str) {
+        if let Err(e) = self.context.check_compatible(&other.context) {
+            panic!("Cannot {verb} polynomials: {e}");
+        }
+    }
 
+    /// `self + rhs`, or an error if the contexts have different moduli.
+    ///
+    /// The `+` operator panics in that case instead.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let a = NttPolynomial::zero(NttContext::<4>::new(17));
+    /// let b = NttPolynomial::zero(NttContext::<4>::new(41));
+    /// let err = a.checked_add(&b).unwrap_err();
+    /// assert_eq!((err.lhs_modulus, err.rhs_modulus), (17, 41));
+    /// ```
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, ContextMismatch> {
+        self.context.check_compatible(&rhs.context)?;
+        Ok(self.add_unchecked(rhs))
+    }
+
+    /// `self - rhs`, or an error if the contexts have different moduli
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Self, ContextMismatch> {
+        self.context.check_compatible(&rhs.context)?;
+        Ok(self.sub_unchecked(rhs))
+    }
+
+    /// `self * rhs`, or an error if the contexts have different moduli
+    pub fn checked_mul(&self, rhs: &Self) -> Result<Self, ContextMismatch> {
+        self.context.check_compatible(&rhs.context)?;
+        Ok(self.mul_unchecked(rhs))
+    }
+
+    /// `self + rhs` without the modulus check, for hot loops whose operands
+    /// are known to share a context
+    pub fn add_unchecked(&self, rhs: &Self) -> Self {
+        self.context.count(Op::ModAdd, DEGREE as u64);
+        Self::from_fn(Arc::clone(&self.context), |i| {
+            self.context.class.modadd(self.coeffs[i], rhs.coeffs[i])
+        })
+    }
+
+    /// `self - rhs` without the modulus check
+    pub fn sub_unchecked(&self, rhs: &Self) -> Self {
+        self.context.count(Op::ModAdd, DEGREE as u64);
+        Self::from_fn(Arc::clone(&self.context), |i| {
+            self.context.class.modsub(self.coeffs[i], rhs.coeffs[i])
+        })
+    }
+
+    /// `self += rhs` without the modulus check
+    pub fn add_assign_unchecked(&mut self, rhs: &Self) {
+        self.context.count(Op::ModAdd, DEGREE as u64);
+        for i in 0..DEGREE {
+            self.context
+                .class
+                .modadd_eq(&mut self.coeffs[i], rhs.coeffs[i]);
+        }
+    }
+
+    /// `self -= rhs` without the modulus check
+    pub fn sub_assign_unchecked(&mut self, rhs: &Self) {
+        self.context.count(Op::ModAdd, DEGREE as u64);
         for i in 0..DEGREE {
             self.context
                 .class
                 .modsub_eq(&mut self.coeffs[i], rhs.coeffs[i]);
         }
     }
+
+    /// `self + c`, adding the constant `c` (any `u64`, reduced mod `q`) to
+    /// the constant term.
+    ///
+    /// In the evaluation domain a constant adds to every slot, see
+    /// [`add_scalar_to_all`](Self::add_scalar_to_all).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    ///
+    /// let a = NttPolynomial::from_coeffs([1, 2, 3, 4], NttContext::<4>::new(17));
+    /// assert_eq!(a.add_scalar(20).coeffs(), &[4, 2, 3, 4]);
+    /// assert_eq!(a.add_scalar_to_all(20).coeffs(), &[4, 5, 6, 7]);
+    /// assert_eq!(a.sub_scalar(2).coeffs(), &[16, 2, 3, 4]);
+    /// ```
+    pub fn add_scalar(&self, c: u64) -> Self {
+        let class = &self.context.class;
+        let mut result = self.clone();
+        self.context.count(Op::ModAdd, 1);
+        class.modadd_eq(&mut result.coeffs[0], class.reduce(c));
+        result
+    }
+
+    /// `self - c`, subtracting the constant `c` from the constant term
+    pub fn sub_scalar(&self, c: u64) -> Self {
+        let class = &self.context.class;
+        let mut result = self.clone();
+        self.context.count(Op::ModAdd, 1);
+        class.modsub_eq(&mut result.coeffs[0], class.reduce(c));
+        result
+    }
+
+    /// Add `c` to every coefficient, i.e. add the constant `c` to a
+    /// polynomial in the evaluation domain
+    pub fn add_scalar_to_all(&self, c: u64) -> Self {
+        let class = &self.context.class;
+        let c = class.reduce(c);
+        self.context.count(Op::ModAdd, DEGREE as u64);
+        Self::from_fn(Arc::clone(&self.context), |i| {
+            class.modadd(self.coeffs[i], c)
+        })
+    }
+
+    /// `c·self` for any `u64` scalar, reduced mod `q`; the `*` operator
+    /// with a `u64` operand calls this
+    pub fn mul_scalar(&self, c: u64) -> Self {
+        let mut result = self.clone();
+        result.mul_scalar_assign(c);
+        result
+    }
+
+    /// `self = c·self`, with one product by the precomputed `c` of the
+    /// context's [reducer](crate::reducer) per coefficient, Shoup's by
+    /// default
+    pub fn mul_scalar_assign(&mut self, c: u64) {
+        let context = &self.context;
+        let r = context.reducer();
+        let c = context.class.reduce(c);
+        let c_prec = r.precompute(c);
+
+        context.count(Op::ModMul, DEGREE as u64);
+        for x in self.coeffs.iter_mut() {
+            *x = r.modmul_precomp(*x, c, c_prec);
+        }
+    }
+
+    /// Subtract `c` from every coefficient
+    pub fn sub_scalar_from_all(&self, c: u64) -> Self {
+        let class = &self.context.class;
+        let c = class.reduce(c);
+        self.context.count(Op::ModAdd, DEGREE as u64);
+        Self::from_fn(Arc::clone(&self.context), |i| {
+            class.modsub(self.coeffs[i], c)
+        })
+    }
+
+    /// `a·p + b·q` for scalars `a`, `b` (any `u64`, reduced mod `q`) in
+    /// one pass, with products by the scalars precomputed for the
+    /// context's [reducer](crate::reducer), Shoup's by default.
+    ///
+    /// Being linear, this works in either domain.
+    ///
+    /// # Panics
+    /// * If `p` and `q` have different moduli
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use std::sync::Arc;
+    ///
+    /// let ctx = NttContext::<4>::new(17);
+    /// let p = NttPolynomial::from_coeffs([1, 2, 3, 4], Arc::clone(&ctx));
+    /// let q = NttPolynomial::from_coeffs([1, 0, 0, 1], ctx);
+    /// // 2·p - q
+    /// let r = NttPolynomial::affine(2, &p, 16, &q);
+    /// assert_eq!(r.coeffs(), &[1, 4, 6, 7]);
+    /// ```
+    pub fn affine(a: u64, p: &Self, b: u64, q: &Self) -> Self {
+        p.expect_compatible(q, "combine");
+
+        let (class, r) = (&p.context.class, p.context.reducer());
+        let (a, b) = (class.reduce(a), class.reduce(b));
+        let (a_prec, b_prec) = (r.precompute(a), r.precompute(b));
+
+        p.context.count(Op::ModMul, 2 * DEGREE as u64);
+        p.context.count(Op::ModAdd, DEGREE as u64);
+        Self::from_fn(Arc::clone(&p.context), |i| {
+            class.modadd(
+                r.modmul_precomp(p.coeffs[i], a, a_prec),
+                r.modmul_precomp(q.coeffs[i], b, b_prec),
+            )
+        })
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer> Add
+    for &NttPolynomial<DEGREE, S, R>
+{
+    type Output = NttPolynomial<DEGREE, S, R>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.expect_compatible(rhs, "add");
+        self.add_unchecked(rhs)
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer> Add<&NttPolynomial<DEGREE, S, R>>
+    for NttPolynomial<DEGREE, S, R>
+{
+    type Output = NttPolynomial<DEGREE, S, R>;
+
+    fn add(self, rhs: &NttPolynomial<DEGREE, S, R>) -> Self::Output {
+        &self + rhs
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer> Add
+    for NttPolynomial<DEGREE, S, R>
+{
+    type Output = NttPolynomial<DEGREE, S, R>;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += &rhs;
+        self
+    }
 }
 
-impl<const DEGREE: usize> Mul for &NttPolynomial<DEGREE> {
-    type Output = NttPolynomial<DEGREE>;
+impl<const DEGREE: usize, S: Storage, R: Reducer> Add<NttPolynomial<DEGREE, S, R>>
+    for &NttPolynomial<DEGREE, S, R>
+{
+    type Output = NttPolynomial<DEGREE, S, R>;
+
+    fn add(self, mut rhs: NttPolynomial<DEGREE, S, R>) -> Self::Output {
+        rhs += self;
+        rhs
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer>
+    AddAssign<&NttPolynomial<DEGREE, S, R>> for NttPolynomial<DEGREE, S, R>
+{
+    fn add_assign(&mut self, rhs: &NttPolynomial<DEGREE, S, R>) {
+        self.expect_compatible(rhs, "add");
+        self.add_assign_unchecked(rhs);
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer> AddAssign
+    for NttPolynomial<DEGREE, S, R>
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer> Sub
+    for &NttPolynomial<DEGREE, S, R>
+{
+    type Output = NttPolynomial<DEGREE, S, R>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.expect_compatible(rhs, "subtract");
+        self.sub_unchecked(rhs)
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer> Sub<&NttPolynomial<DEGREE, S, R>>
+    for NttPolynomial<DEGREE, S, R>
+{
+    type Output = NttPolynomial<DEGREE, S, R>;
+
+    fn sub(self, rhs: &NttPolynomial<DEGREE, S, R>) -> Self::Output {
+        &self - rhs
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer> Sub
+    for NttPolynomial<DEGREE, S, R>
+{
+    type Output = NttPolynomial<DEGREE, S, R>;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= &rhs;
+        self
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer> Sub<NttPolynomial<DEGREE, S, R>>
+    for &NttPolynomial<DEGREE, S, R>
+{
+    type Output = NttPolynomial<DEGREE, S, R>;
+
+    fn sub(self, rhs: NttPolynomial<DEGREE, S, R>) -> Self::Output {
+        self - &rhs
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer>
+    SubAssign<&NttPolynomial<DEGREE, S, R>> for NttPolynomial<DEGREE, S, R>
+{
+    fn sub_assign(&mut self, rhs: &NttPolynomial<DEGREE, S, R>) {
+        self.expect_compatible(rhs, "subtract");
+        self.sub_assign_unchecked(rhs);
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer> SubAssign
+    for NttPolynomial<DEGREE, S, R>
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+/// Checked construction from owned coefficients, see
+/// [`NttPolynomial::try_from_slice`]
+impl<const DEGREE: usize, R: Reducer>
+    TryFrom<(Vec<u64>, Arc<NttContext<DEGREE, R>>)>
+    for NttPolynomial<DEGREE, Heap, R>
+{
+    type Error = InvalidCoefficients;
+
+    fn try_from(
+        (coeffs, context): (Vec<u64>, Arc<NttContext<DEGREE, R>>),
+    ) -> Result<Self, Self::Error> {
+        Self::try_from_slice(&coeffs, context)
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer> Mul
+    for &NttPolynomial<DEGREE, S, R>
+{
+    type Output = NttPolynomial<DEGREE, S, R>;
 
     fn mul(self, rhs: Self) -> Self::Output {
         self.negacyclic_convolution(rhs)
     }
 }
 
-impl<const DEGREE: usize> Mul<&NttPolynomial<DEGREE>> for NttPolynomial<DEGREE> {
-    type Output = NttPolynomial<DEGREE>;
+impl<const DEGREE: usize, S: Storage, R: Reducer> Mul<&NttPolynomial<DEGREE, S, R>>
+    for NttPolynomial<DEGREE, S, R>
+{
+    type Output = NttPolynomial<DEGREE, S, R>;
 
-    fn mul(self, rhs: &NttPolynomial<DEGREE>) -> Self::Output {
+    fn mul(self, rhs: &NttPolynomial<DEGREE, S, R>) -> Self::Output {
         &self * rhs
     }
 }
 
-impl<const DEGREE: usize> MulAssign<&NttPolynomial<DEGREE>>
-    for NttPolynomial<DEGREE>
+impl<const DEGREE: usize, S: Storage, R: Reducer> Mul
+    for NttPolynomial<DEGREE, S, R>
 {
-    fn mul_assign(&mut self, rhs: &NttPolynomial<DEGREE>) {
+    type Output = NttPolynomial<DEGREE, S, R>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer> Mul<NttPolynomial<DEGREE, S, R>>
+    for &NttPolynomial<DEGREE, S, R>
+{
+    type Output = NttPolynomial<DEGREE, S, R>;
+
+    fn mul(self, rhs: NttPolynomial<DEGREE, S, R>) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer>
+    MulAssign<&NttPolynomial<DEGREE, S, R>> for NttPolynomial<DEGREE, S, R>
+{
+    fn mul_assign(&mut self, rhs: &NttPolynomial<DEGREE, S, R>) {
         *self = &*self * rhs;
     }
 }
 
-impl<const DEGREE: usize> Neg for NttPolynomial<DEGREE> {
-    type Output = Self;
+impl<const DEGREE: usize, S: Storage, R: Reducer> MulAssign
+    for NttPolynomial<DEGREE, S, R>
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self *= &rhs;
+    }
+}
 
-    fn neg(self) -> Self::Output {
-        let result_coeffs = self.coeffs.map(|c| self.context.class.modneg(c));
+/// Scalar multiplication, see [`NttPolynomial::mul_scalar`]
+impl<const DEGREE: usize, S: Storage, R: Reducer> Mul<u64>
+    for &NttPolynomial<DEGREE, S, R>
+{
+    type Output = NttPolynomial<DEGREE, S, R>;
+
+    fn mul(self, c: u64) -> Self::Output {
+        self.mul_scalar(c)
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer> Mul<u64>
+    for NttPolynomial<DEGREE, S, R>
+{
+    type Output = NttPolynomial<DEGREE, S, R>;
+
+    fn mul(mut self, c: u64) -> Self::Output {
+        self.mul_scalar_assign(c);
+        self
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer> MulAssign<u64>
+    for NttPolynomial<DEGREE, S, R>
+{
+    fn mul_assign(&mut self, c: u64) {
+        self.mul_scalar_assign(c);
+    }
+}
+
+impl<const DEGREE: usize, S: Storage, R: Reducer> Neg
+    for NttPolynomial<DEGREE, S, R>
+{
+    type Output = Self;
 
-        NttPolynomial {
-            coeffs: result_coeffs,
-            context: self.context,
+    fn neg(mut self) -> Self::Output {
+        self.context.count(Op::ModAdd, DEGREE as u64);
+        let class = &self.context.class;
+        for c in self.coeffs.iter_mut() {
+            *c = class.modneg(*c);
         }
+        self
     }
 }
 
-impl<const DEGREE: usize> Neg for &NttPolynomial<DEGREE> {
-    type Output = NttPolynomial<DEGREE>;
+impl<const DEGREE: usize, S: Storage, R: Reducer> Neg
+    for &NttPolynomial<DEGREE, S, R>
+{
+    type Output = NttPolynomial<DEGREE, S, R>;
 
     fn neg(self) -> Self::Output {
-        let result_coeffs = self.coeffs.map(|c| self.context.class.modneg(c));
+        -self.clone()
+    }
+}
 
-        NttPolynomial {
-            coeffs: result_coeffs,
-            context: Arc::clone(&self.context),
-        }
+/// Read-only access to an archived polynomial, without deserializing it.
+#[cfg(feature = "rkyv")]
+impl<const DEGREE: usize> ArchivedNttPolynomial<DEGREE> {
+    pub fn coeffs(&self) -> &[rkyv::Archived<u64>; DEGREE] {
+        &self.coeffs
+    }
+
+    pub fn context(&self) -> &ArchivedNttContext<DEGREE> {
+        &self.context
     }
 }
 
@@ -465,6 +2390,49 @@ mod tests {
     use crate::math::find_first_prime_up;
     use rand::{SeedableRng, rngs::StdRng};
 
+    // The radix-4 passes only run where AVX2 is missing, so call the
+    // scalar kernels directly, against the Barrett transforms
+    #[test]
+    fn test_scalar_radix4_matches_radix2() {
+        fn check<const N: usize>() {
+            let mut rng = StdRng::seed_from_u64(42);
+            for bits in [30, 62] {
+                let ctx = NttContext::<N>::new(find_first_prime_up(bits, N));
+                let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+                let mut expected = a.clone();
+                expected.ntt_forward();
+
+                for radix4 in [false, true] {
+                    let mut evals = a.clone().into_boxed_coeffs();
+                    let (tf, tf_shoup) = (&ctx.tf[..], &ctx.tf_shoup[..]);
+                    forward_shoup_scalar(
+                        &ctx.class,
+                        tf,
+                        tf_shoup,
+                        &mut evals[..],
+                        radix4,
+                    );
+                    assert_eq!(&*evals, expected.coeffs());
+
+                    inverse_shoup_scalar(
+                        &ctx.class,
+                        &ctx.itf[..],
+                        &ctx.itf_shoup[..],
+                        (ctx.inv_n, ctx.inv_n_shoup),
+                        (ctx.inv_n_itf, ctx.inv_n_itf_shoup),
+                        &mut evals[..],
+                        radix4,
+                    );
+                    assert_eq!(&*evals, a.coeffs());
+                }
+            }
+        }
+
+        check::<16>();
+        check::<1024>();
+        check::<2048>();
+    }
+
     #[test]
     fn test_polynomial_creation() {
         const N: usize = 4;
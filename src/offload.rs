@@ -0,0 +1,221 @@
+//! Running heavy polynomial arithmetic off an async executor.
+//!
+//! A negacyclic product of degree 2^14 or more takes long enough to stall
+//! the executor thread it runs on. [`NttPolynomial::convolve_async`] hands
+//! the work to a [`WorkerPool`] of plain threads and returns an [`Offload`]
+//! future that resolves once the result is ready. The future needs no
+//! particular runtime: it only stores the waker of the last poll and wakes
+//! it from the worker.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::*;
+//! use std::sync::Arc;
+//! # use std::task::{Context, Poll, Wake, Waker};
+//! # struct Unpark(std::thread::Thread);
+//! # impl Wake for Unpark {
+//! #     fn wake(self: Arc<Self>) { self.0.unpark() }
+//! # }
+//! # fn block_on<F: Future>(future: F) -> F::Output {
+//! #     let mut future = std::pin::pin!(future);
+//! #     let waker = Waker::from(Arc::new(Unpark(std::thread::current())));
+//! #     loop {
+//! #         match future.as_mut().poll(&mut Context::from_waker(&waker)) {
+//! #             Poll::Ready(value) => return value,
+//! #             Poll::Pending => std::thread::park(),
+//! #         }
+//! #     }
+//! # }
+//!
+//! async fn square(a: NttPolynomial<1024>) -> NttPolynomial<1024> {
+//!     // The executor thread is free while a worker computes the product
+//!     a.convolve_async(&a).await
+//! }
+//!
+//! let ctx = NttContext::<1024>::new(find_first_prime_up(40, 1024));
+//! let a = NttPolynomial::from_coeffs([3; 1024], Arc::clone(&ctx));
+//! let b = block_on(square(a.clone()));
+//! assert_eq!(b.coeffs(), (&a * &a).coeffs());
+//! ```
+use crate::ntt::NttPolynomial;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::task::{Context, Poll, Waker};
+use std::thread::{self, JoinHandle};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Fixed set of threads running offloaded jobs in submission order.
+#[derive(Debug)]
+pub struct WorkerPool {
+    sender: Option<Sender<Job>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl WorkerPool {
+    /// Start `threads` workers.
+    ///
+    /// # Panics
+    /// * If `threads` is zero or a thread cannot be spawned
+    pub fn new(threads: usize) -> Self {
+        assert!(threads > 0, "a worker pool needs at least one thread");
+
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = (0..threads)
+            .map(|i| {
+                let receiver = Arc::clone(&receiver);
+                thread::Builder::new()
+                    .name(format!("rust-ntt-worker-{i}"))
+                    .spawn(move || work(&receiver))
+                    .expect("failed to spawn worker thread")
+            })
+            .collect();
+
+        Self {
+            sender: Some(sender),
+            workers,
+        }
+    }
+
+    /// Process-wide pool with one thread per available core, started on
+    /// first use
+    pub fn global() -> &'static Self {
+        static POOL: OnceLock<WorkerPool> = OnceLock::new();
+        POOL.get_or_init(|| {
+            Self::new(thread::available_parallelism().map_or(1, |n| n.get()))
+        })
+    }
+
+    pub fn threads(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Run `job` on a worker, resolving the returned future with its
+    /// result. A panic in `job` is re-raised when the future is polled.
+    pub fn spawn<T, F>(&self, job: F) -> Offload<T>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let shared = Arc::new(Mutex::new(Shared {
+            result: None,
+            waker: None,
+        }));
+
+        let slot = Arc::clone(&shared);
+        let job: Job = Box::new(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(job));
+            let mut slot = slot.lock().unwrap();
+            slot.result = Some(result);
+            if let Some(waker) = slot.waker.take() {
+                waker.wake();
+            }
+        });
+
+        self.sender
+            .as_ref()
+            .expect("worker pool is shut down")
+            .send(job)
+            .expect("worker threads have exited");
+
+        Offload { shared }
+    }
+}
+
+/// Finish queued jobs, then join the workers
+impl Drop for WorkerPool {
+    fn drop(&mut self) {
+        drop(self.sender.take());
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn work(receiver: &Mutex<Receiver<Job>>) {
+    loop {
+        // Hold the lock only while waiting, not while running the job
+        let job = receiver.lock().unwrap().recv();
+        match job {
+            Ok(job) => job(),
+            Err(_) => return,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Shared<T> {
+    result: Option<thread::Result<T>>,
+    waker: Option<Waker>,
+}
+
+/// Future resolving to the result of a job on a [`WorkerPool`].
+///
+/// Dropping it does not cancel the job; the result is discarded.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless awaited"]
+pub struct Offload<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl<T> Future for Offload<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut shared = self.shared.lock().unwrap();
+        match shared.result.take() {
+            Some(Ok(value)) => Poll::Ready(value),
+            Some(Err(payload)) => {
+                drop(shared);
+                panic::resume_unwind(payload)
+            }
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<const DEGREE: usize> NttPolynomial<DEGREE> {
+    /// Negacyclic product computed on the [global](WorkerPool::global)
+    /// pool, see [`convolve_async_on`](Self::convolve_async_on).
+    pub fn convolve_async(&self, other: &Self) -> Offload<Self> {
+        self.convolve_async_on(other, WorkerPool::global())
+    }
+
+    /// Negacyclic product computed on `pool` with the Shoup transforms.
+    ///
+    /// Both operands are copied into the job, so they stay usable while it
+    /// runs.
+    pub fn convolve_async_on(
+        &self,
+        other: &Self,
+        pool: &WorkerPool,
+    ) -> Offload<Self> {
+        let (a, b) = (self.clone(), other.clone());
+        pool.spawn(move || a.negacyclic_convolution_shoup(&b))
+    }
+
+    /// Forward Shoup transform computed on the [global](WorkerPool::global)
+    /// pool
+    pub fn ntt_forward_async(mut self) -> Offload<Self> {
+        WorkerPool::global().spawn(move || {
+            self.ntt_forward_shoup();
+            self
+        })
+    }
+
+    /// Inverse Shoup transform computed on the [global](WorkerPool::global)
+    /// pool
+    pub fn ntt_inverse_async(mut self) -> Offload<Self> {
+        WorkerPool::global().spawn(move || {
+            self.ntt_inverse_shoup();
+            self
+        })
+    }
+}
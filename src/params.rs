@@ -0,0 +1,145 @@
+//! Ready-made parameter sets for common lattice schemes and FHE profiles.
+//!
+//! Each preset bundles a modulus (or a chain of RNS moduli), the polynomial
+//! degree, the error standard deviation `σ` and a gadget decomposition base,
+//! so a context can be created without going through the prime search
+//! utilities by hand.
+//!
+//! # Examples
+//! ```
+//! use rust_ntt::params::{DILITHIUM, FHE_4096};
+//!
+//! let ctx = DILITHIUM.context();
+//! assert_eq!(ctx.modulus(), 8380417);
+//!
+//! let chain = FHE_4096.contexts();
+//! assert_eq!(chain.len(), 3);
+//! ```
+use crate::context::NttContext;
+use crate::math::{find_first_prime_down, find_next_prime_down};
+use std::sync::Arc;
+
+/// Single-modulus parameter set `(q, N, σ, base)`.
+#[derive(Debug, Clone, Copy)]
+pub struct ParamSet<const DEGREE: usize> {
+    /// Human-readable name of the preset
+    pub name: &'static str,
+    /// Prime modulus, q ≡ 1 (mod 2*DEGREE)
+    pub q: u64,
+    /// Standard deviation of the error/secret distribution
+    pub sigma: f64,
+    /// Gadget decomposition base (equal to `q` when the scheme has none)
+    pub base: u64,
+}
+
+impl<const DEGREE: usize> ParamSet<DEGREE> {
+    /// Polynomial degree of this preset
+    pub fn degree(&self) -> usize {
+        DEGREE
+    }
+
+    /// Create the NTT context for this preset.
+    pub fn context(&self) -> Arc<NttContext<DEGREE>> {
+        NttContext::<DEGREE>::new(self.q)
+    }
+}
+
+/// RNS parameter profile: a chain of NTT-friendly primes of the given sizes.
+#[derive(Debug, Clone, Copy)]
+pub struct FheProfile<const DEGREE: usize> {
+    /// Human-readable name of the profile
+    pub name: &'static str,
+    /// Bit sizes of the primes in the RNS chain
+    pub prime_bits: &'static [usize],
+    /// Standard deviation of the error distribution
+    pub sigma: f64,
+    /// Gadget decomposition base used for key switching
+    pub base: u64,
+}
+
+impl<const DEGREE: usize> FheProfile<DEGREE> {
+    /// Polynomial degree of this profile
+    pub fn degree(&self) -> usize {
+        DEGREE
+    }
+
+    /// Moduli of the RNS chain, one distinct prime per entry of `prime_bits`.
+    ///
+    /// For every bit size the largest unused prime below `2^bits` with
+    /// q ≡ 1 (mod 2*DEGREE) is taken, so the chain is deterministic.
+    pub fn moduli(&self) -> Vec<u64> {
+        let mut moduli: Vec<u64> = Vec::with_capacity(self.prime_bits.len());
+
+        for &bits in self.prime_bits {
+            let mut q = find_first_prime_down(bits, DEGREE);
+            while moduli.contains(&q) {
+                q = find_next_prime_down(q, DEGREE);
+            }
+            moduli.push(q);
+        }
+
+        moduli
+    }
+
+    /// Total bit size of the modulus chain `Q = ∏ q_i`.
+    pub fn log_modulus(&self) -> f64 {
+        self.moduli().iter().map(|&q| (q as f64).log2()).sum()
+    }
+
+    /// Create one NTT context per modulus of the RNS chain.
+    pub fn contexts(&self) -> Vec<Arc<NttContext<DEGREE>>> {
+        self.moduli()
+            .into_iter()
+            .map(NttContext::<DEGREE>::new)
+            .collect()
+    }
+}
+
+/// CRYSTALS-Dilithium ring: q = 2^23 - 2^13 + 1, η = 2 uniform secrets,
+/// Power2Round with d = 13.
+pub const DILITHIUM: ParamSet<256> = ParamSet {
+    name: "Dilithium",
+    q: 8380417,
+    sigma: std::f64::consts::SQRT_2,
+    base: 1 << 13,
+};
+
+/// Falcon-512 ring: q = 12289 = 3 * 2^12 + 1.
+pub const FALCON_512: ParamSet<512> = ParamSet {
+    name: "Falcon-512",
+    q: 12289,
+    sigma: 4.05,
+    base: 12289,
+};
+
+/// Falcon-1024 ring: q = 12289 = 3 * 2^12 + 1.
+pub const FALCON_1024: ParamSet<1024> = ParamSet {
+    name: "Falcon-1024",
+    q: 12289,
+    sigma: 2.87,
+    base: 12289,
+};
+
+/// 128-bit secure RNS profile for N = 4096 (109-bit modulus chain).
+pub const FHE_4096: FheProfile<4096> = FheProfile {
+    name: "FHE-4096",
+    prime_bits: &[36, 36, 37],
+    sigma: 3.2,
+    base: 1 << 16,
+};
+
+/// 128-bit secure RNS profile for N = 8192 (218-bit modulus chain).
+pub const FHE_8192: FheProfile<8192> = FheProfile {
+    name: "FHE-8192",
+    prime_bits: &[43, 43, 44, 44, 44],
+    sigma: 3.2,
+    base: 1 << 20,
+};
+
+/// 128-bit secure RNS profile for N = 16384 (438-bit modulus chain).
+pub const FHE_16384: FheProfile<16384> = FheProfile {
+    name: "FHE-16384",
+    prime_bits: &[48, 48, 48, 49, 49, 49, 49, 49, 49],
+    sigma: 3.2,
+    base: 1 << 24,
+};
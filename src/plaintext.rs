@@ -0,0 +1,142 @@
+//! Integer messages scaled by Δ.
+//!
+//! LWE-style schemes place a small integer message `m` in the high bits of
+//! a coefficient as `m·Δ`, typically with `Δ = ⌊q/t⌋` for a plaintext
+//! modulus `t`, so that noise of magnitude below `Δ/2` is removed by
+//! rounding `c/Δ`. [`NttPolynomial::encode_scaled`] and
+//! [`NttPolynomial::decode_scaled`] implement this encoding for signed
+//! messages, reading coefficients as centered representatives in
+//! `(-q/2, q/2]`.
+//!
+//! A message is representable when `|m|·Δ ≤ (q-1)/2`, i.e. `|m|` is at most
+//! [`max_message`]. Encoding rejects larger messages instead of letting
+//! them wrap around, and decoding reports a coefficient that rounds past
+//! that bound, a sign that noise has grown too large. Noise that carries a
+//! coefficient all the way across `q/2` lands on another valid message and
+//! cannot be detected, so messages near the bound need correspondingly
+//! smaller noise.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::*;
+//! use std::sync::Arc;
+//!
+//! let ctx = NttContext::<4>::new(find_first_prime_up(20, 4));
+//! let delta = ctx.modulus() / 16;
+//!
+//! let m = NttPolynomial::encode_scaled(&[3, -5, 0, 7], delta, Arc::clone(&ctx)).unwrap();
+//! let noise = NttPolynomial::from_coeffs([2, ctx.modulus() - 9, 40, 1], ctx);
+//! assert_eq!((&m + &noise).decode_scaled(delta).unwrap(), [3, -5, 0, 7]);
+//! ```
+use crate::context::{NttContext, boxed_zeros};
+use crate::noise::centered;
+use crate::ntt::NttPolynomial;
+use std::fmt;
+use std::sync::Arc;
+
+/// A scaling factor or message outside the representable range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalingError {
+    /// Δ is zero or leaves no room for a nonzero message
+    InvalidDelta { delta: u64, modulus: u64 },
+    /// Message `index` exceeds `±max`; when decoding, a coefficient was
+    /// pushed past the last representable message by noise
+    OutOfRange { index: usize, value: i64, max: i64 },
+}
+
+impl fmt::Display for ScalingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDelta { delta, modulus } => {
+                write!(f, "scaling factor {delta} is unusable for q = {modulus}")
+            }
+            Self::OutOfRange { index, value, max } => {
+                write!(f, "message {index} = {value} is outside ±{max}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScalingError {}
+
+/// Largest `|m|` with `|m|·Δ ≤ (q-1)/2`, or an error if Δ admits no
+/// nonzero message.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::plaintext::max_message;
+///
+/// assert_eq!(max_message(97, 10), Ok(4));
+/// assert!(max_message(97, 49).is_err());
+/// ```
+pub fn max_message(modulus: u64, delta: u64) -> Result<i64, ScalingError> {
+    let max = ((modulus - 1) / 2).checked_div(delta).unwrap_or(0);
+    if max == 0 {
+        return Err(ScalingError::InvalidDelta { delta, modulus });
+    }
+    Ok(max as i64)
+}
+
+impl<const DEGREE: usize> NttPolynomial<DEGREE> {
+    /// Encode `message[i]·Δ` into coefficient `i`, the coefficients past
+    /// `message.len()` being zero.
+    ///
+    /// # Panics
+    /// * If the message has more than `DEGREE` entries
+    pub fn encode_scaled(
+        message: &[i64],
+        delta: u64,
+        context: Arc<NttContext<DEGREE>>,
+    ) -> Result<Self, ScalingError> {
+        assert!(
+            message.len() <= DEGREE,
+            "expected at most {DEGREE} coefficients, got {}",
+            message.len()
+        );
+        let q = context.modulus();
+        let max = max_message(q, delta)?;
+
+        let mut coeffs = boxed_zeros::<DEGREE>();
+        for (index, (c, &m)) in coeffs.iter_mut().zip(message).enumerate() {
+            if m.unsigned_abs() > max as u64 {
+                return Err(ScalingError::OutOfRange {
+                    index,
+                    value: m,
+                    max,
+                });
+            }
+            let scaled = m.unsigned_abs() * delta;
+            *c = if m < 0 { q - scaled } else { scaled };
+        }
+
+        Ok(Self::from_boxed_coeffs(coeffs, context))
+    }
+
+    /// Recover the message by rounding every centered coefficient divided
+    /// by Δ to the nearest integer, ties away from zero.
+    pub fn decode_scaled(&self, delta: u64) -> Result<[i64; DEGREE], ScalingError> {
+        let q = self.context().modulus();
+        let max = max_message(q, delta)?;
+
+        let mut message = [0i64; DEGREE];
+        for (index, (m, &c)) in message.iter_mut().zip(self.coeffs()).enumerate() {
+            let c = centered(c % q, q);
+            let rounded = (c.unsigned_abs() + delta / 2) / delta;
+            if rounded > max as u64 {
+                let value = rounded as i64;
+                return Err(ScalingError::OutOfRange {
+                    index,
+                    value: if c < 0 { -value } else { value },
+                    max,
+                });
+            }
+            *m = if c < 0 {
+                -(rounded as i64)
+            } else {
+                rounded as i64
+            };
+        }
+
+        Ok(message)
+    }
+}
@@ -0,0 +1,452 @@
+//! Plantard arithmetic for moduli below 2^32.
+//!
+//! Plantard's word-size reduction multiplies a residue `a` by a constant
+//! `b` precomputed as `b' = (b·(-2^64) mod q)·q^(-1) mod 2^64` with one
+//! wrapping 64-bit product, a shift and a high multiply by `q`:
+//!
+//! `((⌊(a·b' mod 2^64) / 2^32⌋ + 1)·q) / 2^32 = a·b mod q`
+//!
+//! For `q < 2^32/φ` (`φ` the golden ratio) and `a, b ∈ [0, q]` the result is
+//! already in `[0, q)`, with no correction step. That makes it cheaper than
+//! both Barrett and Shoup for the small primes of lattice schemes such as
+//! Kyber (`q = 3329`) and Dilithium (`q = 8380417`). [`CongruenceClass32`]
+//! holds the parameters, [`NttContext32`] runs a negacyclic NTT on `u32`
+//! coefficients with Plantard butterflies, and [`NttPolynomial32`] stores
+//! ring elements in half the space of an [`NttPolynomial`](crate::NttPolynomial).
+//!
+//! See T. Plantard, "Efficient word size modular arithmetic", IEEE TETC 2021.
+use crate::context::{ContextMismatch, NttContext, boxed_from_fn};
+use std::ops::{Add, Mul, Sub};
+use std::sync::Arc;
+
+/// Largest modulus Plantard reduction supports, `⌊2^32/φ⌋`
+pub const PLANTARD_MAX_MODULUS: u32 = 2_654_435_769;
+
+/// Modular arithmetic modulo an odd `q < 2^32/φ` with Plantard reduction.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::plantard::CongruenceClass32;
+///
+/// let class = CongruenceClass32::new(8380417);
+/// let b = class.precompute_plantard(4_000_000);
+/// assert_eq!(class.modmul_plantard(123_456, b) as u64, 123_456 * 4_000_000 % 8380417);
+/// assert_eq!(class.modmul(123_456, 4_000_000), class.modmul_plantard(123_456, b));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CongruenceClass32 {
+    q: u32,      // Odd modulus, q < 2^32/φ
+    q_inv: u64,  // q^(-1) mod 2^64
+    r2_neg: u32, // -2^64 mod q, the factor Plantard reduction divides out
+    fix: u64,    // Plantard constant of -2^64, restoring a raw product
+}
+
+impl CongruenceClass32 {
+    /// Precompute Plantard parameters for the modulus `q`.
+    ///
+    /// # Panics
+    /// * If `q` is even, below 3 or above [`PLANTARD_MAX_MODULUS`]
+    pub const fn new(q: u32) -> Self {
+        assert!(q % 2 == 1 && q >= 3, "modulus must be odd and ≥ 3");
+        assert!(q <= PLANTARD_MAX_MODULUS, "modulus must be < 2^32/φ");
+
+        // Newton iteration for q^(-1) mod 2^64, from 3 correct bits
+        let q64 = q as u64;
+        let mut q_inv = q64;
+        let mut i = 0;
+        while i < 5 {
+            q_inv = q_inv.wrapping_mul(2u64.wrapping_sub(q64.wrapping_mul(q_inv)));
+            i += 1;
+        }
+
+        let r2 = ((u64::MAX % q64) + 1) % q64;
+        let r2_neg = ((q64 - r2) % q64) as u32;
+        let fix = ((r2_neg as u64 * r2_neg as u64) % q64).wrapping_mul(q_inv);
+
+        Self {
+            q,
+            q_inv,
+            r2_neg,
+            fix,
+        }
+    }
+
+    #[inline]
+    pub fn q(&self) -> u32 {
+        self.q
+    }
+
+    /// Plantard constant `(b·(-2^64) mod q)·q^(-1) mod 2^64` for repeated
+    /// multiplications by `b`
+    pub fn precompute_plantard(&self, b: u32) -> u64 {
+        let b = (b as u64 * self.r2_neg as u64) % self.q as u64;
+        b.wrapping_mul(self.q_inv)
+    }
+
+    /// `a·b mod q` for `a ∈ [0, q]`, with `b_prec` from
+    /// [`precompute_plantard(b)`](Self::precompute_plantard)
+    #[inline]
+    pub fn modmul_plantard(&self, a: u32, b_prec: u64) -> u32 {
+        self.reduce((a as u64).wrapping_mul(b_prec))
+    }
+
+    /// `a·b mod q` for residues whose product is not known in advance: a
+    /// raw Plantard product `a·b·(-2^(-64))` followed by a multiplication
+    /// by the constant `-2^64`
+    #[inline]
+    pub fn modmul(&self, a: u32, b: u32) -> u32 {
+        self.modmul_plantard(self.mul_raw(a, b), self.fix)
+    }
+
+    /// `a·b·(-2^(-64)) mod q`, the bare Plantard product
+    #[inline]
+    fn mul_raw(&self, a: u32, b: u32) -> u32 {
+        self.modmul_plantard(a, (b as u64).wrapping_mul(self.q_inv))
+    }
+
+    /// Plantard reduction of `x = a·b·q^(-1) mod 2^64` to `a·b·(-2^(-64))`.
+    /// The sum is at most 2^32 and q < 2^32, so the product fits in a u64.
+    #[inline]
+    fn reduce(&self, x: u64) -> u32 {
+        ((((x >> 32) + 1) * self.q as u64) >> 32) as u32
+    }
+
+    /// `(a + b) mod q`; compares against `q - b` as the sum may not fit
+    /// in a u32 once q exceeds 2^31
+    #[inline]
+    pub fn modadd(&self, a: u32, b: u32) -> u32 {
+        let d = self.q - b;
+        if a >= d { a - d } else { a + b }
+    }
+
+    #[inline]
+    pub fn modsub(&self, a: u32, b: u32) -> u32 {
+        if a >= b { a - b } else { (self.q - b) + a }
+    }
+}
+
+/// Negacyclic NTT over `u32` coefficients with Plantard butterflies.
+///
+/// The twiddles are those of [`NttContext`] for the same `q` and `DEGREE`,
+/// so both transforms produce the same evaluations in the same
+/// bit-reversed order.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::plantard::NttContext32;
+///
+/// // Dilithium
+/// let ctx = NttContext32::<256>::new(8380417);
+/// let a: [u32; 256] = std::array::from_fn(|i| i as u32);
+///
+/// let mut evals = a;
+/// ctx.ntt_forward(&mut evals);
+/// ctx.ntt_inverse(&mut evals);
+/// assert_eq!(evals, a);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NttContext32<const DEGREE: usize> {
+    class: CongruenceClass32,
+    /// Plantard constants of the forward twiddles (bit-reversed order)
+    tf: Box<[u64; DEGREE]>,
+    /// Plantard constants of the inverse twiddles (bit-reversed order)
+    itf: Box<[u64; DEGREE]>,
+    /// Plantard constants of `(N^(-1), itf[1]·N^(-1))` for the last inverse
+    /// stage
+    inv_n: (u64, u64),
+    /// The same times `-2^64`, undoing the factor a raw pointwise product
+    /// leaves behind
+    inv_n_fix: (u64, u64),
+}
+
+impl<const DEGREE: usize> NttContext32<DEGREE> {
+    /// Create a transform context of size `DEGREE` modulo `q`.
+    ///
+    /// # Panics
+    /// * If `q` and `DEGREE` admit no negacyclic NTT, as for
+    ///   [`NttContext::new`]
+    /// * If `q` exceeds [`PLANTARD_MAX_MODULUS`]
+    pub fn new(q: u32) -> Arc<Self> {
+        let class = CongruenceClass32::new(q);
+        let context = NttContext::<DEGREE>::new(q as u64);
+        let barrett = context.class();
+        let plantard = |w: u64| class.precompute_plantard(w as u32);
+
+        let scaling = [context.inv_n(), context.inv_n_itf];
+        let fixed = scaling.map(|s| barrett.modmul(s, class.r2_neg as u64));
+
+        Arc::new(Self {
+            class,
+            tf: boxed_from_fn(|i| plantard(context.tf()[i])),
+            itf: boxed_from_fn(|i| plantard(context.itf()[i])),
+            inv_n: (plantard(scaling[0]), plantard(scaling[1])),
+            inv_n_fix: (plantard(fixed[0]), plantard(fixed[1])),
+        })
+    }
+
+    pub fn class(&self) -> &CongruenceClass32 {
+        &self.class
+    }
+
+    /// Forward negacyclic NTT of residues in `[0, q)`, in place
+    pub fn ntt_forward(&self, coeffs: &mut [u32; DEGREE]) {
+        let c = &self.class;
+        let mut t = DEGREE >> 1;
+        let mut n = 1;
+
+        while n < DEGREE {
+            for i in 0..n {
+                let j1 = 2 * i * t;
+                let s = self.tf[n + i];
+
+                for j in j1..j1 + t {
+                    let u = coeffs[j];
+                    let v = c.modmul_plantard(coeffs[j + t], s);
+
+                    coeffs[j] = c.modadd(u, v);
+                    coeffs[j + t] = c.modsub(u, v);
+                }
+            }
+
+            n <<= 1;
+            t >>= 1;
+        }
+    }
+
+    /// Inverse negacyclic NTT, in place; undoes [`ntt_forward`](Self::ntt_forward)
+    pub fn ntt_inverse(&self, coeffs: &mut [u32; DEGREE]) {
+        self.inverse_scaled(coeffs, self.inv_n);
+    }
+
+    /// Gentleman-Sande butterflies with the scaling folded into the last
+    /// stage
+    fn inverse_scaled(
+        &self,
+        coeffs: &mut [u32; DEGREE],
+        (inv_n, inv_n_itf): (u64, u64),
+    ) {
+        let c = &self.class;
+        let mut t = 1;
+        let mut h = DEGREE >> 1;
+
+        while h > 1 {
+            let mut j1 = 0;
+
+            for i in 0..h {
+                let s = self.itf[h + i];
+
+                for j in j1..j1 + t {
+                    let (u, v) = (coeffs[j], coeffs[j + t]);
+
+                    coeffs[j] = c.modadd(u, v);
+                    coeffs[j + t] = c.modmul_plantard(c.modsub(u, v), s);
+                }
+
+                j1 += t << 1;
+            }
+
+            h >>= 1;
+            t <<= 1;
+        }
+
+        if DEGREE > 1 {
+            let (lo, hi) = coeffs.split_at_mut(t);
+            for (x, y) in lo.iter_mut().zip(hi) {
+                let (u, v) = (*x, *y);
+                *x = c.modmul_plantard(c.modadd(u, v), inv_n);
+                *y = c.modmul_plantard(c.modsub(u, v), inv_n_itf);
+            }
+        } else {
+            coeffs[0] = c.modmul_plantard(coeffs[0], inv_n);
+        }
+    }
+
+    /// Product of `a` and `b` in `Z_q[x]/(x^DEGREE + 1)`, for residues in
+    /// `[0, q)`
+    pub fn negacyclic_convolution(
+        &self,
+        a: &[u32; DEGREE],
+        b: &[u32; DEGREE],
+    ) -> [u32; DEGREE] {
+        let (mut a, mut b) = (*a, *b);
+        self.convolve_in_place(&mut a, &mut b);
+        a
+    }
+
+    /// `a = a·b`, using `b` as scratch space for its transform
+    fn convolve_in_place(&self, a: &mut [u32; DEGREE], b: &mut [u32; DEGREE]) {
+        self.ntt_forward(a);
+        self.ntt_forward(b);
+
+        // Raw products carry a factor -2^(-64), cancelled by the scaling
+        for (x, &y) in a.iter_mut().zip(b.iter()) {
+            *x = self.class.mul_raw(*x, y);
+        }
+
+        self.inverse_scaled(a, self.inv_n_fix);
+    }
+}
+
+/// Heap-allocated `[f(0), ..., f(N-1)]` of `u32` words
+fn boxed_from_fn32<const N: usize>(f: impl FnMut(usize) -> u32) -> Box<[u32; N]> {
+    let boxed: Box<[u32]> = (0..N).map(f).collect();
+    boxed.try_into().expect("iterator of length N")
+}
+
+/// Polynomial of `Z_q[x]/(x^DEGREE + 1)` with `u32` coefficients.
+///
+/// The counterpart of [`NttPolynomial`](crate::NttPolynomial) for moduli
+/// below `2^32/φ`: coefficients take half the memory, so transforms move
+/// half the bytes and SIMD code fits twice the lanes per register, while
+/// products still only need 64-bit intermediates.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::plantard::{NttContext32, NttPolynomial32};
+/// use std::sync::Arc;
+///
+/// let ctx = NttContext32::<4>::new(17);
+/// let a = NttPolynomial32::from_coeffs([1, 2, 3, 4], Arc::clone(&ctx));
+/// let b = NttPolynomial32::from_coeffs([0, 1, 0, 0], ctx);
+///
+/// // Multiplying by x shifts the coefficients, negating the one that wraps
+/// assert_eq!((&a * &b).coeffs(), &[13, 1, 2, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NttPolynomial32<const DEGREE: usize> {
+    coeffs: Box<[u32; DEGREE]>,
+    context: Arc<NttContext32<DEGREE>>,
+}
+
+impl<const DEGREE: usize> NttContext32<DEGREE> {
+    pub fn modulus(&self) -> u32 {
+        self.class.q()
+    }
+
+    /// Whether polynomials over `self` and `other` may be combined
+    pub fn check_compatible(&self, other: &Self) -> Result<(), ContextMismatch> {
+        if self.modulus() == other.modulus() {
+            Ok(())
+        } else {
+            Err(ContextMismatch {
+                lhs_modulus: self.modulus() as u64,
+                rhs_modulus: other.modulus() as u64,
+            })
+        }
+    }
+}
+
+impl<const DEGREE: usize> NttPolynomial32<DEGREE> {
+    /// Polynomial with the given coefficients, which must lie in `[0, q)`
+    pub fn from_coeffs(
+        coeffs: [u32; DEGREE],
+        context: Arc<NttContext32<DEGREE>>,
+    ) -> Self {
+        Self {
+            coeffs: boxed_from_fn32(|i| coeffs[i]),
+            context,
+        }
+    }
+
+    pub fn zero(context: Arc<NttContext32<DEGREE>>) -> Self {
+        Self {
+            coeffs: boxed_from_fn32(|_| 0),
+            context,
+        }
+    }
+
+    pub fn coeffs(&self) -> &[u32; DEGREE] {
+        &self.coeffs
+    }
+
+    pub fn coeffs_mut(&mut self) -> &mut [u32; DEGREE] {
+        &mut self.coeffs
+    }
+
+    pub fn context(&self) -> &Arc<NttContext32<DEGREE>> {
+        &self.context
+    }
+
+    pub fn ntt_forward(&mut self) {
+        self.context.ntt_forward(&mut self.coeffs);
+    }
+
+    pub fn ntt_inverse(&mut self) {
+        self.context.ntt_inverse(&mut self.coeffs);
+    }
+
+    /// Panic unless `other` is over the same ring
+    fn expect_compatible(&self, other: &Self, verb: &str) {
+        if let Err(e) = self.context.check_compatible(&other.context) {
+            panic!("Cannot {verb} polynomials: {e}");
+        }
+    }
+
+    /// `self·other mod (x^N + 1)` through the Plantard NTT
+    ///
+    /// # Panics
+    /// * If the contexts have different moduli
+    pub fn negacyclic_convolution(&self, other: &Self) -> Self {
+        self.expect_compatible(other, "convolve");
+        let mut result = self.clone();
+        let mut scratch = other.coeffs.clone();
+        self.context
+            .convolve_in_place(&mut result.coeffs, &mut scratch);
+        result
+    }
+
+    /// Slot-wise product of two polynomials in NTT form
+    ///
+    /// # Panics
+    /// * If the contexts have different moduli
+    pub fn pointwise_mul(&self, other: &Self) -> Self {
+        self.expect_compatible(other, "multiply");
+        let class = self.context.class();
+        let mut result = self.clone();
+        for (x, &y) in result.coeffs.iter_mut().zip(other.coeffs.iter()) {
+            *x = class.modmul(*x, y);
+        }
+        result
+    }
+
+    /// `self ± other` coefficient-wise with `op`
+    fn zip_with(
+        &self,
+        other: &Self,
+        verb: &str,
+        op: impl Fn(u32, u32) -> u32,
+    ) -> Self {
+        self.expect_compatible(other, verb);
+        let mut result = self.clone();
+        for (x, &y) in result.coeffs.iter_mut().zip(other.coeffs.iter()) {
+            *x = op(*x, y);
+        }
+        result
+    }
+}
+
+impl<const DEGREE: usize> Add for &NttPolynomial32<DEGREE> {
+    type Output = NttPolynomial32<DEGREE>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let class = *self.context.class();
+        self.zip_with(rhs, "add", |a, b| class.modadd(a, b))
+    }
+}
+
+impl<const DEGREE: usize> Sub for &NttPolynomial32<DEGREE> {
+    type Output = NttPolynomial32<DEGREE>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let class = *self.context.class();
+        self.zip_with(rhs, "subtract", |a, b| class.modsub(a, b))
+    }
+}
+
+impl<const DEGREE: usize> Mul for &NttPolynomial32<DEGREE> {
+    type Output = NttPolynomial32<DEGREE>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.negacyclic_convolution(rhs)
+    }
+}
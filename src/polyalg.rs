@@ -0,0 +1,1297 @@
+//! Dense polynomial arithmetic over `Z_q` with runtime lengths.
+//!
+//! Unlike [`NttPolynomial`](crate::NttPolynomial), which lives in the fixed
+//! ring `Z_q[x]/(x^N + 1)`, the functions here work on plain coefficient
+//! slices (constant term first) of any length, as needed by power-series
+//! and Newton-iteration algorithms. The modulus `q` is assumed prime.
+//!
+//! Products use a cyclic NTT of power-of-two length when `q` is prime and
+//! `q - 1` has enough factors of two, and fall back to schoolbook
+//! multiplication otherwise.
+use crate::congruence::CongruenceClass;
+use primal::{Sieve, is_prime};
+
+/// Below this operand length schoolbook multiplication is faster than a
+/// transform pair.
+const SCHOOLBOOK_THRESHOLD: usize = 32;
+
+/// Below this divisor degree schoolbook division beats Newton iteration.
+const DIVISION_THRESHOLD: usize = 64;
+
+/// Degree of `a`, or `None` for the zero polynomial.
+pub fn degree(a: &[u64]) -> Option<usize> {
+    a.iter().rposition(|&c| c != 0)
+}
+
+/// Strip leading zero coefficients, so the zero polynomial becomes the
+/// empty vector.
+fn normalize(a: &mut Vec<u64>) {
+    a.truncate(degree(a).map_or(0, |d| d + 1));
+}
+
+/// Product `a·b` of two polynomials, of length `a.len() + b.len() - 1`.
+///
+/// Returns an empty vector if either operand is empty.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::CongruenceClass;
+/// use rust_ntt::polyalg::mul;
+///
+/// let class = CongruenceClass::new(97);
+/// // (1 + x)(1 + x) = 1 + 2x + x^2
+/// assert_eq!(mul(&[1, 1], &[1, 1], &class), vec![1, 2, 1]);
+/// ```
+pub fn mul(a: &[u64], b: &[u64], class: &CongruenceClass) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    let len = a.len() + b.len() - 1;
+    if a.len().min(b.len()) <= SCHOOLBOOK_THRESHOLD {
+        return schoolbook_mul(a, b, class);
+    }
+
+    match CyclicPlan::new(class, len.next_power_of_two()) {
+        Some(plan) => {
+            let mut product = plan.convolve(a, b);
+            product.truncate(len);
+            product
+        }
+        None => schoolbook_mul(a, b, class),
+    }
+}
+
+/// Middle product of `a` and `b`.
+///
+/// With `n = b.len()` and `m = a.len() - n + 1`, returns the `m`
+/// coefficients of `a·b` at positions `n-1, ..., n+m-2`, i.e.
+/// `c_k = Σ_j a_{k+j}·b_{n-1-j}`. For `a.len() = 2n - 1` this is the middle
+/// `n` coefficients of the `(3n-2)`-length product.
+///
+/// By the transposition principle the middle product costs a single
+/// transform pair of length `a.len()` rather than `a.len() + b.len()`: the
+/// cyclic wrap-around only pollutes the low `n - 1` coefficients, which
+/// are discarded.
+///
+/// # Panics
+/// * If `b` is empty or longer than `a`
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::CongruenceClass;
+/// use rust_ntt::polyalg::{middle_product, mul};
+///
+/// let class = CongruenceClass::new(97);
+/// let a = [1, 2, 3, 4, 5];
+/// let b = [6, 7, 8];
+///
+/// let full = mul(&a, &b, &class);
+/// assert_eq!(middle_product(&a, &b, &class), full[2..5].to_vec());
+/// ```
+pub fn middle_product(a: &[u64], b: &[u64], class: &CongruenceClass) -> Vec<u64> {
+    let n = b.len();
+    assert!(n > 0, "middle product needs a non-empty second operand");
+    assert!(
+        n <= a.len(),
+        "second operand ({n}) must not be longer than the first ({})",
+        a.len()
+    );
+
+    let m = a.len() - n + 1;
+
+    if n <= SCHOOLBOOK_THRESHOLD {
+        return schoolbook_middle_product(a, b, class);
+    }
+
+    match CyclicPlan::new(class, a.len().next_power_of_two()) {
+        Some(plan) => plan.convolve(a, b)[n - 1..n - 1 + m].to_vec(),
+        None => schoolbook_middle_product(a, b, class),
+    }
+}
+
+/// Inverse of the power series `a` modulo `x^n`, by Newton iteration.
+///
+/// Each step `g ← g·(2 - a·g)` doubles the number of correct coefficients,
+/// so the cost is a constant number of multiplications of size `n`.
+///
+/// # Panics
+/// * If `a` is empty or `a[0]` is zero (the series is not invertible)
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::CongruenceClass;
+/// use rust_ntt::polyalg::{inverse_series, mul};
+///
+/// let class = CongruenceClass::new(97);
+/// let a = [3, 1, 4, 1, 5];
+/// let inv = inverse_series(&a, 5, &class);
+/// assert_eq!(mul(&a, &inv, &class)[..5], [1, 0, 0, 0, 0]);
+/// ```
+pub fn inverse_series(a: &[u64], n: usize, class: &CongruenceClass) -> Vec<u64> {
+    assert!(
+        a.first().is_some_and(|&c| c % class.q() != 0),
+        "power series with zero constant term is not invertible"
+    );
+
+    let mut g = vec![class.modinv(a[0])];
+    let mut k = 1;
+
+    while k < n {
+        let next = (2 * k).min(n);
+
+        // e = (a·g - 1) / x^k mod x^(next - k); the low k terms cancel
+        let low = mul(&a[..next.min(a.len())], &g, class);
+        let e: Vec<u64> = (k..next)
+            .map(|i| low.get(i).copied().unwrap_or(0))
+            .collect();
+
+        // g ← g - x^k·(g·e) mod x^next
+        let correction = mul(&g, &e, class);
+        g.extend(correction[..next - k].iter().map(|&c| class.modneg(c)));
+
+        k = next;
+    }
+
+    g.truncate(n);
+    g
+}
+
+/// Quotient and remainder of `a` divided by `b`.
+///
+/// Returns `(quotient, remainder)` with `a = quotient·b + remainder` and
+/// `deg(remainder) < deg(b)`. Both are normalized: no leading zero
+/// coefficients, and the zero polynomial is the empty vector.
+///
+/// Large divisors use the reversal trick: `rev(quotient)` is
+/// `rev(a)·rev(b)^(-1)` modulo `x^(deg a - deg b + 1)`, where the inverse
+/// comes from [`inverse_series`]; the remainder then costs one more
+/// multiplication. Small divisors use [`divrem_schoolbook`].
+///
+/// # Panics
+/// * If `b` is the zero polynomial
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::CongruenceClass;
+/// use rust_ntt::polyalg::divrem;
+///
+/// let class = CongruenceClass::new(97);
+/// // x^2 + 3x + 5 = (x + 1)(x + 2) + 3
+/// let (quot, rem) = divrem(&[5, 3, 1], &[1, 1], &class);
+/// assert_eq!(quot, vec![2, 1]);
+/// assert_eq!(rem, vec![3]);
+/// ```
+pub fn divrem(
+    a: &[u64],
+    b: &[u64],
+    class: &CongruenceClass,
+) -> (Vec<u64>, Vec<u64>) {
+    let db = degree(b).expect("division by the zero polynomial");
+    let Some(da) = degree(a).filter(|&da| da >= db) else {
+        let mut rem = a.to_vec();
+        normalize(&mut rem);
+        return (Vec::new(), rem);
+    };
+
+    if db < DIVISION_THRESHOLD || da - db < DIVISION_THRESHOLD {
+        return divrem_schoolbook(a, b, class);
+    }
+
+    let len = da - db + 1;
+    let a_rev: Vec<u64> = a[..=da].iter().rev().take(len).copied().collect();
+    let b_rev: Vec<u64> = b[..=db].iter().rev().copied().collect();
+
+    let b_rev_inv = inverse_series(&b_rev, len, class);
+    let mut quot = mul(&a_rev, &b_rev_inv, class);
+    quot.truncate(len);
+    quot.reverse();
+
+    // remainder = a - b·quotient, known to fit in the low deg(b) terms
+    let bq = mul(&b[..=db], &quot, class);
+    let mut rem: Vec<u64> = a[..db]
+        .iter()
+        .zip(&bq)
+        .map(|(&x, &y)| class.modsub(x, y))
+        .collect();
+
+    normalize(&mut quot);
+    normalize(&mut rem);
+    (quot, rem)
+}
+
+/// Quotient and remainder by long division, in `O(deg(a)·deg(b))`.
+///
+/// Same contract as [`divrem`].
+///
+/// # Panics
+/// * If `b` is the zero polynomial
+pub fn divrem_schoolbook(
+    a: &[u64],
+    b: &[u64],
+    class: &CongruenceClass,
+) -> (Vec<u64>, Vec<u64>) {
+    let db = degree(b).expect("division by the zero polynomial");
+    let mut rem = a.to_vec();
+    normalize(&mut rem);
+
+    if rem.len() <= db {
+        return (Vec::new(), rem);
+    }
+
+    let lead_inv = class.modinv(b[db]);
+    let mut quot = vec![0u64; rem.len() - db];
+
+    for i in (0..quot.len()).rev() {
+        let coeff = class.modmul(rem[i + db], lead_inv);
+        quot[i] = coeff;
+
+        for (r, &c) in rem[i..=i + db].iter_mut().zip(&b[..=db]) {
+            *r = class.modsub(*r, class.modmul(coeff, c));
+        }
+    }
+
+    rem.truncate(db);
+    normalize(&mut quot);
+    normalize(&mut rem);
+    (quot, rem)
+}
+
+/// Sum `a + b`, normalized.
+pub fn add(a: &[u64], b: &[u64], class: &CongruenceClass) -> Vec<u64> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+
+    let mut sum = long.to_vec();
+    for (x, &y) in sum.iter_mut().zip(short) {
+        *x = class.modadd(*x, y);
+    }
+
+    normalize(&mut sum);
+    sum
+}
+
+/// Product `a·b mod h`, normalized.
+///
+/// # Panics
+/// * If `h` is the zero polynomial
+pub fn mulmod(
+    a: &[u64],
+    b: &[u64],
+    h: &[u64],
+    class: &CongruenceClass,
+) -> Vec<u64> {
+    divrem(&mul(a, b, class), h, class).1
+}
+
+/// Modular composition `f(g(x)) mod h(x)` by the Brent–Kung method.
+///
+/// With `k ≈ √(deg f)`, the baby steps `1, g, ..., g^(k-1) mod h` are
+/// computed once; `f` is split into blocks of `k` coefficients,
+/// `f = Σ_j f_j(x)·x^(jk)`, and `Σ_j f_j(g)·(g^k)^j` is evaluated by Horner's
+/// rule in `g^k`. Each block costs only scalar multiply-adds, so the
+/// number of modular polynomial multiplications drops from `deg f` to
+/// about `2√(deg f)`.
+///
+/// # Panics
+/// * If `h` is the zero polynomial
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::CongruenceClass;
+/// use rust_ntt::polyalg::compose_mod;
+///
+/// let class = CongruenceClass::new(97);
+/// // f = x^2 + 1, g = x + 1, h = x^3:  f(g) = x^2 + 2x + 2
+/// let comp = compose_mod(&[1, 0, 1], &[1, 1], &[0, 0, 0, 1], &class);
+/// assert_eq!(comp, vec![2, 2, 1]);
+/// ```
+pub fn compose_mod(
+    f: &[u64],
+    g: &[u64],
+    h: &[u64],
+    class: &CongruenceClass,
+) -> Vec<u64> {
+    let dh = degree(h).expect("composition modulo the zero polynomial");
+    let Some(df) = degree(f) else {
+        return Vec::new();
+    };
+    if dh == 0 {
+        return Vec::new();
+    }
+
+    let g = divrem(g, h, class).1;
+
+    // k = ⌈√(deg f + 1)⌉ baby steps
+    let mut k = (df + 1).isqrt();
+    if k * k < df + 1 {
+        k += 1;
+    }
+
+    let mut baby = Vec::with_capacity(k + 1);
+    baby.push(vec![1u64]);
+    for i in 1..=k {
+        let next = mulmod(&baby[i - 1], &g, h, class);
+        baby.push(next);
+    }
+    let giant = baby.pop().unwrap_or_default();
+
+    // Horner over blocks, highest block first
+    let mut result = Vec::new();
+    for block in f[..=df].chunks(k).rev() {
+        let mut acc = vec![0u64; dh];
+        for (&c, power) in block.iter().zip(&baby) {
+            for (a, &p) in acc.iter_mut().zip(power) {
+                *a = class.modadd(*a, class.modmul(c, p));
+            }
+        }
+        normalize(&mut acc);
+
+        result = add(&mulmod(&result, &giant, h, class), &acc, class);
+    }
+
+    result
+}
+
+/// Shortest linear recurrence generating `seq`, by Berlekamp–Massey.
+///
+/// Returns the connection polynomial `C(x) = 1 + c_1·x + ... + c_L·x^L`
+/// of minimal length `L` such that `Σ_{i=0}^{L} c_i·s_{n-i} = 0` for all
+/// `L ≤ n < seq.len()`. The linear complexity `L` is `C.len() - 1`; note
+/// that `c_L` may be zero, so `C` is not normalized.
+///
+/// With `2L` terms available the recurrence is uniquely determined, which is
+/// what Wiedemann-style solvers and syndrome decoders rely on.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::CongruenceClass;
+/// use rust_ntt::polyalg::berlekamp_massey;
+///
+/// let class = CongruenceClass::new(97);
+/// // Fibonacci: s_n = s_{n-1} + s_{n-2}
+/// let fib = [0, 1, 1, 2, 3, 5, 8, 13];
+/// assert_eq!(berlekamp_massey(&fib, &class), vec![1, 96, 96]);
+/// ```
+pub fn berlekamp_massey(seq: &[u64], class: &CongruenceClass) -> Vec<u64> {
+    let mut conn = vec![1u64]; // current connection polynomial C
+    let mut prev = vec![1u64]; // C before the last length change
+    let mut len = 0usize; // linear complexity L
+    let mut shift = 1usize; // steps since the last length change
+    let mut prev_discrepancy = 1u64;
+
+    for n in 0..seq.len() {
+        // d = s_n + Σ_{i=1}^{L} c_i·s_{n-i}
+        let discrepancy = conn
+            .iter()
+            .enumerate()
+            .take(len + 1)
+            .fold(0, |acc, (i, &c)| {
+                class.modadd(acc, class.modmul(c, seq[n - i]))
+            });
+
+        if discrepancy == 0 {
+            shift += 1;
+            continue;
+        }
+
+        // C ← C - (d / d_prev)·x^shift·B
+        let scale = class.modmul(discrepancy, class.modinv(prev_discrepancy));
+        let mut next = conn.clone();
+        next.resize(next.len().max(prev.len() + shift), 0);
+        for (i, &b) in prev.iter().enumerate() {
+            next[i + shift] = class.modsub(next[i + shift], class.modmul(scale, b));
+        }
+
+        if 2 * len <= n {
+            len = n + 1 - len;
+            prev = std::mem::replace(&mut conn, next);
+            prev_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            conn = next;
+            shift += 1;
+        }
+    }
+
+    conn.resize(len + 1, 0);
+    conn
+}
+
+/// Monic minimal polynomial `x^L·C(1/x)` of the recurrence found by
+/// [`berlekamp_massey`].
+///
+/// For `s_n = u·A^n·v` with enough terms, this is the minimal polynomial
+/// of the matrix `A` projected by `u` and `v`.
+pub fn minimal_polynomial(seq: &[u64], class: &CongruenceClass) -> Vec<u64> {
+    let mut conn = berlekamp_massey(seq, class);
+    conn.reverse();
+    conn
+}
+
+/// Difference `a - b`, normalized.
+pub fn sub(a: &[u64], b: &[u64], class: &CongruenceClass) -> Vec<u64> {
+    let mut diff = a.to_vec();
+    diff.resize(a.len().max(b.len()), 0);
+    for (x, &y) in diff.iter_mut().zip(b) {
+        *x = class.modsub(*x, y);
+    }
+
+    normalize(&mut diff);
+    diff
+}
+
+/// Scale `a` so that its leading coefficient is one; zero stays zero.
+pub fn make_monic(a: &[u64], class: &CongruenceClass) -> Vec<u64> {
+    let Some(d) = degree(a) else {
+        return Vec::new();
+    };
+
+    let lead_inv = class.modinv(a[d]);
+    a[..=d].iter().map(|&c| class.modmul(c, lead_inv)).collect()
+}
+
+/// Monic greatest common divisor of `a` and `b` (empty if both are zero).
+pub fn gcd(a: &[u64], b: &[u64], class: &CongruenceClass) -> Vec<u64> {
+    let mut r0 = a.to_vec();
+    let mut r1 = b.to_vec();
+    normalize(&mut r1);
+
+    while !r1.is_empty() {
+        let rem = divrem(&r0, &r1, class).1;
+        r0 = std::mem::replace(&mut r1, rem);
+    }
+
+    make_monic(&r0, class)
+}
+
+/// `a^e mod h` by square-and-multiply.
+///
+/// # Panics
+/// * If `h` is the zero polynomial
+pub fn powmod(
+    a: &[u64],
+    mut e: u64,
+    h: &[u64],
+    class: &CongruenceClass,
+) -> Vec<u64> {
+    let mut base = divrem(a, h, class).1;
+    let mut acc = divrem(&[1], h, class).1;
+
+    while e > 0 {
+        if e & 1 == 1 {
+            acc = mulmod(&acc, &base, h, class);
+        }
+        base = mulmod(&base, &base, h, class);
+        e >>= 1;
+    }
+
+    acc
+}
+
+/// Rabin's irreducibility test over `F_q`.
+///
+/// A polynomial `f` of degree `n ≥ 1` is irreducible iff
+/// `x^(q^n) ≡ x (mod f)` and `gcd(x^(q^(n/r)) - x, f) = 1` for every prime
+/// `r | n`. The Frobenius powers `x^(q^i) mod f` are obtained by repeated
+/// [`compose_mod`] with `x^q mod f`, so `q` enters only through one modular
+/// exponentiation.
+///
+/// Constants (including zero) are not irreducible.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::CongruenceClass;
+/// use rust_ntt::polyalg::is_irreducible;
+///
+/// let class = CongruenceClass::new(7);
+/// assert!(is_irreducible(&[1, 0, 1], &class)); // x^2 + 1, since 7 ≡ 3 mod 4
+/// assert!(!is_irreducible(&[6, 0, 1], &class)); // x^2 - 1 = (x - 1)(x + 1)
+/// ```
+pub fn is_irreducible(f: &[u64], class: &CongruenceClass) -> bool {
+    let Some(n) = degree(f).filter(|&n| n >= 1) else {
+        return false;
+    };
+    if n == 1 {
+        return true;
+    }
+
+    let f = make_monic(f, class);
+    let x = [0, 1];
+
+    // frobenius[i] = x^(q^(i+1)) mod f
+    let x_q = powmod(&x, class.q(), &f, class);
+    let mut frobenius = vec![x_q.clone()];
+    for _ in 1..n {
+        let next = compose_mod(frobenius.last().unwrap(), &x_q, &f, class);
+        frobenius.push(next);
+    }
+
+    if sub(&frobenius[n - 1], &x, class) != Vec::<u64>::new() {
+        return false;
+    }
+
+    let factors = Sieve::new(n).factor(n).unwrap_or_default();
+    factors.into_iter().all(|(r, _)| {
+        let diff = sub(&frobenius[n / r - 1], &x, class);
+        gcd(&diff, &f, class) == vec![1]
+    })
+}
+
+/// Sample a uniformly random monic irreducible polynomial of `degree`.
+///
+/// Roughly one in `degree` monic polynomials is irreducible, so the
+/// expected number of [`is_irreducible`] trials is about `degree`.
+///
+/// # Panics
+/// * If `degree` is zero
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::CongruenceClass;
+/// use rust_ntt::polyalg::{is_irreducible, random_irreducible};
+/// use rand::{SeedableRng, rngs::StdRng};
+///
+/// let class = CongruenceClass::new(97);
+/// let mut rng = StdRng::seed_from_u64(42);
+///
+/// let f = random_irreducible(6, &class, &mut rng);
+/// assert_eq!(f.len(), 7);
+/// assert!(is_irreducible(&f, &class));
+/// ```
+pub fn random_irreducible<R: rand::Rng>(
+    degree: usize,
+    class: &CongruenceClass,
+    rng: &mut R,
+) -> Vec<u64> {
+    assert!(
+        degree >= 1,
+        "irreducible polynomials have degree at least 1"
+    );
+
+    loop {
+        let mut f: Vec<u64> = (0..degree)
+            .map(|_| rng.random_range(0..class.q()))
+            .collect();
+        f.push(1);
+
+        if is_irreducible(&f, class) {
+            return f;
+        }
+    }
+}
+
+/// Formal derivative of `a`, normalized.
+pub fn derivative(a: &[u64], class: &CongruenceClass) -> Vec<u64> {
+    let mut da: Vec<u64> = a
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, &c)| class.modmul(c, i as u64 % class.q()))
+        .collect();
+
+    normalize(&mut da);
+    da
+}
+
+/// Square-free factorization of a non-zero polynomial.
+///
+/// Returns pairs `(g_i, i)` of monic, square-free, pairwise coprime factors
+/// with `f = lc(f)·∏ g_i^i`. Over characteristic `p` a zero derivative
+/// means `f` is a `p`-th power; its `p`-th root is taken coefficient-wise
+/// (every element of `F_p` is its own `p`-th root) and factored recursively.
+///
+/// # Panics
+/// * If `f` is the zero polynomial
+pub fn squarefree_factorization(
+    f: &[u64],
+    class: &CongruenceClass,
+) -> Vec<(Vec<u64>, usize)> {
+    assert!(degree(f).is_some(), "cannot factor the zero polynomial");
+
+    let f = make_monic(f, class);
+    let mut factors = Vec::new();
+
+    let mut c = gcd(&f, &derivative(&f, class), class);
+    let mut w = divrem(&f, &c, class).0;
+    let mut multiplicity = 1;
+
+    while w.len() > 1 {
+        let y = gcd(&w, &c, class);
+        let factor = divrem(&w, &y, class).0;
+        if factor.len() > 1 {
+            factors.push((factor, multiplicity));
+        }
+        c = divrem(&c, &y, class).0;
+        w = y;
+        multiplicity += 1;
+    }
+
+    if c.len() > 1 {
+        // c is a p-th power: keep every p-th coefficient
+        let p = class.q() as usize;
+        let root: Vec<u64> = c.iter().step_by(p).copied().collect();
+        for (factor, k) in squarefree_factorization(&root, class) {
+            factors.push((factor, k * p));
+        }
+    }
+
+    factors
+}
+
+/// Distinct-degree factorization of a monic square-free polynomial.
+///
+/// Returns pairs `(g_d, d)` where `g_d` is the product of all irreducible
+/// factors of degree `d`, using `gcd(x^(q^d) - x, f)`.
+///
+/// # Panics
+/// * If `f` is the zero polynomial
+pub fn distinct_degree_factorization(
+    f: &[u64],
+    class: &CongruenceClass,
+) -> Vec<(Vec<u64>, usize)> {
+    assert!(degree(f).is_some(), "cannot factor the zero polynomial");
+
+    let x = [0, 1];
+    let mut rest = make_monic(f, class);
+    let mut frobenius = x.to_vec();
+    let mut factors = Vec::new();
+    let mut d = 1;
+
+    while rest.len() > 2 * d {
+        // frobenius = x^(q^d) mod rest
+        frobenius = powmod(&frobenius, class.q(), &rest, class);
+
+        let g = gcd(&sub(&frobenius, &x, class), &rest, class);
+        if g.len() > 1 {
+            rest = divrem(&rest, &g, class).0;
+            frobenius = divrem(&frobenius, &rest, class).1;
+            factors.push((g, d));
+        }
+        d += 1;
+    }
+
+    if rest.len() > 1 {
+        let d = rest.len() - 1;
+        factors.push((rest, d));
+    }
+
+    factors
+}
+
+/// Split a product of distinct irreducibles of equal degree `d` into its
+/// monic factors, by the Cantor–Zassenhaus algorithm.
+///
+/// For random `a`, `gcd(a^((q^d - 1)/2) - 1, f)` contains each factor
+/// independently with probability about one half. The exponent is applied
+/// as `(a·a^q···a^(q^(d-1)))^((q-1)/2)`, so `q^d` is never formed.
+///
+/// # Panics
+/// * If `q` is 2 (the quadratic-character split needs odd characteristic)
+/// * If the degree of `f` is not a multiple of `d`
+pub fn equal_degree_factorization<R: rand::Rng>(
+    f: &[u64],
+    d: usize,
+    class: &CongruenceClass,
+    rng: &mut R,
+) -> Vec<Vec<u64>> {
+    assert!(class.q() > 2, "equal-degree factorization needs odd q");
+    let f = make_monic(f, class);
+    let n = f.len().saturating_sub(1);
+    assert!(
+        d >= 1 && n >= d,
+        "degree {n} is not a product of degree-{d} factors"
+    );
+    assert_eq!(
+        n % d,
+        0,
+        "degree {n} is not a product of degree-{d} factors"
+    );
+
+    if n == d {
+        return vec![f];
+    }
+
+    loop {
+        let a: Vec<u64> = (0..n).map(|_| rng.random_range(0..class.q())).collect();
+
+        // norm = a^(1 + q + ... + q^(d-1)) mod f
+        let mut conjugate = divrem(&a, &f, class).1;
+        let mut norm = conjugate.clone();
+        for _ in 1..d {
+            conjugate = powmod(&conjugate, class.q(), &f, class);
+            norm = mulmod(&norm, &conjugate, &f, class);
+        }
+        let b = powmod(&norm, (class.q() - 1) / 2, &f, class);
+
+        let g = gcd(&sub(&b, &[1], class), &f, class);
+        if g.len() > 1 && g.len() < f.len() {
+            let h = divrem(&f, &g, class).0;
+            let mut factors = equal_degree_factorization(&g, d, class, rng);
+            factors.extend(equal_degree_factorization(&h, d, class, rng));
+            return factors;
+        }
+    }
+}
+
+/// Complete factorization of a non-zero polynomial over `F_q`, `q` odd.
+///
+/// Returns the leading coefficient and the monic irreducible factors with
+/// multiplicities, sorted by degree and then coefficients. Combines
+/// [`squarefree_factorization`], [`distinct_degree_factorization`] and
+/// [`equal_degree_factorization`].
+///
+/// # Panics
+/// * If `f` is the zero polynomial or `q` is 2
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::CongruenceClass;
+/// use rust_ntt::polyalg::{factor, mul};
+/// use rand::{SeedableRng, rngs::StdRng};
+///
+/// let class = CongruenceClass::new(7);
+/// let mut rng = StdRng::seed_from_u64(42);
+///
+/// // 3·(x + 1)^2·(x^2 + 1)
+/// let square = mul(&[1, 1], &[1, 1], &class);
+/// let f = mul(&mul(&square, &[1, 0, 1], &class), &[3], &class);
+///
+/// let (lead, factors) = factor(&f, &class, &mut rng);
+/// assert_eq!(lead, 3);
+/// assert_eq!(factors, vec![(vec![1, 1], 2), (vec![1, 0, 1], 1)]);
+/// ```
+pub fn factor<R: rand::Rng>(
+    f: &[u64],
+    class: &CongruenceClass,
+    rng: &mut R,
+) -> (u64, Vec<(Vec<u64>, usize)>) {
+    let lead = degree(f)
+        .map(|d| f[d])
+        .expect("cannot factor the zero polynomial");
+
+    let mut factors = Vec::new();
+    for (squarefree, multiplicity) in squarefree_factorization(f, class) {
+        for (product, d) in distinct_degree_factorization(&squarefree, class) {
+            for irreducible in equal_degree_factorization(&product, d, class, rng) {
+                factors.push((irreducible, multiplicity));
+            }
+        }
+    }
+
+    factors.sort_by(|(a, _), (b, _)| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+    (lead, factors)
+}
+
+/// Value of `f` at `x`, by Horner's rule.
+pub fn evaluate(f: &[u64], x: u64, class: &CongruenceClass) -> u64 {
+    f.iter()
+        .rev()
+        .fold(0, |acc, &c| class.modadd(class.modmul(acc, x), c))
+}
+
+/// Values of `f` at every point, through a subproduct tree.
+///
+/// `f` is reduced modulo `∏ (x - x_i)` and the remainder pushed down the
+/// tree, so the cost is `O(M(n) log n)` instead of `n` Horner evaluations.
+/// Few points fall back to Horner's rule.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::CongruenceClass;
+/// use rust_ntt::polyalg::evaluate_multipoint;
+///
+/// let class = CongruenceClass::new(97);
+/// // f = 1 + x^2
+/// let values = evaluate_multipoint(&[1, 0, 1], &[0, 1, 2, 3], &class);
+/// assert_eq!(values, vec![1, 2, 5, 10]);
+/// ```
+pub fn evaluate_multipoint(
+    f: &[u64],
+    points: &[u64],
+    class: &CongruenceClass,
+) -> Vec<u64> {
+    if points.len() <= SUBPRODUCT_THRESHOLD {
+        return points.iter().map(|&x| evaluate(f, x, class)).collect();
+    }
+
+    SubproductTree::new(points, class).evaluate(f, class)
+}
+
+/// Values `f(1), f(c), f(c^2), …, f(c^(count-1))` by the chirp-z
+/// (Bluestein) transform.
+///
+/// With `ij = C(i+j, 2) - C(i, 2) - C(j, 2)` the evaluations become
+/// `f(c^j) = c^(-C(j,2)) · Σ_i f_i·c^(-C(i,2)) · c^(C(i+j,2))`, a single
+/// [`middle_product`] of length `f.len() + count`, so the cost is
+/// `O(M(n + count))` instead of `count` Horner evaluations. `c = 0` is
+/// handled directly, as it has no inverse.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::CongruenceClass;
+/// use rust_ntt::polyalg::evaluate_at_powers;
+///
+/// let class = CongruenceClass::new(97);
+/// // f = 1 + x^2 at 1, 2, 4, 8
+/// let values = evaluate_at_powers(&[1, 0, 1], 2, 4, &class);
+/// assert_eq!(values, vec![2, 5, 17, 65]);
+/// ```
+pub fn evaluate_at_powers(
+    f: &[u64],
+    c: u64,
+    count: usize,
+    class: &CongruenceClass,
+) -> Vec<u64> {
+    let n = f.len();
+    if n == 0 || count == 0 {
+        return vec![0; count];
+    }
+    if c == 0 {
+        let mut values = vec![f[0]; count];
+        values[0] = f.iter().fold(0, |acc, &x| class.modadd(acc, x));
+        return values;
+    }
+
+    // chirp[k] = c^C(k, 2), from C(k+1, 2) = C(k, 2) + k
+    let chirp = |c: u64, len: usize| {
+        let mut powers = Vec::with_capacity(len);
+        let (mut t, mut ck) = (1, 1);
+        for _ in 0..len {
+            powers.push(t);
+            class.modmul_eq(&mut t, ck);
+            class.modmul_eq(&mut ck, c);
+        }
+        powers
+    };
+    let forward = chirp(c, n + count - 1);
+    let inverse = chirp(class.modinv(c), n.max(count));
+
+    let weighted: Vec<u64> = (0..n)
+        .rev()
+        .map(|i| class.modmul(f[i], inverse[i]))
+        .collect();
+
+    middle_product(&forward, &weighted, class)
+        .into_iter()
+        .zip(&inverse)
+        .map(|(sum, &scale)| class.modmul(sum, scale))
+        .collect()
+}
+
+/// Polynomial of degree `< n` through the `n` points `(points[i], values[i])`.
+///
+/// Uses [`interpolate_naive`] for few points and a subproduct tree
+/// otherwise: the weights `values[i] / M'(points[i])` come from one
+/// multi-point evaluation of the derivative of `M = ∏ (x - x_i)`, and the
+/// weighted sum `Σ w_i·M/(x - x_i)` is assembled bottom-up in
+/// `O(M(n) log n)`.
+///
+/// # Panics
+/// * If the slices differ in length or the points are not distinct
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::CongruenceClass;
+/// use rust_ntt::polyalg::interpolate;
+///
+/// let class = CongruenceClass::new(97);
+/// // The parabola 1 + x^2 through x = 0, 1, 2
+/// assert_eq!(interpolate(&[0, 1, 2], &[1, 2, 5], &class), vec![1, 0, 1]);
+/// ```
+pub fn interpolate(
+    points: &[u64],
+    values: &[u64],
+    class: &CongruenceClass,
+) -> Vec<u64> {
+    assert_eq!(
+        points.len(),
+        values.len(),
+        "got {} points but {} values",
+        points.len(),
+        values.len()
+    );
+
+    if points.len() <= SUBPRODUCT_THRESHOLD {
+        return interpolate_naive(points, values, class);
+    }
+
+    let tree = SubproductTree::new(points, class);
+    let derivative_values = tree.evaluate(&derivative(tree.root(), class), class);
+
+    let weights: Vec<u64> = values
+        .iter()
+        .zip(&derivative_values)
+        .map(|(&y, &d)| class.modmul(y, class.modinv(d)))
+        .collect();
+
+    let mut result = tree.linear_combination(&weights, class);
+    result.resize(points.len(), 0);
+    result
+}
+
+/// O(n²) Lagrange interpolation with the same contract as [`interpolate`].
+///
+/// Builds `M = ∏ (x - x_i)` once and divides out each `(x - x_i)`
+/// synthetically, avoiding any polynomial multiplication.
+///
+/// # Panics
+/// * If the slices differ in length or the points are not distinct
+pub fn interpolate_naive(
+    points: &[u64],
+    values: &[u64],
+    class: &CongruenceClass,
+) -> Vec<u64> {
+    assert_eq!(
+        points.len(),
+        values.len(),
+        "got {} points but {} values",
+        points.len(),
+        values.len()
+    );
+    let n = points.len();
+
+    // M(x) = ∏ (x - x_i), stored from the constant term upwards
+    let mut master = vec![0u64; n + 1];
+    master[0] = 1;
+    for (len, &x) in points.iter().enumerate() {
+        for j in (0..=len).rev() {
+            let shifted = master[j];
+            master[j + 1] = class.modadd(master[j + 1], shifted);
+            master[j] = class.modmul(shifted, class.modneg(x));
+        }
+    }
+
+    let mut result = vec![0u64; n];
+    let mut quotient = vec![0u64; n];
+
+    for (&x, &y) in points.iter().zip(values) {
+        // quotient = M(x) / (x - x_i) by synthetic division
+        let mut carry = 0u64;
+        for j in (0..n).rev() {
+            carry = class.modadd(master[j + 1], class.modmul(carry, x));
+            quotient[j] = carry;
+        }
+
+        // quotient(x_i) = ∏_{j≠i} (x_i - x_j)
+        let denom = evaluate(&quotient, x, class);
+        let scale = class.modmul(y, class.modinv(denom));
+
+        for (r, &c) in result.iter_mut().zip(&quotient) {
+            *r = class.modadd(*r, class.modmul(scale, c));
+        }
+    }
+
+    result
+}
+
+/// Below this many points Horner's rule and naive interpolation win.
+const SUBPRODUCT_THRESHOLD: usize = 64;
+
+/// Products of `(x - x_i)` over dyadic ranges of the points.
+///
+/// `levels[0]` holds the linear leaves and each higher level the pairwise
+/// products of the one below; an odd node out is carried up unchanged.
+struct SubproductTree {
+    levels: Vec<Vec<Vec<u64>>>,
+}
+
+impl SubproductTree {
+    fn new(points: &[u64], class: &CongruenceClass) -> Self {
+        let leaves: Vec<Vec<u64>> =
+            points.iter().map(|&x| vec![class.modneg(x), 1]).collect();
+        let mut levels = vec![leaves];
+
+        while levels.last().is_some_and(|level| level.len() > 1) {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => mul(a, b, class),
+                    [a] => a.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+
+        Self { levels }
+    }
+
+    /// M = ∏ (x - x_i)
+    fn root(&self) -> &[u64] {
+        &self.levels.last().unwrap()[0]
+    }
+
+    /// Remainder tree: f mod each node, down to f(x_i) at the leaves
+    fn evaluate(&self, f: &[u64], class: &CongruenceClass) -> Vec<u64> {
+        let mut remainders = vec![divrem(f, self.root(), class).1];
+
+        for level in self.levels.iter().rev().skip(1) {
+            remainders = level
+                .iter()
+                .enumerate()
+                .map(|(i, node)| divrem(&remainders[i / 2], node, class).1)
+                .collect();
+        }
+
+        remainders
+            .into_iter()
+            .map(|r| r.first().copied().unwrap_or(0))
+            .collect()
+    }
+
+    /// Σ w_i·M/(x - x_i), combined bottom-up as
+    /// `left·M_right + right·M_left` at each internal node
+    fn linear_combination(
+        &self,
+        weights: &[u64],
+        class: &CongruenceClass,
+    ) -> Vec<u64> {
+        let mut combos: Vec<Vec<u64>> = weights.iter().map(|&w| vec![w]).collect();
+
+        for level in &self.levels[..self.levels.len() - 1] {
+            combos = combos
+                .chunks(2)
+                .zip(level.chunks(2))
+                .map(|(c, m)| match (c, m) {
+                    ([c0, c1], [m0, m1]) => {
+                        add(&mul(c0, m1, class), &mul(c1, m0, class), class)
+                    }
+                    ([c0], [_]) => c0.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        combos.pop().unwrap_or_default()
+    }
+}
+
+fn schoolbook_mul(a: &[u64], b: &[u64], class: &CongruenceClass) -> Vec<u64> {
+    let mut product = vec![0u64; a.len() + b.len() - 1];
+
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            product[i + j] = class.modadd(product[i + j], class.modmul(x, y));
+        }
+    }
+
+    product
+}
+
+fn schoolbook_middle_product(
+    a: &[u64],
+    b: &[u64],
+    class: &CongruenceClass,
+) -> Vec<u64> {
+    let n = b.len();
+
+    (0..a.len() - n + 1)
+        .map(|k| {
+            a[k..k + n]
+                .iter()
+                .zip(b.iter().rev())
+                .fold(0, |acc, (&x, &y)| class.modadd(acc, class.modmul(x, y)))
+        })
+        .collect()
+}
+
+/// Cyclic NTT of a fixed power-of-two length over `Z_q`.
+///
+/// The forward transform is decimation-in-frequency (natural order in,
+/// bit-reversed out) and the inverse is decimation-in-time (bit-reversed in,
+/// natural out), so a convolution never needs an explicit permutation.
+#[derive(Debug, Clone)]
+pub(crate) struct CyclicPlan {
+    class: CongruenceClass,
+    len: usize,
+    /// ω^j for j < len/2, with ω a primitive len-th root of unity
+    roots: Vec<u64>,
+    /// ω^(-j) for j < len/2
+    inv_roots: Vec<u64>,
+    /// len^(-1) mod q
+    inv_len: u64,
+}
+
+impl CyclicPlan {
+    /// Plan a transform of length `len`, or `None` if `q` is not prime or
+    /// `len ∤ q - 1`.
+    pub(crate) fn new(class: &CongruenceClass, len: usize) -> Option<Self> {
+        debug_assert!(len.is_power_of_two());
+
+        // Composite moduli such as p^k may have no non-residue of the kind
+        // searched for below, and the scan would run up to q
+        let q = class.q();
+        if q < 3 || (q - 1) & (len as u64 - 1) != 0 || !is_prime(q) {
+            return None;
+        }
+
+        // A quadratic non-residue carries the full 2-power part of q - 1
+        // in its order, so its ((q-1)/len)-th power has order exactly len
+        let non_residue =
+            (2..q).find(|&x| class.modexp(x, (q - 1) / 2) == q - 1)?;
+        let omega = class.modexp(non_residue, (q - 1) / len as u64);
+
+        Some(Self::with_root(class, len, omega))
+    }
+
+    /// Plan a transform of length `len` from a primitive `len`-th root `omega`.
+    pub(crate) fn with_root(
+        class: &CongruenceClass,
+        len: usize,
+        omega: u64,
+    ) -> Self {
+        let omega_inv = class.modinv(omega);
+
+        let powers = |base: u64| {
+            let mut acc = 1u64;
+            (0..len / 2)
+                .map(|_| {
+                    let power = acc;
+                    acc = class.modmul(acc, base);
+                    power
+                })
+                .collect()
+        };
+
+        Self {
+            class: *class,
+            len,
+            roots: powers(omega),
+            inv_roots: powers(omega_inv),
+            inv_len: class.modinv(len as u64),
+        }
+    }
+
+    /// Bytes held by the root tables
+    pub(crate) fn heap_bytes(&self) -> usize {
+        (self.roots.len() + self.inv_roots.len()) * size_of::<u64>()
+    }
+
+    /// Forward transform in place; output is in bit-reversed order
+    pub(crate) fn forward(&self, a: &mut [u64]) {
+        debug_assert_eq!(a.len(), self.len);
+        let class = &self.class;
+
+        let mut half = self.len >> 1;
+        let mut stride = 1;
+
+        while half > 0 {
+            for block in a.chunks_exact_mut(half << 1) {
+                let (lo, hi) = block.split_at_mut(half);
+                for (j, (u, v)) in lo.iter_mut().zip(hi.iter_mut()).enumerate() {
+                    let (x, y) = (*u, *v);
+                    *u = class.modadd(x, y);
+                    *v = class.modmul(class.modsub(x, y), self.roots[j * stride]);
+                }
+            }
+
+            half >>= 1;
+            stride <<= 1;
+        }
+    }
+
+    /// Inverse transform in place; input is in bit-reversed order
+    pub(crate) fn inverse(&self, a: &mut [u64]) {
+        debug_assert_eq!(a.len(), self.len);
+        let class = &self.class;
+
+        let mut half = 1;
+        let mut stride = self.len >> 1;
+
+        while stride > 0 {
+            for block in a.chunks_exact_mut(half << 1) {
+                let (lo, hi) = block.split_at_mut(half);
+                for (j, (u, v)) in lo.iter_mut().zip(hi.iter_mut()).enumerate() {
+                    let x = *u;
+                    let y = class.modmul(*v, self.inv_roots[j * stride]);
+                    *u = class.modadd(x, y);
+                    *v = class.modsub(x, y);
+                }
+            }
+
+            half <<= 1;
+            stride >>= 1;
+        }
+
+        for coeff in a.iter_mut() {
+            *coeff = class.modmul(*coeff, self.inv_len);
+        }
+    }
+
+    /// Cyclic convolution of `a` and `b` (zero-padded to the plan length)
+    pub(crate) fn convolve(&self, a: &[u64], b: &[u64]) -> Vec<u64> {
+        let mut fa = a.to_vec();
+        fa.resize(self.len, 0);
+        let mut fb = b.to_vec();
+        fb.resize(self.len, 0);
+
+        self.forward(&mut fa);
+        self.forward(&mut fb);
+        for (x, &y) in fa.iter_mut().zip(&fb) {
+            *x = self.class.modmul(*x, y);
+        }
+        self.inverse(&mut fa);
+
+        fa
+    }
+}
+
+/// Negacyclic NTT of length `n` as a twisted cyclic one.
+///
+/// Weighting coefficient i by `ψ^i` maps `Z_q[x]/(x^n + 1)` onto
+/// `Z_q[x]/(x^n - 1)`, so a cyclic transform with `ω = ψ^2` does the rest;
+/// the inverse runs the cyclic inverse and removes the weights.
+#[derive(Debug, Clone)]
+pub(crate) struct TwistedPlan {
+    core: CyclicPlan,
+    /// ψ^i for i < n
+    twist: Vec<u64>,
+    /// ψ^(-i) for i < n
+    untwist: Vec<u64>,
+}
+
+impl TwistedPlan {
+    /// Plan from a primitive `2n`-th root of unity `psi`
+    pub(crate) fn new(class: &CongruenceClass, n: usize, psi: u64) -> Self {
+        let powers = |base: u64| {
+            let mut acc = 1u64;
+            (0..n)
+                .map(|_| {
+                    let power = acc;
+                    acc = class.modmul(acc, base);
+                    power
+                })
+                .collect()
+        };
+
+        Self {
+            core: CyclicPlan::with_root(class, n, class.modmul(psi, psi)),
+            twist: powers(psi),
+            untwist: powers(class.modinv(psi)),
+        }
+    }
+
+    /// Bytes held by the weight and root tables
+    pub(crate) fn heap_bytes(&self) -> usize {
+        (self.twist.len() + self.untwist.len()) * size_of::<u64>()
+            + self.core.heap_bytes()
+    }
+
+    /// Pre-weight, then cyclic forward; output is in bit-reversed order
+    pub(crate) fn forward(&self, a: &mut [u64]) {
+        for (x, &w) in a.iter_mut().zip(&self.twist) {
+            self.core.class.modmul_eq(x, w);
+        }
+        self.core.forward(a);
+    }
+
+    /// Cyclic inverse, then post-weight; input is in bit-reversed order
+    pub(crate) fn inverse(&self, a: &mut [u64]) {
+        self.core.inverse(a);
+        for (x, &w) in a.iter_mut().zip(&self.untwist) {
+            self.core.class.modmul_eq(x, w);
+        }
+    }
+}
@@ -0,0 +1,533 @@
+//! Scalar reduction strategies behind the transforms and products.
+//!
+//! The butterflies of the negacyclic NTT only need modular addition,
+//! subtraction and multiplication by a twiddle known in advance. A
+//! [`Reducer`] supplies those for one modulus. It is the last type
+//! parameter of [`NttContext`] and [`NttPolynomial`], so the kernels call
+//! it statically:
+//!
+//! * [`Barrett`]: Barrett reduction throughout, no precomputation
+//! * [`Shoup`], the default: Shoup products for twiddles, on the unrolled,
+//!   radix-4 and AVX2 kernels of the crate
+//! * [`Montgomery`]: twiddles in Montgomery form, see [`crate::montgomery`]
+//! * [`Plantard`]: Plantard products for moduli below `2^32/φ`, see
+//!   [`crate::plantard`]
+//!
+//! [`NttContext::with_reducer`] creates a context over a strategy, and
+//! [`NttContext::into_reducer`] moves any context onto one, keeping its
+//! tables and strictness, so a reducer combines with
+//! [`new_strict`](NttContext::new_strict),
+//! [`with_tables`](NttContext::with_tables) and
+//! [`new_prime_power`](NttContext::new_prime_power);
+//! [`into_kernel`](NttContext::into_kernel) then installs a kernel for it.
+//! Polynomials take the strategy of their context, and these
+//! `NttPolynomial` operations run on it:
+//!
+//! * every negacyclic transform: `ntt_forward`, `ntt_inverse`, the Shoup,
+//!   ordered, coset, padded and lazy variants, and the `to`/`into` forms
+//! * every product: `*`, `checked_mul`, the `negacyclic_convolution`
+//!   family, `convolve_short`, the pointwise products and the naive
+//!   convolution
+//! * scalings and evaluations: `mul_scalar`, `affine`, `scale_argument`,
+//!   `evaluate` and `probably_equal`
+//!
+//! Additions, subtractions and negations give the same canonical result
+//! under every strategy and stay on the context's
+//! [`CongruenceClass`]. So do the transforms with plans of their own: the
+//! cyclic, twisted, linear, four-step and Montgomery transforms.
+//!
+//! The trait is sealed: the kernels rely on every operation returning
+//! canonical residues, which a new strategy would have to be checked
+//! against. Strategies outside the crate plug in as an
+//! [`NttKernel`] instead.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::*;
+//! use rust_ntt::reducer::Montgomery;
+//! use std::sync::Arc;
+//!
+//! let q = find_first_prime_up(40, 8);
+//! let ctx = NttContext::<8>::new_strict(q).into_reducer::<Montgomery>();
+//! let a = NttPolynomial::from_coeffs([1, 2, 3, 4, 5, 6, 7, 8], Arc::clone(&ctx));
+//!
+//! // Transforms and products now run on Montgomery arithmetic
+//! let reference = NttPolynomial::from_coeffs(*a.coeffs(), NttContext::new(q));
+//! assert_eq!((&a * &a).coeffs(), (&reference * &reference).coeffs());
+//! assert_eq!(
+//!     a.negacyclic_convolution_shoup(&a).coeffs(),
+//!     reference.naive_negacyclic_convolution(&reference).coeffs()
+//! );
+//! assert!(ctx.is_strict());
+//! ```
+//!
+//! [`NttPolynomial`]: crate::NttPolynomial
+//! [`NttKernel`]: crate::kernel::NttKernel
+use crate::congruence::CongruenceClass;
+use crate::context::{NttContext, boxed_from_fn};
+use crate::ntt::{
+    HARVEY_MODULUS_BOUND, forward_harvey_kernel, forward_shoup_kernel_padded,
+};
+use crate::plantard::CongruenceClass32;
+use crate::unrolled;
+use std::fmt;
+use std::sync::Arc;
+
+pub(crate) mod sealed {
+    use super::Reducer;
+    use crate::context::NttContext;
+
+    /// Transforms of a strategy over the tables of a context. The defaults
+    /// run the butterflies on [`Reducer`] products with twiddles in the
+    /// precomputed form of the strategy; [`Shoup`](super::Shoup) keeps the
+    /// tuned Shoup kernels.
+    pub trait Sealed {
+        /// Forward transform in place, bit-reversed evaluations out
+        fn forward<const DEGREE: usize>(
+            ctx: &NttContext<DEGREE, Self>,
+            coeffs: &mut [u64; DEGREE],
+        ) where
+            Self: Reducer,
+        {
+            let r = ctx.reducer();
+            let tables = ctx.reducer_tables();
+            let (tf, tf_prec) = (&ctx.tf, &tables.tf);
+
+            let mut t = DEGREE >> 1;
+            let mut n = 1;
+
+            while n < DEGREE {
+                for i in 0..n {
+                    let j1 = 2 * i * t;
+                    let (s, s_prec) = (tf[n + i], tf_prec[n + i]);
+
+                    for j in j1..j1 + t {
+                        let v = r.modmul_precomp(coeffs[j + t], s, s_prec);
+
+                        coeffs[j + t] = r.modsub(coeffs[j], v);
+                        coeffs[j] = r.modadd(coeffs[j], v);
+                    }
+                }
+
+                n <<= 1;
+                t >>= 1;
+            }
+        }
+
+        /// Inverse transform in place, including the `N^(-1)` scaling
+        fn inverse<const DEGREE: usize>(
+            ctx: &NttContext<DEGREE, Self>,
+            evals: &mut [u64; DEGREE],
+        ) where
+            Self: Reducer,
+        {
+            let r = ctx.reducer();
+            let tables = ctx.reducer_tables();
+            let (itf, itf_prec) = (&ctx.itf, &tables.itf);
+
+            let mut t = 1;
+            let mut h = DEGREE >> 1;
+
+            while h > 1 {
+                let mut j1 = 0;
+
+                for i in 0..h {
+                    let (s, s_prec) = (itf[h + i], itf_prec[h + i]);
+
+                    for j in j1..j1 + t {
+                        let (u, v) = (evals[j], evals[j + t]);
+
+                        evals[j] = r.modadd(u, v);
+                        evals[j + t] = r.modmul_precomp(r.modsub(u, v), s, s_prec);
+                    }
+
+                    j1 += t << 1;
+                }
+
+                h >>= 1;
+                t <<= 1;
+            }
+
+            // Last stage with normalization; for N = 1 the transform is the
+            // identity and N^(-1) = 1
+            if DEGREE > 1 {
+                let [n_prec, n_itf_prec] = tables.inv_n;
+                let (lo, hi) = evals.split_at_mut(t);
+                for (x, y) in lo.iter_mut().zip(hi) {
+                    let (u, v) = (*x, *y);
+                    *x = r.modmul_precomp(r.modadd(u, v), ctx.inv_n, n_prec);
+                    *y =
+                        r.modmul_precomp(r.modsub(u, v), ctx.inv_n_itf, n_itf_prec);
+                }
+            }
+        }
+
+        /// Forward transform of an array that is zero from `len` on,
+        /// returning the number of stages it could skip
+        fn forward_padded<const DEGREE: usize>(
+            ctx: &NttContext<DEGREE, Self>,
+            coeffs: &mut [u64; DEGREE],
+            _len: usize,
+        ) -> u32
+        where
+            Self: Reducer,
+        {
+            ctx.kernel().forward(ctx, coeffs);
+            0
+        }
+
+        /// Forward transform with Harvey's lazy butterflies where the
+        /// strategy has them
+        fn forward_lazy<const DEGREE: usize>(
+            ctx: &NttContext<DEGREE, Self>,
+            coeffs: &mut [u64; DEGREE],
+        ) where
+            Self: Reducer,
+        {
+            ctx.kernel().forward(ctx, coeffs);
+        }
+    }
+}
+
+/// Modular arithmetic on residues in `[0, q)` for one modulus `q`.
+///
+/// Every operation takes canonical residues and returns a canonical
+/// residue. Multiplication by a fixed `b` may use a precomputed form of
+/// it, which [`modmul_precomp`](Self::modmul_precomp) receives alongside
+/// `b` itself.
+pub trait Reducer:
+    Clone + fmt::Debug + Send + Sync + sealed::Sealed + 'static
+{
+    /// Reducer for the modulus `q`
+    ///
+    /// # Panics
+    /// * If the strategy does not support `q`
+    fn new(q: u64) -> Self;
+
+    /// The modulus
+    fn q(&self) -> u64;
+
+    /// `(a + b) mod q`
+    fn modadd(&self, a: u64, b: u64) -> u64;
+
+    /// `(a - b) mod q`
+    fn modsub(&self, a: u64, b: u64) -> u64;
+
+    /// `a·b mod q`
+    fn modmul(&self, a: u64, b: u64) -> u64;
+
+    /// Precomputed form of a multiplicand `b` used many times
+    fn precompute(&self, b: u64) -> u64;
+
+    /// `a·b mod q`, with `b_prec` from [`precompute(b)`](Self::precompute)
+    fn modmul_precomp(&self, a: u64, b: u64, b_prec: u64) -> u64;
+}
+
+/// Barrett reduction, ignoring the precomputed form
+#[derive(Debug, Clone, Copy)]
+pub struct Barrett(CongruenceClass);
+
+/// Barrett reduction with Shoup products for fixed multiplicands
+#[derive(Debug, Clone, Copy)]
+pub struct Shoup(CongruenceClass);
+
+/// Montgomery products with `R = 2^64`; a fixed multiplicand is kept as
+/// `b·R mod q`, so its product with a plain residue is plain again
+#[derive(Debug, Clone, Copy)]
+pub struct Montgomery(CongruenceClass);
+
+/// Plantard products for odd `q < 2^32/φ`
+#[derive(Debug, Clone, Copy)]
+pub struct Plantard(CongruenceClass32);
+
+impl sealed::Sealed for Barrett {}
+impl sealed::Sealed for Montgomery {}
+impl sealed::Sealed for Plantard {}
+
+impl sealed::Sealed for Shoup {
+    fn forward<const DEGREE: usize>(
+        ctx: &NttContext<DEGREE, Self>,
+        coeffs: &mut [u64; DEGREE],
+    ) {
+        unrolled::forward_shoup(&ctx.class, &ctx.tf, &ctx.tf_shoup, coeffs);
+    }
+
+    fn inverse<const DEGREE: usize>(
+        ctx: &NttContext<DEGREE, Self>,
+        evals: &mut [u64; DEGREE],
+    ) {
+        unrolled::inverse_shoup(
+            &ctx.class,
+            &ctx.itf,
+            &ctx.itf_shoup,
+            (ctx.inv_n, ctx.inv_n_shoup),
+            (ctx.inv_n_itf, ctx.inv_n_itf_shoup),
+            evals,
+        );
+    }
+
+    fn forward_padded<const DEGREE: usize>(
+        ctx: &NttContext<DEGREE, Self>,
+        coeffs: &mut [u64; DEGREE],
+        len: usize,
+    ) -> u32 {
+        forward_shoup_kernel_padded(
+            &ctx.class,
+            &ctx.tf[..],
+            &ctx.tf_shoup[..],
+            coeffs,
+            len,
+        )
+    }
+
+    fn forward_lazy<const DEGREE: usize>(
+        ctx: &NttContext<DEGREE, Self>,
+        coeffs: &mut [u64; DEGREE],
+    ) {
+        if ctx.class.q() >= HARVEY_MODULUS_BOUND {
+            return ctx.kernel().forward(ctx, coeffs);
+        }
+        forward_harvey_kernel(&ctx.class, &ctx.tf[..], &ctx.tf_shoup[..], coeffs);
+    }
+}
+
+impl Reducer for Barrett {
+    fn new(q: u64) -> Self {
+        Self(CongruenceClass::new(q))
+    }
+
+    fn q(&self) -> u64 {
+        self.0.q()
+    }
+
+    #[inline]
+    fn modadd(&self, a: u64, b: u64) -> u64 {
+        self.0.modadd(a, b)
+    }
+
+    #[inline]
+    fn modsub(&self, a: u64, b: u64) -> u64 {
+        self.0.modsub(a, b)
+    }
+
+    #[inline]
+    fn modmul(&self, a: u64, b: u64) -> u64 {
+        self.0.modmul(a, b)
+    }
+
+    fn precompute(&self, b: u64) -> u64 {
+        b
+    }
+
+    #[inline]
+    fn modmul_precomp(&self, a: u64, b: u64, _: u64) -> u64 {
+        self.0.modmul(a, b)
+    }
+}
+
+impl Reducer for Shoup {
+    fn new(q: u64) -> Self {
+        Self(CongruenceClass::new(q))
+    }
+
+    fn q(&self) -> u64 {
+        self.0.q()
+    }
+
+    #[inline]
+    fn modadd(&self, a: u64, b: u64) -> u64 {
+        self.0.modadd(a, b)
+    }
+
+    #[inline]
+    fn modsub(&self, a: u64, b: u64) -> u64 {
+        self.0.modsub(a, b)
+    }
+
+    #[inline]
+    fn modmul(&self, a: u64, b: u64) -> u64 {
+        self.0.modmul(a, b)
+    }
+
+    fn precompute(&self, b: u64) -> u64 {
+        self.0.precompute_shoup(b)
+    }
+
+    #[inline]
+    fn modmul_precomp(&self, a: u64, b: u64, b_prec: u64) -> u64 {
+        self.0.modmul_shoup(a, b, b_prec)
+    }
+}
+
+impl Reducer for Montgomery {
+    /// # Panics
+    /// * If `q` is even
+    fn new(q: u64) -> Self {
+        assert!(q & 1 == 1, "Montgomery form needs an odd modulus");
+        Self(CongruenceClass::new(q))
+    }
+
+    fn q(&self) -> u64 {
+        self.0.q()
+    }
+
+    #[inline]
+    fn modadd(&self, a: u64, b: u64) -> u64 {
+        self.0.modadd(a, b)
+    }
+
+    #[inline]
+    fn modsub(&self, a: u64, b: u64) -> u64 {
+        self.0.modsub(a, b)
+    }
+
+    /// Barrett product: two plain residues would need a second REDC to
+    /// leave Montgomery form, so only fixed multiplicands, through
+    /// [`modmul_precomp`](Reducer::modmul_precomp), use it
+    #[inline]
+    fn modmul(&self, a: u64, b: u64) -> u64 {
+        self.0.modmul(a, b)
+    }
+
+    fn precompute(&self, b: u64) -> u64 {
+        self.0.to_mont(b)
+    }
+
+    #[inline]
+    fn modmul_precomp(&self, a: u64, _: u64, b_prec: u64) -> u64 {
+        self.0.mont_mul(a, b_prec)
+    }
+}
+
+impl Reducer for Plantard {
+    /// # Panics
+    /// * If `q` is even or exceeds
+    ///   [`PLANTARD_MAX_MODULUS`](crate::plantard::PLANTARD_MAX_MODULUS)
+    fn new(q: u64) -> Self {
+        let q = u32::try_from(q).expect("modulus must be < 2^32/φ");
+        Self(CongruenceClass32::new(q))
+    }
+
+    fn q(&self) -> u64 {
+        self.0.q() as u64
+    }
+
+    #[inline]
+    fn modadd(&self, a: u64, b: u64) -> u64 {
+        self.0.modadd(a as u32, b as u32) as u64
+    }
+
+    #[inline]
+    fn modsub(&self, a: u64, b: u64) -> u64 {
+        self.0.modsub(a as u32, b as u32) as u64
+    }
+
+    #[inline]
+    fn modmul(&self, a: u64, b: u64) -> u64 {
+        self.0.modmul(a as u32, b as u32) as u64
+    }
+
+    fn precompute(&self, b: u64) -> u64 {
+        self.0.precompute_plantard(b as u32)
+    }
+
+    #[inline]
+    fn modmul_precomp(&self, a: u64, _: u64, b_prec: u64) -> u64 {
+        self.0.modmul_plantard(a as u32, b_prec) as u64
+    }
+}
+
+/// Twiddles of a context in the precomputed form of its reducer, for the
+/// default transforms of [`sealed::Sealed`]
+#[derive(Debug, Clone)]
+pub(crate) struct ReducerTables<const DEGREE: usize> {
+    /// Forward twiddles (bit-reversed order)
+    tf: Box<[u64; DEGREE]>,
+    /// Inverse twiddles (bit-reversed order)
+    itf: Box<[u64; DEGREE]>,
+    /// `N^(-1)` and `itf[1]·N^(-1)` for the last inverse stage
+    inv_n: [u64; 2],
+}
+
+impl<const DEGREE: usize> NttContext<DEGREE> {
+    /// Create a context whose transforms and multiplications run on the
+    /// reduction strategy `R`, see [`crate::reducer`] for which
+    /// operations honour it
+    ///
+    /// # Panics
+    /// * Under the same conditions as [`NttContext::new`], or if `R` does
+    ///   not support `q`
+    pub fn with_reducer<R: Reducer>(q: u64) -> Arc<NttContext<DEGREE, R>> {
+        Self::new(q).into_reducer()
+    }
+}
+
+impl<const DEGREE: usize, R: Reducer> NttContext<DEGREE, R> {
+    /// This context with its transforms and multiplications on the
+    /// strategy `R2`, keeping the tables, strictness and caches; the
+    /// kernel goes back to [`ScalarKernel`](crate::kernel::ScalarKernel),
+    /// as a kernel is written for one strategy
+    ///
+    /// # Panics
+    /// * If `R2` does not support the modulus
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use rust_ntt::reducer::{Plantard, Reducer};
+    ///
+    /// let p = find_first_prime_up(10, 16);
+    /// let ctx = NttContext::<16>::new_prime_power(p, 2).into_reducer::<Plantard>();
+    /// assert_eq!(ctx.reducer().q(), p * p);
+    /// ```
+    pub fn into_reducer<R2: Reducer>(
+        self: Arc<Self>,
+    ) -> Arc<NttContext<DEGREE, R2>> {
+        let ctx = Arc::unwrap_or_clone(self);
+        let reducer = R2::new(ctx.class.q());
+        Arc::new(NttContext {
+            class: ctx.class,
+            inv_n: ctx.inv_n,
+            inv_n_shoup: ctx.inv_n_shoup,
+            inv_n_itf: ctx.inv_n_itf,
+            inv_n_itf_shoup: ctx.inv_n_itf_shoup,
+            tf: ctx.tf,
+            tf_shoup: ctx.tf_shoup,
+            itf: ctx.itf,
+            itf_shoup: ctx.itf_shoup,
+            coset_shift: ctx.coset_shift,
+            coset_powers: ctx.coset_powers,
+            coset_inv_powers: ctx.coset_inv_powers,
+            galois: ctx.galois,
+            linear: ctx.linear,
+            cyclic: ctx.cyclic,
+            twisted: ctx.twisted,
+            montgomery: ctx.montgomery,
+            four_step: ctx.four_step,
+            strict: ctx.strict,
+            kernel: Default::default(),
+            reducer: reducer.into(),
+            reducer_tables: Default::default(),
+            #[cfg(feature = "counters")]
+            counters: ctx.counters,
+        })
+    }
+
+    /// Reduction strategy of the context, see [`crate::reducer`]
+    pub fn reducer(&self) -> &R {
+        // Only an archived context comes back without it
+        self.reducer.get_or_init(|| R::new(self.class.q()))
+    }
+
+    /// Twiddles in the precomputed form of `R`, built on first use
+    pub(crate) fn reducer_tables(&self) -> &ReducerTables<DEGREE> {
+        self.reducer_tables.get_or_init(|| {
+            let r = self.reducer();
+            ReducerTables {
+                tf: boxed_from_fn(|i| r.precompute(self.tf[i])),
+                itf: boxed_from_fn(|i| r.precompute(self.itf[i])),
+                inv_n: [self.inv_n, self.inv_n_itf].map(|s| r.precompute(s)),
+            }
+        })
+    }
+}
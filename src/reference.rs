@@ -0,0 +1,241 @@
+//! Exact big-integer reference arithmetic for differential testing.
+//!
+//! [`ReferenceRing`] implements the ring operations of `Z_q[x]/(x^N + 1)`
+//! with plain `BigUint` schoolbook arithmetic, for any modulus `q ≥ 2` (not
+//! necessarily prime, not necessarily NTT-friendly, and of any size). It is
+//! slow and makes no attempt at being clever, which is the point: results of
+//! the fast paths can be checked against it with [`ReferenceRing::first_mismatch`]
+//! and [`ReferenceRing::assert_matches`].
+//!
+//! Polynomials are coefficient vectors of length `N` in the standard basis.
+//! Inputs need not be reduced; every result is.
+use crate::context::NttContext;
+use crate::ntt::NttPolynomial;
+use num_bigint::BigUint;
+
+/// Exact model of `Z_q[x]/(x^N + 1)`.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::*;
+/// use rust_ntt::reference::ReferenceRing;
+/// use std::sync::Arc;
+///
+/// const N: usize = 16;
+/// let q = find_first_prime_up(50, N);
+/// let ctx = NttContext::<N>::new(q);
+/// let ring = ReferenceRing::from_context(&ctx);
+///
+/// let a = NttPolynomial::from_coeffs(std::array::from_fn(|i| i as u64), Arc::clone(&ctx));
+/// let b = NttPolynomial::from_coeffs(std::array::from_fn(|i| q - i as u64 - 1), ctx);
+///
+/// let expected = ring.mul(&ring.lift(a.coeffs()), &ring.lift(b.coeffs()));
+/// ring.assert_matches(&expected, a.negacyclic_convolution(&b).coeffs());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceRing {
+    modulus: BigUint,
+    degree: usize,
+}
+
+impl ReferenceRing {
+    /// # Panics
+    /// * If `modulus < 2` or `degree == 0`
+    pub fn new(modulus: BigUint, degree: usize) -> Self {
+        assert!(modulus >= BigUint::from(2u8), "modulus must be at least 2");
+        assert!(degree > 0, "degree must be positive");
+        Self { modulus, degree }
+    }
+
+    /// Reference ring with the same modulus and degree as `ctx`
+    pub fn from_context<const DEGREE: usize>(ctx: &NttContext<DEGREE>) -> Self {
+        Self::new(BigUint::from(ctx.modulus()), DEGREE)
+    }
+
+    pub fn modulus(&self) -> &BigUint {
+        &self.modulus
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Canonical representative of `x` in `[0, q)`
+    pub fn reduce(&self, x: &BigUint) -> BigUint {
+        x % &self.modulus
+    }
+
+    pub fn add_scalar(&self, x: &BigUint, y: &BigUint) -> BigUint {
+        (x + y) % &self.modulus
+    }
+
+    pub fn sub_scalar(&self, x: &BigUint, y: &BigUint) -> BigUint {
+        (x % &self.modulus + &self.modulus - y % &self.modulus) % &self.modulus
+    }
+
+    pub fn neg_scalar(&self, x: &BigUint) -> BigUint {
+        self.sub_scalar(&BigUint::ZERO, x)
+    }
+
+    pub fn mul_scalar(&self, x: &BigUint, y: &BigUint) -> BigUint {
+        (x * y) % &self.modulus
+    }
+
+    pub fn pow_scalar(&self, x: &BigUint, e: &BigUint) -> BigUint {
+        x.modpow(e, &self.modulus)
+    }
+
+    /// Inverse of `x` modulo `q`, or `None` if `gcd(x, q) ≠ 1`
+    pub fn inv_scalar(&self, x: &BigUint) -> Option<BigUint> {
+        self.reduce(x).modinv(&self.modulus)
+    }
+
+    /// Lift machine-word coefficients into the reference representation
+    pub fn lift(&self, coeffs: &[u64]) -> Vec<BigUint> {
+        assert_eq!(coeffs.len(), self.degree, "length must equal the degree");
+        coeffs
+            .iter()
+            .map(|&c| self.reduce(&BigUint::from(c)))
+            .collect()
+    }
+
+    pub fn zero(&self) -> Vec<BigUint> {
+        vec![BigUint::ZERO; self.degree]
+    }
+
+    pub fn add(&self, a: &[BigUint], b: &[BigUint]) -> Vec<BigUint> {
+        self.check_len(a);
+        self.check_len(b);
+        a.iter()
+            .zip(b)
+            .map(|(x, y)| self.add_scalar(x, y))
+            .collect()
+    }
+
+    pub fn sub(&self, a: &[BigUint], b: &[BigUint]) -> Vec<BigUint> {
+        self.check_len(a);
+        self.check_len(b);
+        a.iter()
+            .zip(b)
+            .map(|(x, y)| self.sub_scalar(x, y))
+            .collect()
+    }
+
+    pub fn neg(&self, a: &[BigUint]) -> Vec<BigUint> {
+        self.check_len(a);
+        a.iter().map(|x| self.neg_scalar(x)).collect()
+    }
+
+    /// Multiply every coefficient by the scalar `s`
+    pub fn scale(&self, a: &[BigUint], s: &BigUint) -> Vec<BigUint> {
+        self.check_len(a);
+        a.iter().map(|x| self.mul_scalar(x, s)).collect()
+    }
+
+    /// Schoolbook negacyclic product, wrapping `x^N` to `-1`
+    pub fn mul(&self, a: &[BigUint], b: &[BigUint]) -> Vec<BigUint> {
+        self.check_len(a);
+        self.check_len(b);
+        let n = self.degree;
+
+        let mut positive = vec![BigUint::ZERO; n];
+        let mut negative = vec![BigUint::ZERO; n];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                let k = i + j;
+                if k < n {
+                    positive[k] += x * y;
+                } else {
+                    negative[k - n] += x * y;
+                }
+            }
+        }
+
+        positive
+            .iter()
+            .zip(&negative)
+            .map(|(p, m)| self.sub_scalar(p, m))
+            .collect()
+    }
+
+    /// Evaluate `a` at `x` by Horner's rule
+    pub fn evaluate(&self, a: &[BigUint], x: &BigUint) -> BigUint {
+        self.check_len(a);
+        a.iter()
+            .rev()
+            .fold(BigUint::ZERO, |acc, c| (acc * x + c) % &self.modulus)
+    }
+
+    /// Galois automorphism `x ↦ x^k` for odd `k`
+    pub fn automorphism(&self, a: &[BigUint], k: usize) -> Vec<BigUint> {
+        self.check_len(a);
+        assert_eq!(k % 2, 1, "automorphism index must be odd, got {k}");
+        let n = self.degree;
+
+        let mut out = self.zero();
+        for (i, c) in a.iter().enumerate() {
+            // x^(ik) = ±x^(ik mod N), with sign (-1)^floor(ik/N)
+            let e = (i * k) % (2 * n);
+            if e < n {
+                out[e] = self.add_scalar(&out[e], c);
+            } else {
+                out[e - n] = self.sub_scalar(&out[e - n], c);
+            }
+        }
+        out
+    }
+
+    /// Index of the first coefficient where `actual` differs from `expected`
+    /// modulo `q`, or `None` if they agree.
+    ///
+    /// Unreduced `actual` values (e.g. `q` itself) are accepted as long as
+    /// they are congruent to the expected coefficient.
+    pub fn first_mismatch(
+        &self,
+        expected: &[BigUint],
+        actual: &[u64],
+    ) -> Option<usize> {
+        self.check_len(expected);
+        self.check_len(actual);
+        expected
+            .iter()
+            .zip(actual)
+            .position(|(e, &a)| self.reduce(e) != self.reduce(&BigUint::from(a)))
+    }
+
+    /// # Panics
+    /// * If `actual` and `expected` differ modulo `q`, naming the first
+    ///   offending coefficient
+    pub fn assert_matches(&self, expected: &[BigUint], actual: &[u64]) {
+        if let Some(i) = self.first_mismatch(expected, actual) {
+            panic!(
+                "coefficient {i} differs from the reference: expected {}, got {}",
+                expected[i], actual[i]
+            );
+        }
+    }
+
+    /// Check a fast negacyclic product against the reference product.
+    ///
+    /// # Panics
+    /// * Like [`ReferenceRing::assert_matches`]
+    pub fn check_product<const DEGREE: usize>(
+        &self,
+        a: &NttPolynomial<DEGREE>,
+        b: &NttPolynomial<DEGREE>,
+        product: &NttPolynomial<DEGREE>,
+    ) {
+        let expected = self.mul(&self.lift(a.coeffs()), &self.lift(b.coeffs()));
+        self.assert_matches(&expected, product.coeffs());
+    }
+
+    fn check_len<T>(&self, a: &[T]) {
+        assert_eq!(
+            a.len(),
+            self.degree,
+            "polynomial length {} does not match degree {}",
+            a.len(),
+            self.degree
+        );
+    }
+}
@@ -0,0 +1,182 @@
+//! Polynomials over a chain of RNS moduli.
+//!
+//! An [`RnsPolynomial`] represents an element of `Z_Q[x]/(x^N + 1)` with
+//! `Q = ∏ q_i` by its residues modulo each prime of the chain, as built by
+//! [`FheProfile::contexts`](crate::params::FheProfile::contexts). The
+//! residues never interact, so transforms and arithmetic run per prime;
+//! with the `rayon` feature they run in parallel across the primes, which
+//! is the natural parallelism of FHE workloads.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::params::FHE_4096;
+//! use rust_ntt::rns::RnsPolynomial;
+//!
+//! let chain = FHE_4096.contexts();
+//! let mut coeffs = [0i64; 4096];
+//! coeffs[..3].copy_from_slice(&[1, -2, 3]);
+//!
+//! let a = RnsPolynomial::from_signed(&coeffs, &chain);
+//! let b = &a * &a;
+//! // (1 - 2x + 3x^2)^2 = 1 - 4x + 10x^2 - 12x^3 + 9x^4
+//! for residue in b.residues() {
+//!     let q = residue.context().modulus();
+//!     assert_eq!(residue.coeffs()[..5], [1, q - 4, 10, q - 12, 9]);
+//! }
+//! ```
+use crate::context::NttContext;
+use crate::ntt::NttPolynomial;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::ops::{Add, Mul, Sub};
+use std::sync::Arc;
+
+/// Polynomial stored as one residue polynomial per RNS prime.
+#[derive(Debug, Clone)]
+pub struct RnsPolynomial<const DEGREE: usize> {
+    residues: Vec<NttPolynomial<DEGREE>>,
+}
+
+impl<const DEGREE: usize> RnsPolynomial<DEGREE> {
+    /// Create from residues, one per prime of the chain
+    pub fn from_residues(residues: Vec<NttPolynomial<DEGREE>>) -> Self {
+        Self { residues }
+    }
+
+    /// Reduce signed integer coefficients modulo every prime of `contexts`
+    pub fn from_signed(
+        coeffs: &[i64; DEGREE],
+        contexts: &[Arc<NttContext<DEGREE>>],
+    ) -> Self {
+        let residues = contexts
+            .iter()
+            .map(|ctx| {
+                let q = ctx.modulus();
+                let reduced = coeffs.map(|c| {
+                    let r = c.unsigned_abs() % q;
+                    if c < 0 && r != 0 { q - r } else { r }
+                });
+                NttPolynomial::from_coeffs(reduced, Arc::clone(ctx))
+            })
+            .collect();
+
+        Self { residues }
+    }
+
+    /// Create zero polynomial over `contexts`
+    pub fn zero(contexts: &[Arc<NttContext<DEGREE>>]) -> Self {
+        let residues = contexts
+            .iter()
+            .map(|ctx| NttPolynomial::zero(Arc::clone(ctx)))
+            .collect();
+
+        Self { residues }
+    }
+
+    /// Number of primes
+    pub fn len(&self) -> usize {
+        self.residues.len()
+    }
+
+    /// Returns true if the chain is empty
+    pub fn is_empty(&self) -> bool {
+        self.residues.is_empty()
+    }
+
+    /// Residue polynomials, in chain order
+    pub fn residues(&self) -> &[NttPolynomial<DEGREE>] {
+        &self.residues
+    }
+
+    /// Mutable residue polynomials
+    pub fn residues_mut(&mut self) -> &mut [NttPolynomial<DEGREE>] {
+        &mut self.residues
+    }
+
+    /// Forward Shoup NTT of every residue
+    pub fn ntt_forward(&mut self) {
+        self.for_each(|r| r.ntt_forward_shoup());
+    }
+
+    /// Inverse Shoup NTT of every residue
+    pub fn ntt_inverse(&mut self) {
+        self.for_each(|r| r.ntt_inverse_shoup());
+    }
+
+    /// Multiply by `rhs` slot-wise, for operands already in the evaluation
+    /// domain
+    ///
+    /// # Panics
+    /// * If the chains differ
+    pub fn pointwise_mul_assign(&mut self, rhs: &Self) {
+        self.zip_each(rhs, |x, y| {
+            let context = Arc::clone(x.context());
+            context.pointwise_mul_assign(x.coeffs_mut(), y.coeffs());
+        });
+    }
+
+    /// Apply `f` to every residue, in parallel with `rayon`
+    fn for_each(&mut self, f: impl Fn(&mut NttPolynomial<DEGREE>) + Send + Sync) {
+        #[cfg(feature = "rayon")]
+        self.residues.par_iter_mut().for_each(f);
+        #[cfg(not(feature = "rayon"))]
+        self.residues.iter_mut().for_each(f);
+    }
+
+    /// Apply `f` to matching residues of `self` and `rhs`, in parallel with
+    /// `rayon`
+    fn zip_each(
+        &mut self,
+        rhs: &Self,
+        f: impl Fn(&mut NttPolynomial<DEGREE>, &NttPolynomial<DEGREE>) + Send + Sync,
+    ) {
+        assert_eq!(self.len(), rhs.len(), "RNS chains differ in length");
+        for (x, y) in self.residues.iter().zip(&rhs.residues) {
+            if let Err(e) = x.context().check_compatible(y.context()) {
+                panic!("Cannot combine RNS polynomials: {e}");
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        self.residues
+            .par_iter_mut()
+            .zip(rhs.residues.par_iter())
+            .for_each(|(x, y)| f(x, y));
+        #[cfg(not(feature = "rayon"))]
+        self.residues
+            .iter_mut()
+            .zip(&rhs.residues)
+            .for_each(|(x, y)| f(x, y));
+    }
+}
+
+impl<const DEGREE: usize> Add for &RnsPolynomial<DEGREE> {
+    type Output = RnsPolynomial<DEGREE>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut result = self.clone();
+        result.zip_each(rhs, |x, y| x.add_assign_unchecked(y));
+        result
+    }
+}
+
+impl<const DEGREE: usize> Sub for &RnsPolynomial<DEGREE> {
+    type Output = RnsPolynomial<DEGREE>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut result = self.clone();
+        result.zip_each(rhs, |x, y| x.sub_assign_unchecked(y));
+        result
+    }
+}
+
+/// Negacyclic product, one transform-based product per prime
+impl<const DEGREE: usize> Mul for &RnsPolynomial<DEGREE> {
+    type Output = RnsPolynomial<DEGREE>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut result = self.clone();
+        result.zip_each(rhs, |x, y| *x = x.negacyclic_convolution_shoup(y));
+        result
+    }
+}
@@ -0,0 +1,130 @@
+//! Reed–Solomon codes over `Z_q` using the NTT as the evaluation map.
+//!
+//! A message of `k` symbols is identified with a polynomial `f` of degree
+//! `< k`, and its codeword is the vector of evaluations of `f` at the `N`
+//! points of the context's evaluation domain (see
+//! `NttContext::evaluation_points`), computed with a single forward NTT.
+//!
+//! Encoding is systematic: the first `k` codeword symbols equal the message.
+//! Up to `N - k` erasures can be corrected by interpolating `f` from any `k`
+//! surviving symbols.
+use crate::context::NttContext;
+use crate::ntt::NttPolynomial;
+use crate::polyalg::interpolate;
+use std::sync::Arc;
+
+/// Systematic `[N, k]` Reed–Solomon code over the prime field of a context.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::*;
+/// use rust_ntt::rs::ReedSolomon;
+///
+/// const N: usize = 8;
+/// let ctx = NttContext::<N>::new(find_first_prime_up(20, N));
+/// let code = ReedSolomon::new(ctx, 3);
+///
+/// let codeword = code.encode(&[10, 20, 30]);
+/// assert_eq!(&codeword[..3], &[10, 20, 30]);
+///
+/// // Lose five of the eight symbols
+/// let mut received = codeword.map(Some);
+/// for i in [0, 2, 3, 5, 6] {
+///     received[i] = None;
+/// }
+/// assert_eq!(code.decode_erasures(&received), Some(vec![10, 20, 30]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReedSolomon<const DEGREE: usize> {
+    context: Arc<NttContext<DEGREE>>,
+    k: usize,
+    points: Box<[u64; DEGREE]>,
+}
+
+impl<const DEGREE: usize> ReedSolomon<DEGREE> {
+    /// Create a code with messages of `k` symbols and codewords of `DEGREE`.
+    ///
+    /// # Panics
+    /// * If `k == 0` or `k > DEGREE`
+    pub fn new(context: Arc<NttContext<DEGREE>>, k: usize) -> Self {
+        assert!(
+            k > 0 && k <= DEGREE,
+            "message length must be in 1..={DEGREE}, got {k}"
+        );
+
+        let points = context.evaluation_points();
+        Self { context, k, points }
+    }
+
+    /// Number of message symbols
+    pub fn message_len(&self) -> usize {
+        self.k
+    }
+
+    /// Number of codeword symbols
+    pub fn codeword_len(&self) -> usize {
+        DEGREE
+    }
+
+    /// Evaluation point of each codeword position
+    pub fn points(&self) -> &[u64; DEGREE] {
+        &self.points
+    }
+
+    /// Systematically encode `k` message symbols.
+    ///
+    /// # Panics
+    /// * If `message.len() != k` or a symbol is not below the modulus
+    pub fn encode(&self, message: &[u64]) -> [u64; DEGREE] {
+        assert_eq!(
+            message.len(),
+            self.k,
+            "message must have {} symbols, got {}",
+            self.k,
+            message.len()
+        );
+        assert!(
+            message.iter().all(|&m| m < self.context.modulus()),
+            "message symbols must be below the modulus"
+        );
+
+        self.evaluate_interpolant(&self.points[..self.k], message)
+    }
+
+    /// Recover the message from a codeword with erased (`None`) positions.
+    ///
+    /// Returns `None` if fewer than `k` symbols survived.
+    pub fn decode_erasures(
+        &self,
+        received: &[Option<u64>; DEGREE],
+    ) -> Option<Vec<u64>> {
+        let (xs, ys): (Vec<u64>, Vec<u64>) = self
+            .points
+            .iter()
+            .zip(received)
+            .filter_map(|(&x, y)| y.map(|y| (x, y)))
+            .take(self.k)
+            .unzip();
+
+        if xs.len() < self.k {
+            return None;
+        }
+
+        let codeword = self.evaluate_interpolant(&xs, &ys);
+        Some(codeword[..self.k].to_vec())
+    }
+
+    /// Interpolate the degree `< k` polynomial through `(xs, ys)` and
+    /// evaluate it over the whole domain with one forward NTT.
+    fn evaluate_interpolant(&self, xs: &[u64], ys: &[u64]) -> [u64; DEGREE] {
+        let f = interpolate(xs, ys, self.context.class());
+
+        let mut coeffs = [0u64; DEGREE];
+        coeffs[..f.len()].copy_from_slice(&f);
+
+        let mut poly =
+            NttPolynomial::from_coeffs(coeffs, Arc::clone(&self.context));
+        poly.ntt_forward_shoup();
+        *poly.coeffs()
+    }
+}
@@ -0,0 +1,265 @@
+//! Reproducible samplers with a fixed, versioned byte-level definition.
+//!
+//! The samplers in [`crate::distr`] go through `rand`'s range and shuffle
+//! algorithms, which may change between `rand` releases. The functions here
+//! instead read raw bytes with [`RngCore::fill_bytes`] in a documented
+//! order and turn them into coefficients with integer arithmetic only, so
+//! the same byte stream gives the same polynomial on every platform and in
+//! every release that reports the same [`VERSION`]. Paired with a portable
+//! generator such as [`Shake128Rng`], a seed fully determines the output.
+//!
+//! Version 1 consumes bytes as follows, coefficient 0 first:
+//!
+//! * [`uniform`]: for each coefficient, read `⌈b/8⌉` bytes with
+//!   `b = ⌈log2 q⌉`, interpret them little endian, keep the low `b` bits and
+//!   retry on a value `≥ q`.
+//! * [`cbd`]: read `⌈2ηN/8⌉` bytes at once and split them into an LSB-first
+//!   bit stream; coefficient `i` is the number of set bits among bits
+//!   `2ηi .. 2ηi+η` minus those among the next `η`.
+//! * [`DiscreteGaussian`]: for each coefficient read 8 bytes as a little
+//!   endian `u64`; bit 63 is the sign and the low 63 bits select the
+//!   magnitude from a cumulative table (the sign is read even for zero).
+//!
+//! Negative values are encoded as `q - |c|`.
+use crate::context::{NttContext, boxed_from_fn, boxed_zeros};
+use crate::ntt::NttPolynomial;
+use rand::RngCore;
+use sha3::Shake128;
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use std::fmt;
+use std::sync::Arc;
+
+/// Version of the byte-level sampling definitions; any change to the output
+/// for a given byte stream increments it.
+pub const VERSION: u32 = 1;
+
+/// Uniform polynomial by rejection sampling.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::*;
+/// use rust_ntt::sampling::{self, Shake128Rng};
+///
+/// let ctx = NttContext::<16>::new(find_first_prime_up(30, 16));
+/// let a = sampling::uniform(ctx.clone(), &mut Shake128Rng::from_seed(b"seed"));
+/// let b = sampling::uniform(ctx, &mut Shake128Rng::from_seed(b"seed"));
+/// assert_eq!(a.coeffs(), b.coeffs());
+/// ```
+pub fn uniform<const DEGREE: usize, R: RngCore + ?Sized>(
+    context: Arc<NttContext<DEGREE>>,
+    rng: &mut R,
+) -> NttPolynomial<DEGREE> {
+    uniform_with(|buf| rng.fill_bytes(buf), context)
+}
+
+/// Rejection sampling over any byte source, shared with the XOF expansion
+/// of [`PolyMatrix`](crate::matrix::PolyMatrix)
+pub(crate) fn uniform_with<const DEGREE: usize>(
+    mut fill: impl FnMut(&mut [u8]),
+    context: Arc<NttContext<DEGREE>>,
+) -> NttPolynomial<DEGREE> {
+    let q = context.modulus();
+    let logq = 64 - q.leading_zeros();
+    let nbytes = logq.div_ceil(8) as usize;
+    let mask = (1u64 << logq) - 1;
+
+    let mut coeffs = boxed_zeros::<DEGREE>();
+    let mut buf = [0u8; 8];
+
+    for coeff in coeffs.iter_mut() {
+        *coeff = loop {
+            fill(&mut buf[..nbytes]);
+            let candidate = u64::from_le_bytes(buf) & mask;
+            if candidate < q {
+                break candidate;
+            }
+        };
+    }
+
+    NttPolynomial::from_boxed_coeffs(coeffs, context)
+}
+
+/// Centered binomial distribution with parameter `eta`, coefficients in
+/// `[-η, η]`, as in Kyber and Saber.
+///
+/// # Panics
+/// * If `eta` is not in `1..=16` or `q ≤ 2η`
+pub fn cbd<const DEGREE: usize, R: RngCore + ?Sized>(
+    context: Arc<NttContext<DEGREE>>,
+    eta: u32,
+    rng: &mut R,
+) -> NttPolynomial<DEGREE> {
+    assert!((1..=16).contains(&eta), "eta must be in 1..=16, got {eta}");
+    let q = context.modulus();
+    assert!(q > 2 * eta as u64, "modulus {q} too small for eta = {eta}");
+
+    let eta = eta as usize;
+    let mut bytes = vec![0u8; (2 * eta * DEGREE).div_ceil(8)];
+    rng.fill_bytes(&mut bytes);
+    let bit = |i: usize| u64::from(bytes[i / 8] >> (i % 8) & 1);
+    let count = |from: usize| (from..from + eta).map(bit).sum::<u64>();
+
+    let coeffs = boxed_from_fn(|i| {
+        let (a, b) = (count(2 * eta * i), count(2 * eta * i + eta));
+        if a >= b { a - b } else { q - (b - a) }
+    });
+
+    NttPolynomial::from_boxed_coeffs(coeffs, context)
+}
+
+/// Discrete Gaussian over the integers with standard deviation `sigma`,
+/// truncated at `TAIL·σ`, sampled by cumulative table lookup.
+///
+/// The table is computed with basic IEEE operations only, so it is
+/// bit-identical on every platform.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscreteGaussian {
+    sigma: f64,
+    /// `cdt[k]` is `P(|X| ≤ k)` scaled to `2^63`
+    cdt: Vec<u64>,
+}
+
+impl DiscreteGaussian {
+    /// Number of standard deviations kept before truncating
+    pub const TAIL: f64 = 12.0;
+
+    /// # Panics
+    /// * If `sigma` is not in `(0, 2^20]`
+    pub fn new(sigma: f64) -> Self {
+        assert!(
+            sigma > 0.0 && sigma <= (1u64 << 20) as f64,
+            "sigma must be in (0, 2^20], got {sigma}"
+        );
+
+        let bound = (Self::TAIL * sigma) as usize + 1;
+        let rho: Vec<f64> = (0..=bound)
+            .map(|k| exp_neg((k * k) as f64 / (2.0 * sigma * sigma)))
+            .collect();
+        // P(|X| = 0) ∝ ρ(0), P(|X| = k) ∝ 2ρ(k) for k > 0
+        let total = rho[0] + 2.0 * rho[1..].iter().sum::<f64>();
+        let scale = (1u64 << 63) as f64;
+
+        let mut acc = 0.0;
+        let cdt = rho
+            .iter()
+            .enumerate()
+            .map(|(k, &r)| {
+                acc += if k == 0 { r } else { 2.0 * r };
+                (acc / total * scale) as u64
+            })
+            .collect();
+
+        Self { sigma, cdt }
+    }
+
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// One signed sample, consuming 8 bytes
+    pub fn sample_i64<R: RngCore + ?Sized>(&self, rng: &mut R) -> i64 {
+        let mut buf = [0u8; 8];
+        rng.fill_bytes(&mut buf);
+        let word = u64::from_le_bytes(buf);
+        let (negative, u) = (word >> 63 == 1, word & (u64::MAX >> 1));
+
+        // Scan the whole table so the time does not depend on the sample
+        let magnitude = self.cdt.iter().filter(|&&c| c <= u).count();
+        let magnitude = magnitude.min(self.cdt.len() - 1) as i64;
+        if negative { -magnitude } else { magnitude }
+    }
+
+    /// Polynomial with independent Gaussian coefficients
+    ///
+    /// # Panics
+    /// * If `q` does not exceed twice the tail bound
+    pub fn sample<const DEGREE: usize, R: RngCore + ?Sized>(
+        &self,
+        context: Arc<NttContext<DEGREE>>,
+        rng: &mut R,
+    ) -> NttPolynomial<DEGREE> {
+        let q = context.modulus();
+        assert!(
+            q > 2 * self.cdt.len() as u64,
+            "modulus {q} too small for sigma = {}",
+            self.sigma
+        );
+
+        let coeffs = boxed_from_fn(|_| {
+            let v = self.sample_i64(rng);
+            if v >= 0 {
+                v as u64
+            } else {
+                q - v.unsigned_abs()
+            }
+        });
+
+        NttPolynomial::from_boxed_coeffs(coeffs, context)
+    }
+}
+
+/// `e^(-y)` for `y ≥ 0` from `+`, `*` and `/` alone, avoiding the platform
+/// `exp`, whose last bits may differ between libms
+fn exp_neg(y: f64) -> f64 {
+    // Halve the argument (exactly) until the series converges fast, then
+    // square the result back up
+    let mut r = y;
+    let mut halvings = 0;
+    while r > 1.0 / 1024.0 {
+        r /= 2.0;
+        halvings += 1;
+    }
+
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for i in 1..=12 {
+        term *= -r / i as f64;
+        sum += term;
+    }
+    for _ in 0..halvings {
+        sum *= sum;
+    }
+    sum
+}
+
+/// Portable random generator: the SHAKE128 stream of a seed.
+///
+/// Unlike `rand::rngs::StdRng`, whose algorithm may change between `rand`
+/// releases, its output is fixed by FIPS 202.
+pub struct Shake128Rng {
+    reader: <Shake128 as ExtendableOutput>::Reader,
+}
+
+impl Shake128Rng {
+    pub fn from_seed(seed: &[u8]) -> Self {
+        let mut xof = Shake128::default();
+        xof.update(seed);
+        Self {
+            reader: xof.finalize_xof(),
+        }
+    }
+}
+
+impl fmt::Debug for Shake128Rng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shake128Rng").finish_non_exhaustive()
+    }
+}
+
+impl RngCore for Shake128Rng {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0u8; 4];
+        self.reader.read(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0u8; 8];
+        self.reader.read(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.reader.read(dst);
+    }
+}
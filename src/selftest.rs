@@ -0,0 +1,259 @@
+//! Known-answer self test for verifying a build on its target machine.
+//!
+//! [`selftest`] exercises the arithmetic a miscompilation or a bad target
+//! feature would most likely break: scalar Barrett/Shoup reduction at the
+//! edges of the modulus range, the transform identities, and products
+//! against both the schoolbook algorithm and a vector computed with SageMath.
+//! It takes a few milliseconds and allocates only small contexts.
+use crate::congruence::CongruenceClass;
+use crate::context::NttContext;
+use crate::math::find_first_prime_up;
+use crate::ntt::NttPolynomial;
+use std::fmt;
+use std::sync::Arc;
+
+/// Outcome of a single check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+    pub name: &'static str,
+    /// Description of the first failing case, `None` if the check passed
+    pub failure: Option<String>,
+}
+
+impl CheckResult {
+    pub fn passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Results of every check run by [`selftest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelfTestReport {
+    checks: Vec<CheckResult>,
+}
+
+impl SelfTestReport {
+    /// True if every check passed
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(CheckResult::passed)
+    }
+
+    pub fn checks(&self) -> &[CheckResult] {
+        &self.checks
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &CheckResult> {
+        self.checks.iter().filter(|c| !c.passed())
+    }
+}
+
+impl fmt::Display for SelfTestReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in &self.checks {
+            match &check.failure {
+                None => writeln!(f, "[ ok ] {}", check.name)?,
+                Some(why) => writeln!(f, "[FAIL] {}: {why}", check.name)?,
+            }
+        }
+        let failed = self.failures().count();
+        write!(f, "{} checks, {failed} failed", self.checks.len())
+    }
+}
+
+/// Run the built-in known-answer checks.
+///
+/// # Examples
+/// ```rust
+/// let report = rust_ntt::selftest();
+/// assert!(report.passed(), "{report}");
+/// ```
+pub fn selftest() -> SelfTestReport {
+    let checks = vec![
+        run("scalar modmul (Barrett)", check_modmul),
+        run("scalar modmul (Shoup)", check_modmul_shoup),
+        run("scalar modadd/modsub", check_modadd_modsub),
+        run("scalar modinv/modexp", check_modinv_modexp),
+        run("forward/inverse identity", check_round_trip),
+        run("known-answer convolution", check_known_answer),
+        run("convolution vs naive", check_convolution_vs_naive),
+    ];
+
+    SelfTestReport { checks }
+}
+
+fn run(name: &'static str, check: fn() -> Result<(), String>) -> CheckResult {
+    CheckResult {
+        name,
+        failure: check().err(),
+    }
+}
+
+/// Moduli from tiny to the largest supported size
+fn test_moduli() -> [u64; 4] {
+    [
+        17,
+        find_first_prime_up(31, 1024),
+        find_first_prime_up(50, 1024),
+        find_first_prime_up(62, 1024),
+    ]
+}
+
+/// Values at and around the boundaries of `[0, q)`
+fn edge_values(q: u64) -> [u64; 7] {
+    [0, 1, 2, q / 2, q / 2 + 1, q - 2, q - 1]
+}
+
+fn expect(ok: bool, what: impl FnOnce() -> String) -> Result<(), String> {
+    if ok { Ok(()) } else { Err(what()) }
+}
+
+fn exact_mul(a: u64, b: u64, q: u64) -> u64 {
+    ((a as u128 * b as u128) % q as u128) as u64
+}
+
+fn check_modmul() -> Result<(), String> {
+    for q in test_moduli() {
+        let class = CongruenceClass::new(q);
+        for a in edge_values(q) {
+            for b in edge_values(q) {
+                let got = class.modmul(a, b);
+                let want = exact_mul(a, b, q);
+                expect(got == want, || {
+                    format!("{a}·{b} mod {q}: got {got}, want {want}")
+                })?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_modmul_shoup() -> Result<(), String> {
+    for q in test_moduli() {
+        let class = CongruenceClass::new(q);
+        for b in edge_values(q) {
+            let b_shoup = class.precompute_shoup(b);
+            for a in edge_values(q) {
+                let got = class.modmul_shoup(a, b, b_shoup);
+                let want = exact_mul(a, b, q);
+                expect(got % q == want, || {
+                    format!("{a}·{b} mod {q}: got {got}, want {want}")
+                })?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_modadd_modsub() -> Result<(), String> {
+    for q in test_moduli() {
+        let class = CongruenceClass::new(q);
+        for a in edge_values(q) {
+            for b in edge_values(q) {
+                let sum = class.modadd(a, b);
+                let want = ((a as u128 + b as u128) % q as u128) as u64;
+                expect(sum == want, || {
+                    format!("{a}+{b} mod {q}: got {sum}, want {want}")
+                })?;
+
+                let diff = class.modsub(a, b);
+                let want = (a as u128 + q as u128 - b as u128) % q as u128;
+                expect(diff as u128 == want, || {
+                    format!("{a}-{b} mod {q}: got {diff}, want {want}")
+                })?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_modinv_modexp() -> Result<(), String> {
+    for q in test_moduli() {
+        let class = CongruenceClass::new(q);
+        for a in edge_values(q).into_iter().filter(|&a| a != 0) {
+            let inv = class.modinv(a);
+            expect(exact_mul(a, inv, q) == 1, || {
+                format!("{a}^(-1) mod {q}: got {inv}")
+            })?;
+
+            // Fermat: a^(q-1) = 1 for prime q
+            let fermat = class.modexp(a, q - 1);
+            expect(fermat == 1, || {
+                format!("{a}^(q-1) mod {q}: got {fermat}, want 1")
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Deterministic coefficients spread over `[0, q)` (splitmix64)
+fn sample<const DEGREE: usize>(seed: u64, q: u64) -> [u64; DEGREE] {
+    let mut state = seed;
+    std::array::from_fn(|_| {
+        state = state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        (z ^ (z >> 31)) % q
+    })
+}
+
+fn check_round_trip() -> Result<(), String> {
+    for bits in [20, 40, 61] {
+        let ctx = NttContext::<64>::new(find_first_prime_up(bits, 64));
+        let q = ctx.modulus();
+        let poly =
+            NttPolynomial::from_coeffs(sample(bits as u64, q), Arc::clone(&ctx));
+
+        let mut plain = poly.clone();
+        plain.ntt_forward();
+        plain.ntt_inverse();
+
+        let mut shoup = poly.clone();
+        shoup.ntt_forward_shoup();
+        shoup.ntt_inverse_shoup();
+
+        for (name, result) in [("Barrett", &plain), ("Shoup", &shoup)] {
+            let same = result
+                .coeffs()
+                .iter()
+                .zip(poly.coeffs())
+                .all(|(&x, &y)| x % q == y);
+            expect(same, || format!("{name} transform round trip, q = {q}"))?;
+        }
+    }
+    Ok(())
+}
+
+fn check_known_answer() -> Result<(), String> {
+    // Computed in SageMath, see examples/convolution.rs
+    let ctx = NttContext::<4>::new(1033);
+    let a = NttPolynomial::from_coeffs([544, 561, 657, 419], Arc::clone(&ctx));
+    let b = NttPolynomial::from_coeffs([36, 429, 762, 877], ctx);
+    let want = [30, 631, 453, 128];
+
+    for (name, c) in [
+        ("Barrett", a.negacyclic_convolution(&b)),
+        ("Shoup", a.negacyclic_convolution_shoup(&b)),
+        ("naive", a.naive_negacyclic_convolution(&b)),
+    ] {
+        let got = c.coeffs().map(|x| x % 1033);
+        expect(got == want, || {
+            format!("{name} product: got {got:?}, want {want:?}")
+        })?;
+    }
+    Ok(())
+}
+
+fn check_convolution_vs_naive() -> Result<(), String> {
+    for bits in [30, 55] {
+        let ctx = NttContext::<32>::new(find_first_prime_up(bits, 32));
+        let q = ctx.modulus();
+        let a = NttPolynomial::from_coeffs(sample(1, q), Arc::clone(&ctx));
+        let b = NttPolynomial::from_coeffs(sample(2, q), ctx);
+
+        let naive = a.naive_negacyclic_convolution(&b).coeffs().map(|x| x % q);
+        let fast = a.negacyclic_convolution_shoup(&b).coeffs().map(|x| x % q);
+        expect(naive == fast, || format!("products differ, q = {q}"))?;
+    }
+    Ok(())
+}
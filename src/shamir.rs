@@ -0,0 +1,148 @@
+//! Shamir secret sharing over `Z_q`.
+//!
+//! A secret `s` is hidden as the constant term of a random polynomial `f` of
+//! degree `threshold - 1`; share `i` is the point `(x_i, f(x_i))`. Any
+//! `threshold` shares determine `f` and hence `s = f(0)` by Lagrange
+//! interpolation, while fewer shares reveal nothing about `s`.
+//!
+//! [`split_batched`] produces `N` shares with a single forward NTT by using
+//! the context's evaluation domain as share points.
+use crate::congruence::CongruenceClass;
+use crate::context::{NttContext, boxed_zeros};
+use crate::ntt::NttPolynomial;
+use crate::polyalg::evaluate_multipoint;
+use std::sync::Arc;
+
+/// A single share: the evaluation `y = f(x)` of the sharing polynomial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Share {
+    pub x: u64,
+    pub y: u64,
+}
+
+/// Random sharing polynomial of degree `threshold - 1` with constant term `secret`
+fn sharing_coeffs<R: rand::Rng>(
+    secret: u64,
+    threshold: usize,
+    q: u64,
+    rng: &mut R,
+) -> Vec<u64> {
+    assert!(threshold >= 1, "threshold must be at least 1");
+    assert!(secret < q, "secret must be below the modulus");
+
+    let mut coeffs = Vec::with_capacity(threshold);
+    coeffs.push(secret);
+    coeffs.extend((1..threshold).map(|_| rng.random_range(0..q)));
+    coeffs
+}
+
+/// Split `secret` into `shares` shares at the points `x = 1, ..., shares`.
+///
+/// # Panics
+/// * If `threshold` is zero or larger than `shares`
+/// * If `shares >= q` (share points must be distinct and non-zero)
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::CongruenceClass;
+/// use rust_ntt::shamir::{reconstruct, split};
+/// use rand::{SeedableRng, rngs::StdRng};
+///
+/// let class = CongruenceClass::new(1_000_003);
+/// let mut rng = StdRng::seed_from_u64(42);
+///
+/// let shares = split(123_456, 3, 5, &class, &mut rng);
+/// assert_eq!(reconstruct(&shares[1..4], &class), 123_456);
+/// ```
+pub fn split<R: rand::Rng>(
+    secret: u64,
+    threshold: usize,
+    shares: usize,
+    class: &CongruenceClass,
+    rng: &mut R,
+) -> Vec<Share> {
+    assert!(
+        threshold <= shares,
+        "threshold {threshold} exceeds number of shares {shares}"
+    );
+    assert!(
+        (shares as u64) < class.q(),
+        "number of shares must be below the modulus"
+    );
+
+    let coeffs = sharing_coeffs(secret, threshold, class.q(), rng);
+    let points: Vec<u64> = (1..=shares as u64).collect();
+
+    evaluate_multipoint(&coeffs, &points, class)
+        .into_iter()
+        .zip(points)
+        .map(|(y, x)| Share { x, y })
+        .collect()
+}
+
+/// Split `secret` into `DEGREE` shares with one forward NTT.
+///
+/// Share `i` is taken at the `i`-th point of `NttContext::evaluation_points`.
+///
+/// # Panics
+/// * If `threshold` is zero or larger than `DEGREE`
+pub fn split_batched<const DEGREE: usize, R: rand::Rng>(
+    secret: u64,
+    threshold: usize,
+    context: &Arc<NttContext<DEGREE>>,
+    rng: &mut R,
+) -> Vec<Share> {
+    assert!(
+        threshold <= DEGREE,
+        "threshold {threshold} exceeds number of shares {DEGREE}"
+    );
+
+    let f = sharing_coeffs(secret, threshold, context.modulus(), rng);
+
+    let mut coeffs = boxed_zeros::<DEGREE>();
+    coeffs[..threshold].copy_from_slice(&f);
+
+    let mut poly = NttPolynomial::from_boxed_coeffs(coeffs, Arc::clone(context));
+    poly.ntt_forward_shoup();
+
+    context
+        .evaluation_points()
+        .iter()
+        .zip(poly.coeffs())
+        .map(|(&x, &y)| Share { x, y })
+        .collect()
+}
+
+/// Reconstruct the secret `f(0)` from at least `threshold` shares.
+///
+/// All given shares are used; passing fewer than `threshold` shares yields an
+/// unrelated value.
+///
+/// # Panics
+/// * If `shares` is empty or two shares have the same `x`
+pub fn reconstruct(shares: &[Share], class: &CongruenceClass) -> u64 {
+    assert!(!shares.is_empty(), "cannot reconstruct from zero shares");
+
+    let mut secret = 0u64;
+
+    for (i, share) in shares.iter().enumerate() {
+        // Lagrange basis at zero: ∏_{j≠i} x_j / (x_j - x_i)
+        let mut num = 1u64;
+        let mut den = 1u64;
+
+        for (j, other) in shares.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            assert_ne!(share.x, other.x, "duplicate share point {}", share.x);
+
+            num = class.modmul(num, other.x);
+            den = class.modmul(den, class.modsub(other.x, share.x));
+        }
+
+        let basis = class.modmul(num, class.modinv(den));
+        secret = class.modadd(secret, class.modmul(share.y, basis));
+    }
+
+    secret
+}
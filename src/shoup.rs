@@ -0,0 +1,114 @@
+//! Fixed multiplicands with precomputed Shoup constants.
+//!
+//! Shoup multiplication by a known `b` needs `⌊b·2^64/q⌋` and then costs a
+//! high multiply and one conditional subtraction instead of a Barrett
+//! reduction. The butterflies already use it for the twiddles; a
+//! [`ShoupPolynomial`] does the same for every coefficient of a polynomial
+//! that multiplies many others slot-wise, such as a plaintext or key in NTT
+//! form applied to a stream of ciphertexts. The precomputation costs about
+//! as much as one Barrett product per coefficient, so it pays off from the
+//! second use.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::*;
+//! use rust_ntt::shoup::ShoupPolynomial;
+//! use std::sync::Arc;
+//!
+//! let ctx = NttContext::<4>::new(find_first_prime_up(20, 4));
+//! let mut key = NttPolynomial::from_coeffs([5, 0, 1, 0], Arc::clone(&ctx));
+//! key.ntt_forward_shoup();
+//! let key = ShoupPolynomial::new(&key);
+//!
+//! for coeffs in [[1, 2, 3, 4], [0, 0, 7, 1]] {
+//!     let mut a = NttPolynomial::from_coeffs(coeffs, Arc::clone(&ctx));
+//!     a.ntt_forward_shoup();
+//!     assert_eq!(a.pointwise_mul_shoup(&key).coeffs(), a.pointwise_mul(key.poly()).coeffs());
+//! }
+//! ```
+use crate::context::{NttContext, boxed_from_fn};
+use crate::counters::Op;
+use crate::ntt::NttPolynomial;
+use std::sync::Arc;
+
+/// A polynomial together with the Shoup constant of each coefficient.
+#[derive(Debug, Clone)]
+pub struct ShoupPolynomial<const DEGREE: usize> {
+    poly: NttPolynomial<DEGREE>,
+    shoup: Box<[u64; DEGREE]>,
+}
+
+impl<const DEGREE: usize> ShoupPolynomial<DEGREE> {
+    /// Precompute the Shoup constants of `poly`, reducing its coefficients
+    /// into `[0, q)` first as Shoup multiplication requires
+    pub fn new(poly: &NttPolynomial<DEGREE>) -> Self {
+        let context = Arc::clone(poly.context());
+        let class = context.class();
+        let coeffs = boxed_from_fn(|i| class.reduce(poly.coeffs()[i]));
+        let shoup = boxed_from_fn(|i| class.precompute_shoup(coeffs[i]));
+
+        Self {
+            poly: NttPolynomial::from_boxed_coeffs(coeffs, context),
+            shoup,
+        }
+    }
+
+    /// The multiplicand, with canonical coefficients
+    pub fn poly(&self) -> &NttPolynomial<DEGREE> {
+        &self.poly
+    }
+
+    pub fn coeffs(&self) -> &[u64; DEGREE] {
+        self.poly.coeffs()
+    }
+
+    /// `⌊c_i·2^64/q⌋` for each coefficient `c_i`
+    pub fn shoup(&self) -> &[u64; DEGREE] {
+        &self.shoup
+    }
+
+    pub fn context(&self) -> &Arc<NttContext<DEGREE>> {
+        self.poly.context()
+    }
+
+    pub fn into_poly(self) -> NttPolynomial<DEGREE> {
+        self.poly
+    }
+}
+
+impl<const DEGREE: usize> From<&NttPolynomial<DEGREE>> for ShoupPolynomial<DEGREE> {
+    fn from(poly: &NttPolynomial<DEGREE>) -> Self {
+        Self::new(poly)
+    }
+}
+
+impl<const DEGREE: usize> NttPolynomial<DEGREE> {
+    /// Slot-wise product with a fixed multiplicand, see
+    /// [`pointwise_mul`](Self::pointwise_mul)
+    ///
+    /// # Panics
+    /// * If the contexts have different moduli
+    pub fn pointwise_mul_shoup(&self, rhs: &ShoupPolynomial<DEGREE>) -> Self {
+        let mut result = self.clone();
+        result.pointwise_mul_shoup_assign(rhs);
+        result
+    }
+
+    /// `self = self∘rhs` with a fixed multiplicand
+    ///
+    /// # Panics
+    /// * If the contexts have different moduli
+    pub fn pointwise_mul_shoup_assign(&mut self, rhs: &ShoupPolynomial<DEGREE>) {
+        if let Err(e) = self.context().check_compatible(rhs.context()) {
+            panic!("Cannot multiply polynomials: {e}");
+        }
+        let context = Arc::clone(self.context());
+        let class = context.class();
+
+        context.count(Op::ModMul, DEGREE as u64);
+        let (b, b_shoup) = (rhs.coeffs(), rhs.shoup());
+        for (i, x) in self.coeffs_mut().iter_mut().enumerate() {
+            class.modmul_shoup_eq(x, b[i], b_shoup[i]);
+        }
+    }
+}
@@ -0,0 +1,238 @@
+//! Shoup butterflies over `std::simd`, for targets without hand-written
+//! intrinsics.
+//!
+//! [`SimdKernel`] is an [`NttKernel`] whose transforms run on
+//! `Simd<u64, LANES>`, leaving instruction selection to the compiler, so
+//! it vectorizes on any target. It is opt-in: contexts keep the default
+//! kernel unless built with [`NttContext::with_kernel`], as in the example
+//! below. It also serves as a third, independently written
+//! implementation to test the scalar and AVX2 kernels against. Stages
+//! whose half-blocks hold fewer than `LANES` coefficients run the scalar
+//! butterflies.
+//!
+//! `std::simd` has no high-half multiplication, so `mul_hi` builds it
+//! from 32-bit halves. Needs a nightly compiler and the `portable-simd`
+//! feature.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::*;
+//! use rust_ntt::simd::SimdKernel;
+//! use std::sync::Arc;
+//!
+//! let q = find_first_prime_up(50, 64);
+//! let ctx = NttContext::<64>::with_kernel(q, Arc::new(SimdKernel::<4>));
+//! let reference = NttContext::<64>::new(q);
+//!
+//! let coeffs: [u64; 64] = std::array::from_fn(|i| i as u64);
+//! let mut a = NttPolynomial::from_coeffs(coeffs, ctx);
+//! let mut b = NttPolynomial::from_coeffs(coeffs, reference);
+//! a.ntt_forward_shoup();
+//! b.ntt_forward_shoup();
+//! assert_eq!(a.coeffs(), b.coeffs());
+//! ```
+use crate::congruence::CongruenceClass;
+use crate::context::NttContext;
+use crate::kernel::NttKernel;
+use std::simd::cmp::SimdPartialOrd;
+use std::simd::{Select, Simd};
+
+/// Kernel running the Shoup transforms on vectors of `LANES` coefficients
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SimdKernel<const LANES: usize>;
+
+impl<const DEGREE: usize, const LANES: usize> NttKernel<DEGREE>
+    for SimdKernel<LANES>
+{
+    fn forward(&self, ctx: &NttContext<DEGREE>, coeffs: &mut [u64; DEGREE]) {
+        forward_shoup::<LANES>(ctx.class(), ctx.tf(), ctx.tf_shoup(), coeffs);
+    }
+
+    fn inverse(&self, ctx: &NttContext<DEGREE>, evals: &mut [u64; DEGREE]) {
+        inverse_shoup::<LANES>(
+            ctx.class(),
+            ctx.itf(),
+            ctx.itf_shoup(),
+            (ctx.inv_n, ctx.inv_n_shoup),
+            (ctx.inv_n_itf, ctx.inv_n_itf_shoup),
+            evals,
+        );
+    }
+}
+
+/// Forward Shoup NTT in place, in the slot order of the scalar kernel
+fn forward_shoup<const LANES: usize>(
+    class: &CongruenceClass,
+    tf: &[u64],
+    tf_shoup: &[u64],
+    coeffs: &mut [u64],
+) {
+    let degree = coeffs.len();
+    assert!(tf.len() == degree && tf_shoup.len() == degree);
+
+    let q = Simd::<u64, LANES>::splat(class.q());
+    let mut t = degree >> 1;
+    let mut n = 1;
+
+    while t >= LANES {
+        for (i, block) in coeffs.chunks_exact_mut(2 * t).enumerate() {
+            let w = Simd::splat(tf[n + i]);
+            let w_shoup = Simd::splat(tf_shoup[n + i]);
+            let (lo, hi) = block.split_at_mut(t);
+
+            for (x, y) in lo.chunks_exact_mut(LANES).zip(hi.chunks_exact_mut(LANES))
+            {
+                let u = Simd::from_slice(x);
+                let v = mul_shoup(Simd::from_slice(y), w, w_shoup, q);
+                add(u, v, q).copy_to_slice(x);
+                sub(u, v, q).copy_to_slice(y);
+            }
+        }
+
+        n <<= 1;
+        t >>= 1;
+    }
+
+    while n < degree {
+        for (i, block) in coeffs.chunks_exact_mut(2 * t).enumerate() {
+            let (w, w_shoup) = (tf[n + i], tf_shoup[n + i]);
+            let (lo, hi) = block.split_at_mut(t);
+
+            for (x, y) in lo.iter_mut().zip(hi) {
+                let v = class.modmul_shoup(*y, w, w_shoup);
+                *y = class.modsub(*x, v);
+                class.modadd_eq(x, v);
+            }
+        }
+
+        n <<= 1;
+        t >>= 1;
+    }
+}
+
+/// Inverse Shoup NTT in place; `inv_n` and `inv_n_itf` scale the last
+/// stage as in the scalar kernel, each paired with its Shoup value
+fn inverse_shoup<const LANES: usize>(
+    class: &CongruenceClass,
+    itf: &[u64],
+    itf_shoup: &[u64],
+    (inv_n, inv_n_shoup): (u64, u64),
+    (inv_n_itf, inv_n_itf_shoup): (u64, u64),
+    evals: &mut [u64],
+) {
+    let degree = evals.len();
+    assert!(itf.len() == degree && itf_shoup.len() == degree);
+
+    let q = Simd::<u64, LANES>::splat(class.q());
+    let mut t = 1;
+    let mut h = degree >> 1;
+
+    while h > 1 && t < LANES {
+        for (i, block) in evals.chunks_exact_mut(2 * t).enumerate() {
+            let (w, w_shoup) = (itf[h + i], itf_shoup[h + i]);
+            let (lo, hi) = block.split_at_mut(t);
+
+            for (x, y) in lo.iter_mut().zip(hi) {
+                let (u, v) = (*x, *y);
+                *x = class.modadd(u, v);
+                *y = class.modmul_shoup(class.modsub(u, v), w, w_shoup);
+            }
+        }
+
+        h >>= 1;
+        t <<= 1;
+    }
+
+    while h > 1 {
+        for (i, block) in evals.chunks_exact_mut(2 * t).enumerate() {
+            let w = Simd::splat(itf[h + i]);
+            let w_shoup = Simd::splat(itf_shoup[h + i]);
+            let (lo, hi) = block.split_at_mut(t);
+
+            for (x, y) in lo.chunks_exact_mut(LANES).zip(hi.chunks_exact_mut(LANES))
+            {
+                let (u, v) = (Simd::from_slice(x), Simd::from_slice(y));
+                add(u, v, q).copy_to_slice(x);
+                mul_shoup(sub(u, v, q), w, w_shoup, q).copy_to_slice(y);
+            }
+        }
+
+        h >>= 1;
+        t <<= 1;
+    }
+
+    // Last stage with normalization; for N = 1 the transform is the
+    // identity and N^(-1) = 1
+    if degree > 1 {
+        let (lo, hi) = evals.split_at_mut(t);
+        let mut lo_chunks = lo.chunks_exact_mut(LANES);
+        let mut hi_chunks = hi.chunks_exact_mut(LANES);
+        let (s, s_shoup) = (Simd::splat(inv_n), Simd::splat(inv_n_shoup));
+        let (d, d_shoup) = (Simd::splat(inv_n_itf), Simd::splat(inv_n_itf_shoup));
+
+        for (x, y) in (&mut lo_chunks).zip(&mut hi_chunks) {
+            let (u, v) = (Simd::from_slice(x), Simd::from_slice(y));
+            mul_shoup(add(u, v, q), s, s_shoup, q).copy_to_slice(x);
+            mul_shoup(sub(u, v, q), d, d_shoup, q).copy_to_slice(y);
+        }
+
+        // Fewer than LANES coefficients per half
+        let tails = lo_chunks.into_remainder().iter_mut();
+        for (x, y) in tails.zip(hi_chunks.into_remainder()) {
+            let (u, v) = (*x, *y);
+            *x = class.modmul_shoup(class.modadd(u, v), inv_n, inv_n_shoup);
+            *y = class.modmul_shoup(class.modsub(u, v), inv_n_itf, inv_n_itf_shoup);
+        }
+    }
+}
+
+/// `a·w mod q` for `a < 2^64`, with `w_shoup = ⌊w·2^64/q⌋`
+#[inline]
+fn mul_shoup<const LANES: usize>(
+    a: Simd<u64, LANES>,
+    w: Simd<u64, LANES>,
+    w_shoup: Simd<u64, LANES>,
+    q: Simd<u64, LANES>,
+) -> Simd<u64, LANES> {
+    // Lane products wrap, leaving a·w - ⌊a·w_shoup/2^64⌋·q in [0, 2q)
+    let r = a * w - mul_hi(a, w_shoup) * q;
+    r.simd_ge(q).select(r - q, r)
+}
+
+/// `(a + b) mod q` for `a, b < q < 2^63`
+#[inline]
+fn add<const LANES: usize>(
+    a: Simd<u64, LANES>,
+    b: Simd<u64, LANES>,
+    q: Simd<u64, LANES>,
+) -> Simd<u64, LANES> {
+    let s = a + b;
+    s.simd_ge(q).select(s - q, s)
+}
+
+/// `(a - b) mod q` for `a, b < q`
+#[inline]
+fn sub<const LANES: usize>(
+    a: Simd<u64, LANES>,
+    b: Simd<u64, LANES>,
+    q: Simd<u64, LANES>,
+) -> Simd<u64, LANES> {
+    let d = a - b;
+    a.simd_ge(b).select(d, d + q)
+}
+
+/// High words of the lane-wise 128-bit products `a·b`
+#[inline]
+fn mul_hi<const LANES: usize>(
+    a: Simd<u64, LANES>,
+    b: Simd<u64, LANES>,
+) -> Simd<u64, LANES> {
+    let (low, shift) = (Simd::splat(0xffff_ffff), Simd::splat(32));
+    let (a_lo, a_hi) = (a & low, a >> shift);
+    let (b_lo, b_hi) = (b & low, b >> shift);
+
+    // Neither sum overflows: (2^32 - 1)^2 + 2^32 - 1 < 2^64
+    let mid = a_hi * b_lo + ((a_lo * b_lo) >> shift);
+    let mid_lo = a_lo * b_hi + (mid & low);
+    a_hi * b_hi + (mid >> shift) + (mid_lo >> shift)
+}
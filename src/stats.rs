@@ -0,0 +1,251 @@
+//! Distribution statistics of centered coefficients.
+//!
+//! Noise analysis needs the empirical distribution of coefficients, read as
+//! centered representatives in `(-q/2, q/2]`. [`CoeffStats`] keeps running
+//! min/max/mean/variance and [`Histogram`] exact value counts; both accept
+//! single polynomials or a stream of them, so large experiments never hold
+//! more than one polynomial at a time.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::*;
+//! use rust_ntt::stats::{CoeffStats, Histogram};
+//!
+//! let ctx = NttContext::<4>::new(17);
+//! let e = NttPolynomial::from_coeffs([1, 16, 0, 16], ctx);
+//!
+//! let stats = CoeffStats::of(&e);
+//! assert_eq!((stats.min(), stats.max()), (-1, 1));
+//! assert_eq!(stats.mean(), -0.25);
+//!
+//! let histogram = Histogram::of(&e);
+//! assert_eq!(histogram.get(-1), 2);
+//! ```
+use crate::noise::centered;
+use crate::ntt::NttPolynomial;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Running summary of centered coefficients.
+///
+/// Mean and variance are updated with Welford's method, so long streams do
+/// not lose precision to a large running sum of squares.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CoeffStats {
+    count: u64,
+    min: i64,
+    max: i64,
+    mean: f64,
+    /// Sum of squared deviations from the running mean
+    m2: f64,
+}
+
+impl CoeffStats {
+    /// Empty summary
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Summary of one polynomial
+    pub fn of<const DEGREE: usize>(poly: &NttPolynomial<DEGREE>) -> Self {
+        let mut stats = Self::new();
+        stats.add_poly(poly);
+        stats
+    }
+
+    /// Summary over a stream of polynomials
+    pub fn of_stream<'a, const DEGREE: usize>(
+        polys: impl IntoIterator<Item = &'a NttPolynomial<DEGREE>>,
+    ) -> Self {
+        let mut stats = Self::new();
+        for poly in polys {
+            stats.add_poly(poly);
+        }
+        stats
+    }
+
+    /// Add every centered coefficient of `poly`
+    pub fn add_poly<const DEGREE: usize>(&mut self, poly: &NttPolynomial<DEGREE>) {
+        let q = poly.context().modulus();
+        for &c in poly.coeffs() {
+            self.add(centered(c, q));
+        }
+    }
+
+    /// Add one value
+    pub fn add(&mut self, value: i64) {
+        if self.count == 0 {
+            (self.min, self.max) = (value, value);
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+
+        self.count += 1;
+        let delta = value as f64 - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value as f64 - self.mean);
+    }
+
+    /// Combine with a summary of other values, e.g. from another thread
+    pub fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        self.m2 += other.m2
+            + delta * delta * (self.count as f64 * other.count as f64)
+                / count as f64;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.count = count;
+    }
+
+    /// Number of values seen
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Smallest value, 0 if empty
+    pub fn min(&self) -> i64 {
+        self.min
+    }
+
+    /// Largest value, 0 if empty
+    pub fn max(&self) -> i64 {
+        self.max
+    }
+
+    /// Largest absolute value, i.e. the infinity norm over everything seen
+    pub fn max_abs(&self) -> u64 {
+        self.min.unsigned_abs().max(self.max.unsigned_abs())
+    }
+
+    /// Mean, 0 if empty
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Population variance, 0 if empty
+    pub fn variance(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.m2 / self.count as f64
+        }
+    }
+
+    /// Population standard deviation
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl fmt::Display for CoeffStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "n = {}, min = {}, max = {}, mean = {:.4}, std dev = {:.4}",
+            self.count,
+            self.min,
+            self.max,
+            self.mean,
+            self.std_dev()
+        )
+    }
+}
+
+/// Exact counts of centered coefficient values.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Histogram {
+    counts: BTreeMap<i64, u64>,
+}
+
+impl Histogram {
+    /// Empty histogram
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Histogram of one polynomial
+    pub fn of<const DEGREE: usize>(poly: &NttPolynomial<DEGREE>) -> Self {
+        let mut histogram = Self::new();
+        histogram.add_poly(poly);
+        histogram
+    }
+
+    /// Histogram over a stream of polynomials
+    pub fn of_stream<'a, const DEGREE: usize>(
+        polys: impl IntoIterator<Item = &'a NttPolynomial<DEGREE>>,
+    ) -> Self {
+        let mut histogram = Self::new();
+        for poly in polys {
+            histogram.add_poly(poly);
+        }
+        histogram
+    }
+
+    /// Count every centered coefficient of `poly`
+    pub fn add_poly<const DEGREE: usize>(&mut self, poly: &NttPolynomial<DEGREE>) {
+        let q = poly.context().modulus();
+        for &c in poly.coeffs() {
+            self.add(centered(c, q));
+        }
+    }
+
+    /// Count one value
+    pub fn add(&mut self, value: i64) {
+        *self.counts.entry(value).or_default() += 1;
+    }
+
+    /// Number of times `value` was seen
+    pub fn get(&self, value: i64) -> u64 {
+        self.counts.get(&value).copied().unwrap_or(0)
+    }
+
+    /// Total number of values seen
+    pub fn total(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
+    /// `(value, count)` pairs in increasing order of value
+    pub fn iter(&self) -> impl Iterator<Item = (i64, u64)> + '_ {
+        self.counts.iter().map(|(&v, &c)| (v, c))
+    }
+
+    /// Counts grouped into buckets `[k·width, (k+1)·width)`, keyed by their
+    /// lower end, for wide distributions
+    ///
+    /// # Panics
+    /// * If `width` is 0
+    pub fn bucketed(&self, width: u64) -> BTreeMap<i64, u64> {
+        assert!(width > 0, "bucket width must be positive");
+        let width = width as i128;
+        let mut buckets = BTreeMap::new();
+        for (value, count) in self.iter() {
+            let low = (value as i128).div_euclid(width) * width;
+            *buckets.entry(low as i64).or_default() += count;
+        }
+        buckets
+    }
+}
+
+/// One line per value with a bar scaled to the most frequent one
+impl fmt::Display for Histogram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const WIDTH: u64 = 40;
+        let peak = self.counts.values().copied().max().unwrap_or(0).max(1);
+        for (value, count) in self.iter() {
+            let bar = "#".repeat((count * WIDTH).div_ceil(peak) as usize);
+            writeln!(f, "{value:>6} {count:>8} {bar}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+//! Where an [`NttPolynomial`](crate::NttPolynomial) keeps its coefficients.
+//!
+//! The storage is the second type parameter of the polynomial:
+//!
+//! * [`Heap`], the default: a `Box<[u64; N]>`, built on the heap without
+//!   passing through the stack, so rings of degree `2^15` and up work on
+//!   threads with ordinary stacks
+//! * [`Inline`]: a plain `[u64; N]` inside the polynomial, which saves one
+//!   allocation per polynomial and per result; meant for small degrees
+//!
+//! The methods of `NttPolynomial` and its operators work with either
+//! storage, and results keep the storage of their operands. Constructors
+//! such as [`from_coeffs`](crate::NttPolynomial::from_coeffs) build heap
+//! polynomials, so existing code needs no annotations, and
+//! [`from_boxed_coeffs`](crate::NttPolynomial::from_boxed_coeffs) takes
+//! coefficients already on the heap without copying them through the stack;
+//! [`from_coeffs_in`](crate::NttPolynomial::from_coeffs_in),
+//! [`zero_in`](crate::NttPolynomial::zero_in) and
+//! [`sample_random_in`](crate::NttPolynomial::sample_random_in) take the
+//! storage as an argument instead, as `Vec::new_in` takes an allocator.
+//! Functions elsewhere in the crate take the default, heap-backed
+//! polynomial.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::*;
+//! use std::sync::Arc;
+//!
+//! let ctx = NttContext::<4>::new(17);
+//! let a = NttPolynomial::from_coeffs_in([1, 2, 3, 4], Arc::clone(&ctx), Inline);
+//! let b = NttPolynomial::from_coeffs_in([0, 1, 0, 0], ctx, Inline);
+//! let c: NttPolynomial<4, Inline> = &a * &b;
+//! assert_eq!(c.coeffs(), &[13, 1, 2, 3]);
+//! ```
+use crate::context::{boxed_from_fn, boxed_zeros};
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Coefficient storage of a polynomial, either [`Heap`] or [`Inline`]
+pub trait Storage: fmt::Debug + sealed::Sealed + 'static {
+    /// The array of `N` coefficients
+    type Array<const N: usize>: Deref<Target = [u64; N]>
+        + DerefMut
+        + Clone
+        + fmt::Debug
+        + Send
+        + Sync;
+
+    /// `[f(0), ..., f(N-1)]`
+    fn from_fn<const N: usize>(f: impl FnMut(usize) -> u64) -> Self::Array<N>;
+
+    /// `N` zeros
+    fn zeros<const N: usize>() -> Self::Array<N>;
+
+    /// Coefficients received by value
+    fn from_array<const N: usize>(coeffs: [u64; N]) -> Self::Array<N>;
+
+    /// Coefficients already on the heap
+    fn from_boxed<const N: usize>(coeffs: Box<[u64; N]>) -> Self::Array<N>;
+
+    /// The coefficients on the heap
+    fn into_boxed<const N: usize>(coeffs: Self::Array<N>) -> Box<[u64; N]>;
+}
+
+/// Coefficients in a `Box<[u64; N]>`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Heap;
+
+/// Coefficients in a `[u64; N]` held by value
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Inline;
+
+/// The array of an [`Inline`] polynomial
+#[derive(Debug, Clone, Copy)]
+pub struct InlineArray<const N: usize>([u64; N]);
+
+impl sealed::Sealed for Heap {}
+impl sealed::Sealed for Inline {}
+
+impl Storage for Heap {
+    type Array<const N: usize> = Box<[u64; N]>;
+
+    fn from_fn<const N: usize>(f: impl FnMut(usize) -> u64) -> Box<[u64; N]> {
+        boxed_from_fn(f)
+    }
+
+    fn zeros<const N: usize>() -> Box<[u64; N]> {
+        boxed_zeros()
+    }
+
+    fn from_array<const N: usize>(coeffs: [u64; N]) -> Box<[u64; N]> {
+        Box::new(coeffs)
+    }
+
+    fn from_boxed<const N: usize>(coeffs: Box<[u64; N]>) -> Box<[u64; N]> {
+        coeffs
+    }
+
+    fn into_boxed<const N: usize>(coeffs: Box<[u64; N]>) -> Box<[u64; N]> {
+        coeffs
+    }
+}
+
+impl Storage for Inline {
+    type Array<const N: usize> = InlineArray<N>;
+
+    fn from_fn<const N: usize>(f: impl FnMut(usize) -> u64) -> InlineArray<N> {
+        InlineArray(std::array::from_fn(f))
+    }
+
+    fn zeros<const N: usize>() -> InlineArray<N> {
+        InlineArray([0; N])
+    }
+
+    fn from_array<const N: usize>(coeffs: [u64; N]) -> InlineArray<N> {
+        InlineArray(coeffs)
+    }
+
+    fn from_boxed<const N: usize>(coeffs: Box<[u64; N]>) -> InlineArray<N> {
+        InlineArray(*coeffs)
+    }
+
+    fn into_boxed<const N: usize>(coeffs: InlineArray<N>) -> Box<[u64; N]> {
+        Box::new(coeffs.0)
+    }
+}
+
+impl<const N: usize> Deref for InlineArray<N> {
+    type Target = [u64; N];
+
+    fn deref(&self) -> &[u64; N] {
+        &self.0
+    }
+}
+
+impl<const N: usize> DerefMut for InlineArray<N> {
+    fn deref_mut(&mut self) -> &mut [u64; N] {
+        &mut self.0
+    }
+}
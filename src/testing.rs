@@ -0,0 +1,100 @@
+//! Reusable proptest strategies for crates building on this one.
+//!
+//! The degree of a context is a const generic, so strategies generate over
+//! the bit size of the modulus for a fixed `DEGREE`; to cover several degrees,
+//! instantiate a property once per degree. [`ntt_primes`] works at run time
+//! for any degree when only the modulus is needed.
+//!
+//! # Examples
+//! ```rust
+//! use proptest::prelude::*;
+//! use rust_ntt::testing::context_with_polynomials;
+//!
+//! proptest! {
+//!     fn addition_commutes((_ctx, p) in context_with_polynomials::<8>(20..=40, 2)) {
+//!         let (ab, ba) = (&p[0] + &p[1], &p[1] + &p[0]);
+//!         prop_assert_eq!(ab.coeffs(), ba.coeffs());
+//!     }
+//! }
+//! addition_commutes();
+//! ```
+use crate::context::NttContext;
+use crate::math::{find_first_prime_up, find_next_prime_up};
+use crate::ntt::NttPolynomial;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+/// How many primes past the first one above `2^bits` may be chosen
+const MAX_PRIME_SKIP: usize = 3;
+
+fn check_bits(bits: &RangeInclusive<usize>, degree: usize) {
+    assert!(
+        !bits.is_empty() && *bits.end() <= 62,
+        "bit sizes must be a non-empty range up to 62, got {bits:?}"
+    );
+    assert!(
+        1usize << *bits.start() > 2 * degree,
+        "2^{} is too small for degree {degree}",
+        bits.start()
+    );
+}
+
+/// Primes `q ≡ 1 (mod 2·degree)` with `bits` bits, among the first few
+/// above `2^bits`.
+///
+/// # Panics
+/// * If `bits` is empty, exceeds 62, or starts below `log2(2·degree) + 1`
+pub fn ntt_primes(
+    degree: usize,
+    bits: RangeInclusive<usize>,
+) -> impl Strategy<Value = u64> {
+    check_bits(&bits, degree);
+    (bits, 0..=MAX_PRIME_SKIP).prop_map(move |(bits, skip)| {
+        let mut q = find_first_prime_up(bits, degree);
+        for _ in 0..skip {
+            q = find_next_prime_up(q, degree);
+        }
+        q
+    })
+}
+
+/// Valid contexts of degree `DEGREE` with moduli of `bits` bits.
+///
+/// # Panics
+/// * As for [`ntt_primes`]
+pub fn contexts<const DEGREE: usize>(
+    bits: RangeInclusive<usize>,
+) -> impl Strategy<Value = Arc<NttContext<DEGREE>>> {
+    ntt_primes(DEGREE, bits).prop_map(NttContext::<DEGREE>::new)
+}
+
+/// Coefficient arrays in `[0, q)` for the given context.
+pub fn coefficients<const DEGREE: usize>(
+    context: &NttContext<DEGREE>,
+) -> impl Strategy<Value = [u64; DEGREE]> + use<DEGREE> {
+    prop::array::uniform(0..context.modulus())
+}
+
+/// Polynomials over the given context with coefficients in `[0, q)`.
+pub fn polynomials<const DEGREE: usize>(
+    context: Arc<NttContext<DEGREE>>,
+) -> impl Strategy<Value = NttPolynomial<DEGREE>> {
+    coefficients(&context).prop_map(move |coeffs| {
+        NttPolynomial::from_coeffs(coeffs, Arc::clone(&context))
+    })
+}
+
+/// A random context together with `count` polynomials over it.
+///
+/// # Panics
+/// * As for [`ntt_primes`]
+pub fn context_with_polynomials<const DEGREE: usize>(
+    bits: RangeInclusive<usize>,
+    count: usize,
+) -> impl Strategy<Value = (Arc<NttContext<DEGREE>>, Vec<NttPolynomial<DEGREE>>)> {
+    contexts::<DEGREE>(bits).prop_flat_map(move |context| {
+        (Just(Arc::clone(&context)), vec(polynomials(context), count))
+    })
+}
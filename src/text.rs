@@ -0,0 +1,147 @@
+//! Text import/export in the formats of NTL and SageMath.
+//!
+//! - NTL prints a `ZZ_pX` as its coefficients from low to high degree,
+//!   separated by spaces, in brackets, with trailing zeros dropped:
+//!   `[544 561 657 419]`, and `[]` for zero.
+//! - Sage's `list(c)` of an element of `GF(q)[x]/(x^N + 1)` is a Python list
+//!   of all `N` coefficients from low to high: `[544, 561, 657, 419]`, which
+//!   `S(list)` reads back.
+//!
+//! Both parsers accept any integer coefficients (including negative ones,
+//! which both tools allow on input), reduce them modulo `q`, and pad missing
+//! high coefficients with zeros.
+use crate::context::{NttContext, boxed_zeros};
+use crate::ntt::NttPolynomial;
+use std::fmt;
+use std::sync::Arc;
+
+/// Reason a polynomial could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// Input is not enclosed in `[` and `]`
+    MissingBrackets,
+    /// A coefficient is not an integer fitting in 128 bits
+    InvalidCoefficient(String),
+    /// More coefficients than the ring degree
+    TooManyCoefficients { found: usize, degree: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingBrackets => {
+                write!(f, "polynomial must be enclosed in brackets")
+            }
+            Self::InvalidCoefficient(token) => {
+                write!(f, "invalid coefficient {token:?}")
+            }
+            Self::TooManyCoefficients { found, degree } => {
+                write!(f, "found {found} coefficients, ring degree is {degree}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl<const DEGREE: usize> NttPolynomial<DEGREE> {
+    /// NTL `ZZ_pX` representation, e.g. `[1 0 5]` for `1 + 5x^2`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rust_ntt::*;
+    /// use std::sync::Arc;
+    ///
+    /// let ctx = NttContext::<4>::new(17);
+    /// let a = NttPolynomial::from_coeffs([1, 0, 5, 0], Arc::clone(&ctx));
+    /// assert_eq!(a.to_ntl_string(), "[1 0 5]");
+    ///
+    /// let b = NttPolynomial::from_ntl_string("[1 0 -12]", ctx).unwrap();
+    /// assert_eq!(b.coeffs(), a.coeffs());
+    /// ```
+    pub fn to_ntl_string(&self) -> String {
+        let q = self.context().modulus();
+        let len = self
+            .coeffs()
+            .iter()
+            .rposition(|&c| c % q != 0)
+            .map_or(0, |i| i + 1);
+
+        let body: Vec<String> = self.coeffs()[..len]
+            .iter()
+            .map(|&c| (c % q).to_string())
+            .collect();
+        format!("[{}]", body.join(" "))
+    }
+
+    /// Parse NTL's `ZZ_pX` format, see [`NttPolynomial::to_ntl_string`].
+    pub fn from_ntl_string(
+        s: &str,
+        context: Arc<NttContext<DEGREE>>,
+    ) -> Result<Self, ParseError> {
+        let body = strip_brackets(s)?;
+        Self::from_tokens(body.split_whitespace(), context)
+    }
+
+    /// Sage list representation of all `DEGREE` coefficients, e.g.
+    /// `[1, 0, 5, 0]`, ready for `S([1, 0, 5, 0])`.
+    pub fn to_sage_string(&self) -> String {
+        let q = self.context().modulus();
+        let body: Vec<String> =
+            self.coeffs().iter().map(|&c| (c % q).to_string()).collect();
+        format!("[{}]", body.join(", "))
+    }
+
+    /// Parse a Sage/Python list of coefficients, see
+    /// [`NttPolynomial::to_sage_string`].
+    pub fn from_sage_string(
+        s: &str,
+        context: Arc<NttContext<DEGREE>>,
+    ) -> Result<Self, ParseError> {
+        let body = strip_brackets(s)?;
+        let tokens = body.split(',').map(str::trim);
+
+        // Python allows a trailing comma, and `[]` is the empty list
+        let tokens: Vec<&str> = tokens.collect();
+        let tokens = match tokens.as_slice() {
+            [rest @ .., ""] => rest,
+            all => all,
+        };
+        Self::from_tokens(tokens.iter().copied(), context)
+    }
+
+    fn from_tokens<'a>(
+        tokens: impl Iterator<Item = &'a str>,
+        context: Arc<NttContext<DEGREE>>,
+    ) -> Result<Self, ParseError> {
+        let q = context.modulus() as i128;
+        let mut coeffs = boxed_zeros::<DEGREE>();
+        let mut found = 0;
+
+        for token in tokens {
+            let value: i128 = token
+                .parse()
+                .map_err(|_| ParseError::InvalidCoefficient(token.to_string()))?;
+            if found < DEGREE {
+                coeffs[found] = value.rem_euclid(q) as u64;
+            }
+            found += 1;
+        }
+
+        if found > DEGREE {
+            return Err(ParseError::TooManyCoefficients {
+                found,
+                degree: DEGREE,
+            });
+        }
+
+        Ok(Self::from_boxed_coeffs(coeffs, context))
+    }
+}
+
+fn strip_brackets(s: &str) -> Result<&str, ParseError> {
+    s.trim()
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .ok_or(ParseError::MissingBrackets)
+}
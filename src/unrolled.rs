@@ -0,0 +1,243 @@
+//! Fully unrolled Shoup transforms for `N ∈ {4, 8, 16}`.
+//!
+//! At these sizes the stage and block loops of the generic kernels cost
+//! about as much as the butterflies themselves. The functions here spell
+//! out every butterfly with constant indices, so there is no loop control
+//! and no bounds check left, and produce the same slot order and values as
+//! [`forward_shoup_kernel`] and [`inverse_shoup_kernel`].
+//!
+//! [`forward_shoup`] and [`inverse_shoup`] pick them by matching on the
+//! const generic degree, which the compiler resolves per instantiation;
+//! every other degree goes to the generic kernels. The default
+//! [`NttKernel`](crate::kernel::NttKernel) methods and the
+//! [`StaticNttContext`](crate::embedded::StaticNttContext) transforms go
+//! through this dispatch.
+use crate::congruence::CongruenceClass;
+use crate::ntt::{forward_shoup_kernel, inverse_shoup_kernel};
+
+/// Cooley-Tukey butterflies `(j, k, w)` on slots `j < k` with twiddle `w`
+macro_rules! ct_butterflies {
+    ($class:ident, $tf:ident, $tf_shoup:ident, $c:ident;
+     $(($j:literal, $k:literal, $w:literal))*) => {
+        $(
+            let v = $class.modmul_shoup($c[$k], $tf[$w], $tf_shoup[$w]);
+            $c[$k] = $class.modsub($c[$j], v);
+            $class.modadd_eq(&mut $c[$j], v);
+        )*
+    };
+}
+
+/// Gentleman-Sande butterflies `(j, k, w)` on slots `j < k` with twiddle `w`
+macro_rules! gs_butterflies {
+    ($class:ident, $itf:ident, $itf_shoup:ident, $c:ident;
+     $(($j:literal, $k:literal, $w:literal))*) => {
+        $(
+            let (u, v) = ($c[$j], $c[$k]);
+            $c[$j] = $class.modadd(u, v);
+            $c[$k] = $class.modmul_shoup(
+                $class.modsub(u, v),
+                $itf[$w],
+                $itf_shoup[$w],
+            );
+        )*
+    };
+}
+
+/// Last inverse stage `(j, k)`, scaling sums by `inv_n` and differences by
+/// `inv_n_itf`
+macro_rules! scaled_butterflies {
+    ($class:ident, $inv_n:ident, $inv_n_itf:ident, $c:ident;
+     $(($j:literal, $k:literal))*) => {
+        $(
+            let (u, v) = ($c[$j], $c[$k]);
+            $c[$j] = $class.modmul_shoup($class.modadd(u, v), $inv_n.0, $inv_n.1);
+            $c[$k] =
+                $class.modmul_shoup($class.modsub(u, v), $inv_n_itf.0, $inv_n_itf.1);
+        )*
+    };
+}
+
+/// Forward Shoup NTT in place, unrolled for the small degrees
+#[inline]
+pub(crate) fn forward_shoup<const DEGREE: usize>(
+    class: &CongruenceClass,
+    tf: &[u64; DEGREE],
+    tf_shoup: &[u64; DEGREE],
+    coeffs: &mut [u64; DEGREE],
+) {
+    match DEGREE {
+        4 => forward_4(class, fixed(tf), fixed(tf_shoup), fixed_mut(coeffs)),
+        8 => forward_8(class, fixed(tf), fixed(tf_shoup), fixed_mut(coeffs)),
+        16 => forward_16(class, fixed(tf), fixed(tf_shoup), fixed_mut(coeffs)),
+        _ => forward_shoup_kernel(class, tf, tf_shoup, coeffs),
+    }
+}
+
+/// Inverse Shoup NTT in place with the scaling of [`inverse_shoup_kernel`],
+/// unrolled for the small degrees
+#[inline]
+pub(crate) fn inverse_shoup<const DEGREE: usize>(
+    class: &CongruenceClass,
+    itf: &[u64; DEGREE],
+    itf_shoup: &[u64; DEGREE],
+    inv_n: (u64, u64),
+    inv_n_itf: (u64, u64),
+    evals: &mut [u64; DEGREE],
+) {
+    match DEGREE {
+        4 => inverse_4(
+            class,
+            fixed(itf),
+            fixed(itf_shoup),
+            inv_n,
+            inv_n_itf,
+            fixed_mut(evals),
+        ),
+        8 => inverse_8(
+            class,
+            fixed(itf),
+            fixed(itf_shoup),
+            inv_n,
+            inv_n_itf,
+            fixed_mut(evals),
+        ),
+        16 => inverse_16(
+            class,
+            fixed(itf),
+            fixed(itf_shoup),
+            inv_n,
+            inv_n_itf,
+            fixed_mut(evals),
+        ),
+        _ => inverse_shoup_kernel(class, itf, itf_shoup, inv_n, inv_n_itf, evals),
+    }
+}
+
+/// `values` as an array of the length the dispatch matched
+#[inline(always)]
+fn fixed<const N: usize>(values: &[u64]) -> &[u64; N] {
+    values.try_into().expect("degree matched by dispatch")
+}
+
+#[inline(always)]
+fn fixed_mut<const N: usize>(values: &mut [u64]) -> &mut [u64; N] {
+    values.try_into().expect("degree matched by dispatch")
+}
+
+#[inline(always)]
+fn forward_4(
+    class: &CongruenceClass,
+    tf: &[u64; 4],
+    tf_shoup: &[u64; 4],
+    c: &mut [u64; 4],
+) {
+    ct_butterflies!(class, tf, tf_shoup, c;
+        // t = 2
+        (0, 2, 1) (1, 3, 1)
+        // t = 1
+        (0, 1, 2) (2, 3, 3)
+    );
+}
+
+#[inline(always)]
+fn forward_8(
+    class: &CongruenceClass,
+    tf: &[u64; 8],
+    tf_shoup: &[u64; 8],
+    c: &mut [u64; 8],
+) {
+    ct_butterflies!(class, tf, tf_shoup, c;
+        // t = 4
+        (0, 4, 1) (1, 5, 1) (2, 6, 1) (3, 7, 1)
+        // t = 2
+        (0, 2, 2) (1, 3, 2) (4, 6, 3) (5, 7, 3)
+        // t = 1
+        (0, 1, 4) (2, 3, 5) (4, 5, 6) (6, 7, 7)
+    );
+}
+
+#[inline(always)]
+fn forward_16(
+    class: &CongruenceClass,
+    tf: &[u64; 16],
+    tf_shoup: &[u64; 16],
+    c: &mut [u64; 16],
+) {
+    ct_butterflies!(class, tf, tf_shoup, c;
+        // t = 8
+        (0, 8, 1) (1, 9, 1) (2, 10, 1) (3, 11, 1)
+        (4, 12, 1) (5, 13, 1) (6, 14, 1) (7, 15, 1)
+        // t = 4
+        (0, 4, 2) (1, 5, 2) (2, 6, 2) (3, 7, 2)
+        (8, 12, 3) (9, 13, 3) (10, 14, 3) (11, 15, 3)
+        // t = 2
+        (0, 2, 4) (1, 3, 4) (4, 6, 5) (5, 7, 5)
+        (8, 10, 6) (9, 11, 6) (12, 14, 7) (13, 15, 7)
+        // t = 1
+        (0, 1, 8) (2, 3, 9) (4, 5, 10) (6, 7, 11)
+        (8, 9, 12) (10, 11, 13) (12, 13, 14) (14, 15, 15)
+    );
+}
+
+#[inline(always)]
+fn inverse_4(
+    class: &CongruenceClass,
+    itf: &[u64; 4],
+    itf_shoup: &[u64; 4],
+    inv_n: (u64, u64),
+    inv_n_itf: (u64, u64),
+    c: &mut [u64; 4],
+) {
+    gs_butterflies!(class, itf, itf_shoup, c;
+        // t = 1
+        (0, 1, 2) (2, 3, 3)
+    );
+    scaled_butterflies!(class, inv_n, inv_n_itf, c;
+        (0, 2) (1, 3)
+    );
+}
+
+#[inline(always)]
+fn inverse_8(
+    class: &CongruenceClass,
+    itf: &[u64; 8],
+    itf_shoup: &[u64; 8],
+    inv_n: (u64, u64),
+    inv_n_itf: (u64, u64),
+    c: &mut [u64; 8],
+) {
+    gs_butterflies!(class, itf, itf_shoup, c;
+        // t = 1
+        (0, 1, 4) (2, 3, 5) (4, 5, 6) (6, 7, 7)
+        // t = 2
+        (0, 2, 2) (1, 3, 2) (4, 6, 3) (5, 7, 3)
+    );
+    scaled_butterflies!(class, inv_n, inv_n_itf, c;
+        (0, 4) (1, 5) (2, 6) (3, 7)
+    );
+}
+
+#[inline(always)]
+fn inverse_16(
+    class: &CongruenceClass,
+    itf: &[u64; 16],
+    itf_shoup: &[u64; 16],
+    inv_n: (u64, u64),
+    inv_n_itf: (u64, u64),
+    c: &mut [u64; 16],
+) {
+    gs_butterflies!(class, itf, itf_shoup, c;
+        // t = 1
+        (0, 1, 8) (2, 3, 9) (4, 5, 10) (6, 7, 11)
+        (8, 9, 12) (10, 11, 13) (12, 13, 14) (14, 15, 15)
+        // t = 2
+        (0, 2, 4) (1, 3, 4) (4, 6, 5) (5, 7, 5)
+        (8, 10, 6) (9, 11, 6) (12, 14, 7) (13, 15, 7)
+        // t = 4
+        (0, 4, 2) (1, 5, 2) (2, 6, 2) (3, 7, 2)
+        (8, 12, 3) (9, 13, 3) (10, 14, 3) (11, 15, 3)
+    );
+    scaled_butterflies!(class, inv_n, inv_n_itf, c;
+        (0, 8) (1, 9) (2, 10) (3, 11) (4, 12) (5, 13) (6, 14) (7, 15)
+    );
+}
@@ -0,0 +1,167 @@
+//! Vectors of polynomials and their bilinear products.
+//!
+//! Inner, outer and tensor products transform every input entry once and do
+//! all cross products pointwise in the NTT domain, which is what relinearization
+//! style operations (`(c0, c1) ⊗ (d0, d1)`) need.
+use crate::context::NttContext;
+use crate::matrix::{PolyMatrix, forward_all};
+use crate::ntt::NttPolynomial;
+use std::sync::Arc;
+
+/// Vector of polynomials in `Z_q[x]/(x^N + 1)`.
+#[derive(Debug, Clone)]
+pub struct PolyVec<const DEGREE: usize> {
+    entries: Vec<NttPolynomial<DEGREE>>,
+    context: Arc<NttContext<DEGREE>>,
+}
+
+impl<const DEGREE: usize> PolyVec<DEGREE> {
+    /// Create vector from polynomials
+    pub fn from_entries(
+        entries: Vec<NttPolynomial<DEGREE>>,
+        context: Arc<NttContext<DEGREE>>,
+    ) -> Self {
+        Self { entries, context }
+    }
+
+    /// Create zero vector of the given length
+    pub fn zero(len: usize, context: Arc<NttContext<DEGREE>>) -> Self {
+        let entries = (0..len)
+            .map(|_| NttPolynomial::zero(Arc::clone(&context)))
+            .collect();
+
+        Self { entries, context }
+    }
+
+    /// Generate vector with random entries, see `NttPolynomial::sample_random`.
+    pub fn sample_random<R: rand::Rng>(
+        len: usize,
+        context: Arc<NttContext<DEGREE>>,
+        rng: &mut R,
+    ) -> Self {
+        let entries = (0..len)
+            .map(|_| NttPolynomial::sample_random(Arc::clone(&context), rng))
+            .collect();
+
+        Self { entries, context }
+    }
+
+    /// Number of entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the vector has no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Get entries
+    pub fn entries(&self) -> &[NttPolynomial<DEGREE>] {
+        &self.entries
+    }
+
+    /// Get mutable entries
+    pub fn entries_mut(&mut self) -> &mut [NttPolynomial<DEGREE>] {
+        &mut self.entries
+    }
+
+    /// Get context
+    pub fn context(&self) -> &Arc<NttContext<DEGREE>> {
+        &self.context
+    }
+
+    /// Inner product `Σ a_i * b_i`, with a single inverse transform.
+    ///
+    /// # Panics
+    /// * If the vectors have different lengths
+    pub fn inner(&self, other: &Self) -> NttPolynomial<DEGREE> {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "Cannot take inner product of vectors with different lengths"
+        );
+        assert_eq!(
+            self.context.modulus(),
+            other.context.modulus(),
+            "Cannot multiply vectors with different moduli"
+        );
+
+        let class = self.context.class();
+
+        let lhs_hat = forward_all(&self.entries);
+        let rhs_hat = forward_all(&other.entries);
+
+        let mut acc = NttPolynomial::zero(Arc::clone(&self.context));
+        for (a_hat, b_hat) in lhs_hat.iter().zip(&rhs_hat) {
+            let (a, b) = (a_hat.coeffs(), b_hat.coeffs());
+            for (slot, c) in acc.coeffs_mut().iter_mut().enumerate() {
+                class.modadd_eq(c, class.modmul(a[slot], b[slot]));
+            }
+        }
+
+        acc.ntt_inverse_shoup();
+        acc
+    }
+
+    /// Outer product `a b^T`, see [`tensor`].
+    pub fn outer(&self, other: &Self) -> PolyMatrix<DEGREE> {
+        tensor(self, other)
+    }
+}
+
+/// Tensor (outer) product of two polynomial vectors.
+///
+/// Returns the `a.len() × b.len()` matrix with entries `a_i * b_j`. Each input
+/// entry is transformed once, so the cost is `m + n` forward and `mn` inverse
+/// transforms instead of `3mn` for separate convolutions.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::*;
+/// use rust_ntt::vector::{PolyVec, tensor};
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use std::sync::Arc;
+///
+/// const N: usize = 8;
+/// let ctx = NttContext::<N>::new(find_first_prime_up(20, N));
+/// let mut rng = StdRng::seed_from_u64(42);
+///
+/// let c = PolyVec::sample_random(2, Arc::clone(&ctx), &mut rng);
+/// let d = PolyVec::sample_random(2, Arc::clone(&ctx), &mut rng);
+///
+/// let cd = tensor(&c, &d);
+/// let c0d1 = &c.entries()[0] * &d.entries()[1];
+/// assert_eq!(cd.get(0, 1).coeffs(), c0d1.coeffs());
+/// ```
+pub fn tensor<const DEGREE: usize>(
+    a: &PolyVec<DEGREE>,
+    b: &PolyVec<DEGREE>,
+) -> PolyMatrix<DEGREE> {
+    assert_eq!(
+        a.context.modulus(),
+        b.context.modulus(),
+        "Cannot multiply vectors with different moduli"
+    );
+
+    let class = a.context.class();
+
+    let lhs_hat = forward_all(&a.entries);
+    let rhs_hat = forward_all(&b.entries);
+
+    let mut entries = Vec::with_capacity(a.len() * b.len());
+
+    for x in &lhs_hat {
+        for y in &rhs_hat {
+            let mut entry = NttPolynomial::zero(Arc::clone(&a.context));
+            for (slot, c) in entry.coeffs_mut().iter_mut().enumerate() {
+                *c = class.modmul(x.coeffs()[slot], y.coeffs()[slot]);
+            }
+
+            entry.ntt_inverse_shoup();
+            entries.push(entry);
+        }
+    }
+
+    PolyMatrix::from_entries(a.len(), b.len(), entries, Arc::clone(&a.context))
+}
@@ -0,0 +1,320 @@
+//! Exhaustive verification of the kernels over small moduli.
+//!
+//! Randomized tests over large moduli almost never hit the handful of inputs
+//! where a reduction goes wrong, such as `a + b = q` or operands equal to
+//! `q - 1`. Over a small prime these inputs are a noticeable fraction of the
+//! space, and the space itself is small enough to enumerate: every scalar
+//! pair, and every polynomial (or pair, or triple) while `q^(N·arity)` stays
+//! below [`VerifyOptions::exhaustive_limit`]. Larger spaces are sampled
+//! densely instead.
+//!
+//! Each check compares against exact `u128` arithmetic or the schoolbook
+//! product. In [`VerifyOptions::strict`] mode every output must also be
+//! canonical, i.e. in `[0, q)`, rather than merely congruent.
+use crate::congruence::CongruenceClass;
+use crate::context::NttContext;
+use crate::ntt::NttPolynomial;
+use primal::is_prime;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::sync::Arc;
+
+/// How thoroughly to verify.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifyOptions {
+    /// Enumerate a check's whole input space if it has at most this many
+    /// cases, otherwise sample it
+    pub exhaustive_limit: u64,
+    /// Number of random cases for checks that are not enumerated
+    pub samples: usize,
+    /// Require outputs in `[0, q)` rather than only correct modulo `q`
+    pub strict: bool,
+    /// Seed for the sampled checks
+    pub seed: u64,
+}
+
+impl Default for VerifyOptions {
+    fn default() -> Self {
+        Self {
+            exhaustive_limit: 1 << 16,
+            samples: 1000,
+            strict: false,
+            seed: 42,
+        }
+    }
+}
+
+/// First failing case of one check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub q: u64,
+    /// Ring degree, or 1 for scalar checks
+    pub degree: usize,
+    pub check: &'static str,
+    pub detail: String,
+}
+
+/// Every prime `q < bound` with `q ≡ 1 (mod 2·DEGREE)`
+pub fn ntt_primes_below<const DEGREE: usize>(bound: u64) -> Vec<u64> {
+    let m = 2 * DEGREE as u64;
+    (1..)
+        .map(|k| k * m + 1)
+        .take_while(|&q| q < bound)
+        .filter(|&q| is_prime(q))
+        .collect()
+}
+
+/// Verify the scalar and ring kernels for every NTT-friendly prime below
+/// `bound`, returning all violations found.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::verify::{VerifyOptions, verify_primes_below};
+///
+/// let violations = verify_primes_below::<4>(100, &VerifyOptions::default());
+/// assert!(violations.is_empty(), "{violations:?}");
+/// ```
+pub fn verify_primes_below<const DEGREE: usize>(
+    bound: u64,
+    options: &VerifyOptions,
+) -> Vec<Violation> {
+    ntt_primes_below::<DEGREE>(bound)
+        .into_iter()
+        .flat_map(|q| {
+            let mut found = verify_scalar(q, options);
+            found.extend(verify_ring::<DEGREE>(q, options));
+            found
+        })
+        .collect()
+}
+
+/// Check `CongruenceClass` arithmetic modulo `q` on every pair of residues
+/// (or a sample, for large `q`).
+pub fn verify_scalar(q: u64, options: &VerifyOptions) -> Vec<Violation> {
+    let class = CongruenceClass::new(q);
+    let mut checker = Checker::new(q, 1, options);
+
+    checker.run("modadd", 2, |v| {
+        let (a, b) = (v[0][0], v[1][0]);
+        let got = class.modadd(a, b);
+        check_scalar(got, (a as u128 + b as u128) % q as u128, options, q)
+            .map(|why| format!("{a} + {b}: {why}"))
+    });
+    checker.run("modsub", 2, |v| {
+        let (a, b) = (v[0][0], v[1][0]);
+        let got = class.modsub(a, b);
+        let want = (a as u128 + q as u128 - b as u128) % q as u128;
+        check_scalar(got, want, options, q).map(|why| format!("{a} - {b}: {why}"))
+    });
+    checker.run("modneg", 1, |v| {
+        let a = v[0][0];
+        let mut got = a;
+        class.modneg_eq(&mut got);
+        let want = (q - a) as u128 % q as u128;
+        check_scalar(got, want, options, q).map(|why| format!("-{a}: {why}"))
+    });
+    checker.run("modmul", 2, |v| {
+        let (a, b) = (v[0][0], v[1][0]);
+        let got = class.modmul(a, b);
+        let want = a as u128 * b as u128 % q as u128;
+        check_scalar(got, want, options, q).map(|why| format!("{a}·{b}: {why}"))
+    });
+    checker.run("modmul_shoup", 2, |v| {
+        let (a, b) = (v[0][0], v[1][0]);
+        let got = class.modmul_shoup(a, b, class.precompute_shoup(b));
+        let want = a as u128 * b as u128 % q as u128;
+        check_scalar(got, want, options, q).map(|why| format!("{a}·{b}: {why}"))
+    });
+
+    checker.violations
+}
+
+/// Check the ring axioms and transform identities of `Z_q[x]/(x^N + 1)`.
+///
+/// # Panics
+/// * If `q` is not a valid modulus for an `NttContext<DEGREE>`
+pub fn verify_ring<const DEGREE: usize>(
+    q: u64,
+    options: &VerifyOptions,
+) -> Vec<Violation> {
+    let ctx = NttContext::<DEGREE>::new(q);
+    let mut checker = Checker::new(q, DEGREE, options);
+    let poly = |c: &[u64]| {
+        NttPolynomial::from_coeffs(c.try_into().unwrap(), Arc::clone(&ctx))
+    };
+    let check = |got: &NttPolynomial<DEGREE>, want: &NttPolynomial<DEGREE>| {
+        check_poly(got.coeffs(), want.coeffs(), options, q)
+    };
+
+    checker.run("forward/inverse identity", 1, |v| {
+        let a = poly(v[0]);
+        let mut b = a.clone();
+        b.ntt_forward();
+        b.ntt_inverse();
+        check(&b, &a)
+    });
+    checker.run("Shoup forward/inverse identity", 1, |v| {
+        let a = poly(v[0]);
+        let mut b = a.clone();
+        b.ntt_forward_shoup();
+        b.ntt_inverse_shoup();
+        check(&b, &a)
+    });
+    checker.run("Shoup transform equals Barrett", 1, |v| {
+        let (mut a, mut b) = (poly(v[0]), poly(v[0]));
+        a.ntt_forward();
+        b.ntt_forward_shoup();
+        check(&b, &a)
+    });
+    checker.run("additive inverse", 1, |v| {
+        let a = poly(v[0]);
+        check(&(&a + &(-&a)), &NttPolynomial::zero(Arc::clone(&ctx)))
+    });
+    checker.run("multiplication by x wraps negacyclically", 1, |v| {
+        // x·a shifts coefficients up, negating the one that wraps past x^N;
+        // for N = 1 the ring is Z_q[x]/(x + 1) and x itself is -1
+        let a = poly(v[0]);
+        let mut x = [0; DEGREE];
+        x[1 % DEGREE] = 1;
+        if DEGREE == 1 {
+            x[0] = q - 1;
+        }
+        let mut shifted = [0; DEGREE];
+        for i in 0..DEGREE {
+            let c = a.coeffs()[i];
+            let j = (i + 1) % DEGREE;
+            shifted[j] = if i + 1 == DEGREE { (q - c) % q } else { c };
+        }
+        check(&(&a * &poly(&x)), &poly(&shifted))
+    });
+    checker.run("addition commutes", 2, |v| {
+        let (a, b) = (poly(v[0]), poly(v[1]));
+        check(&(&a + &b), &(&b + &a))
+    });
+    checker.run("subtraction inverts addition", 2, |v| {
+        let (a, b) = (poly(v[0]), poly(v[1]));
+        check(&(&(&a + &b) - &b), &a)
+    });
+    checker.run("product equals schoolbook", 2, |v| {
+        let (a, b) = (poly(v[0]), poly(v[1]));
+        let naive = a.naive_negacyclic_convolution(&b);
+        check(&a.negacyclic_convolution(&b), &naive)
+            .or_else(|| check(&a.negacyclic_convolution_shoup(&b), &naive))
+    });
+    checker.run("product commutes", 2, |v| {
+        let (a, b) = (poly(v[0]), poly(v[1]));
+        check(&(&a * &b), &(&b * &a))
+    });
+    checker.run("product associates", 3, |v| {
+        let (a, b, c) = (poly(v[0]), poly(v[1]), poly(v[2]));
+        check(&(&(&a * &b) * &c), &(&a * &(&b * &c)))
+    });
+    checker.run("product distributes", 3, |v| {
+        let (a, b, c) = (poly(v[0]), poly(v[1]), poly(v[2]));
+        check(&(&a * &(&b + &c)), &(&(&a * &b) + &(&a * &c)))
+    });
+
+    checker.violations
+}
+
+fn check_scalar(
+    got: u64,
+    want: u128,
+    options: &VerifyOptions,
+    q: u64,
+) -> Option<String> {
+    if got as u128 % q as u128 != want {
+        Some(format!("got {got}, want {want}"))
+    } else if options.strict && got >= q {
+        Some(format!("got {got}, not reduced below q"))
+    } else {
+        None
+    }
+}
+
+fn check_poly(
+    got: &[u64],
+    want: &[u64],
+    options: &VerifyOptions,
+    q: u64,
+) -> Option<String> {
+    for (i, (&g, &w)) in got.iter().zip(want).enumerate() {
+        if g % q != w % q {
+            return Some(format!("coefficient {i}: got {g}, want {}", w % q));
+        }
+        if options.strict && g >= q {
+            return Some(format!("coefficient {i}: got {g}, not reduced below q"));
+        }
+    }
+    None
+}
+
+/// Runs checks over enumerated or sampled inputs, keeping the first
+/// violation of each.
+struct Checker {
+    q: u64,
+    degree: usize,
+    options: VerifyOptions,
+    rng: StdRng,
+    violations: Vec<Violation>,
+}
+
+impl Checker {
+    fn new(q: u64, degree: usize, options: &VerifyOptions) -> Self {
+        Self {
+            q,
+            degree,
+            options: *options,
+            rng: StdRng::seed_from_u64(options.seed ^ q),
+            violations: Vec::new(),
+        }
+    }
+
+    /// Feed `arity` coefficient vectors of length `degree` to `check`
+    fn run(
+        &mut self,
+        name: &'static str,
+        arity: usize,
+        mut check: impl FnMut(&[&[u64]]) -> Option<String>,
+    ) {
+        let (q, n) = (self.q, self.degree);
+        let cases = (q as u128)
+            .checked_pow((n * arity) as u32)
+            .filter(|&c| c <= self.options.exhaustive_limit as u128);
+
+        let mut digits = vec![0u64; n * arity];
+        let mut report = |digits: &[u64]| {
+            let inputs: Vec<&[u64]> = digits.chunks(n).collect();
+            check(&inputs)
+        };
+
+        let failure = match cases {
+            Some(cases) => (0..cases).find_map(|mut index| {
+                for d in digits.iter_mut() {
+                    *d = (index % q as u128) as u64;
+                    index /= q as u128;
+                }
+                report(&digits)
+            }),
+            None => (0..self.options.samples).find_map(|_| {
+                // Bias towards the boundary values where reductions fail
+                for d in digits.iter_mut() {
+                    *d = match self.rng.random_range(0..4) {
+                        0 => q - 1 - self.rng.random_range(0..q.min(4)),
+                        1 => self.rng.random_range(0..q.min(4)),
+                        _ => self.rng.random_range(0..q),
+                    };
+                }
+                report(&digits)
+            }),
+        };
+
+        if let Some(detail) = failure {
+            self.violations.push(Violation {
+                q,
+                degree: n,
+                check: name,
+                detail,
+            });
+        }
+    }
+}
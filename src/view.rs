@@ -0,0 +1,196 @@
+//! Borrowed polynomials over caller-owned buffers.
+//!
+//! [`NttPolynomial`] owns its coefficients, so data that lives inside a
+//! larger structure (one limb of an RNS tower, a component of a ciphertext)
+//! would have to be copied in and out. [`PolyView`] and [`PolyViewMut`]
+//! instead borrow `N` coefficients from any `u64` slice together with a
+//! context, and transform or combine them in place.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::*;
+//! use rust_ntt::view::{PolyView, PolyViewMut};
+//!
+//! const N: usize = 4;
+//! let ctx = NttContext::<N>::new(find_first_prime_up(20, N));
+//!
+//! // Two polynomials stored back to back in one buffer
+//! let mut buffer = [1, 1, 0, 0, 0, 1, 0, 0];
+//! let (a, b) = buffer.split_at_mut(N);
+//!
+//! let mut a = PolyViewMut::new(a, &ctx);
+//! a *= PolyView::new(b, &ctx);
+//! // (1 + x)·x = x + x^2
+//! assert_eq!(buffer[..N], [0, 1, 1, 0]);
+//! ```
+use crate::context::NttContext;
+use crate::counters::Op;
+use crate::ntt::NttPolynomial;
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+/// Read-only polynomial borrowing its coefficients.
+#[derive(Debug, Clone, Copy)]
+pub struct PolyView<'a, const DEGREE: usize> {
+    coeffs: &'a [u64; DEGREE],
+    context: &'a NttContext<DEGREE>,
+}
+
+/// Polynomial borrowing its coefficients mutably; operations write through
+/// to the underlying buffer.
+#[derive(Debug)]
+pub struct PolyViewMut<'a, const DEGREE: usize> {
+    coeffs: &'a mut [u64; DEGREE],
+    context: &'a NttContext<DEGREE>,
+}
+
+impl<'a, const DEGREE: usize> PolyView<'a, DEGREE> {
+    /// View `coeffs` as a polynomial over `context`.
+    ///
+    /// # Panics
+    /// * If `coeffs.len() != DEGREE`
+    pub fn new(coeffs: &'a [u64], context: &'a NttContext<DEGREE>) -> Self {
+        let coeffs = coeffs.try_into().unwrap_or_else(|_| {
+            panic!("view needs {DEGREE} coefficients, got {}", coeffs.len())
+        });
+        Self { coeffs, context }
+    }
+
+    pub fn coeffs(&self) -> &'a [u64; DEGREE] {
+        self.coeffs
+    }
+
+    pub fn context(&self) -> &'a NttContext<DEGREE> {
+        self.context
+    }
+}
+
+impl<'a, const DEGREE: usize> PolyViewMut<'a, DEGREE> {
+    /// View `coeffs` as a mutable polynomial over `context`.
+    ///
+    /// # Panics
+    /// * If `coeffs.len() != DEGREE`
+    pub fn new(coeffs: &'a mut [u64], context: &'a NttContext<DEGREE>) -> Self {
+        let len = coeffs.len();
+        let coeffs = coeffs.try_into().unwrap_or_else(|_| {
+            panic!("view needs {DEGREE} coefficients, got {len}")
+        });
+        Self { coeffs, context }
+    }
+
+    /// Reborrow as a read-only view
+    pub fn as_view(&self) -> PolyView<'_, DEGREE> {
+        PolyView {
+            coeffs: self.coeffs,
+            context: self.context,
+        }
+    }
+
+    pub fn coeffs(&self) -> &[u64; DEGREE] {
+        self.coeffs
+    }
+
+    pub fn coeffs_mut(&mut self) -> &mut [u64; DEGREE] {
+        self.coeffs
+    }
+
+    pub fn context(&self) -> &'a NttContext<DEGREE> {
+        self.context
+    }
+
+    /// Forward transform in place, with Shoup multiplication
+    pub fn ntt_forward(&mut self) {
+        self.context.forward_shoup_in_place(self.coeffs);
+    }
+
+    /// Inverse transform in place, with Shoup multiplication
+    pub fn ntt_inverse(&mut self) {
+        self.context.inverse_shoup_in_place(self.coeffs);
+    }
+
+    pub fn neg_assign(&mut self) {
+        let class = self.context.class();
+        self.context.count(Op::ModAdd, DEGREE as u64);
+        for c in self.coeffs.iter_mut() {
+            *c = class.modneg(*c);
+        }
+    }
+
+    /// Multiply by `rhs` coefficient-wise, for operands already in the
+    /// evaluation domain
+    pub fn pointwise_mul_assign(&mut self, rhs: PolyView<'_, DEGREE>) {
+        check_moduli(self.context, rhs.context);
+        let class = self.context.class();
+        self.context.count(Op::ModMul, DEGREE as u64);
+        for (x, &y) in self.coeffs.iter_mut().zip(rhs.coeffs) {
+            class.modmul_eq(x, y);
+        }
+    }
+}
+
+fn check_moduli<const DEGREE: usize>(
+    lhs: &NttContext<DEGREE>,
+    rhs: &NttContext<DEGREE>,
+) {
+    if let Err(e) = lhs.check_compatible(rhs) {
+        panic!("Cannot combine polynomials: {e}");
+    }
+}
+
+impl<const DEGREE: usize> AddAssign<PolyView<'_, DEGREE>>
+    for PolyViewMut<'_, DEGREE>
+{
+    fn add_assign(&mut self, rhs: PolyView<'_, DEGREE>) {
+        check_moduli(self.context, rhs.context);
+        let class = self.context.class();
+        self.context.count(Op::ModAdd, DEGREE as u64);
+        for (x, &y) in self.coeffs.iter_mut().zip(rhs.coeffs) {
+            class.modadd_eq(x, y);
+        }
+    }
+}
+
+impl<const DEGREE: usize> SubAssign<PolyView<'_, DEGREE>>
+    for PolyViewMut<'_, DEGREE>
+{
+    fn sub_assign(&mut self, rhs: PolyView<'_, DEGREE>) {
+        check_moduli(self.context, rhs.context);
+        let class = self.context.class();
+        self.context.count(Op::ModAdd, DEGREE as u64);
+        for (x, &y) in self.coeffs.iter_mut().zip(rhs.coeffs) {
+            class.modsub_eq(x, y);
+        }
+    }
+}
+
+/// Negacyclic product in place. The borrowed `rhs` is transformed on a
+/// stack copy, since it cannot be modified.
+impl<const DEGREE: usize> MulAssign<PolyView<'_, DEGREE>>
+    for PolyViewMut<'_, DEGREE>
+{
+    fn mul_assign(&mut self, rhs: PolyView<'_, DEGREE>) {
+        check_moduli(self.context, rhs.context);
+        let mut rhs_evals = *rhs.coeffs;
+        self.context.forward_shoup_in_place(&mut rhs_evals);
+
+        self.ntt_forward();
+        self.pointwise_mul_assign(PolyView {
+            coeffs: &rhs_evals,
+            context: self.context,
+        });
+        self.ntt_inverse();
+    }
+}
+
+impl<const DEGREE: usize> NttPolynomial<DEGREE> {
+    /// Borrow as a [`PolyView`]
+    pub fn view(&self) -> PolyView<'_, DEGREE> {
+        PolyView::new(self.coeffs(), self.context())
+    }
+
+    /// Borrow as a [`PolyViewMut`], so view-based code can also operate on
+    /// owned polynomials
+    pub fn view_mut(&mut self) -> PolyViewMut<'_, DEGREE> {
+        let (coeffs, context) = self.parts_mut();
+        PolyViewMut { coeffs, context }
+    }
+}
@@ -0,0 +1,399 @@
+//! Arithmetic and NTT modulo a single prime of up to 127 bits.
+//!
+//! An RNS chain of 64-bit primes is the usual way to work with large
+//! moduli, but some protocols fix one big prime. [`CongruenceClassWide`]
+//! handles `u128` residues with Montgomery multiplication (`R = 2^128`),
+//! forming the 256-bit products from four 64×64-bit limb products, and
+//! [`NttContextWide`] runs the negacyclic NTT over it with twiddles kept in
+//! Montgomery form.
+//!
+//! # Examples
+//! ```rust
+//! use rust_ntt::wide::{NttContextWide, find_first_prime_up_wide};
+//!
+//! let q = find_first_prime_up_wide(100, 8);
+//! let ctx = NttContextWide::<8>::new(q);
+//!
+//! let a = [q - 1, 2, 3, 4, 5, 6, 7, 8];
+//! let mut evals = a;
+//! ctx.ntt_forward(&mut evals);
+//! ctx.ntt_inverse(&mut evals);
+//! assert_eq!(evals, a);
+//! ```
+use crate::context::bit_reverse;
+use std::sync::Arc;
+
+/// Modular arithmetic modulo an odd `q < 2^127`.
+///
+/// # Examples
+/// ```rust
+/// use rust_ntt::wide::CongruenceClassWide;
+///
+/// let q = (1u128 << 127) - 1; // Mersenne prime
+/// let class = CongruenceClassWide::new(q);
+/// let a = 1u128 << 100;
+/// // 2^200 = 2^(127 + 73) ≡ 2^73
+/// assert_eq!(class.modmul(a, a), 1 << 73);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CongruenceClassWide {
+    q: u128,         // Odd modulus, q < 2^127
+    q_neg_inv: u128, // -q^(-1) mod 2^128
+    r2: u128,        // R² mod q with R = 2^128
+}
+
+impl CongruenceClassWide {
+    /// Precompute Montgomery parameters for the modulus `q`.
+    ///
+    /// # Panics
+    /// * If `q` is even, below 3 or not below 2^127
+    pub const fn new(q: u128) -> Self {
+        assert!(q % 2 == 1 && q >= 3, "modulus must be odd and ≥ 3");
+        assert!(q < (1u128 << 127), "modulus must be < 2^127");
+
+        // Newton iteration for q^(-1) mod 2^128, from 3 correct bits
+        let mut inv = q;
+        let mut i = 0;
+        while i < 6 {
+            inv = inv.wrapping_mul(2u128.wrapping_sub(q.wrapping_mul(inv)));
+            i += 1;
+        }
+
+        // R mod q, then doubled 128 times for R² mod q; q < 2^127 keeps
+        // every doubling below 2^128
+        let mut r2 = (u128::MAX % q + 1) % q;
+        let mut i = 0;
+        while i < 128 {
+            r2 <<= 1;
+            if r2 >= q {
+                r2 -= q;
+            }
+            i += 1;
+        }
+
+        Self {
+            q,
+            q_neg_inv: inv.wrapping_neg(),
+            r2,
+        }
+    }
+
+    #[inline]
+    pub fn q(&self) -> u128 {
+        self.q
+    }
+
+    #[inline]
+    pub fn modadd(&self, a: u128, b: u128) -> u128 {
+        let d = self.q - b;
+        if a >= d { a - d } else { a + b }
+    }
+
+    #[inline]
+    pub fn modsub(&self, a: u128, b: u128) -> u128 {
+        if a >= b { a - b } else { (self.q - b) + a }
+    }
+
+    #[inline]
+    pub fn modneg(&self, a: u128) -> u128 {
+        if a == 0 { 0 } else { self.q - a }
+    }
+
+    /// Montgomery reduction of the 256-bit `hi·2^128 + lo < q·2^128` to
+    /// `(hi·2^128 + lo)·2^(-128) mod q`
+    #[inline]
+    fn redc(&self, (lo, hi): (u128, u128)) -> u128 {
+        let m = lo.wrapping_mul(self.q_neg_inv);
+        let (mq_lo, mq_hi) = widening_mul(m, self.q);
+        // lo + m·q ≡ 0 (mod 2^128): the low sum is zero, with a carry
+        // unless lo is
+        let carry = (lo != 0) as u128;
+        debug_assert_eq!(lo.wrapping_add(mq_lo), 0);
+
+        // hi, mq_hi < q, so t < 2q < 2^128
+        let t = hi + mq_hi + carry;
+        if t >= self.q { t - self.q } else { t }
+    }
+
+    /// Montgomery product `a·b·2^(-128) mod q` for `a·b < q·2^128`
+    #[inline]
+    pub fn mont_mul(&self, a: u128, b: u128) -> u128 {
+        self.redc(widening_mul(a, b))
+    }
+
+    /// Montgomery form `a·2^128 mod q` of a residue `a`
+    #[inline]
+    pub fn to_mont(&self, a: u128) -> u128 {
+        self.mont_mul(a, self.r2)
+    }
+
+    /// Residue of a value in Montgomery form
+    #[inline]
+    pub fn from_mont(&self, a: u128) -> u128 {
+        self.redc((a, 0))
+    }
+
+    /// `a·b mod q` for residues: a Montgomery product, then one more by
+    /// `R²` to cancel its `R^(-1)`
+    #[inline]
+    pub fn modmul(&self, a: u128, b: u128) -> u128 {
+        self.to_mont(self.mont_mul(a, b))
+    }
+
+    /// `a^e mod q` for a residue `a`
+    pub fn modexp(&self, a: u128, mut e: u128) -> u128 {
+        let mut base = self.to_mont(a);
+        let mut acc = self.to_mont(1);
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = self.mont_mul(acc, base);
+            }
+            base = self.mont_mul(base, base);
+            e >>= 1;
+        }
+        self.from_mont(acc)
+    }
+
+    /// Inverse of a non-zero residue by Fermat's little theorem, so `q`
+    /// must be prime
+    ///
+    /// # Panics
+    /// * If `a ≡ 0`
+    pub fn modinv(&self, a: u128) -> u128 {
+        assert_ne!(a % self.q, 0, "zero has no inverse");
+        self.modexp(a, self.q - 2)
+    }
+
+    /// Miller-Rabin test of `q` against the first twenty prime bases; a
+    /// composite passes with probability below `4^(-20)`
+    pub fn is_probable_prime(&self) -> bool {
+        const BASES: [u128; 20] = [
+            2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67,
+            71,
+        ];
+        let q = self.q;
+        let s = (q - 1).trailing_zeros();
+        let d = (q - 1) >> s;
+
+        'bases: for a in BASES {
+            if a % q == 0 {
+                continue;
+            }
+            let mut x = self.modexp(a % q, d);
+            if x == 1 || x == q - 1 {
+                continue;
+            }
+            for _ in 1..s {
+                x = self.modmul(x, x);
+                if x == q - 1 {
+                    continue 'bases;
+                }
+            }
+            return false;
+        }
+        true
+    }
+}
+
+/// Full 256-bit product `(lo, hi)` of two `u128` values from four 64-bit
+/// limb products
+#[inline]
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a0, a1) = (a & MASK, a >> 64);
+    let (b0, b1) = (b & MASK, b >> 64);
+
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+
+    // Middle column, below 3·2^64
+    let mid = (p00 >> 64) + (p01 & MASK) + (p10 & MASK);
+    let lo = (p00 & MASK) | (mid << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (lo, hi)
+}
+
+/// First probable prime `q ≡ 1 (mod 2n)` above `2^logq`, the `u128`
+/// counterpart of [`find_first_prime_up`](crate::find_first_prime_up)
+///
+/// # Panics
+/// * If `logq ≥ 127`
+pub fn find_first_prime_up_wide(logq: usize, n: usize) -> u128 {
+    assert!(logq < 127, "modulus must be < 2^127");
+    let m = (n as u128) << 1;
+    let mut q = (1u128 << logq) + 1;
+
+    while !CongruenceClassWide::new(q).is_probable_prime() {
+        q += m;
+    }
+    q
+}
+
+/// Negacyclic NTT over `u128` residues modulo a prime `q < 2^127`.
+///
+/// Follows the layout of [`NttContext`](crate::NttContext): twiddles are
+/// powers of a primitive `2·DEGREE`-th root `ψ` in bit-reversed order, so
+/// the forward transform leaves `a(ψ^(2·bitrev(i)+1))` in slot `i`.
+#[derive(Debug, Clone)]
+pub struct NttContextWide<const DEGREE: usize> {
+    class: CongruenceClassWide,
+    /// Forward twiddles in Montgomery form (bit-reversed order)
+    tf: Box<[u128; DEGREE]>,
+    /// Inverse twiddles in Montgomery form (bit-reversed order)
+    itf: Box<[u128; DEGREE]>,
+    /// `N^(-1)` in Montgomery form
+    inv_n: u128,
+    /// `N^(-1)·R` in Montgomery form, cancelling the `R^(-1)` of a
+    /// Montgomery product of plain operands
+    inv_n_r: u128,
+}
+
+impl<const DEGREE: usize> NttContextWide<DEGREE> {
+    /// Create a transform context of size `DEGREE` modulo the prime `q`.
+    ///
+    /// # Panics
+    /// * If DEGREE is not a power of 2
+    /// * If `q ≢ 1 (mod 2·DEGREE)` or `q` is not a probable prime
+    /// * Under the conditions of [`CongruenceClassWide::new`]
+    pub fn new(q: u128) -> Arc<Self> {
+        assert!(
+            DEGREE.is_power_of_two(),
+            "DEGREE must be a power of 2, got {DEGREE}"
+        );
+        let class = CongruenceClassWide::new(q);
+        let order = 2 * DEGREE as u128;
+        // order is a power of two, so the remainder is a mask
+        assert!(
+            (q - 1) & (order - 1) == 0,
+            "{q} is not 1 modulo 2·{DEGREE}, so it has no primitive 2N-th root of unity"
+        );
+        assert!(class.is_probable_prime(), "modulus {q} is not prime");
+
+        // x^((q-1)/2N) has order dividing 2N; it is exactly 2N when its
+        // N-th power is -1, which holds for half of all x
+        let psi = (2..)
+            .map(|x| class.modexp(x, (q - 1) / order))
+            .find(|&y| class.modexp(y, DEGREE as u128) == q - 1)
+            .expect("a prime modulus has a primitive 2N-th root");
+        let psi_inv = class.modinv(psi);
+
+        let log_degree = DEGREE.trailing_zeros() as usize;
+        let mut tf = vec![0u128; DEGREE];
+        let mut itf = vec![0u128; DEGREE];
+        let (mut power, mut inv_power) = (1u128, 1u128);
+        for i in 0..DEGREE {
+            let j = bit_reverse(i, log_degree);
+            tf[j] = class.to_mont(power);
+            itf[j] = class.to_mont(inv_power);
+            power = class.modmul(power, psi);
+            inv_power = class.modmul(inv_power, psi_inv);
+        }
+
+        let inv_n = class.to_mont(class.modinv(DEGREE as u128));
+        let boxed = |table: Vec<u128>| -> Box<[u128; DEGREE]> {
+            table
+                .into_boxed_slice()
+                .try_into()
+                .expect("table of length DEGREE")
+        };
+
+        Arc::new(Self {
+            class,
+            tf: boxed(tf),
+            itf: boxed(itf),
+            inv_n,
+            inv_n_r: class.to_mont(inv_n),
+        })
+    }
+
+    pub fn class(&self) -> &CongruenceClassWide {
+        &self.class
+    }
+
+    pub fn modulus(&self) -> u128 {
+        self.class.q()
+    }
+
+    /// Forward negacyclic NTT of residues, in place
+    pub fn ntt_forward(&self, coeffs: &mut [u128; DEGREE]) {
+        let c = &self.class;
+        let mut t = DEGREE >> 1;
+        let mut n = 1;
+
+        while n < DEGREE {
+            for i in 0..n {
+                let j1 = 2 * i * t;
+                let s = self.tf[n + i];
+
+                for j in j1..j1 + t {
+                    let u = coeffs[j];
+                    let v = c.mont_mul(coeffs[j + t], s);
+
+                    coeffs[j] = c.modadd(u, v);
+                    coeffs[j + t] = c.modsub(u, v);
+                }
+            }
+
+            n <<= 1;
+            t >>= 1;
+        }
+    }
+
+    /// Inverse negacyclic NTT, in place; undoes [`ntt_forward`](Self::ntt_forward)
+    pub fn ntt_inverse(&self, coeffs: &mut [u128; DEGREE]) {
+        self.inverse_scaled(coeffs, self.inv_n);
+    }
+
+    /// Gentleman-Sande butterflies followed by a Montgomery product with
+    /// `scaling`
+    fn inverse_scaled(&self, coeffs: &mut [u128; DEGREE], scaling: u128) {
+        let c = &self.class;
+        let mut t = 1;
+        let mut h = DEGREE >> 1;
+
+        while h > 0 {
+            let mut j1 = 0;
+
+            for i in 0..h {
+                let s = self.itf[h + i];
+
+                for j in j1..j1 + t {
+                    let (u, v) = (coeffs[j], coeffs[j + t]);
+
+                    coeffs[j] = c.modadd(u, v);
+                    coeffs[j + t] = c.mont_mul(c.modsub(u, v), s);
+                }
+
+                j1 += t << 1;
+            }
+
+            h >>= 1;
+            t <<= 1;
+        }
+
+        for coeff in coeffs.iter_mut() {
+            *coeff = c.mont_mul(*coeff, scaling);
+        }
+    }
+
+    /// Product of `a` and `b` in `Z_q[x]/(x^DEGREE + 1)`
+    pub fn negacyclic_convolution(
+        &self,
+        a: &[u128; DEGREE],
+        b: &[u128; DEGREE],
+    ) -> [u128; DEGREE] {
+        let (mut a, mut b) = (*a, *b);
+        self.ntt_forward(&mut a);
+        self.ntt_forward(&mut b);
+
+        // Montgomery products of plain values carry a factor R^(-1)
+        for (x, &y) in a.iter_mut().zip(&b) {
+            *x = self.class.mont_mul(*x, y);
+        }
+
+        self.inverse_scaled(&mut a, self.inv_n_r);
+        a
+    }
+}
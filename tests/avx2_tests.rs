@@ -0,0 +1,83 @@
+use proptest::prelude::*;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use rust_ntt::dynamic::NttPlan;
+use rust_ntt::*;
+use std::sync::Arc;
+
+// The Shoup transforms take the AVX2 path whenever the CPU has it, and the
+// Barrett ones never do, so comparing the two checks the vector code on
+// such machines and the scalar kernels elsewhere.
+
+/// Shoup against Barrett, both ways, for one polynomial
+fn check_against_barrett<const N: usize>(a: &NttPolynomial<N>) {
+    let mut expected = a.clone();
+    expected.ntt_forward();
+    let mut evals = a.clone();
+    evals.ntt_forward_shoup();
+    assert_eq!(evals.coeffs(), expected.coeffs());
+
+    let mut expected = evals.clone();
+    expected.ntt_inverse();
+    evals.ntt_inverse_shoup();
+    assert_eq!(evals.coeffs(), expected.coeffs());
+    assert_eq!(evals.coeffs(), a.coeffs());
+}
+
+// Property: Shoup and Barrett transforms agree for every vector stage
+// layout, from the smallest vectorized degree up, and moduli up to 62 bits
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+    #[test]
+    fn shoup_matches_barrett(
+        bits in prop::sample::select(vec![20usize, 40, 61, 62]),
+        seed in any::<u64>(),
+    ) {
+        fn check<const N: usize>(bits: usize, seed: u64) {
+            let ctx = NttContext::<N>::new(find_first_prime_up(bits, N));
+            let mut rng = StdRng::seed_from_u64(seed);
+            check_against_barrett(&NttPolynomial::sample_random(ctx, &mut rng));
+        }
+
+        check::<8>(bits, seed);
+        check::<32>(bits, seed);
+        check::<64>(bits, seed);
+        check::<256>(bits, seed);
+    }
+}
+
+#[test]
+fn test_vector_transforms_at_extremes() {
+    const N: usize = 2048;
+    // Near 2^62 sums of two residues come close to the top of the lanes
+    for bits in [20, 50, 62] {
+        let ctx = NttContext::<N>::new(find_first_prime_down(bits, N));
+        let q = ctx.modulus();
+        for value in [0, 1, q - 1] {
+            let a = NttPolynomial::from_coeffs([value; N], Arc::clone(&ctx));
+            check_against_barrett(&a);
+        }
+
+        let alternating =
+            std::array::from_fn(|i| if i % 2 == 0 { q - 1 } else { 1 });
+        check_against_barrett(&NttPolynomial::from_coeffs(alternating, ctx));
+    }
+}
+
+#[test]
+fn test_vector_transforms_of_dynamic_plans() {
+    let mut rng = StdRng::seed_from_u64(42);
+    for log_n in 3..=12 {
+        let n = 1 << log_n;
+        let q = find_first_prime_up(58, n);
+        let plan = NttPlan::new(q, n);
+
+        let a: Vec<u64> = (0..n).map(|_| rng.random_range(0..q)).collect();
+        // a·x shifts a up by one slot, negating the wrapped coefficient
+        let mut x = vec![0; n];
+        x[1] = 1;
+        let mut product = a.clone();
+        plan.negacyclic_mul(&mut product, &x);
+        assert_eq!(product[0], (q - a[n - 1]) % q);
+        assert_eq!(&product[1..], &a[..n - 1]);
+    }
+}
@@ -0,0 +1,80 @@
+use proptest::prelude::*;
+use rust_ntt::backend::NttBackend;
+use rust_ntt::{NttContext, NttPolynomial, find_first_prime_up};
+use std::sync::Arc;
+
+const N: usize = 64;
+
+fn naive_product(ctx: &Arc<NttContext<N>>, a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+    let a = NttPolynomial::from_coeffs(*a, Arc::clone(ctx));
+    let b = NttPolynomial::from_coeffs(*b, Arc::clone(ctx));
+    *a.naive_negacyclic_convolution(&b).coeffs()
+}
+
+#[test]
+fn test_native_backend_matches_polynomial_transform() {
+    let q = find_first_prime_up(40, N);
+    let ctx = NttContext::<N>::new(q);
+    let coeffs = std::array::from_fn(|i| (i as u64 * 7919) % q);
+
+    let mut evals = coeffs;
+    ctx.forward(&mut evals);
+
+    let mut poly = NttPolynomial::from_coeffs(coeffs, Arc::clone(&ctx));
+    poly.ntt_forward_shoup();
+    assert_eq!(&evals, poly.coeffs());
+    assert_eq!(ctx.modulus(), q);
+}
+
+// Property: the native backend round-trips and multiplies like the naive convolution
+proptest! {
+    #[test]
+    fn native_backend_matches_naive(
+        a in prop::array::uniform32(any::<u64>()),
+        b in prop::array::uniform32(any::<u64>()),
+    ) {
+        let q = find_first_prime_up(40, N);
+        let ctx = NttContext::<N>::new(q);
+        let a: [u64; N] = std::array::from_fn(|i| a[i % 32] % q);
+        let b: [u64; N] = std::array::from_fn(|i| b[i % 32].rotate_left(i as u32) % q);
+
+        let mut evals = a;
+        ctx.forward(&mut evals);
+        ctx.inverse(&mut evals);
+        prop_assert_eq!(evals.map(|c| c % q), a);
+
+        let expected = naive_product(&ctx, &a, &b).map(|c| c % q);
+        prop_assert_eq!(ctx.negacyclic_mul(&a, &b).map(|c| c % q), expected);
+    }
+}
+
+#[cfg(feature = "concrete-ntt")]
+mod concrete {
+    use super::*;
+    use rust_ntt::backend::ConcreteNtt;
+
+    // Property: concrete-ntt and the native backend compute the same products
+    proptest! {
+        #[test]
+        fn concrete_backend_matches_native(
+            a in prop::array::uniform32(any::<u64>()),
+            b in prop::array::uniform32(any::<u64>()),
+        ) {
+            let q = find_first_prime_up(40, N);
+            let native = NttContext::<N>::new(q);
+            let concrete = ConcreteNtt::<N>::new(q).unwrap();
+            let a: [u64; N] = std::array::from_fn(|i| a[i % 32] % q);
+            let b: [u64; N] = std::array::from_fn(|i| b[i % 32].rotate_left(i as u32) % q);
+
+            let mut evals = a;
+            concrete.forward(&mut evals);
+            concrete.inverse(&mut evals);
+            prop_assert_eq!(evals, a);
+
+            prop_assert_eq!(
+                concrete.negacyclic_mul(&a, &b),
+                native.negacyclic_mul(&a, &b).map(|c| c % q)
+            );
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::batch::ColumnBatch;
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 16;
+
+fn sample(count: usize) -> Vec<NttPolynomial<N>> {
+    let ctx = NttContext::<N>::new(find_first_prime_up(40, N));
+    let mut rng = StdRng::seed_from_u64(42);
+    (0..count)
+        .map(|_| NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng))
+        .collect()
+}
+
+#[test]
+fn test_layout_conversions_roundtrip() {
+    let polys = sample(3);
+    let ctx = Arc::clone(polys[0].context());
+    let rows: Vec<u64> = polys.iter().flat_map(|p| *p.coeffs()).collect();
+
+    let batch = ColumnBatch::from_row_major(&rows, Arc::clone(&ctx));
+    assert_eq!(batch.len(), 3);
+    assert_eq!(
+        batch.coefficient(5),
+        &[
+            polys[0].coeffs()[5],
+            polys[1].coeffs()[5],
+            polys[2].coeffs()[5]
+        ]
+    );
+    assert_eq!(batch.to_row_major(), rows);
+
+    let from_polys = ColumnBatch::from_polys(&polys);
+    assert_eq!(from_polys.as_slice(), batch.as_slice());
+    for (k, poly) in from_polys.to_polys().iter().enumerate() {
+        assert_eq!(poly.coeffs(), polys[k].coeffs());
+    }
+
+    let columns = ColumnBatch::from_columns(batch.as_slice().to_vec(), ctx);
+    assert_eq!(columns.to_row_major(), rows);
+}
+
+#[test]
+fn test_batched_transforms_match_single() {
+    let polys = sample(5);
+    let mut batch = ColumnBatch::from_polys(&polys);
+
+    batch.ntt_forward();
+    for (k, poly) in polys.iter().enumerate() {
+        let mut expected = poly.clone();
+        expected.ntt_forward_shoup();
+        assert_eq!(batch.poly(k).coeffs(), expected.coeffs());
+    }
+
+    batch.ntt_inverse();
+    for (k, poly) in polys.iter().enumerate() {
+        assert_eq!(batch.poly(k).coeffs(), poly.coeffs());
+    }
+}
+
+#[test]
+fn test_batched_products() {
+    let a = sample(4);
+    let b: Vec<_> = sample(8).split_off(4);
+
+    let mut lhs = ColumnBatch::from_polys(&a);
+    let mut rhs = ColumnBatch::from_polys(&b);
+    lhs.ntt_forward();
+    rhs.ntt_forward();
+    lhs.pointwise_mul_assign(&rhs);
+    lhs.ntt_inverse();
+
+    for k in 0..4 {
+        let expected = &a[k] * &b[k];
+        assert_eq!(lhs.poly(k).coeffs(), expected.coeffs());
+    }
+}
+
+#[test]
+#[should_panic(expected = "Cannot batch polynomials")]
+fn test_from_polys_rejects_mixed_moduli() {
+    let a = NttPolynomial::zero(NttContext::<N>::new(find_first_prime_up(20, N)));
+    let b = NttPolynomial::zero(NttContext::<N>::new(find_first_prime_up(21, N)));
+    ColumnBatch::from_polys(&[a, b]);
+}
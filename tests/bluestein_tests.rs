@@ -0,0 +1,93 @@
+use proptest::prelude::*;
+use rust_ntt::bluestein::*;
+use rust_ntt::context::NttError;
+use rust_ntt::{CongruenceClass, find_primitive_root};
+
+// NTT-friendly for every embedding used below (2^20 | q - 1)
+const Q: u64 = 7_340_033;
+
+/// Schoolbook `a·b mod (x^n - 1)`
+fn cyclic_naive(a: &[u64], b: &[u64], class: &CongruenceClass) -> Vec<u64> {
+    let n = a.len();
+    let mut out = vec![0; n];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            let k = (i + j) % n;
+            out[k] = class.modadd(out[k], class.modmul(x, y));
+        }
+    }
+    out
+}
+
+// Property: the embedded convolution matches the schoolbook one, and the
+// chirp-z transform matches direct evaluation at the powers of ω, for
+// lengths of every residue modulo a power of two
+proptest! {
+    #[test]
+    fn arbitrary_len_matches_naive(
+        (a, b) in (1usize..50).prop_flat_map(|n| (
+            prop::collection::vec(0..Q, n),
+            prop::collection::vec(0..Q, n),
+        )),
+        omega in 1..Q,
+    ) {
+        let class = CongruenceClass::new(Q);
+        prop_assert_eq!(
+            convolution_arbitrary_len(&a, &b, Q),
+            cyclic_naive(&a, &b, &class)
+        );
+
+        let evals = bluestein_dft(&a, omega, Q);
+        for (k, &e) in evals.iter().enumerate() {
+            let x = class.modexp(omega, k as u64);
+            let direct = a.iter().rev().fold(0, |acc, &c| {
+                class.modadd(class.modmul(acc, x), c)
+            });
+            prop_assert_eq!(e, direct);
+        }
+    }
+}
+
+#[test]
+fn test_dft_at_order_n_root_inverts() {
+    let class = CongruenceClass::new(Q);
+    // Q - 1 = 7·2^20, so the 7th roots of unity exist
+    let n = 7;
+    let omega = class.modexp(find_primitive_root(Q), (Q - 1) / n as u64);
+    let a: Vec<u64> = (1..=n as u64).collect();
+
+    // The DFT at ω^(-1), scaled by 1/n, undoes the DFT at ω
+    let evals = bluestein_dft(&a, omega, Q);
+    let inv_n = class.modinv(n as u64);
+    let back: Vec<u64> = bluestein_dft(&evals, class.modinv(omega), Q)
+        .iter()
+        .map(|&x| class.modmul(x, inv_n))
+        .collect();
+    assert_eq!(back, a);
+}
+
+#[test]
+fn test_modulus_without_embedding_is_rejected() {
+    // 211 - 1 = 2·3·5·7 has no room for the length-16 embedding of n = 5
+    assert!(matches!(
+        try_convolution_arbitrary_len(&[1; 5], &[1; 5], 211),
+        Err(NttError::WrongCongruence { .. })
+    ));
+    assert!(matches!(
+        try_bluestein_dft(&[1; 5], 2, 211),
+        Err(NttError::WrongCongruence { .. })
+    ));
+}
+
+#[test]
+fn test_empty_and_single_inputs() {
+    assert!(convolution_arbitrary_len(&[], &[], Q).is_empty());
+    assert_eq!(convolution_arbitrary_len(&[3], &[Q + 5], Q), [15]);
+    assert_eq!(bluestein_dft(&[Q + 2], 9, Q), [2]);
+}
+
+#[test]
+#[should_panic(expected = "Operands must have the same length")]
+fn test_length_mismatch_panics() {
+    let _ = convolution_arbitrary_len(&[1, 2], &[3], Q);
+}
@@ -0,0 +1,161 @@
+use proptest::prelude::*;
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 8;
+
+fn assert_canonical(name: &str, coeffs: &[u64], q: u64) {
+    if let Some(c) = coeffs.iter().find(|&&c| c >= q) {
+        panic!("{name}: coefficient {c} is not below q = {q}");
+    }
+}
+
+/// Coefficients biased towards 0 and q - 1, where reductions go wrong
+fn edge_coeffs(q: u64) -> impl Strategy<Value = [u64; N]> {
+    prop::array::uniform8(prop_oneof![
+        Just(0),
+        Just(1),
+        Just(q - 1),
+        Just(q / 2),
+        Just(q / 2 + 1),
+        0..q,
+    ])
+}
+
+fn ring() -> impl Strategy<Value = (Arc<NttContext<N>>, [u64; N], [u64; N])> {
+    prop_oneof![
+        Just(17u64),
+        Just(97),
+        (10usize..62).prop_map(|bits| { find_first_prime_up(bits, N) })
+    ]
+    .prop_flat_map(|q| {
+        (
+            Just(NttContext::<N>::new(q)),
+            edge_coeffs(q),
+            edge_coeffs(q),
+        )
+    })
+}
+
+// Property: Every ring operation returns coefficients in [0, q)
+proptest! {
+    #[test]
+    fn ring_operations_are_canonical((ctx, a, b) in ring()) {
+        let q = ctx.modulus();
+        let a = NttPolynomial::from_coeffs(a, Arc::clone(&ctx));
+        let b = NttPolynomial::from_coeffs(b, Arc::clone(&ctx));
+
+        assert_canonical("add", (&a + &b).coeffs(), q);
+        assert_canonical("sub", (&a - &b).coeffs(), q);
+        assert_canonical("neg", (-&a).coeffs(), q);
+        assert_canonical("mul", (&a * &b).coeffs(), q);
+        assert_canonical("shoup mul", a.negacyclic_convolution_shoup(&b).coeffs(), q);
+        assert_canonical("naive mul", a.naive_negacyclic_convolution(&b).coeffs(), q);
+        assert_canonical("linear mul", &a.linear_convolution(&b), q);
+
+        let mut c = a.clone();
+        c += &b;
+        assert_canonical("add_assign", c.coeffs(), q);
+        c -= &b;
+        assert_canonical("sub_assign", c.coeffs(), q);
+        c *= &b;
+        assert_canonical("mul_assign", c.coeffs(), q);
+
+        assert_canonical("scale_argument", a.scale_argument(q - 1).coeffs(), q);
+        assert_canonical("automorphism", a.automorphism(3).coeffs(), q);
+        assert_canonical("conjugate", a.conjugate().coeffs(), q);
+        prop_assert!(a.evaluate(q - 1) < q);
+    }
+}
+
+// Property: Every transform returns values in [0, q)
+proptest! {
+    #[test]
+    fn transforms_are_canonical((ctx, a, _) in ring()) {
+        let q = ctx.modulus();
+        let a = NttPolynomial::from_coeffs(a, ctx);
+
+        let mut t = a.clone();
+        t.ntt_forward();
+        assert_canonical("forward", t.coeffs(), q);
+        t.ntt_inverse();
+        assert_canonical("inverse", t.coeffs(), q);
+        prop_assert_eq!(t.coeffs(), a.coeffs());
+
+        t.ntt_forward_shoup();
+        assert_canonical("forward shoup", t.coeffs(), q);
+        t.ntt_inverse_shoup();
+        assert_canonical("inverse shoup", t.coeffs(), q);
+        prop_assert_eq!(t.coeffs(), a.coeffs());
+
+        t.ntt_forward_coset();
+        assert_canonical("forward coset", t.coeffs(), q);
+        t.ntt_inverse_coset();
+        assert_canonical("inverse coset", t.coeffs(), q);
+        prop_assert_eq!(t.coeffs(), a.coeffs());
+    }
+}
+
+// Property: Scalar operations on canonical residues are canonical
+proptest! {
+    #[test]
+    fn scalar_operations_are_canonical((ctx, a, b) in ring()) {
+        let class = ctx.class();
+        let q = class.q();
+        for (&x, &y) in a.iter().zip(&b) {
+            prop_assert!(class.modadd(x, y) < q);
+            prop_assert!(class.modsub(x, y) < q);
+            prop_assert!(class.modneg(x) < q);
+            prop_assert!(class.modmul(x, y) < q);
+            prop_assert!(class.modmul_shoup(x, y, class.precompute_shoup(y)) < q);
+
+            let mut z = x;
+            class.modadd_eq(&mut z, y);
+            prop_assert!(z < q);
+            class.modneg_eq(&mut z);
+            prop_assert!(z < q);
+        }
+    }
+}
+
+#[test]
+fn test_sum_to_q_is_zero() {
+    let class = CongruenceClass::new(17);
+    assert_eq!(class.modadd(5, 12), 0);
+    assert_eq!(class.modneg(0), 0);
+
+    let mut x = 0;
+    class.modneg_eq(&mut x);
+    assert_eq!(x, 0);
+    assert_eq!(math::modnegate(0, 17), 0);
+}
+
+// Property: bulk reduction agrees with `%` for every u64 and modulus size
+proptest! {
+    #[test]
+    fn reduce_slice_matches_remainder(
+        q in prop_oneof![Just(2u64), Just(17), 2u64..1 << 32, 2u64..1 << 63],
+        values in prop::collection::vec(
+            prop_oneof![Just(0u64), Just(u64::MAX), any::<u64>()],
+            0..32,
+        ),
+    ) {
+        let class = CongruenceClass::new(q);
+        let mut reduced = values.clone();
+        class.reduce_slice(&mut reduced);
+
+        let expected: Vec<u64> = values.iter().map(|v| v % q).collect();
+        prop_assert_eq!(reduced, expected);
+    }
+}
+
+#[test]
+fn test_from_unreduced_accepts_any_input() {
+    let q = find_first_prime_up(20, N);
+    let ctx = NttContext::<N>::new_strict(q);
+    let input = [q, q + 1, 2 * q - 1, u64::MAX, 7];
+
+    let a = NttPolynomial::from_unreduced(&input, Arc::clone(&ctx));
+    assert_eq!(a.coeffs(), &[0, 1, q - 1, u64::MAX % q, 7, 0, 0, 0]);
+    assert!(a.is_canonical());
+}
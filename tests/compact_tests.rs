@@ -0,0 +1,85 @@
+use proptest::prelude::*;
+use rust_ntt::compact::CompactNttContext;
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 64;
+
+// Property: the compact transforms give the evaluations of the table-driven
+// ones, invert them, and multiply like them
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+    #[test]
+    fn compact_matches_tables(
+        bits in prop::sample::select(vec![20usize, 40, 62]),
+        a in prop::collection::vec(any::<u64>(), N),
+        b in prop::collection::vec(any::<u64>(), N),
+    ) {
+        let q = find_first_prime_up(bits, N);
+        let ctx = NttContext::<N>::new(q);
+        let compact = CompactNttContext::<N>::new(q);
+        prop_assert_eq!(compact.psi(), ctx.psi());
+
+        let a: [u64; N] = std::array::from_fn(|i| a[i] % q);
+        let b: [u64; N] = std::array::from_fn(|i| b[i] % q);
+        let pa = NttPolynomial::from_coeffs(a, Arc::clone(&ctx));
+        let pb = NttPolynomial::from_coeffs(b, Arc::clone(&ctx));
+
+        let mut evals = a;
+        compact.ntt_forward(&mut evals);
+        let mut expected = pa.clone();
+        expected.ntt_forward_shoup();
+        prop_assert_eq!(&evals, expected.coeffs());
+
+        compact.ntt_inverse(&mut evals);
+        prop_assert_eq!(evals, a);
+
+        let product = &pa * &pb;
+        prop_assert_eq!(&compact.negacyclic_convolution(&a, &b), product.coeffs());
+    }
+}
+
+#[test]
+fn test_small_degrees() {
+    fn check<const M: usize>() {
+        let q = find_first_prime_up(20, M);
+        let compact = CompactNttContext::<M>::new(q);
+        let a: [u64; M] = std::array::from_fn(|i| i as u64 + 1);
+        let mut expected = NttPolynomial::from_coeffs(a, NttContext::<M>::new(q));
+        expected.ntt_forward();
+
+        let mut evals = a;
+        compact.ntt_forward(&mut evals);
+        assert_eq!(&evals, expected.coeffs());
+        compact.ntt_inverse(&mut evals);
+        assert_eq!(evals, a);
+    }
+
+    check::<1>();
+    check::<2>();
+    check::<4>();
+}
+
+#[test]
+fn test_footprint_is_logarithmic() {
+    const M: usize = 1 << 12;
+    let compact = CompactNttContext::<M>::new(find_first_prime_up(50, M));
+    let footprint = compact.memory_footprint();
+
+    assert_eq!(footprint.degree, M);
+    assert_eq!(
+        footprint.tables,
+        [("psi_powers", 12 * 8), ("inv_psi_powers", 12 * 8)]
+    );
+}
+
+#[test]
+fn test_invalid_modulus_is_rejected() {
+    assert_eq!(
+        CompactNttContext::<16>::try_new(17).unwrap_err(),
+        NttError::WrongCongruence {
+            modulus: 17,
+            degree: 16
+        }
+    );
+}
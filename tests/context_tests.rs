@@ -1,6 +1,12 @@
 use proptest::prelude::*;
-use rust_ntt::NttContext;
+use rust_ntt::context::bit_reverse;
+use rust_ntt::dynamic::DynNttContext;
 use rust_ntt::math::find_first_prime_up;
+use rust_ntt::{
+    NttContext, NttError, NttPolynomial, Ordering, TwiddleError, from_bitrev_order,
+    to_bitrev_order,
+};
+use std::sync::Arc;
 
 proptest! {
     #[test]
@@ -57,3 +63,293 @@ proptest! {
         prop_assert_eq!(product, 1);
     }
 }
+
+// Property: Prime-power contexts multiply exactly modulo p^k
+proptest! {
+    #[test]
+    fn prime_power_context_convolution_matches_naive(
+        log_bits in 10u32..20u32,
+        k in 1u32..4u32,
+        a in prop::array::uniform8(any::<u64>()),
+        b in prop::array::uniform8(any::<u64>()),
+    ) {
+        const DEGREE: usize = 8;
+        let p = find_first_prime_up(log_bits as usize, DEGREE);
+        let ctx = NttContext::<DEGREE>::new_prime_power(p, k);
+        let q = ctx.modulus();
+        prop_assert_eq!(q, p.pow(k));
+
+        // The lifted root is still a primitive 2n-th root: ψ^n ≡ -1 (mod p^k)
+        let psi = ctx.tf()[DEGREE / 2];
+        prop_assert_eq!(ctx.class().modexp(psi, DEGREE as u64), q - 1);
+        prop_assert_eq!(ctx.generator(), psi);
+
+        let a = NttPolynomial::from_coeffs(a.map(|c| c % q), Arc::clone(&ctx));
+        let b = NttPolynomial::from_coeffs(b.map(|c| c % q), Arc::clone(&ctx));
+
+        let fast = a.negacyclic_convolution(&b);
+        let naive = a.naive_negacyclic_convolution(&b);
+        prop_assert_eq!(fast.coeffs(), naive.coeffs());
+    }
+}
+
+#[test]
+fn test_bitrev_order_conversion() {
+    let mut values: Vec<usize> = (0..8).collect();
+    to_bitrev_order(&mut values);
+    assert_eq!(values, [0, 4, 2, 6, 1, 5, 3, 7]);
+    from_bitrev_order(&mut values);
+    assert_eq!(values, (0..8).collect::<Vec<_>>());
+
+    // Natural-order evaluations of a polynomial, as other libraries lay
+    // them out, match the forward transform after reordering
+    const N: usize = 16;
+    let ctx = NttContext::<N>::new(find_first_prime_up(20, N));
+    let a = NttPolynomial::from_coeffs(
+        std::array::from_fn(|i| i as u64),
+        Arc::clone(&ctx),
+    );
+    let psi = ctx.psi();
+    let psi_sq = ctx.class().modmul(psi, psi);
+    let mut point = psi;
+    let mut natural = [0u64; N];
+    for value in &mut natural {
+        *value = a.evaluate(point);
+        point = ctx.class().modmul(point, psi_sq);
+    }
+
+    let mut evals = a.clone();
+    evals.ntt_forward();
+    from_bitrev_order(evals.coeffs_mut());
+    assert_eq!(evals.coeffs(), &natural);
+}
+
+#[test]
+fn test_bitrev_order_matches_bit_reverse() {
+    for log_len in 0..=10 {
+        let len = 1usize << log_len;
+        let mut values: Vec<usize> = (0..len).collect();
+        to_bitrev_order(&mut values);
+        for (i, &v) in values.iter().enumerate() {
+            assert_eq!(v, bit_reverse(i, log_len));
+        }
+    }
+}
+
+#[test]
+fn test_ordered_transforms() {
+    const N: usize = 32;
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+    let a = NttPolynomial::from_coeffs(
+        std::array::from_fn(|i| i as u64 * 3 + 1),
+        Arc::clone(&ctx),
+    );
+    let mut bitrev = a.clone();
+    bitrev.ntt_forward();
+
+    let mut evals = a.clone();
+    evals.ntt_forward_ordered(Ordering::BitReversed);
+    assert_eq!(evals.coeffs(), bitrev.coeffs());
+    evals.ntt_inverse_ordered(Ordering::default());
+    assert_eq!(evals.coeffs(), a.coeffs());
+
+    let mut natural = a.clone();
+    natural.ntt_forward_ordered(Ordering::Natural);
+    let psi_sq = ctx.class().modmul(ctx.psi(), ctx.psi());
+    let mut point = ctx.psi();
+    for &value in natural.coeffs() {
+        assert_eq!(value, a.evaluate(point));
+        point = ctx.class().modmul(point, psi_sq);
+    }
+    natural.ntt_inverse_ordered(Ordering::Natural);
+    assert_eq!(natural.coeffs(), a.coeffs());
+}
+
+#[test]
+#[should_panic(expected = "length must be a power of two, got 6")]
+fn test_bitrev_order_rejects_other_lengths() {
+    to_bitrev_order(&mut [0u8; 6]);
+}
+
+#[test]
+fn test_derive_half_chain_multiplies_correctly() {
+    fn check<const N: usize>(ctx: &Arc<NttContext<N>>) {
+        let coeffs = std::array::from_fn(|i| (7 * i as u64 + 3) % ctx.modulus());
+        let a = NttPolynomial::from_coeffs(coeffs, Arc::clone(ctx));
+        let expected = a.naive_negacyclic_convolution(&a);
+        assert_eq!(a.negacyclic_convolution(&a).coeffs(), expected.coeffs());
+        assert_eq!(
+            a.negacyclic_convolution_shoup(&a).coeffs(),
+            expected.coeffs()
+        );
+
+        let mut evals = a.clone();
+        evals.ntt_forward_coset();
+        evals.ntt_inverse_coset();
+        assert_eq!(evals.coeffs(), a.coeffs());
+    }
+
+    let ctx = NttContext::<16>::new_strict(find_first_prime_up(30, 16));
+    let c8 = ctx.derive_half::<8>();
+    let c4 = c8.derive_half::<4>();
+    let c2 = c4.derive_half::<2>();
+    let c1 = c2.derive_half::<1>();
+    check(&ctx);
+    check(&c8);
+    check(&c4);
+    check(&c2);
+    check(&c1);
+    assert!(c1.is_strict());
+    assert_eq!(c4.psi(), ctx.class().modexp(ctx.psi(), 4));
+}
+
+#[test]
+#[should_panic(expected = "HALF (4) must be half of DEGREE (16)")]
+fn test_derive_half_rejects_wrong_degree() {
+    NttContext::<16>::new(find_first_prime_up(30, 16)).derive_half::<4>();
+}
+
+#[test]
+fn test_try_new_reports_invalid_parameters() {
+    assert!(NttContext::<8>::try_new(find_first_prime_up(20, 8)).is_ok());
+
+    assert_eq!(
+        NttContext::<6>::try_new(13).unwrap_err(),
+        NttError::DegreeNotPowerOfTwo { degree: 6 }
+    );
+    assert_eq!(
+        NttContext::<4>::try_new(1 << 63).unwrap_err(),
+        NttError::ModulusTooLarge { modulus: 1 << 63 }
+    );
+    // 33 ≡ 1 (mod 8) but is composite
+    for q in [0, 1, 33] {
+        assert_eq!(
+            NttContext::<4>::try_new(q).unwrap_err(),
+            NttError::NotPrime { modulus: q }
+        );
+    }
+    let err = NttContext::<4>::try_new(19).unwrap_err();
+    assert_eq!(
+        err,
+        NttError::WrongCongruence {
+            modulus: 19,
+            degree: 4
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "Modulus 19 must satisfy q ≡ 1 (mod 2*DEGREE=8)"
+    );
+
+    assert_eq!(
+        DynNttContext::try_new(17, 3).unwrap_err(),
+        NttError::DegreeNotPowerOfTwo { degree: 3 }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Modulus 33 is not prime")]
+fn test_new_panics_on_composite_modulus() {
+    let _ = NttContext::<4>::new(33);
+}
+
+/// `ψ^bitrev(i)` and `ψ^(-bitrev(i))` for the given root
+fn tables_of<const N: usize>(
+    ctx: &NttContext<N>,
+    psi: u64,
+) -> ([u64; N], [u64; N]) {
+    let class = ctx.class();
+    let bits = N.trailing_zeros() as usize;
+    let tf =
+        std::array::from_fn(|i| class.modexp(psi, bit_reverse(i, bits) as u64));
+    let itf = tf.map(|w| class.modinv(w));
+    (tf, itf)
+}
+
+#[test]
+fn test_with_tables_reproduces_context() {
+    const N: usize = 64;
+    let ctx = NttContext::<N>::new(find_first_prime_up(40, N));
+    let copy = NttContext::<N>::with_tables(ctx.modulus(), ctx.tf(), ctx.itf());
+
+    assert_eq!(copy.psi(), ctx.psi());
+    assert_eq!(copy.tf_shoup(), ctx.tf_shoup());
+    assert_eq!(copy.itf_shoup(), ctx.itf_shoup());
+    assert_eq!(copy.coset_shift(), ctx.coset_shift());
+}
+
+#[test]
+fn test_with_tables_uses_supplied_root() {
+    const N: usize = 32;
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+    // Any odd power of ψ is another primitive 2N-th root
+    let psi = ctx.class().modexp(ctx.psi(), 5);
+    let (tf, itf) = tables_of(&ctx, psi);
+    let other = NttContext::<N>::with_tables(ctx.modulus(), &tf, &itf);
+    assert_eq!(other.psi(), psi);
+    assert_eq!(other.generator(), psi);
+
+    let a: [u64; N] = std::array::from_fn(|i| i as u64 + 1);
+    let mut evals = NttPolynomial::from_coeffs(a, Arc::clone(&other));
+    evals.ntt_forward();
+    // Slot i evaluates at ψ^(2·bitrev(i)+1)
+    let class = ctx.class();
+    for (i, &value) in evals.coeffs().iter().enumerate() {
+        let x = class.modexp(psi, 2 * bit_reverse(i, 5) as u64 + 1);
+        let expected = a
+            .iter()
+            .rev()
+            .fold(0, |acc, &c| class.modadd(class.modmul(acc, x), c));
+        assert_eq!(value, expected);
+    }
+    evals.ntt_inverse();
+    assert_eq!(evals.coeffs(), &a);
+}
+
+#[test]
+fn test_with_tables_rejects_bad_tables() {
+    const N: usize = 8;
+    let ctx = NttContext::<N>::new(find_first_prime_up(20, N));
+    let q = ctx.modulus();
+
+    assert_eq!(
+        NttContext::<N>::try_with_tables(33, ctx.tf(), ctx.itf()).unwrap_err(),
+        TwiddleError::Parameters(NttError::NotPrime { modulus: 33 })
+    );
+
+    // ψ^2 has order N, so ψ^(2N/2) = 1 rather than -1
+    let psi2 = ctx.class().modsquare(ctx.psi());
+    let (tf, itf) = tables_of(&ctx, psi2);
+    assert_eq!(
+        NttContext::<N>::try_with_tables(q, &tf, &itf).unwrap_err(),
+        TwiddleError::NotPrimitiveRoot { psi: tf[N / 2] }
+    );
+
+    let mut tf = *ctx.tf();
+    tf.swap(1, 2);
+    assert_eq!(
+        NttContext::<N>::try_with_tables(q, &tf, ctx.itf()).unwrap_err(),
+        TwiddleError::NotBitReversedPowers { index: 1 }
+    );
+
+    let mut itf = *ctx.itf();
+    itf[6] = ctx.tf()[6];
+    let err = NttContext::<N>::try_with_tables(q, ctx.tf(), &itf).unwrap_err();
+    assert_eq!(err, TwiddleError::NotInverse { index: 6 });
+    assert_eq!(err.to_string(), "itf[6] is not the inverse of tf[6]");
+
+    // Unreduced inverses are rejected, not silently reduced
+    let mut itf = *ctx.itf();
+    itf[3] += q;
+    assert_eq!(
+        NttContext::<N>::try_with_tables(q, ctx.tf(), &itf).unwrap_err(),
+        TwiddleError::NotInverse { index: 3 }
+    );
+}
+
+#[test]
+#[should_panic(expected = "itf[0] is not the inverse of tf[0]")]
+fn test_with_tables_panics_on_bad_inverse() {
+    let ctx = NttContext::<4>::new(find_first_prime_up(20, 4));
+    let _ = NttContext::<4>::with_tables(ctx.modulus(), ctx.tf(), &[0; 4]);
+}
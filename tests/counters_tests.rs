@@ -0,0 +1,106 @@
+#![cfg(feature = "counters")]
+
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::counters::OpStats;
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 64;
+const LOG_N: u64 = 6;
+
+fn context() -> Arc<NttContext<N>> {
+    NttContext::<N>::new(find_first_prime_up(40, N))
+}
+
+#[test]
+fn test_transform_counts_match_complexity() {
+    let ctx = context();
+    let mut a = NttPolynomial::zero(Arc::clone(&ctx));
+    ctx.reset_stats();
+
+    a.ntt_forward_shoup();
+    a.ntt_inverse();
+
+    let butterflies = N as u64 / 2 * LOG_N;
+    assert_eq!(
+        ctx.stats(),
+        OpStats {
+            modmul: 2 * butterflies + N as u64,
+            modadd: 4 * butterflies,
+            forward: 1,
+            inverse: 1,
+            allocations: 0,
+        }
+    );
+}
+
+#[test]
+fn test_naive_convolution_is_quadratic() {
+    let ctx = context();
+    let mut rng = StdRng::seed_from_u64(42);
+    let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+    let b = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+    ctx.reset_stats();
+
+    let naive = a.naive_negacyclic_convolution(&b).context().stats();
+    ctx.reset_stats();
+    let fast = (&a * &b).context().stats();
+
+    assert_eq!(naive.modmul, (N * N) as u64);
+    assert_eq!(naive.allocations, 1);
+    assert!(fast.modmul < naive.modmul / 4);
+    assert_eq!((fast.forward, fast.inverse), (2, 1));
+}
+
+#[test]
+fn test_counts_are_per_context_and_track_allocations() {
+    let ctx = context();
+    let other = context();
+    ctx.reset_stats();
+
+    let a = NttPolynomial::zero(Arc::clone(&ctx));
+    let b = a.clone();
+    let _ = &a + &b;
+    let _ = -&a;
+
+    let stats = ctx.stats();
+    assert_eq!(stats.allocations, 4);
+    assert_eq!(stats.modadd, 2 * N as u64);
+    assert_eq!(other.stats().allocations, 0);
+}
+
+#[test]
+fn test_padded_forward_skips_zero_stages() {
+    let ctx = context();
+    // 8 nonzero coefficients out of 64: the first 3 stages are copies
+    let mut a = NttPolynomial::from_short_coeffs(&[1; 8], Arc::clone(&ctx));
+    ctx.reset_stats();
+
+    a.ntt_forward_padded(8);
+
+    let stats = ctx.stats();
+    assert_eq!(stats.forward, 1);
+    assert_eq!(stats.modmul, N as u64 / 2 * (LOG_N - 3));
+}
+
+#[test]
+fn test_convolution_into_reuses_buffers() {
+    let ctx = context();
+    let mut rng = StdRng::seed_from_u64(42);
+    let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+    let mut b = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+    let mut out = NttPolynomial::zero(Arc::clone(&ctx));
+    ctx.reset_stats();
+
+    a.negacyclic_convolution_into(&b, &mut out);
+    let stats = ctx.stats();
+    assert_eq!((stats.forward, stats.inverse), (2, 1));
+    assert_eq!(stats.allocations, 0);
+
+    b.ntt_forward_shoup();
+    ctx.reset_stats();
+    out.negacyclic_convolution_assign_evals(&b);
+    let stats = ctx.stats();
+    assert_eq!((stats.forward, stats.inverse), (1, 1));
+    assert_eq!(stats.allocations, 0);
+}
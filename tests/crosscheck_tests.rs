@@ -0,0 +1,60 @@
+#![cfg(feature = "cross-check")]
+
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::crosscheck::NAIVE_MAX_DEGREE;
+use rust_ntt::kernel::{NttKernel, ScalarKernel};
+use rust_ntt::*;
+use std::sync::Arc;
+
+/// Scalar kernel whose pointwise product corrupts one slot
+#[derive(Debug)]
+struct CorruptSlot;
+
+impl<const N: usize> NttKernel<N> for CorruptSlot {
+    fn pointwise(&self, ctx: &NttContext<N>, lhs: &mut [u64; N], rhs: &[u64; N]) {
+        ScalarKernel.pointwise(ctx, lhs, rhs);
+        lhs[N / 2] = ctx.class().modadd(lhs[N / 2], 1);
+    }
+}
+
+fn corrupt_product<const N: usize>() {
+    let ctx = NttContext::<N>::with_kernel(
+        find_first_prime_up(30, N),
+        Arc::new(CorruptSlot),
+    );
+    let mut rng = StdRng::seed_from_u64(42);
+    let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+    let b = NttPolynomial::sample_random(ctx, &mut rng);
+    let _ = a.negacyclic_convolution_shoup(&b);
+}
+
+#[test]
+fn test_correct_products_pass() {
+    fn check<const N: usize>() {
+        let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+        let mut rng = StdRng::seed_from_u64(42);
+        let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+        let b = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+        let _ = a.negacyclic_convolution(&b);
+        let _ = a.negacyclic_convolution_shoup(&b);
+        let _ = a.linear_convolution(&b);
+        let _ = NttPolynomial::convolve_short(&[1, 2, 3], &[4, 5], ctx);
+    }
+
+    // Naive reference below the threshold, evaluations above it
+    check::<64>();
+    check::<{ 4 * NAIVE_MAX_DEGREE }>();
+}
+
+#[test]
+#[should_panic(expected = "negacyclic_convolution_shoup mismatch in")]
+fn test_naive_check_reports_diff() {
+    corrupt_product::<64>();
+}
+
+#[test]
+#[should_panic(expected = "negacyclic_convolution_shoup mismatch in")]
+fn test_large_degree_check_catches_bad_slot() {
+    corrupt_product::<{ 4 * NAIVE_MAX_DEGREE }>();
+}
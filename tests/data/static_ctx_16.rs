@@ -0,0 +1,31 @@
+// Generated by rust_ntt::embedded::render_static_context
+// q = 1099511627873, N = 16
+pub static NTT_16: ::rust_ntt::embedded::StaticNttContext<16> = ::rust_ntt::embedded::StaticNttContext::from_raw_parts(
+    1099511627873,
+    1030792151131,
+    17293822569103753215,
+    [
+        1, 961209656835, 173652341105, 492802496977,
+        108163207722, 1078607292638, 442970909252, 706124624362,
+        776063673133, 1049526395927, 731435143368, 98413694689,
+        1049016110526, 526147334759, 112972882832, 721712190608,
+    ],
+    [
+        16777215, 16126422032583982506, 2913402835367240409, 8267853936393077805,
+        1814677499044769313, 18096027526166486415, 7431818625581560064, 11846805344794999342,
+        13020187872757083915, 17608131040615392548, 12271445389193304475, 1651107813009743464,
+        17599569872221924207, 8827287482297298937, 1895370457247944261, 12108321310595379282,
+    ],
+    [
+        1, 138301971038, 606709130896, 925859286768,
+        393387003511, 656540718621, 20904335235, 991348420151,
+        377799437265, 986538745041, 573364293114, 50495517347,
+        1001097933184, 368076484505, 49985231946, 323447954740,
+    ],
+    [
+        16777215, 2320322041125569109, 10178890137316473810, 15533341238342311206,
+        6599938728914552273, 11014925448127991551, 350716547543065200, 16632066574664782302,
+        6338422763114172333, 16551373616461607354, 9619456591412252678, 847174201487627408,
+        16795636260699808151, 6175298684516247140, 838613033094159067, 5426556200952467700,
+    ],
+);
@@ -0,0 +1,103 @@
+use rand::distr::Distribution;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use rust_ntt::distr::{TernaryPoly, UniformPoly};
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 256;
+
+#[test]
+fn test_uniform_covers_range() {
+    let q = find_first_prime_up(20, N);
+    let ctx = NttContext::<N>::new(q);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let polys: Vec<NttPolynomial<N>> = UniformPoly::new(ctx)
+        .sample_iter(&mut rng)
+        .take(8)
+        .collect();
+    let coeffs: Vec<u64> = polys.iter().flat_map(|p| *p.coeffs()).collect();
+
+    assert!(coeffs.iter().all(|&c| c < q));
+    // 2048 draws from ~2^20 values: both halves of the range are hit
+    assert!(coeffs.iter().any(|&c| c < q / 2));
+    assert!(coeffs.iter().any(|&c| c >= q / 2));
+}
+
+#[test]
+fn test_ternary_values_and_balance() {
+    let q = find_first_prime_up(30, N);
+    let ctx = NttContext::<N>::new(q);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let s: NttPolynomial<N> = rng.sample(TernaryPoly::new(ctx));
+    let count = |v| s.coeffs().iter().filter(|&&c| c == v).count();
+
+    assert_eq!(count(0) + count(1) + count(q - 1), N);
+    for v in [0, 1, q - 1] {
+        assert!(count(v) > N / 6, "value {v} badly underrepresented");
+    }
+}
+
+#[test]
+fn test_ternary_hamming_weight_is_exact() {
+    let q = find_first_prime_up(30, N);
+    let ctx = NttContext::<N>::new(q);
+    let mut rng = StdRng::seed_from_u64(42);
+    let dist = TernaryPoly::with_hamming_weight(ctx, 64);
+
+    for s in dist.sample_iter(&mut rng).take(10) {
+        let nonzero: Vec<_> = s.coeffs().iter().filter(|&&c| c != 0).collect();
+        assert_eq!(nonzero.len(), 64);
+        assert!(nonzero.iter().all(|&&c| c == 1 || c == q - 1));
+    }
+}
+
+#[test]
+#[should_panic(expected = "exceeds degree")]
+fn test_ternary_hamming_weight_too_large_panics() {
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+    TernaryPoly::with_hamming_weight(ctx, N + 1);
+}
+
+#[test]
+fn test_sparse_uniform_density_and_positions() {
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut hits = [0u32; N];
+
+    for k in [0, 1, 16, N] {
+        let a = NttPolynomial::sample_sparse_uniform(Arc::clone(&ctx), k, &mut rng);
+        assert_eq!(a.coeffs().iter().filter(|&&c| c != 0).count(), k);
+        assert!(a.is_canonical());
+    }
+
+    // Positions are spread over the whole range
+    for _ in 0..200 {
+        let a =
+            NttPolynomial::sample_sparse_uniform(Arc::clone(&ctx), 16, &mut rng);
+        for (hit, &c) in hits.iter_mut().zip(a.coeffs()) {
+            *hit += (c != 0) as u32;
+        }
+    }
+    assert!(hits.iter().all(|&h| h > 0));
+
+    // Sparse operands multiply like any other
+    let s = NttPolynomial::sample_sparse_uniform(Arc::clone(&ctx), 8, &mut rng);
+    let t = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+    assert_eq!(
+        (&s * &t).coeffs(),
+        s.naive_negacyclic_convolution(&t).coeffs()
+    );
+}
+
+#[test]
+#[should_panic(expected = "nonzero count 257 exceeds degree 256")]
+fn test_sparse_uniform_too_dense_panics() {
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+    NttPolynomial::sample_sparse_uniform(
+        ctx,
+        N + 1,
+        &mut StdRng::seed_from_u64(42),
+    );
+}
@@ -0,0 +1,133 @@
+use proptest::prelude::*;
+use rust_ntt::dynamic::{DynNttContext, DynNttPolynomial, NttPlan};
+use rust_ntt::{
+    LengthMismatch, NttContext, NttError, NttPolynomial, find_first_prime_up,
+};
+use std::sync::Arc;
+
+const N: usize = 16;
+
+// Property: a runtime degree reproduces the const-generic transforms and
+// products exactly
+proptest! {
+    #[test]
+    fn dyn_matches_const_generic(
+        a in prop::array::uniform16(0u64..12289),
+        b in prop::array::uniform16(0u64..12289),
+    ) {
+        let q = 12289;
+        let ctx = NttContext::<N>::new(q);
+        let dyn_ctx = DynNttContext::new(q, N);
+        prop_assert_eq!(dyn_ctx.tf(), &ctx.tf()[..]);
+
+        let x = NttPolynomial::from_coeffs(a, Arc::clone(&ctx));
+        let y = NttPolynomial::from_coeffs(b, ctx);
+        let dx = DynNttPolynomial::from_coeffs(a.to_vec(), Arc::clone(&dyn_ctx));
+        let dy = DynNttPolynomial::from_coeffs(b.to_vec(), dyn_ctx);
+
+        let (expected, product) = (&x * &y, &dx * &dy);
+        prop_assert_eq!(product.coeffs(), &expected.coeffs()[..]);
+
+        let mut evals = dx.clone();
+        evals.ntt_forward();
+        let mut x_evals = x.clone();
+        x_evals.ntt_forward_shoup();
+        prop_assert_eq!(evals.coeffs(), &x_evals.coeffs()[..]);
+
+        evals.ntt_inverse();
+        prop_assert_eq!(evals.coeffs(), &a[..]);
+    }
+}
+
+#[test]
+fn test_degree_chosen_at_runtime() {
+    for log_n in 0..12 {
+        let n = 1usize << log_n;
+        let ctx = DynNttContext::new(find_first_prime_up(30, n), n);
+        assert_eq!(ctx.degree(), n);
+
+        // x^(N-1)·x = x^N = -1
+        let mut x = vec![0; n];
+        let mut top = vec![0; n];
+        x[1 % n] = 1;
+        top[n - 1] = 1;
+        let product = DynNttPolynomial::from_coeffs(x, Arc::clone(&ctx))
+            .negacyclic_convolution(&DynNttPolynomial::from_coeffs(top, ctx));
+        let q = product.context().modulus();
+        let expected: Vec<u64> = if n == 1 {
+            vec![1]
+        } else {
+            (0..n).map(|i| if i == 0 { q - 1 } else { 0 }).collect()
+        };
+        assert_eq!(product.coeffs(), &expected[..], "n = {n}");
+    }
+}
+
+#[test]
+fn test_length_must_match_degree() {
+    let ctx = DynNttContext::new(17, 4);
+    let err = DynNttPolynomial::try_from_coeffs(vec![1, 2, 3], Arc::clone(&ctx))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        LengthMismatch::TooFew {
+            expected: 4,
+            found: 3
+        }
+    );
+    assert_eq!(DynNttPolynomial::zero(ctx).into_coeffs(), vec![0; 4]);
+}
+
+#[test]
+#[should_panic(expected = "Cannot convolve polynomials: degrees differ, 4 vs 8")]
+fn test_mixed_degrees_panic() {
+    let a = DynNttPolynomial::zero(DynNttContext::new(17, 4));
+    let b = DynNttPolynomial::zero(DynNttContext::new(17, 8));
+    let _ = &a * &b;
+}
+
+// Property: a plan on caller-owned slices agrees with the polynomial API
+proptest! {
+    #[test]
+    fn plan_matches_polynomials(
+        a in prop::collection::vec(0u64..12289, N),
+        b in prop::collection::vec(0u64..12289, N),
+    ) {
+        let plan = NttPlan::new(12289, N);
+        let ctx = Arc::clone(plan.context());
+        let x = DynNttPolynomial::from_coeffs(a.clone(), Arc::clone(&ctx));
+        let y = DynNttPolynomial::from_coeffs(b.clone(), ctx);
+
+        let mut product = a.clone();
+        plan.negacyclic_mul(&mut product, &b);
+        let expected = &x * &y;
+        prop_assert_eq!(&product[..], expected.coeffs());
+
+        let mut evals = a.clone();
+        plan.fwd(&mut evals);
+        let mut x_evals = x.clone();
+        x_evals.ntt_forward();
+        prop_assert_eq!(&evals[..], x_evals.coeffs());
+        plan.inv(&mut evals);
+        prop_assert_eq!(evals, a);
+    }
+}
+
+#[test]
+fn test_plan_validates_parameters_and_lengths() {
+    assert_eq!(
+        NttPlan::try_new(19, 4).unwrap_err(),
+        NttError::WrongCongruence {
+            modulus: 19,
+            degree: 4
+        }
+    );
+    let plan = NttPlan::try_new(17, 8).unwrap();
+    assert_eq!((plan.modulus(), plan.ntt_size()), (17, 8));
+}
+
+#[test]
+#[should_panic(expected = "expected 8 coefficients, got 4")]
+fn test_plan_rejects_wrong_length() {
+    NttPlan::new(17, 8).fwd(&mut [0; 4]);
+}
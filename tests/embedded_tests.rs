@@ -0,0 +1,91 @@
+use rust_ntt::backend::NttBackend;
+use rust_ntt::embedded::render_static_context;
+use rust_ntt::*;
+use std::sync::Arc;
+
+// Checked-in output of render_static_context, as a build script would emit it
+include!("data/static_ctx_16.rs");
+
+const N: usize = 16;
+
+fn source_context() -> Arc<NttContext<N>> {
+    NttContext::<N>::new(find_first_prime_up(40, N))
+}
+
+#[test]
+fn test_rendered_tables_are_up_to_date() {
+    let rendered = render_static_context("NTT_16", &source_context());
+    assert_eq!(rendered, include_str!("data/static_ctx_16.rs"));
+}
+
+#[test]
+fn test_static_context_matches_runtime_context() {
+    let ctx = source_context();
+    assert_eq!(NTT_16.modulus(), ctx.modulus());
+    assert_eq!(NTT_16.tf(), ctx.tf());
+    assert_eq!(NTT_16.itf(), ctx.itf());
+
+    let coeffs: [u64; N] = std::array::from_fn(|i| (i as u64 + 1) * 0x1234567);
+    let mut poly = NttPolynomial::from_coeffs(coeffs, Arc::clone(&ctx));
+    poly.ntt_forward_shoup();
+
+    let mut evals = coeffs;
+    NTT_16.ntt_forward(&mut evals);
+    assert_eq!(&evals, poly.coeffs());
+
+    NTT_16.ntt_inverse(&mut evals);
+    assert_eq!(evals.map(|c| c % ctx.modulus()), coeffs);
+}
+
+#[test]
+fn test_static_context_multiplies() {
+    let ctx = source_context();
+    let a: [u64; N] = std::array::from_fn(|i| i as u64 * 31 + 7);
+    let b: [u64; N] = std::array::from_fn(|i| (i * i) as u64);
+
+    let expected = NttPolynomial::from_coeffs(a, Arc::clone(&ctx))
+        .naive_negacyclic_convolution(&NttPolynomial::from_coeffs(b, ctx));
+    let product = NTT_16.negacyclic_mul(&a, &b);
+    assert_eq!(
+        product.map(|c| c % NTT_16.modulus()),
+        expected.coeffs().map(|c| c % NTT_16.modulus())
+    );
+}
+
+static MACRO_16: rust_ntt::embedded::StaticNttContext<N> =
+    ntt_context!(q = 1099511627873, n = 16);
+
+#[test]
+fn test_macro_context_multiplies_like_runtime_context() {
+    let ctx = source_context();
+    assert_eq!(MACRO_16.modulus(), ctx.modulus());
+
+    let a: [u64; N] = std::array::from_fn(|i| (i as u64 + 3) * 0xabcdef);
+    let b: [u64; N] = std::array::from_fn(|i| (i as u64) << 30);
+    let q = ctx.modulus();
+
+    let mut evals = a;
+    MACRO_16.ntt_forward(&mut evals);
+    MACRO_16.ntt_inverse(&mut evals);
+    assert_eq!(evals.map(|c| c % q), a);
+
+    assert_eq!(
+        MACRO_16.negacyclic_mul(&a, &b).map(|c| c % q),
+        ctx.negacyclic_mul(&a, &b).map(|c| c % q)
+    );
+}
+
+#[test]
+fn test_macro_context_large_modulus() {
+    const Q: u64 = 4611686018427322369; // 2^62 - 2^15 + 1
+    let ctx = ntt_context!(q = Q, n = 1024);
+    let runtime = NttContext::<1024>::new(Q);
+
+    let a: [u64; 1024] =
+        std::array::from_fn(|i| (i as u64).wrapping_mul(0x9e3779b97f4a7c15) % Q);
+    let b: [u64; 1024] = std::array::from_fn(|i| (i as u64 * 7 + 1) % Q);
+    assert_eq!(
+        ctx.negacyclic_mul(&a, &b).map(|c| c % Q),
+        runtime.negacyclic_mul(&a, &b).map(|c| c % Q)
+    );
+}
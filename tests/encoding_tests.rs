@@ -0,0 +1,90 @@
+use proptest::prelude::*;
+use rust_ntt::encoding::DecodeError;
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 64;
+
+#[test]
+fn test_packed_size() {
+    for bits in [12, 30, 45, 62] {
+        let ctx = NttContext::<N>::new(find_first_prime_up(bits, N));
+        let a = NttPolynomial::zero(ctx);
+        // q just above 2^bits needs bits + 1 bits per coefficient
+        assert_eq!(a.to_packed_bytes().len(), 14 + N * (bits + 1) / 8);
+        assert_eq!(a.packed_len(), a.to_packed_bytes().len());
+    }
+}
+
+#[test]
+fn test_rejects_other_rings_and_corruption() {
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+    let other = NttContext::<N>::new(find_first_prime_up(31, N));
+    let a = NttPolynomial::from_coeffs([ctx.modulus() - 1; N], Arc::clone(&ctx));
+    let bytes = a.to_packed_bytes();
+
+    assert!(matches!(
+        NttPolynomial::from_packed_bytes(&bytes, other),
+        Err(DecodeError::RingMismatch { .. })
+    ));
+    assert!(matches!(
+        NttPolynomial::from_packed_bytes(
+            &bytes[..bytes.len() - 1],
+            Arc::clone(&ctx)
+        ),
+        Err(DecodeError::Truncated { .. })
+    ));
+
+    let mut longer = bytes.clone();
+    longer.push(0);
+    assert!(matches!(
+        NttPolynomial::from_packed_bytes(&longer, Arc::clone(&ctx)),
+        Err(DecodeError::TrailingBytes { .. })
+    ));
+
+    // All-ones in the first coefficient is 2^31 - 1 > q
+    let mut corrupt = bytes.clone();
+    corrupt[14..18].copy_from_slice(&[0xff; 4]);
+    assert_eq!(
+        NttPolynomial::from_packed_bytes(&corrupt, Arc::clone(&ctx)).err(),
+        Some(DecodeError::CoefficientOutOfRange {
+            index: 0,
+            value: (1 << 31) - 1
+        })
+    );
+
+    let mut version = bytes;
+    version[0] = 9;
+    assert_eq!(
+        NttPolynomial::from_packed_bytes(&version, ctx).err(),
+        Some(DecodeError::UnsupportedVersion(9))
+    );
+}
+
+#[test]
+fn test_rejects_nonzero_padding() {
+    // 17 needs 5 bits; 4 coefficients use 20 bits, leaving 4 padding bits
+    let ctx = NttContext::<4>::new(17);
+    let mut bytes = NttPolynomial::zero(Arc::clone(&ctx)).to_packed_bytes();
+    *bytes.last_mut().unwrap() |= 0x80;
+    assert_eq!(
+        NttPolynomial::from_packed_bytes(&bytes, ctx).err(),
+        Some(DecodeError::NonZeroPadding)
+    );
+}
+
+// Property: Packing round-trips for every modulus size
+proptest! {
+    #[test]
+    fn packed_bytes_round_trip(
+        bits in 10usize..62,
+        coeffs in prop::array::uniform32(any::<u64>()),
+    ) {
+        let ctx = NttContext::<32>::new(find_first_prime_up(bits, 32));
+        let q = ctx.modulus();
+        let a = NttPolynomial::from_coeffs(coeffs.map(|c| c % q), Arc::clone(&ctx));
+
+        let b = NttPolynomial::from_packed_bytes(&a.to_packed_bytes(), ctx).unwrap();
+        prop_assert_eq!(b.coeffs(), a.coeffs());
+    }
+}
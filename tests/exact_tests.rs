@@ -0,0 +1,135 @@
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use rust_ntt::exact::{self, ExactConvolver};
+
+const N: usize = 64;
+
+fn schoolbook(a: &[i64], b: &[i64]) -> Vec<i128> {
+    let mut out = vec![0i128; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            out[i + j] += x as i128 * y as i128;
+        }
+    }
+    out
+}
+
+#[test]
+fn test_prime_count_follows_bound() {
+    assert_eq!(ExactConvolver::<N>::new(60).moduli().len(), 1);
+    assert_eq!(ExactConvolver::<N>::new(100).moduli().len(), 2);
+    assert_eq!(ExactConvolver::<N>::new(124).moduli().len(), 3);
+
+    for bits in [1, 60, 61, 122, 123, 124] {
+        let moduli = ExactConvolver::<N>::new(bits).moduli();
+        let product = moduli
+            .iter()
+            .try_fold(1u128, |acc, &q| acc.checked_mul(q as u128));
+        assert!(product.unwrap() >> (bits + 1) > 0, "bits = {bits}");
+    }
+}
+
+#[test]
+fn test_signed_matches_schoolbook() {
+    let mut rng = StdRng::seed_from_u64(42);
+    for (bits, magnitude) in [(40, 1i64 << 16), (122, 1 << 57), (124, 1 << 58)] {
+        let conv = ExactConvolver::<N>::new(bits);
+        let a: Vec<i64> = (0..N)
+            .map(|_| rng.random_range(-magnitude..magnitude))
+            .collect();
+        let b: Vec<i64> = (0..37)
+            .map(|_| rng.random_range(-magnitude..magnitude))
+            .collect();
+        assert_eq!(
+            conv.convolve_i64(&a, &b),
+            schoolbook(&a, &b),
+            "bits = {bits}"
+        );
+    }
+}
+
+#[test]
+fn test_unsigned_full_range() {
+    let conv = ExactConvolver::<N>::new(124);
+    let a = vec![u64::MAX >> 5; 16];
+    let b = vec![u64::MAX >> 5; 16];
+
+    let expected = (u64::MAX >> 5) as u128 * (u64::MAX >> 5) as u128;
+    let c = conv.convolve_u64(&a, &b);
+    assert_eq!(c.len(), 31);
+    assert_eq!(c[0], expected);
+    assert_eq!(c[15], 16 * expected);
+    assert!(conv.convolve_u64(&[], &b).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "product coefficients may reach 2^60")]
+fn test_rejects_inputs_beyond_bound() {
+    ExactConvolver::<N>::new(60).convolve_u64(&[1 << 30, 1], &[1 << 30, 1]);
+}
+
+fn schoolbook_u64(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut out = vec![0u64; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            out[i + j] += x * y;
+        }
+    }
+    out
+}
+
+#[test]
+fn test_byte_sequences_match_schoolbook() {
+    let mut rng = StdRng::seed_from_u64(42);
+    for (la, lb) in [(1, 1), (3, 200), (1000, 777), (5000, 5000)] {
+        let a: Vec<u8> = (0..la).map(|_| rng.random()).collect();
+        let b: Vec<u8> = (0..lb).map(|_| rng.random()).collect();
+        let wide = |v: &[u8]| v.iter().map(|&x| x as u64).collect::<Vec<_>>();
+        assert_eq!(
+            exact::convolve_u8(&a, &b),
+            schoolbook_u64(&wide(&a), &wide(&b))
+        );
+    }
+
+    // Worst case sums reach 5000·(2^16 - 1)^2 > 2^32
+    let a = vec![u16::MAX; 5000];
+    let c = exact::convolve_u16(&a, &a);
+    assert_eq!(c[4999], 5000 * (u16::MAX as u64).pow(2));
+    assert!(exact::convolve_u16(&a, &[]).is_empty());
+}
+
+#[test]
+fn test_wildcard_matching() {
+    // Pattern p matches text t at i iff Σ p_j·(p_j - t_(i+j))^2 = 0, with
+    // wildcards encoded as 0; the cross terms are convolutions with the
+    // reversed pattern
+    let text = b"abracadabra";
+    let pattern: Vec<u8> = b"a?ra"
+        .iter()
+        .rev()
+        .map(|&c| if c == b'?' { 0 } else { c })
+        .collect();
+
+    let cube: Vec<u64> = pattern.iter().map(|&p| (p as u64).pow(3)).collect();
+    let sq: Vec<u64> = pattern.iter().map(|&p| (p as u64).pow(2)).collect();
+    let ones: Vec<u64> = pattern.iter().map(|&p| p as u64).collect();
+    let t1: Vec<u16> = text.iter().map(|&c| c as u16).collect();
+    let t2: Vec<u16> = text.iter().map(|&c| (c as u16).pow(2)).collect();
+
+    let mask: Vec<u8> = pattern.iter().map(|&p| (p != 0) as u8).collect();
+    let a = exact::convolve_u8(
+        &mask,
+        text.iter().map(|_| 1).collect::<Vec<u8>>().as_slice(),
+    );
+    let _ = a;
+
+    let as_u16 = |v: &[u64]| v.iter().map(|&x| x as u16).collect::<Vec<_>>();
+    let s1 = exact::convolve_u16(&as_u16(&sq), &t1);
+    let s2 = exact::convolve_u16(&as_u16(&ones), &t2);
+    let m = pattern.len();
+    let s0: u64 = cube.iter().sum();
+
+    let matches: Vec<usize> = (0..=text.len() - m)
+        .filter(|&i| s0 + s2[i + m - 1] == 2 * s1[i + m - 1])
+        .collect();
+    assert_eq!(matches, vec![0, 7]);
+}
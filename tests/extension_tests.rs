@@ -0,0 +1,74 @@
+use proptest::prelude::*;
+use rust_ntt::CongruenceClass;
+use rust_ntt::extension::{Fq2, Fq2Element};
+
+// q ≡ 1 mod 4 forces a non-trivial non-residue; q - 1 = 2^4·...
+const Q: u64 = 65_537;
+
+fn element() -> impl Strategy<Value = Fq2Element> {
+    (0..Q, 0..Q).prop_map(|(c0, c1)| Fq2Element::new(c0, c1))
+}
+
+// Property: F_q^2 is a field: associative, distributive, invertible
+proptest! {
+    #[test]
+    fn field_axioms(x in element(), y in element(), z in element()) {
+        let f = Fq2::new(Q);
+
+        prop_assert_eq!(f.mul(f.mul(x, y), z), f.mul(x, f.mul(y, z)));
+        prop_assert_eq!(f.mul(x, y), f.mul(y, x));
+        prop_assert_eq!(
+            f.mul(x, f.add(y, z)),
+            f.add(f.mul(x, y), f.mul(x, z))
+        );
+        prop_assert_eq!(f.sub(f.add(x, y), y), x);
+
+        if x != f.zero() {
+            prop_assert_eq!(f.mul(x, f.inv(x)), f.one());
+        }
+    }
+}
+
+// Property: Frobenius is x^q, multiplicative, and fixes exactly F_q
+proptest! {
+    #[test]
+    fn frobenius_is_qth_power(x in element(), y in element(), c in 0..Q) {
+        let f = Fq2::new(Q);
+
+        prop_assert_eq!(f.frobenius(x), f.pow(x, Q as u128));
+        prop_assert_eq!(
+            f.frobenius(f.mul(x, y)),
+            f.mul(f.frobenius(x), f.frobenius(y))
+        );
+        prop_assert_eq!(f.frobenius(f.from_base(c)), f.from_base(c));
+        prop_assert_eq!(f.from_base(f.norm(x)), f.mul(x, f.frobenius(x)));
+    }
+}
+
+#[test]
+fn test_non_residue_is_smallest() {
+    let f = Fq2::new(Q);
+    let class = CongruenceClass::new(Q);
+    let beta = f.non_residue();
+
+    assert_eq!(class.modexp(beta, (Q - 1) / 2), Q - 1);
+    assert!((2..beta).all(|x| class.modexp(x, (Q - 1) / 2) == 1));
+}
+
+#[test]
+#[should_panic(expected = "not a quadratic non-residue")]
+fn test_with_residue_panics() {
+    Fq2::with_non_residue(Q, 4);
+}
+
+#[test]
+fn test_two_adic_root_beyond_base_field() {
+    // q = 2^31 - 1 has q - 1 = 2·odd, so F_q only has roots of order 2,
+    // while q + 1 = 2^31 gives F_q^2 roots of order up to 2^32
+    let q = (1u64 << 31) - 1;
+    let f = Fq2::new(q);
+
+    let root = f.two_adic_root(1 << 32);
+    assert_eq!(f.pow(root, 1 << 32), f.one());
+    assert_eq!(f.pow(root, 1 << 31), f.neg(f.one()));
+}
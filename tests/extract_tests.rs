@@ -0,0 +1,76 @@
+use proptest::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 16;
+
+fn context() -> Arc<NttContext<N>> {
+    NttContext::<N>::new(find_first_prime_up(30, N))
+}
+
+// Property: every coefficient of a·s is the extracted form applied to s
+proptest! {
+    #[test]
+    fn extraction_matches_product(seed in any::<u64>(), i in 0..N) {
+        let ctx = context();
+        let class = ctx.class();
+        let mut rng = StdRng::seed_from_u64(seed);
+        let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+        let s = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+        let dot = a
+            .extract_coefficient(i)
+            .iter()
+            .zip(s.coeffs())
+            .fold(0, |acc, (&v, &x)| class.modadd(acc, class.modmul(v, x)));
+        let product = &a * &s;
+        prop_assert_eq!(dot, product.coeffs()[i]);
+    }
+}
+
+// Property: multiplying by x^k matches the product with the monomial
+proptest! {
+    #[test]
+    fn mul_monomial_matches_product(seed in any::<u64>(), k in -40isize..40) {
+        let ctx = context();
+        let q = ctx.modulus();
+        let mut rng = StdRng::seed_from_u64(seed);
+        let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+        let e = k.rem_euclid(2 * N as isize) as usize;
+        let mut monomial = [0u64; N];
+        monomial[e % N] = if e >= N { q - 1 } else { 1 };
+        let monomial = NttPolynomial::from_coeffs(monomial, ctx);
+
+        prop_assert_eq!(*a.mul_monomial(k).coeffs(), *(&a * &monomial).coeffs());
+    }
+}
+
+#[test]
+fn test_repack_gathers_constant_terms() {
+    let ctx = context();
+    let mut rng = StdRng::seed_from_u64(42);
+    let products: Vec<_> = (0..4)
+        .map(|_| {
+            let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+            let b = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+            &a * &b
+        })
+        .collect();
+
+    let entries: Vec<_> = products.iter().map(|p| (p, 0)).collect();
+    let packed = NttPolynomial::repack(&entries);
+    for (k, p) in products.iter().enumerate() {
+        assert_eq!(packed.coeffs()[k], p.coeffs()[0]);
+    }
+    assert!(packed.coeffs()[4..].iter().all(|&c| c == 0));
+}
+
+#[test]
+#[should_panic(expected = "Cannot repack polynomials")]
+fn test_repack_rejects_mixed_moduli() {
+    let a = NttPolynomial::zero(context());
+    let b = NttPolynomial::zero(NttContext::<N>::new(find_first_prime_up(31, N)));
+    NttPolynomial::repack(&[(&a, 0), (&b, 0)]);
+}
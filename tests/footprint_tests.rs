@@ -0,0 +1,62 @@
+use rust_ntt::footprint::chain_footprint;
+use rust_ntt::params::FHE_4096;
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 256;
+
+fn table(ctx: &NttContext<N>, name: &str) -> usize {
+    let footprint = ctx.memory_footprint();
+    footprint.tables.iter().find(|(n, _)| *n == name).unwrap().1
+}
+
+#[test]
+fn test_fresh_context_has_only_eager_tables() {
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+    let footprint = ctx.memory_footprint();
+
+    assert_eq!(footprint.degree, N);
+    assert_eq!(footprint.modulus, ctx.modulus());
+    assert_eq!(table(&ctx, "tf"), 8 * N);
+    assert_eq!(table(&ctx, "coset_inv_powers"), 8 * N);
+    assert_eq!(table(&ctx, "galois"), 0);
+    assert_eq!(table(&ctx, "linear"), 0);
+    assert_eq!(footprint.total(), size_of::<NttContext<N>>() + 6 * 8 * N);
+}
+
+#[test]
+fn test_lazy_tables_are_counted_once_built() {
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+    let before = ctx.memory_footprint().total();
+
+    let a = NttPolynomial::zero(Arc::clone(&ctx));
+    let _ = a.automorphism(3);
+    let _ = a.automorphism(5);
+    let _ = a.linear_convolution(&a);
+    let _ = a.negacyclic_convolution_montgomery(&a);
+
+    let map = N * size_of::<(usize, bool)>();
+    assert_eq!(table(&ctx, "galois"), 2 * map);
+    // ω^j and ω^(-j) for j < N, with ω of order 2N
+    assert_eq!(table(&ctx, "linear"), 2 * N * 8);
+    // Forward and inverse twiddles
+    assert_eq!(table(&ctx, "montgomery"), 2 * N * 8);
+    assert_eq!(ctx.memory_footprint().total(), before + 2 * map + 4 * N * 8);
+}
+
+#[test]
+fn test_chain_footprint_sums_contexts() {
+    let chain = FHE_4096.contexts();
+    let report = chain_footprint(&chain);
+
+    assert_eq!(report.contexts.len(), chain.len());
+    assert_eq!(
+        report.total(),
+        chain.len() * chain[0].memory_footprint().total()
+    );
+    assert!(report.to_string().ends_with(&format!(
+        "{} contexts, {} B in total",
+        chain.len(),
+        report.total()
+    )));
+}
@@ -0,0 +1,92 @@
+use proptest::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::*;
+use std::sync::Arc;
+
+// Property: the four-step transform matches the radix-2 one slot for slot
+// and inverts it, for an even (16) and an odd (32) number of stages
+proptest! {
+    #[test]
+    fn four_step_matches_radix2(
+        bits in prop::sample::select(vec![20usize, 40, 62]),
+        coeffs in prop::array::uniform32(any::<u64>()),
+    ) {
+        let ctx = NttContext::<16>::new(find_first_prime_up(bits, 16));
+        let short: [u64; 16] = std::array::from_fn(|i| coeffs[i]);
+        let a = NttPolynomial::from_unreduced(&short, ctx);
+        let mut expected = a.clone();
+        expected.ntt_forward();
+        let mut evals = a.clone();
+        evals.ntt_forward_four_step();
+        prop_assert_eq!(*evals.coeffs(), *expected.coeffs());
+        evals.ntt_inverse_four_step();
+        prop_assert_eq!(*evals.coeffs(), *a.coeffs());
+
+        let ctx = NttContext::<32>::new(find_first_prime_up(bits, 32));
+        let a = NttPolynomial::from_unreduced(&coeffs, ctx);
+        let mut expected = a.clone();
+        expected.ntt_forward();
+        let mut evals = a.clone();
+        evals.ntt_forward_four_step();
+        prop_assert_eq!(*evals.coeffs(), *expected.coeffs());
+        evals.ntt_inverse_four_step();
+        prop_assert_eq!(*evals.coeffs(), *a.coeffs());
+    }
+}
+
+#[test]
+fn test_four_step_large_degree() {
+    const N: usize = 1 << 16;
+    let mut rng = StdRng::seed_from_u64(42);
+    let ctx = NttContext::<N>::new(find_first_prime_up(50, N));
+    let a = NttPolynomial::sample_random(ctx, &mut rng);
+
+    let mut expected = a.clone();
+    expected.ntt_forward_shoup();
+    let mut evals = a.clone();
+    evals.ntt_forward_four_step();
+    assert_eq!(evals.coeffs(), expected.coeffs());
+
+    evals.ntt_inverse_four_step();
+    assert_eq!(evals.coeffs(), a.coeffs());
+}
+
+#[test]
+fn test_four_step_degenerate_sizes() {
+    fn check<const N: usize>(coeffs: [u64; N]) {
+        let ctx = NttContext::<N>::new(find_first_prime_up(20, N));
+        let a = NttPolynomial::from_coeffs(coeffs, Arc::clone(&ctx));
+        let mut expected = a.clone();
+        expected.ntt_forward();
+
+        let mut evals = a.clone();
+        evals.ntt_forward_four_step();
+        assert_eq!(evals.coeffs(), expected.coeffs());
+        evals.ntt_inverse_four_step();
+        assert_eq!(evals.coeffs(), &coeffs);
+    }
+
+    check([5]);
+    check([3, 7]);
+    check([1, 2, 3, 4]);
+}
+
+#[test]
+fn test_four_step_tables_in_footprint() {
+    const N: usize = 256;
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+    let before = ctx.memory_footprint().total();
+
+    NttPolynomial::zero(Arc::clone(&ctx)).ntt_forward_four_step();
+    let footprint = ctx.memory_footprint();
+    let (_, bytes) = footprint
+        .tables
+        .iter()
+        .find(|(name, _)| *name == "four_step")
+        .unwrap();
+
+    // Weights and twiddles, plus ω^j and ω^(-j) for j < 8 in each of the
+    // two length-16 plans
+    assert_eq!(*bytes, (4 * N + 2 * 16) * 8);
+    assert_eq!(footprint.total(), before + bytes);
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use proptest::prelude::*;
+use rust_ntt::NttPolynomial;
+use rust_ntt::fuzz::{PolyPair, SmallContext};
+
+// Property: Arbitrary contexts are valid and polynomials are reduced
+proptest! {
+    #[test]
+    fn arbitrary_inputs_are_valid(bytes in prop::collection::vec(any::<u8>(), 0..1024)) {
+        let mut u = Unstructured::new(&bytes);
+        let SmallContext(ctx) = SmallContext::<16>::arbitrary(&mut u).unwrap();
+        let q = ctx.modulus();
+        prop_assert_eq!((q - 1) % 32, 0);
+        prop_assert!(q > 1 << 17 && q < 1 << 61);
+
+        let mut u = Unstructured::new(&bytes);
+        let poly = NttPolynomial::<16>::arbitrary(&mut u).unwrap();
+        let q = poly.context().modulus();
+        prop_assert!(poly.coeffs().iter().all(|&c| c < q));
+    }
+}
+
+// Property: Arbitrary pairs share a context, so the kernels accept them
+proptest! {
+    #[test]
+    fn arbitrary_pairs_convolve(bytes in prop::collection::vec(any::<u8>(), 0..1024)) {
+        let mut u = Unstructured::new(&bytes);
+        let PolyPair(a, b) = PolyPair::<16>::arbitrary(&mut u).unwrap();
+        let q = a.context().modulus();
+        prop_assert_eq!(q, b.context().modulus());
+
+        let naive = a.naive_negacyclic_convolution(&b).coeffs().map(|c| c % q);
+        let fast = a.negacyclic_convolution(&b).coeffs().map(|c| c % q);
+        prop_assert_eq!(naive, fast);
+    }
+}
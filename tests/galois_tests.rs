@@ -0,0 +1,65 @@
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::galois::{conjugation_element, galois_element};
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 16;
+
+#[test]
+fn test_galois_element_group_structure() {
+    // Rotations compose additively: 5^a * 5^b = 5^(a+b)
+    for a in -3isize..4 {
+        for b in -3isize..4 {
+            let lhs = galois_element(a, N) * galois_element(b, N) % (2 * N);
+            assert_eq!(lhs, galois_element(a + b, N));
+        }
+    }
+
+    // Rotating by the number of slots is the identity
+    assert_eq!(galois_element((N / 2) as isize, N), 1);
+    assert_eq!(conjugation_element(N), 2 * N - 1);
+}
+
+#[test]
+fn test_galois_element_degree_one() {
+    // No slots to rotate: every rotation is the identity
+    for r in -2isize..3 {
+        assert_eq!(galois_element(r, 1), 1);
+    }
+    assert_eq!(conjugation_element(1), 1);
+}
+
+#[test]
+fn test_rotations_compose_and_cache() {
+    let q = find_first_prime_down(40, N);
+    let ctx = NttContext::<N>::new(q);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+    let twice = a.rotate_slots(1).rotate_slots(2);
+    assert_eq!(twice.coeffs(), a.rotate_slots(3).coeffs());
+    assert_eq!(a.rotate_slots(2).rotate_slots(-2).coeffs(), a.coeffs());
+    assert_eq!(a.conjugate().conjugate().coeffs(), a.coeffs());
+
+    // Tables for k = 5, 25, 125 and 5^(-2), 1 are cached, none recomputed
+    let cached = ctx.cached_automorphisms();
+    a.rotate_slots(1);
+    a.rotate_slots(3);
+    assert_eq!(ctx.cached_automorphisms(), cached);
+}
+
+#[test]
+fn test_precompute_rotations() {
+    let q = find_first_prime_down(40, N);
+    let ctx = NttContext::<N>::new(q);
+    assert_eq!(ctx.cached_automorphisms(), 0);
+
+    ctx.precompute_rotations(&[1, 2, 4]);
+    assert_eq!(ctx.cached_automorphisms(), 4);
+
+    let table = ctx.automorphism_table(galois_element(1, N));
+    assert_eq!(table[0], (0, false));
+    assert_eq!(table[1], (5, false));
+    assert_eq!(table[4], (4, true)); // x^4 -> x^20 = -x^4
+}
@@ -0,0 +1,100 @@
+use proptest::prelude::*;
+use rust_ntt::context::NttError;
+use rust_ntt::*;
+
+const KYBER_Q: u64 = 3329;
+
+/// Schoolbook product in `Z_q[x]/(x^N + 1)`
+fn negacyclic_naive<const N: usize>(
+    a: &[u64; N],
+    b: &[u64; N],
+    q: u64,
+) -> [u64; N] {
+    let class = CongruenceClass::new(q);
+    let mut out = [0; N];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            let p = class.modmul(x, y);
+            let k = (i + j) % N;
+            out[k] = if i + j < N {
+                class.modadd(out[k], p)
+            } else {
+                class.modsub(out[k], p)
+            };
+        }
+    }
+    out
+}
+
+// Property: with Kyber's parameters the 7-level transform inverts and its
+// base multiplication gives the negacyclic product
+proptest! {
+    #[test]
+    fn kyber_product_matches_naive(
+        a in prop::collection::vec(0..KYBER_Q, 256),
+        b in prop::collection::vec(0..KYBER_Q, 256),
+    ) {
+        let ctx = NttContext::<256>::new_incomplete(KYBER_Q, 7);
+        let a: [u64; 256] = a.try_into().unwrap();
+        let b: [u64; 256] = b.try_into().unwrap();
+
+        let mut evals = a;
+        ctx.ntt_forward(&mut evals);
+        ctx.ntt_inverse(&mut evals);
+        prop_assert_eq!(evals, a);
+
+        prop_assert_eq!(
+            ctx.negacyclic_convolution(&a, &b),
+            negacyclic_naive(&a, &b, KYBER_Q)
+        );
+    }
+}
+
+#[test]
+fn test_every_level_count_multiplies() {
+    const N: usize = 16;
+    let q = find_first_prime_up(20, N);
+    let a: [u64; N] = std::array::from_fn(|i| (i as u64 * 31 + 7) % q);
+    let b: [u64; N] = std::array::from_fn(|i| (i as u64 * 17 + 3) % q);
+    let expected = negacyclic_naive(&a, &b, q);
+
+    for levels in 0..=4 {
+        let ctx = NttContext::<N>::new_incomplete(q, levels);
+        assert_eq!(ctx.block_len(), N >> levels);
+        assert_eq!(ctx.negacyclic_convolution(&a, &b), expected);
+    }
+}
+
+#[test]
+fn test_all_levels_match_complete_ntt() {
+    const N: usize = 64;
+    let q = find_first_prime_up(30, N);
+    let ctx = NttContext::<N>::new(q);
+    let incomplete = NttContext::<N>::new_incomplete(q, 6);
+    let a: [u64; N] = std::array::from_fn(|i| i as u64 * 1000);
+
+    let mut evals = a;
+    incomplete.ntt_forward(&mut evals);
+    let mut expected = NttPolynomial::from_coeffs(a, ctx);
+    expected.ntt_forward();
+    assert_eq!(&evals, expected.coeffs());
+}
+
+#[test]
+fn test_missing_roots_are_rejected() {
+    // 3329 - 1 = 2^8·13 has no primitive 512th root
+    assert_eq!(
+        NttContext::<256>::try_new_incomplete(KYBER_Q, 8).unwrap_err(),
+        NttError::WrongCongruence {
+            modulus: KYBER_Q,
+            degree: 256
+        }
+    );
+    assert!(NttContext::<256>::try_new(KYBER_Q).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Cannot run 5 levels of a length-16 NTT")]
+fn test_too_many_levels_panics() {
+    let _ = NttContext::<16>::new_incomplete(find_first_prime_up(20, 16), 5);
+}
@@ -0,0 +1,84 @@
+use rust_ntt::kernel::{NttKernel, ScalarKernel};
+use rust_ntt::*;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const N: usize = 16;
+
+/// Scalar kernel that records which entry points were used
+#[derive(Debug, Default)]
+struct Recording {
+    forward: AtomicUsize,
+    inverse: AtomicUsize,
+    pointwise: AtomicUsize,
+}
+
+impl NttKernel<N> for Recording {
+    fn forward(&self, ctx: &NttContext<N>, coeffs: &mut [u64; N]) {
+        self.forward.fetch_add(1, Ordering::Relaxed);
+        ScalarKernel.forward(ctx, coeffs);
+    }
+
+    fn inverse(&self, ctx: &NttContext<N>, evals: &mut [u64; N]) {
+        self.inverse.fetch_add(1, Ordering::Relaxed);
+        ScalarKernel.inverse(ctx, evals);
+    }
+
+    fn pointwise(&self, ctx: &NttContext<N>, lhs: &mut [u64; N], rhs: &[u64; N]) {
+        self.pointwise.fetch_add(1, Ordering::Relaxed);
+        ScalarKernel.pointwise(ctx, lhs, rhs);
+    }
+}
+
+/// Deliberately wrong kernel, to show results really come from the kernel
+#[derive(Debug)]
+struct Zeroing;
+
+impl NttKernel<N> for Zeroing {
+    fn forward(&self, _: &NttContext<N>, coeffs: &mut [u64; N]) {
+        *coeffs = [0; N];
+    }
+}
+
+fn q() -> u64 {
+    find_first_prime_up(30, N)
+}
+
+#[test]
+fn test_context_dispatches_to_kernel() {
+    let kernel = Arc::new(Recording::default());
+    let ctx = NttContext::<N>::with_kernel(q(), kernel.clone());
+    let plain = NttContext::<N>::new(q());
+
+    let coeffs = std::array::from_fn(|i| i as u64 + 1);
+    let a = NttPolynomial::from_coeffs(coeffs, Arc::clone(&ctx));
+    let expected = NttPolynomial::from_coeffs(coeffs, Arc::clone(&plain));
+
+    let product = a.negacyclic_convolution_shoup(&a);
+    assert_eq!(product.coeffs(), (&expected * &expected).coeffs());
+    assert_eq!(kernel.forward.load(Ordering::Relaxed), 2);
+    assert_eq!(kernel.inverse.load(Ordering::Relaxed), 1);
+    assert_eq!(kernel.pointwise.load(Ordering::Relaxed), 1);
+
+    let mut b = a.clone();
+    b.ntt_forward_shoup();
+    b.ntt_inverse_shoup();
+    assert_eq!(b.coeffs(), &coeffs);
+    assert_eq!(kernel.forward.load(Ordering::Relaxed), 3);
+}
+
+#[test]
+fn test_kernel_overrides_replace_defaults() {
+    let ctx = NttContext::<N>::with_kernel(q(), Arc::new(Zeroing));
+    let mut a = NttPolynomial::from_coeffs([1; N], Arc::clone(&ctx));
+
+    a.ntt_forward_shoup();
+    assert_eq!(a.coeffs(), &[0; N]);
+
+    // The inverse was not overridden and still uses the scalar kernel
+    let mut b = NttPolynomial::from_coeffs([1; N], ctx);
+    let mut reference = b.clone();
+    b.ntt_inverse_shoup();
+    reference.ntt_inverse();
+    assert_eq!(b.coeffs(), reference.coeffs());
+}
@@ -0,0 +1,107 @@
+use proptest::prelude::*;
+use rust_ntt::linalg::{Circulant, NegaCirculant, Toeplitz};
+use rust_ntt::*;
+use std::sync::Arc;
+
+// Property: Transform-based Toeplitz matvec agrees with the schoolbook product
+proptest! {
+    #[test]
+    fn toeplitz_matvec_matches_naive(
+        rows in 1usize..80,
+        cols in 1usize..80,
+        seed in prop::collection::vec(any::<u64>(), 240),
+    ) {
+        for q in [find_first_prime_up(40, 256), 1_000_003] {
+            let class = CongruenceClass::new(q);
+            let diagonals: Vec<u64> =
+                seed[..rows + cols - 1].iter().map(|c| c % q).collect();
+            let v: Vec<u64> = seed[160..160 + cols].iter().map(|c| c % q).collect();
+
+            let t = Toeplitz::new(rows, cols, diagonals);
+            let fast: Vec<u64> =
+                t.matvec(&v, &class).iter().map(|c| c % q).collect();
+            let naive: Vec<u64> =
+                t.matvec_naive(&v, &class).iter().map(|c| c % q).collect();
+            prop_assert_eq!(fast, naive);
+        }
+    }
+}
+
+#[test]
+fn test_from_col_row_layout() {
+    let t = Toeplitz::from_col_row(&[1, 2, 3], &[1, 4, 5, 6]);
+
+    assert_eq!((t.rows(), t.cols()), (3, 4));
+    assert_eq!(t.diagonals(), &[6, 5, 4, 1, 2, 3]);
+    for i in 0..3 {
+        for j in 0..4 {
+            let expected = if i >= j {
+                [1, 2, 3][i - j]
+            } else {
+                [1, 4, 5, 6][j - i]
+            };
+            assert_eq!(t.get(i, j), expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "disagree")]
+fn test_from_col_row_corner_mismatch_panics() {
+    Toeplitz::from_col_row(&[1, 2], &[3, 4]);
+}
+
+const N: usize = 16;
+
+/// Dense matrix from a row-generating rule, for reference products
+fn dense(entry: impl Fn(usize, usize) -> u64) -> Vec<Vec<u64>> {
+    (0..N)
+        .map(|i| (0..N).map(|j| entry(i, j)).collect())
+        .collect()
+}
+
+fn dense_apply(m: &[Vec<u64>], v: &[u64; N], class: &CongruenceClass) -> [u64; N] {
+    std::array::from_fn(|i| {
+        (0..N).fold(0, |acc, j| (acc + class.modmul(m[i][j], v[j])) % class.q())
+    })
+}
+
+// Property: Circulant and negacirculant matrices act like their dense forms
+proptest! {
+    #[test]
+    fn circulant_apply_matches_dense(
+        row in prop::array::uniform16(any::<u64>()),
+        other in prop::array::uniform16(any::<u64>()),
+        v in prop::array::uniform16(any::<u64>()),
+    ) {
+        let q = find_first_prime_up(30, N);
+        let ctx = NttContext::<N>::new(q);
+        let class = *ctx.class();
+        let row = row.map(|c| c % q);
+        let other = other.map(|c| c % q);
+        let v = v.map(|c| c % q);
+        let canonical = |a: [u64; N]| a.map(|c| c % q);
+
+        let c = Circulant::from_first_row(row, Arc::clone(&ctx));
+        let c_dense = dense(|i, j| row[(j + N - i) % N]);
+        prop_assert_eq!(canonical(c.first_row()), row);
+        prop_assert_eq!(canonical(c.apply(&v)), dense_apply(&c_dense, &v, &class));
+
+        let nc = NegaCirculant::from_first_row(row, Arc::clone(&ctx));
+        let nc_dense = dense(|i, j| {
+            if j >= i { row[j - i] } else { class.modneg(row[N + j - i]) }
+        });
+        prop_assert_eq!(canonical(nc.first_row()), row);
+        let expected = dense_apply(&nc_dense, &v, &class);
+        prop_assert_eq!(canonical(nc.apply(&v)), expected);
+
+        // Composition is the matrix product
+        let c2 = Circulant::from_first_row(other, Arc::clone(&ctx));
+        let expected = canonical(c.apply(&c2.apply(&v)));
+        prop_assert_eq!(canonical(c.compose(&c2).apply(&v)), expected);
+
+        let nc2 = NegaCirculant::from_first_row(other, Arc::clone(&ctx));
+        let expected = canonical(nc.apply(&nc2.apply(&v)));
+        prop_assert_eq!(canonical(nc.compose(&nc2).apply(&v)), expected);
+    }
+}
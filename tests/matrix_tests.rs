@@ -0,0 +1,131 @@
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::matrix::PolyMatrix;
+use rust_ntt::vector::{PolyVec, tensor};
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 16;
+
+#[test]
+fn test_ntt_mul_matches_naive() {
+    let q = find_first_prime_down(58, N);
+    let ctx = NttContext::<N>::new(q);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let a = PolyMatrix::sample_random(3, 2, Arc::clone(&ctx), &mut rng);
+    let b = PolyMatrix::sample_random(2, 4, Arc::clone(&ctx), &mut rng);
+
+    let fast = &a * &b;
+    let naive = a.naive_mul(&b);
+
+    assert_eq!((fast.rows(), fast.cols()), (3, 4));
+    for (x, y) in fast.entries().iter().zip(naive.entries()) {
+        assert_eq!(x.coeffs(), y.coeffs());
+    }
+}
+
+#[test]
+fn test_identity_and_transpose() {
+    let q = find_first_prime_up(20, N);
+    let ctx = NttContext::<N>::new(q);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let a = PolyMatrix::sample_random(3, 3, Arc::clone(&ctx), &mut rng);
+    let id = PolyMatrix::identity(3, Arc::clone(&ctx));
+
+    let a_id = &a * &id;
+    for (x, y) in a_id.entries().iter().zip(a.entries()) {
+        assert_eq!(x.coeffs(), y.coeffs());
+    }
+
+    let b = PolyMatrix::sample_random(3, 2, Arc::clone(&ctx), &mut rng);
+
+    // (AB)^T = B^T A^T
+    let left = (&a * &b).transpose();
+    let right = &b.transpose() * &a.transpose();
+    for (x, y) in left.entries().iter().zip(right.entries()) {
+        assert_eq!(x.coeffs(), y.coeffs());
+    }
+}
+
+#[test]
+#[should_panic(expected = "Cannot multiply")]
+fn test_shape_mismatch() {
+    let q = find_first_prime_up(20, N);
+    let ctx = NttContext::<N>::new(q);
+
+    let a = PolyMatrix::zero(2, 3, Arc::clone(&ctx));
+    let b = PolyMatrix::zero(2, 3, Arc::clone(&ctx));
+    let _ = &a * &b;
+}
+
+#[test]
+fn test_inner_product_matches_naive() {
+    let q = find_first_prime_down(58, N);
+    let ctx = NttContext::<N>::new(q);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let a = PolyVec::sample_random(4, Arc::clone(&ctx), &mut rng);
+    let b = PolyVec::sample_random(4, Arc::clone(&ctx), &mut rng);
+
+    let mut expected = NttPolynomial::zero(Arc::clone(&ctx));
+    for (x, y) in a.entries().iter().zip(b.entries()) {
+        expected += &x.naive_negacyclic_convolution(y);
+    }
+
+    assert_eq!(a.inner(&b).coeffs(), expected.coeffs());
+}
+
+#[test]
+fn test_tensor_matches_entrywise_products() {
+    let q = find_first_prime_down(58, N);
+    let ctx = NttContext::<N>::new(q);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let a = PolyVec::sample_random(2, Arc::clone(&ctx), &mut rng);
+    let b = PolyVec::sample_random(3, Arc::clone(&ctx), &mut rng);
+
+    let t = tensor(&a, &b);
+    assert_eq!((t.rows(), t.cols()), (2, 3));
+
+    for i in 0..2 {
+        for j in 0..3 {
+            let expected =
+                a.entries()[i].naive_negacyclic_convolution(&b.entries()[j]);
+            assert_eq!(t.get(i, j).coeffs(), expected.coeffs());
+        }
+    }
+
+    // Outer product of a column and a row agrees with the matrix product
+    let col =
+        PolyMatrix::from_entries(2, 1, a.entries().to_vec(), Arc::clone(&ctx));
+    let row =
+        PolyMatrix::from_entries(1, 3, b.entries().to_vec(), Arc::clone(&ctx));
+    let prod = &col * &row;
+    for (x, y) in prod.entries().iter().zip(a.outer(&b).entries()) {
+        assert_eq!(x.coeffs(), y.coeffs());
+    }
+}
+
+#[test]
+fn test_expand_from_seed_is_deterministic() {
+    let q = find_first_prime_down(40, N);
+    let ctx = NttContext::<N>::new(q);
+
+    let seed = [7u8; 32];
+    let a1 = PolyMatrix::expand_from_seed(2, 3, Arc::clone(&ctx), &seed);
+    let a2 = PolyMatrix::expand_from_seed(2, 3, Arc::clone(&ctx), &seed);
+
+    let mut other_seed = seed;
+    other_seed[0] ^= 1;
+    let b = PolyMatrix::expand_from_seed(2, 3, Arc::clone(&ctx), &other_seed);
+
+    for ((x, y), z) in a1.entries().iter().zip(a2.entries()).zip(b.entries()) {
+        assert_eq!(x.coeffs(), y.coeffs());
+        assert_ne!(x.coeffs(), z.coeffs());
+        assert!(x.coeffs().iter().all(|&c| c < q));
+    }
+
+    // Entries are domain-separated by their position
+    assert_ne!(a1.get(0, 1).coeffs(), a1.get(1, 0).coeffs());
+}
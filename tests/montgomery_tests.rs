@@ -0,0 +1,102 @@
+use proptest::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 16;
+
+fn context(bits: usize) -> Arc<NttContext<N>> {
+    NttContext::<N>::new(find_first_prime_up(bits, N))
+}
+
+// Property: Montgomery products agree with Barrett ones, and conversion
+// round-trips, for moduli up to 62 bits
+proptest! {
+    #[test]
+    fn mont_mul_matches_modmul(
+        bits in 5usize..=62,
+        a in any::<u64>(),
+        b in any::<u64>(),
+    ) {
+        let class = CongruenceClass::new(find_first_prime_up(bits, 1));
+        let (a, b) = (class.reduce(a), class.reduce(b));
+
+        let (am, bm) = (class.to_mont(a), class.to_mont(b));
+        prop_assert!(am < class.q());
+        prop_assert_eq!(class.from_mont(am), a);
+        prop_assert_eq!(class.from_mont(class.mont_mul(am, bm)), class.modmul(a, b));
+        // One factor in Montgomery form gives the plain product
+        prop_assert_eq!(class.mont_mul(a, bm), class.modmul(a, b));
+    }
+}
+
+// Property: the Montgomery NTT produces the same evaluations as the Shoup
+// NTT and inverts it, in either representation
+proptest! {
+    #[test]
+    fn montgomery_ntt_matches_shoup(
+        bits in prop::sample::select(vec![20usize, 40, 62]),
+        coeffs in prop::array::uniform16(any::<u64>()),
+    ) {
+        let ctx = context(bits);
+        let a = NttPolynomial::from_unreduced(&coeffs, ctx);
+
+        let mut expected = a.clone();
+        expected.ntt_forward_shoup();
+        let mut evals = a.clone();
+        evals.ntt_forward_montgomery();
+        prop_assert_eq!(*evals.coeffs(), *expected.coeffs());
+        evals.ntt_inverse_montgomery();
+        prop_assert_eq!(*evals.coeffs(), *a.coeffs());
+
+        let mut mont = a.clone();
+        mont.convert_to_montgomery();
+        mont.ntt_forward_montgomery();
+        mont.convert_from_montgomery();
+        prop_assert_eq!(*mont.coeffs(), *expected.coeffs());
+    }
+}
+
+#[test]
+fn test_montgomery_convolution() {
+    let mut rng = StdRng::seed_from_u64(42);
+
+    for bits in [20, 40, 62] {
+        let ctx = context(bits);
+        let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+        let b = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+        let expected = *a.negacyclic_convolution_shoup(&b).coeffs();
+
+        assert_eq!(*a.negacyclic_convolution_montgomery(&b).coeffs(), expected);
+
+        // The same product computed entirely in Montgomery form
+        let (mut am, mut bm) = (a.clone(), b.clone());
+        for p in [&mut am, &mut bm] {
+            p.convert_to_montgomery();
+            p.ntt_forward_montgomery();
+        }
+        let mut product = am.pointwise_mul_montgomery(&bm);
+        product.ntt_inverse_montgomery();
+        product.convert_from_montgomery();
+        assert_eq!(*product.coeffs(), expected);
+    }
+}
+
+#[test]
+fn test_degree_one_montgomery_convolution() {
+    let ctx = NttContext::<1>::new(find_first_prime_up(30, 1));
+    let a = NttPolynomial::from_coeffs([12345], Arc::clone(&ctx));
+    let b = NttPolynomial::from_coeffs([678], ctx);
+    assert_eq!(
+        a.negacyclic_convolution_montgomery(&b).coeffs(),
+        (&a * &b).coeffs()
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cannot convolve polynomials: moduli differ")]
+fn test_mismatched_moduli_panic() {
+    let a = NttPolynomial::zero(context(50));
+    let b = NttPolynomial::zero(context(30));
+    let _ = a.negacyclic_convolution_montgomery(&b);
+}
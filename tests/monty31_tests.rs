@@ -0,0 +1,83 @@
+use proptest::prelude::*;
+use rust_ntt::monty31::{BABY_BEAR, KOALA_BEAR, Monty31Context, Monty31Field};
+use rust_ntt::{CongruenceClass, NttContext, NttPolynomial};
+use std::sync::Arc;
+
+#[test]
+fn test_field_parameters() {
+    assert_eq!(BABY_BEAR.p(), (1 << 31) - (1 << 27) + 1);
+    assert_eq!(BABY_BEAR.two_adicity(), 27);
+    assert_eq!(KOALA_BEAR.p(), (1 << 31) - (1 << 24) + 1);
+    assert_eq!(KOALA_BEAR.two_adicity(), 24);
+}
+
+#[test]
+fn test_two_adic_roots_have_exact_order() {
+    for field in [BABY_BEAR, KOALA_BEAR] {
+        let bits = field.two_adicity();
+        let root = field.two_adic_root(bits);
+
+        // root^(2^(bits-1)) = -1 certifies order exactly 2^bits
+        assert_eq!(field.pow(root, 1 << (bits - 1)), field.p() - 1);
+        assert_eq!(field.pow(root, 1 << bits), 1);
+    }
+}
+
+#[test]
+#[should_panic(expected = "no root of unity")]
+fn test_two_adic_root_beyond_adicity_panics() {
+    KOALA_BEAR.two_adic_root(25);
+}
+
+fn reference_convolution<const N: usize>(
+    field: Monty31Field,
+    a: &[u32; N],
+    b: &[u32; N],
+) -> [u32; N] {
+    let q = field.p() as u64;
+    let ctx = NttContext::<N>::new(q);
+    let a = NttPolynomial::from_coeffs(a.map(u64::from), Arc::clone(&ctx));
+    let b = NttPolynomial::from_coeffs(b.map(u64::from), Arc::clone(&ctx));
+    a.naive_negacyclic_convolution(&b)
+        .coeffs()
+        .map(|c| (c % q) as u32)
+}
+
+// Property: Montgomery arithmetic agrees with Barrett arithmetic
+proptest! {
+    #[test]
+    fn monty_arithmetic_matches_barrett(a in 0u32..BABY_BEAR.p(), b in 0u32..BABY_BEAR.p()) {
+        for field in [BABY_BEAR, KOALA_BEAR] {
+            let (a, b) = (a % field.p(), b % field.p());
+            let class = CongruenceClass::new(field.p() as u64);
+
+            prop_assert_eq!(field.from_monty(field.to_monty(a)), a);
+            prop_assert_eq!(field.mul(a, b) as u64, class.modmul(a as u64, b as u64));
+            prop_assert_eq!(field.add(a, b) as u64, (a as u64 + b as u64) % field.p() as u64);
+            prop_assert_eq!(field.sub(a, b) as u64, class.modsub(a as u64, b as u64));
+        }
+    }
+}
+
+// Property: Monty-31 NTT round-trips and multiplies like the reference convolution
+proptest! {
+    #[test]
+    fn monty_ntt_convolution_matches_naive(
+        a in prop::array::uniform16(0u32..KOALA_BEAR.p()),
+        b in prop::array::uniform16(0u32..KOALA_BEAR.p()),
+    ) {
+        for field in [BABY_BEAR, KOALA_BEAR] {
+            let a = a.map(|c| c % field.p());
+            let b = b.map(|c| c % field.p());
+            let ctx = Monty31Context::<16>::new(field);
+
+            let mut evals = a;
+            ctx.ntt_forward(&mut evals);
+            ctx.ntt_inverse(&mut evals);
+            prop_assert_eq!(evals, a);
+
+            let expected = reference_convolution(field, &a, &b);
+            prop_assert_eq!(ctx.negacyclic_convolution(&a, &b), expected);
+        }
+    }
+}
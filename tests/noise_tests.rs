@@ -0,0 +1,115 @@
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use rust_ntt::noise::{NoiseHeuristic, TrackedPoly, centered, infinity_norm};
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 16;
+
+fn small_poly(
+    ctx: &Arc<NttContext<N>>,
+    rng: &mut StdRng,
+    bound: i64,
+) -> NttPolynomial<N> {
+    let q = ctx.modulus();
+    let coeffs = std::array::from_fn(|_| {
+        let c: i64 = rng.random_range(-bound..=bound);
+        if c < 0 {
+            q - c.unsigned_abs()
+        } else {
+            c as u64
+        }
+    });
+    NttPolynomial::from_coeffs(coeffs, Arc::clone(ctx))
+}
+
+#[test]
+fn test_centered_representatives() {
+    assert_eq!(centered(0, 17), 0);
+    assert_eq!(centered(8, 17), 8);
+    assert_eq!(centered(9, 17), -8);
+    assert_eq!(centered(16, 17), -1);
+}
+
+#[test]
+fn test_automorphism_is_ring_homomorphism() {
+    let q = find_first_prime_down(40, N);
+    let ctx = NttContext::<N>::new(q);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+    let b = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+    for k in [3, 5, 2 * N - 1] {
+        let lhs = (&a * &b).automorphism(k);
+        let rhs = &a.automorphism(k) * &b.automorphism(k);
+        assert_eq!(lhs.coeffs(), rhs.coeffs());
+    }
+
+    // x -> x^(2N+1) is the identity
+    assert_eq!(a.automorphism(2 * N + 1).coeffs(), a.coeffs());
+}
+
+#[test]
+fn test_worst_case_bounds_hold() {
+    let q = find_first_prime_down(50, N);
+    let ctx = NttContext::<N>::new(q);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let a = TrackedPoly::fresh(
+        small_poly(&ctx, &mut rng, 3),
+        NoiseHeuristic::WorstCase,
+    );
+    let b = TrackedPoly::fresh(
+        small_poly(&ctx, &mut rng, 5),
+        NoiseHeuristic::WorstCase,
+    );
+    assert_eq!(a.bound(), infinity_norm(a.poly()) as f64);
+
+    let sum = &a + &b;
+    assert_eq!(sum.bound(), a.bound() + b.bound());
+    assert!(sum.is_within_bound());
+
+    let prod = &(&a * &b) - &a;
+    assert_eq!(prod.bound(), N as f64 * a.bound() * b.bound() + a.bound());
+    assert!(prod.is_within_bound());
+
+    let rotated = prod.automorphism(5).mul_scalar(q - 2);
+    assert_eq!(rotated.bound(), 2.0 * prod.bound());
+    assert!(rotated.is_within_bound());
+    assert!(rotated.noise_budget_bits() > 30.0);
+}
+
+#[test]
+fn test_canonical_embedding_is_tighter() {
+    // The worst case grows by N per product and the canonical embedding by
+    // about 6·sqrt(N)·σ, so the latter wins along a chain of products once
+    // N is large; for ternary operands at N = 1024 from the third product on
+    const M: usize = 1024;
+    let q = find_first_prime_down(60, M);
+    let ctx = NttContext::<M>::new(q);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let ternary: Vec<NttPolynomial<M>> = (0..4)
+        .map(|_| {
+            let coeffs =
+                std::array::from_fn(|_| [q - 1, 0, 1][rng.random_range(0..3)]);
+            NttPolynomial::from_coeffs(coeffs, Arc::clone(&ctx))
+        })
+        .collect();
+    let chain = |heuristic| {
+        ternary
+            .iter()
+            .map(|p| TrackedPoly::fresh(p.clone(), heuristic))
+            .reduce(|acc, p| &acc * &p)
+            .unwrap()
+    };
+
+    let wc = chain(NoiseHeuristic::WorstCase);
+    let ce = chain(NoiseHeuristic::CanonicalEmbedding);
+
+    assert!(ce.bound() < wc.bound());
+    assert!(ce.is_within_bound());
+    assert!(wc.is_within_bound());
+    assert_eq!(wc.measured_norm(), ce.measured_norm());
+    assert!(ce.heuristic() == NoiseHeuristic::CanonicalEmbedding);
+}
@@ -1,8 +1,10 @@
 use rand::{Rng, rng};
 use rust_ntt::math::{
-    find_first_prime_up, find_generator, find_next_prime_up, modnegate,
+    find_first_prime_down, find_first_prime_up, find_generator, find_next_prime_up,
+    modnegate,
 };
 use rust_ntt::*;
+use std::sync::Arc;
 
 // const Q : u64 = 741507920154517877;
 const Q: u64 = 1u64 << 62 - 1;
@@ -390,3 +392,126 @@ fn test_modmul_shoup_as64_struct() {
         assert_eq!(class.modmul_shoup_as64(a, b, prec), expected);
     }
 }
+
+/// Largest NTT-friendly prime for N = 1024 below 2^63
+fn top_prime() -> u64 {
+    find_first_prime_down(63, 1024)
+}
+
+#[test]
+fn test_add_sub_at_top_of_range() {
+    let q = top_prime();
+    assert!(q > (1 << 62) + (1 << 61));
+    let class = CongruenceClass::new(q);
+    let edges = [0, 1, 2, q / 2, q / 2 + 1, q - 2, q - 1];
+
+    for a in edges {
+        for b in edges {
+            let sum = (a as u128 + b as u128) % q as u128;
+            let diff = (a as u128 + q as u128 - b as u128) % q as u128;
+            assert_eq!(class.modadd(a, b) as u128, sum, "{a} + {b}");
+            assert_eq!(modadd(a, b, q) as u128, sum, "{a} + {b}");
+            assert_eq!(modadd_naive(a, b, q) as u128, sum, "{a} + {b}");
+            assert_eq!(class.modsub(a, b) as u128, diff, "{a} - {b}");
+            assert_eq!(modsub(a, b, q) as u128, diff, "{a} - {b}");
+        }
+    }
+}
+
+#[test]
+fn test_add_sub_unreduced_operand_does_not_wrap() {
+    // A lazily reduced a < 2q no longer fits a + b in 64 bits
+    let q = top_prime();
+    let class = CongruenceClass::new(q);
+    let a = 2 * q - 1;
+    assert!(a.checked_add(q - 1).is_none());
+
+    assert_eq!(class.modadd(a, q - 1) % q, q - 2);
+    assert_eq!(modadd(a, q - 1, q) % q, q - 2);
+    assert_eq!(class.modsub(a, q - 1) % q, 0);
+    assert_eq!(modsub(a, q - 1, q) % q, 0);
+}
+
+#[test]
+fn test_ring_at_top_of_range() {
+    const N: usize = 1024;
+    let ctx = NttContext::<N>::new(top_prime());
+    let q = ctx.modulus();
+    let a = NttPolynomial::from_coeffs([q - 1; N], Arc::clone(&ctx));
+
+    // Every coefficient is -1, so a + a = -2 and a·x^0 = a
+    assert_eq!((&a + &a).coeffs(), &[q - 2; N]);
+    assert_eq!((&a - &a).coeffs(), &[0; N]);
+
+    let mut one = [0; N];
+    one[0] = 1;
+    let one = NttPolynomial::from_coeffs(one, ctx);
+    assert_eq!((&a * &one).coeffs(), a.coeffs());
+    assert_eq!(a.negacyclic_convolution_shoup(&one).coeffs(), a.coeffs());
+}
+
+#[test]
+fn test_modulus_shape_detection() {
+    let mersenne = (1u64 << 61) - 1;
+    assert_eq!(
+        CongruenceClass::new(mersenne).shape(),
+        ModulusShape::Solinas { a: 61, b: 1 }
+    );
+    assert_eq!(
+        CongruenceClass::new((1 << 62) - 57).shape(),
+        ModulusShape::PseudoMersenne { a: 62, c: 57 }
+    );
+    // 2^23 - 2^13 + 1: the tail is too long for two folds
+    assert_eq!(CongruenceClass::new(8380417).shape(), ModulusShape::Generic);
+    assert_eq!(
+        CongruenceClass::new(741507920154517877).shape(),
+        ModulusShape::Generic
+    );
+
+    // Primes found downwards from 2^k are NTT-friendly and special
+    let ctx = NttContext::<1024>::new(find_first_prime_down(58, 1024));
+    assert_ne!(ctx.modulus_shape(), ModulusShape::Generic);
+}
+
+#[test]
+fn test_special_modmul_vs_naive() {
+    let mut rng = rng();
+    let moduli = [
+        (1u64 << 61) - 1,
+        (1 << 62) - 57,
+        find_first_prime_down(58, 1 << 12),
+        find_first_prime_down(40, 1 << 10),
+        (1 << 31) - 1,
+    ];
+
+    for q in moduli {
+        let class = CongruenceClass::new(q);
+        assert_ne!(class.shape(), ModulusShape::Generic);
+        assert_eq!(class.modmul(q - 1, q - 1), 1);
+        assert_eq!(class.reduce(u64::MAX), u64::MAX % q);
+
+        for _ in 0..1000 {
+            let a: u64 = rng.random_range(0..q);
+            let b: u64 = rng.random_range(0..q);
+            assert_eq!(class.modmul(a, b), modmul_naive(a, b, q), "q = {q}");
+            assert_eq!(class.modsquare(a), modmul_naive(a, a, q), "q = {q}");
+        }
+    }
+}
+
+#[test]
+fn test_modmul_shoup_lazy_stays_below_2q() {
+    let mut rng = rng();
+    let q = find_first_prime_down(62, 1024);
+    let class = CongruenceClass::new(q);
+
+    for _ in 0..1000 {
+        // Any u64 operand, as lazy butterflies pass values up to 4q
+        let a: u64 = rng.random();
+        let b: u64 = rng.random_range(0..q);
+        let lazy = class.modmul_shoup_lazy(a, b, class.precompute_shoup(b));
+
+        assert!(lazy < 2 * q);
+        assert_eq!(lazy % q, modmul_naive(a % q, b, q));
+    }
+}
@@ -1,6 +1,11 @@
 use proptest::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
 use rust_ntt::math::find_first_prime_up;
-use rust_ntt::{NttContext, NttPolynomial};
+use rust_ntt::sampling;
+use rust_ntt::{
+    CoefficientOutOfRange, ContextMismatch, InvalidCoefficients, LengthMismatch,
+    Lift, NttContext, NttPolynomial,
+};
 use std::sync::Arc;
 
 // Custom strategy for generating valid contexts
@@ -31,6 +36,39 @@ proptest! {
     }
 }
 
+// Property: scalar and affine operations agree with multiplication by
+// constant polynomials, and a constant adds to every slot after the NTT
+proptest! {
+    #[test]
+    fn scalar_and_affine_match_constant_polynomials(
+        p in prop::array::uniform4(0u64..1000u64),
+        q in prop::array::uniform4(0u64..1000u64),
+        a in any::<u64>(),
+        b in any::<u64>(),
+        ctx in valid_context_strategy()
+    ) {
+        let m = ctx.modulus();
+        let poly = |c: [u64; 4]| NttPolynomial::from_coeffs(c.map(|x| x % m), Arc::clone(&ctx));
+        let constant = |c: u64| poly([c % m, 0, 0, 0]);
+        let (p, q) = (poly(p), poly(q));
+
+        let expected = &(&p * &constant(a)) + &(&q * &constant(b));
+        prop_assert_eq!(*NttPolynomial::affine(a, &p, b, &q).coeffs(), *expected.coeffs());
+        prop_assert_eq!(*p.add_scalar(a).coeffs(), *(&p + &constant(a)).coeffs());
+        prop_assert_eq!(*p.sub_scalar(a).coeffs(), *(&p - &constant(a)).coeffs());
+
+        let mut shifted = p.add_scalar(a);
+        shifted.ntt_forward();
+        let mut evals = p.clone();
+        evals.ntt_forward();
+        prop_assert_eq!(*shifted.coeffs(), *evals.add_scalar_to_all(a).coeffs());
+        prop_assert_eq!(
+            *evals.add_scalar_to_all(a).sub_scalar_from_all(a).coeffs(),
+            *evals.coeffs()
+        );
+    }
+}
+
 // Property: Shoup NTT/INTT is equivalent to regular version
 proptest! {
     #[test]
@@ -194,3 +232,769 @@ proptest! {
         prop_assert_eq!(a_copy.coeffs(), expected_mul.coeffs());
     }
 }
+
+// Property: Low-degree extension agrees with direct evaluation on the larger domain
+proptest! {
+    #[test]
+    fn lde_matches_interpolant_evaluation(
+        coeffs in prop::array::uniform4(0u64..1000u64),
+    ) {
+        let q = find_first_prime_up(20, 16);
+        let small = NttContext::<4>::new(q);
+        let large = NttContext::<16>::new(q);
+
+        let evals = NttPolynomial::from_coeffs(coeffs, Arc::clone(&small));
+        let extended = evals.lde(&large);
+
+        let mut interpolant = evals.clone();
+        interpolant.ntt_inverse();
+
+        for (slot, &x) in large.evaluation_points().iter().enumerate() {
+            prop_assert_eq!(extended.coeffs()[slot], interpolant.evaluate(x));
+        }
+
+        // Blowing up by a factor of one is the identity
+        let same = evals.lde(&small);
+        prop_assert_eq!(same.coeffs(), evals.coeffs());
+    }
+}
+
+// Property: Coset NTT evaluates on the shifted domain and inverts cleanly
+proptest! {
+    #[test]
+    fn coset_ntt_evaluates_on_shifted_domain(
+        coeffs in prop::array::uniform4(0u64..1000u64),
+        ctx in valid_context_strategy()
+    ) {
+        let valid_coeffs = coeffs.map(|c| c % ctx.modulus());
+        let original = NttPolynomial::from_coeffs(valid_coeffs, Arc::clone(&ctx));
+
+        let q = ctx.modulus();
+        let mut coset = original.clone();
+        coset.ntt_forward_coset();
+
+        for (slot, &x) in ctx.coset_evaluation_points().iter().enumerate() {
+            prop_assert_eq!(coset.coeffs()[slot], original.evaluate(x));
+            // x^N + 1 does not vanish on the coset
+            prop_assert_ne!(ctx.class().modexp(x, 4), q - 1);
+        }
+
+        coset.ntt_inverse_coset();
+        prop_assert_eq!(coset.coeffs(), original.coeffs());
+    }
+}
+
+// Property: Linear convolution is the untruncated schoolbook product
+proptest! {
+    #[test]
+    fn linear_convolution_matches_schoolbook(
+        a in prop::array::uniform8(any::<u64>()),
+        b in prop::array::uniform8(any::<u64>()),
+    ) {
+        let q = find_first_prime_up(40, 8);
+        let ctx = NttContext::<8>::new(q);
+        let class = *ctx.class();
+        let a = NttPolynomial::from_coeffs(a.map(|c| c % q), Arc::clone(&ctx));
+        let b = NttPolynomial::from_coeffs(b.map(|c| c % q), Arc::clone(&ctx));
+
+        let mut expected = vec![0u64; 15];
+        for (i, &x) in a.coeffs().iter().enumerate() {
+            for (j, &y) in b.coeffs().iter().enumerate() {
+                expected[i + j] = (expected[i + j] + class.modmul(x, y)) % q;
+            }
+        }
+
+        prop_assert_eq!(a.linear_convolution(&b), expected);
+    }
+}
+
+// Property: Scaling the argument by c evaluates the polynomial at c·x
+proptest! {
+    #[test]
+    fn scale_argument_substitutes_cx(
+        coeffs in prop::array::uniform4(0u64..1000u64),
+        c in 0u64..1000u64,
+        x in 0u64..1000u64,
+        ctx in valid_context_strategy()
+    ) {
+        let q = ctx.modulus();
+        let a = NttPolynomial::from_coeffs(coeffs.map(|v| v % q), Arc::clone(&ctx));
+        let (c, x) = (c % q, x % q);
+
+        let scaled = a.scale_argument(c);
+        prop_assert_eq!(scaled.evaluate(x), a.evaluate(ctx.class().modmul(c, x)));
+    }
+}
+
+// Property: ct_eq agrees with comparing canonical coefficients
+proptest! {
+    #[test]
+    fn ct_eq_matches_canonical_comparison(
+        coeffs in prop::array::uniform4(0u64..1000u64),
+        index in 0usize..4,
+        delta in 0u64..3u64,
+        ctx in valid_context_strategy()
+    ) {
+        let q = ctx.modulus();
+        let coeffs = coeffs.map(|v| v % q);
+        let mut other = coeffs;
+        other[index] = (other[index] + delta) % q;
+
+        let a = NttPolynomial::from_coeffs(coeffs, Arc::clone(&ctx));
+        let b = NttPolynomial::from_coeffs(other, Arc::clone(&ctx));
+        prop_assert_eq!(a.ct_eq(&b), delta == 0);
+        prop_assert_eq!(ctx.class().ct_eq(coeffs[index], other[index]), delta == 0);
+    }
+}
+
+#[test]
+fn test_ct_eq_accepts_unreduced_and_rejects_other_rings() {
+    let ctx = NttContext::<4>::new(17);
+    let zero = NttPolynomial::zero(Arc::clone(&ctx));
+    let q = NttPolynomial::from_coeffs([17; 4], Arc::clone(&ctx));
+    assert!(zero.ct_eq(&q));
+    assert!(zero.ct_eq(&(&q - &q)));
+
+    let other = NttPolynomial::zero(NttContext::<4>::new(41));
+    assert!(!zero.ct_eq(&other));
+}
+
+#[test]
+fn test_probably_equal_detects_single_coefficient_change() {
+    const N: usize = 4096;
+    let ctx = NttContext::<N>::new(find_first_prime_up(40, N));
+    let mut rng = StdRng::seed_from_u64(42);
+    let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+    let mut b = a.clone();
+    assert!(a.probably_equal(&b, &mut rng));
+    b.coeffs_mut()[N - 1] = ctx.class().modadd(b.coeffs()[N - 1], 1);
+    assert!(!a.probably_equal(&b, &mut rng));
+
+    let other =
+        NttPolynomial::zero(NttContext::<N>::new(find_first_prime_up(41, N)));
+    assert!(!NttPolynomial::zero(ctx).probably_equal(&other, &mut rng));
+}
+
+#[test]
+fn test_checked_ops_report_context_mismatch() {
+    let a = NttPolynomial::from_coeffs([1, 2, 3, 4], NttContext::<4>::new(17));
+    let b = NttPolynomial::from_coeffs([1, 2, 3, 4], NttContext::<4>::new(41));
+    let mismatch = ContextMismatch {
+        lhs_modulus: 17,
+        rhs_modulus: 41,
+    };
+
+    assert_eq!(a.checked_add(&b).unwrap_err(), mismatch);
+    assert_eq!(a.checked_sub(&b).unwrap_err(), mismatch);
+    assert_eq!(a.checked_mul(&b).unwrap_err(), mismatch);
+    assert_eq!(mismatch.to_string(), "moduli differ, 17 vs 41");
+
+    let c = a.clone();
+    assert!(a.checked_add(&c).unwrap().ct_eq(&(&a + &c)));
+    assert!(a.checked_sub(&c).unwrap().ct_eq(&(&a - &c)));
+    assert!(a.checked_mul(&c).unwrap().ct_eq(&a.mul_unchecked(&c)));
+}
+
+#[test]
+#[should_panic(expected = "Cannot add polynomials: moduli differ, 17 vs 41")]
+fn test_add_panics_on_context_mismatch() {
+    let a = NttPolynomial::zero(NttContext::<4>::new(17));
+    let b = NttPolynomial::zero(NttContext::<4>::new(41));
+    let _ = &a + &b;
+}
+
+#[test]
+#[should_panic(expected = "Cannot convolve polynomials")]
+fn test_mul_assign_panics_on_context_mismatch() {
+    let mut a = NttPolynomial::zero(NttContext::<4>::new(17));
+    let b = NttPolynomial::zero(NttContext::<4>::new(41));
+    a *= &b;
+}
+
+// Property: A centered lift preserves small signed coefficients
+proptest! {
+    #[test]
+    fn centered_lift_preserves_small_values(
+        values in prop::array::uniform4(-100i64..=100),
+        ctx in valid_context_strategy()
+    ) {
+        let target = NttContext::<4>::new(find_first_prime_up(40, 4));
+        let embed = |q: u64| values.map(|v| v.rem_euclid(q as i64) as u64);
+
+        let a = NttPolynomial::from_coeffs(embed(ctx.modulus()), Arc::clone(&ctx));
+        let b = a.convert_to(Arc::clone(&target), Lift::Centered);
+        prop_assert_eq!(b.coeffs(), &embed(target.modulus()));
+
+        // Plain reduction into a larger modulus keeps the residues unchanged
+        let c = a.convert_to(Arc::clone(&target), Lift::Plain);
+        prop_assert_eq!(c.coeffs(), a.coeffs());
+    }
+}
+
+#[test]
+fn test_convert_to_smaller_modulus() {
+    let a = NttPolynomial::from_coeffs([40, 20, 21, 0], NttContext::<4>::new(41));
+    let target = NttContext::<4>::new(17);
+
+    // 20 → 20 mod 17, 21 ≡ -20 → -3 ≡ 14, 40 ≡ -1 → 16
+    assert_eq!(
+        a.convert_to(Arc::clone(&target), Lift::Centered).coeffs(),
+        &[16, 3, 14, 0]
+    );
+    assert_eq!(a.convert_to(target, Lift::Plain).coeffs(), &[6, 3, 4, 0]);
+}
+
+#[test]
+fn test_canonicalize() {
+    let ctx = NttContext::<4>::new(17);
+    let mut a = NttPolynomial::from_coeffs([1, 17, 35, 16], ctx);
+    assert!(!a.is_canonical());
+
+    a.canonicalize();
+    assert!(a.is_canonical());
+    assert_eq!(a.coeffs(), &[1, 0, 1, 16]);
+}
+
+#[test]
+fn test_strict_context_validates_coefficients() {
+    let lax = NttContext::<4>::new(17);
+    let strict = NttContext::<4>::new_strict(17);
+    assert!(!lax.is_strict());
+    assert!(strict.is_strict());
+
+    let err = NttPolynomial::try_from_coeffs([0, 0, 0, 20], Arc::clone(&lax))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        CoefficientOutOfRange {
+            index: 3,
+            value: 20,
+            modulus: 17
+        }
+    );
+    assert!(NttPolynomial::try_from_coeffs([0, 0, 0, 16], strict).is_ok());
+
+    // Lax contexts keep accepting unreduced input in from_coeffs
+    assert!(!NttPolynomial::from_coeffs([0, 0, 0, 20], lax).is_canonical());
+}
+
+#[test]
+fn test_try_from_vec_checks_length_and_range() {
+    let ctx = NttContext::<4>::new(17);
+    let convert = |coeffs: Vec<u64>| {
+        NttPolynomial::try_from((coeffs, Arc::clone(&ctx))).map(|p| *p.coeffs())
+    };
+
+    assert_eq!(convert(vec![1, 2, 3, 16]), Ok([1, 2, 3, 16]));
+    assert_eq!(
+        convert(vec![1, 2]),
+        Err(InvalidCoefficients::Length(LengthMismatch::TooFew {
+            expected: 4,
+            found: 2
+        }))
+    );
+    assert_eq!(
+        convert(vec![0; 5]),
+        Err(InvalidCoefficients::Length(LengthMismatch::TooMany {
+            expected: 4
+        }))
+    );
+    assert_eq!(
+        convert(vec![0, 40, 0, 0]),
+        Err(InvalidCoefficients::OutOfRange(CoefficientOutOfRange {
+            index: 1,
+            value: 40,
+            modulus: 17
+        }))
+    );
+}
+
+#[test]
+#[should_panic(expected = "coefficient 0 = 17 is not below q = 17")]
+fn test_strict_context_panics_in_from_coeffs() {
+    NttPolynomial::from_coeffs([17, 0, 0, 0], NttContext::<4>::new_strict(17));
+}
+
+// The last inverse stage carries the N^(-1) scaling, including the
+// degenerate sizes where it is the only stage or there is none
+#[test]
+fn test_inverse_roundtrip_small_degrees() {
+    fn check<const N: usize>(coeffs: [u64; N]) {
+        let ctx = NttContext::<N>::new(find_first_prime_up(20, N));
+        let mut a = NttPolynomial::from_coeffs(coeffs, Arc::clone(&ctx));
+        let mut b = a.clone();
+
+        a.ntt_forward();
+        a.ntt_inverse();
+        b.ntt_forward_shoup();
+        b.ntt_inverse_shoup();
+        assert_eq!(a.coeffs(), &coeffs);
+        assert_eq!(b.coeffs(), &coeffs);
+    }
+
+    check([5]);
+    check([3, 7]);
+    check([1, 2, 3, 4]);
+}
+
+#[test]
+fn test_degree_one_and_two_edge_cases() {
+    fn check<const N: usize>(coeffs: [u64; N]) {
+        let ctx = NttContext::<N>::new(find_first_prime_up(20, N));
+        let a = NttPolynomial::from_coeffs(coeffs, Arc::clone(&ctx));
+        let expected = a.naive_negacyclic_convolution(&a);
+
+        // Every transform variant evaluates at the same points
+        let mut evals = a.clone();
+        evals.ntt_forward();
+        for forward in [
+            NttPolynomial::ntt_forward_shoup,
+            NttPolynomial::ntt_forward_twisted,
+            |p: &mut NttPolynomial<N>| p.ntt_forward_padded(N),
+        ] {
+            let mut b = a.clone();
+            forward(&mut b);
+            assert_eq!(b.coeffs(), evals.coeffs());
+        }
+        let points = ctx.evaluation_points();
+        for (&value, &point) in evals.coeffs().iter().zip(points.iter()) {
+            assert_eq!(value, a.evaluate(point));
+        }
+
+        let mut coset = a.clone();
+        coset.ntt_forward_coset();
+        coset.ntt_inverse_coset();
+        assert_eq!(coset.coeffs(), &coeffs);
+
+        assert_eq!(a.negacyclic_convolution(&a).coeffs(), expected.coeffs());
+        assert_eq!(
+            a.negacyclic_convolution_shoup(&a).coeffs(),
+            expected.coeffs()
+        );
+        assert_eq!(a.rotate_slots(1).coeffs(), a.rotate_slots(-1).coeffs());
+        assert_eq!(a.conjugate().conjugate().coeffs(), &coeffs);
+    }
+
+    // Z_q[x]/(x + 1) is Z_q, with the single evaluation point -1
+    check([5]);
+    check([3, 7]);
+}
+
+// Property: the twisted cyclic formulation produces the same evaluations,
+// and each inverse undoes the other forward
+proptest! {
+    #[test]
+    fn twisted_ntt_equivalent_to_regular(
+        coeffs in prop::array::uniform4(0u64..1000u64),
+        ctx in valid_context_strategy()
+    ) {
+        let valid_coeffs = coeffs.map(|c| c % ctx.modulus());
+
+        let mut regular = NttPolynomial::from_coeffs(valid_coeffs, Arc::clone(&ctx));
+        let mut twisted = NttPolynomial::from_coeffs(valid_coeffs, Arc::clone(&ctx));
+
+        regular.ntt_forward();
+        twisted.ntt_forward_twisted();
+        prop_assert_eq!(regular.coeffs(), twisted.coeffs());
+
+        regular.ntt_inverse_twisted();
+        twisted.ntt_inverse_shoup();
+        prop_assert_eq!(regular.coeffs(), &valid_coeffs);
+        prop_assert_eq!(twisted.coeffs(), &valid_coeffs);
+    }
+}
+
+#[test]
+fn test_twisted_ntt_large_degree() {
+    const N: usize = 1024;
+    let ctx = NttContext::<N>::new(find_first_prime_up(40, N));
+    let coeffs = std::array::from_fn(|i| (i as u64 * 7919) % ctx.modulus());
+
+    let mut a = NttPolynomial::from_coeffs(coeffs, Arc::clone(&ctx));
+    let mut b = a.clone();
+    a.ntt_forward_shoup();
+    b.ntt_forward_twisted();
+    assert_eq!(a.coeffs(), b.coeffs());
+
+    b.ntt_inverse_twisted();
+    assert_eq!(b.coeffs(), &coeffs);
+}
+
+// Property: the unrolled Shoup transforms for N = 4, 8, 16 agree with the
+// looped Barrett ones and invert them
+proptest! {
+    #[test]
+    fn unrolled_small_degrees_match_loops(
+        bits in prop::sample::select(vec![20usize, 40, 62]),
+        coeffs in prop::array::uniform16(any::<u64>()),
+    ) {
+        fn check<const N: usize>(bits: usize, coeffs: &[u64; 16]) -> Result<(), TestCaseError> {
+            let ctx = NttContext::<N>::new(find_first_prime_up(bits, N));
+            let short: [u64; N] = std::array::from_fn(|i| coeffs[i]);
+            let a = NttPolynomial::from_unreduced(&short, ctx);
+
+            let (mut looped, mut unrolled) = (a.clone(), a.clone());
+            looped.ntt_forward();
+            unrolled.ntt_forward_shoup();
+            prop_assert_eq!(unrolled.coeffs(), looped.coeffs());
+            unrolled.ntt_inverse_shoup();
+            prop_assert_eq!(unrolled.coeffs(), a.coeffs());
+            Ok(())
+        }
+
+        check::<4>(bits, &coeffs)?;
+        check::<8>(bits, &coeffs)?;
+        check::<16>(bits, &coeffs)?;
+    }
+}
+
+// From degree 1024 the scalar Shoup kernels switch to radix-4 passes, and
+// on AVX2 CPUs the vector kernels run instead; the Barrett transforms stay
+// radix-2, so they serve as the reference for both an even and an odd
+// number of stages. The unit tests in src/ntt.rs force the scalar kernels.
+#[test]
+fn test_radix4_matches_radix2() {
+    fn check<const N: usize>() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for bits in [30, 62] {
+            let ctx = NttContext::<N>::new(find_first_prime_up(bits, N));
+            let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+            let (mut radix2, mut radix4) = (a.clone(), a.clone());
+            radix2.ntt_forward();
+            radix4.ntt_forward_shoup();
+            assert_eq!(radix4.coeffs(), radix2.coeffs());
+
+            radix2.ntt_inverse();
+            radix4.ntt_inverse_shoup();
+            assert_eq!(radix4.coeffs(), a.coeffs());
+            assert_eq!(radix2.coeffs(), a.coeffs());
+        }
+    }
+
+    check::<1024>();
+    check::<2048>();
+}
+
+// Property: skipping the all-zero stages gives the full forward transform,
+// and short convolution agrees with the padded full one
+proptest! {
+    #[test]
+    fn padded_forward_matches_full(
+        a in prop::collection::vec(0u64..1000u64, 0..=16),
+        b in prop::collection::vec(0u64..1000u64, 0..=16),
+    ) {
+        let ctx = NttContext::<16>::new(find_first_prime_up(20, 16));
+
+        let mut padded = NttPolynomial::from_short_coeffs(&a, Arc::clone(&ctx));
+        let mut full = padded.clone();
+        padded.ntt_forward_padded(a.len());
+        full.ntt_forward_shoup();
+        prop_assert_eq!(padded.coeffs(), full.coeffs());
+
+        let short = NttPolynomial::convolve_short(&a, &b, Arc::clone(&ctx));
+        let expected = NttPolynomial::from_short_coeffs(&a, Arc::clone(&ctx))
+            .negacyclic_convolution(&NttPolynomial::from_short_coeffs(&b, ctx));
+        prop_assert_eq!(short.coeffs(), expected.coeffs());
+    }
+}
+
+#[test]
+#[should_panic(expected = "expected at most 4 coefficients, got 5")]
+fn test_from_short_coeffs_rejects_long_input() {
+    NttPolynomial::from_short_coeffs(&[1; 5], NttContext::<4>::new(17));
+}
+
+#[test]
+fn test_transform_to_leaves_source_untouched() {
+    let ctx = NttContext::<16>::new(find_first_prime_up(30, 16));
+    let other = NttContext::<16>::new(find_first_prime_up(31, 16));
+    let coeffs = std::array::from_fn(|i| i as u64 * 3 + 1);
+    let a = NttPolynomial::from_coeffs(coeffs, Arc::clone(&ctx));
+
+    // The output starts over another ring and is fully overwritten
+    let mut evals = NttPolynomial::from_coeffs([5; 16], other);
+    a.ntt_forward_to(&mut evals);
+    assert_eq!(a.coeffs(), &coeffs);
+    assert!(Arc::ptr_eq(evals.context(), &ctx));
+
+    let mut expected = a.clone();
+    expected.ntt_forward_shoup();
+    assert_eq!(evals.coeffs(), expected.coeffs());
+
+    let mut back = NttPolynomial::zero(ctx);
+    evals.ntt_inverse_to(&mut back);
+    assert_eq!(back.coeffs(), &coeffs);
+    assert_eq!(evals.coeffs(), expected.coeffs());
+}
+
+#[test]
+fn test_construction_from_iterators() {
+    let ctx = NttContext::<4>::new(17);
+
+    let a = NttPolynomial::try_from_iter(1..=4, Arc::clone(&ctx)).unwrap();
+    assert_eq!(a.coeffs(), &[1, 2, 3, 4]);
+
+    assert_eq!(
+        NttPolynomial::try_from_iter([1, 2], Arc::clone(&ctx)).unwrap_err(),
+        LengthMismatch::TooFew {
+            expected: 4,
+            found: 2
+        }
+    );
+    // Unbounded input is cut off after the first extra coefficient
+    let err = NttPolynomial::try_from_iter(0.., Arc::clone(&ctx)).unwrap_err();
+    assert_eq!(err, LengthMismatch::TooMany { expected: 4 });
+    assert_eq!(err.to_string(), "expected 4 coefficients, got more");
+
+    let mut builder = NttPolynomial::builder(Arc::clone(&ctx));
+    builder.extend([5, 6, 7]);
+    assert_eq!(builder.len(), 3);
+    builder.push(8);
+    assert_eq!(builder.clone().finish().unwrap().coeffs(), &[5, 6, 7, 8]);
+    builder.push(9);
+    assert!(builder.finish().is_err());
+}
+
+#[test]
+fn test_operator_overloads_for_owned_operands_and_scalars() {
+    let ctx = NttContext::<4>::new(17);
+    let a = NttPolynomial::from_coeffs([1, 2, 3, 4], Arc::clone(&ctx));
+    let b = NttPolynomial::from_coeffs([5, 6, 7, 16], Arc::clone(&ctx));
+
+    let sum = &a + &b;
+    assert_eq!((a.clone() + b.clone()).coeffs(), sum.coeffs());
+    assert_eq!((&a + b.clone()).coeffs(), sum.coeffs());
+
+    let diff = &a - &b;
+    assert_eq!((a.clone() - b.clone()).coeffs(), diff.coeffs());
+    assert_eq!((&a - b.clone()).coeffs(), diff.coeffs());
+
+    let prod = &a * &b;
+    assert_eq!((a.clone() * b.clone()).coeffs(), prod.coeffs());
+    assert_eq!((&a * b.clone()).coeffs(), prod.coeffs());
+
+    let mut c = a.clone();
+    c += b.clone();
+    c -= a.clone();
+    assert_eq!(c.coeffs(), b.coeffs());
+    c *= a.clone();
+    assert_eq!(c.coeffs(), prod.coeffs());
+
+    // Scalars need not be reduced: 3 + 17 ≡ 3
+    assert_eq!((&a * 3).coeffs(), &[3, 6, 9, 12]);
+    assert_eq!((a.clone() * 20).coeffs(), &[3, 6, 9, 12]);
+    let mut d = a.clone();
+    d *= u64::MAX;
+    assert_eq!(d.coeffs(), a.mul_scalar(u64::MAX % 17).coeffs());
+
+    // Expressions compose without references
+    let e = a.clone() * 2 + b.clone() - a.clone() * b.clone();
+    assert_eq!(e.coeffs(), (&(&(&a * 2) + &b) - &prod).coeffs());
+}
+
+#[test]
+fn test_large_degree_stays_off_the_stack() {
+    // Half a megabyte per coefficient array; test threads get 2 MiB of stack
+    const N: usize = 1 << 16;
+    let ctx = NttContext::<N>::new(find_first_prime_up(50, N));
+
+    let coeffs: Box<[u64]> = (0..N as u64).collect();
+    let a = NttPolynomial::from_boxed_coeffs(coeffs.try_into().unwrap(), ctx);
+    let one = NttPolynomial::from_short_coeffs(&[1], Arc::clone(a.context()));
+
+    let product = &a * &one;
+    let sum = product.clone() + -a.clone();
+    assert!(sum.coeffs().iter().all(|&c| c == 0));
+    assert_eq!(product.into_boxed_coeffs()[N - 1], N as u64 - 1);
+}
+
+#[test]
+fn test_large_degree_operators_on_small_stack() {
+    // One coefficient array alone is a megabyte, half the thread's stack
+    const N: usize = 1 << 17;
+    let worker = std::thread::Builder::new().stack_size(2 << 20).spawn(|| {
+        let ctx = NttContext::<N>::new(find_first_prime_up(50, N));
+        let mut rng = StdRng::seed_from_u64(42);
+        let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+        let b = NttPolynomial::sample_random(ctx, &mut rng);
+
+        let sum = &a + &b;
+        let difference = &sum - &b;
+        assert_eq!(difference.coeffs(), a.coeffs());
+        assert!((&difference - &a).coeffs().iter().all(|&c| c == 0));
+    });
+    worker.unwrap().join().unwrap();
+}
+
+#[test]
+fn test_large_degree_sampling_on_small_stack() {
+    const N: usize = 1 << 17;
+    let worker = std::thread::Builder::new().stack_size(2 << 20).spawn(|| {
+        let ctx = NttContext::<N>::new(find_first_prime_up(50, N));
+        let mut rng = StdRng::seed_from_u64(42);
+        let a = sampling::uniform(Arc::clone(&ctx), &mut rng);
+        let e = sampling::cbd(Arc::clone(&ctx), 2, &mut rng);
+        assert!(a.coeffs().iter().all(|&c| c < ctx.modulus()));
+        assert!(e.coeffs().iter().all(|&c| c <= 2 || c >= ctx.modulus() - 2));
+
+        let points = ctx.evaluation_points();
+        assert_eq!(points[0], ctx.psi());
+    });
+    worker.unwrap().join().unwrap();
+}
+
+// Property: the in-place and buffer-reusing products agree with `*`
+proptest! {
+    #[test]
+    fn in_place_convolutions_match_operator(
+        a in prop::array::uniform4(0u64..12289),
+        b in prop::array::uniform4(0u64..12289),
+    ) {
+        let ctx = NttContext::<4>::new(12289);
+        let x = NttPolynomial::from_coeffs(a, Arc::clone(&ctx));
+        let y = NttPolynomial::from_coeffs(b, Arc::clone(&ctx));
+        let expected = &x * &y;
+
+        let mut assigned = x.clone();
+        assigned.negacyclic_convolution_assign(&y);
+        prop_assert_eq!(assigned.coeffs(), expected.coeffs());
+
+        // The output buffer may start over another context
+        let mut out = NttPolynomial::zero(NttContext::<4>::new(17));
+        x.negacyclic_convolution_into(&y, &mut out);
+        prop_assert_eq!(out.coeffs(), expected.coeffs());
+        prop_assert!(Arc::ptr_eq(out.context(), &ctx));
+
+        let mut y_evals = y.clone();
+        y_evals.ntt_forward_shoup();
+        let mut from_evals = x.clone();
+        from_evals.negacyclic_convolution_assign_evals(&y_evals);
+        prop_assert_eq!(from_evals.coeffs(), expected.coeffs());
+    }
+}
+
+// Property: accumulating pointwise products in the NTT domain gives the sum
+// of the negacyclic products
+proptest! {
+    #[test]
+    fn pointwise_mul_add_accumulates_products(
+        a in prop::array::uniform4(0u64..12289),
+        b in prop::array::uniform4(0u64..12289),
+        s in prop::array::uniform4(0u64..12289),
+    ) {
+        let ctx = NttContext::<4>::new(12289);
+        let poly = |c| NttPolynomial::from_coeffs(c, Arc::clone(&ctx));
+        let evals = |c| {
+            let mut p = poly(c);
+            p.ntt_forward_shoup();
+            p
+        };
+        let expected = &(&poly(a) * &poly(s)) + &(&poly(b) * &poly(s));
+
+        let mut acc = evals(a).pointwise_mul(&evals(s));
+        acc.pointwise_mul_add(&evals(b), &evals(s));
+        acc.ntt_inverse_shoup();
+        prop_assert_eq!(acc.coeffs(), expected.coeffs());
+
+        let mut raw = [0u64; 4];
+        ctx.pointwise_mul_add(&mut raw, evals(a).coeffs(), evals(s).coeffs());
+        ctx.pointwise_mul_add(&mut raw, evals(b).coeffs(), evals(s).coeffs());
+        let mut sum = NttPolynomial::from_coeffs(raw, Arc::clone(&ctx));
+        sum.ntt_inverse_shoup();
+        prop_assert_eq!(sum.coeffs(), expected.coeffs());
+
+        let mut prod = evals(a);
+        prod.pointwise_mul_assign(&evals(s));
+        prop_assert_eq!(*prod.coeffs(), *evals(a).pointwise_mul(&evals(s)).coeffs());
+    }
+}
+
+#[test]
+fn test_consuming_and_copying_transforms() {
+    let ctx = NttContext::<8>::new(find_first_prime_up(20, 8));
+    let a = NttPolynomial::from_coeffs([1, 2, 3, 4, 5, 6, 7, 8], Arc::clone(&ctx));
+
+    let mut expected = a.clone();
+    expected.ntt_forward_shoup();
+
+    let evals = a.to_ntt();
+    assert_eq!(evals.coeffs(), expected.coeffs());
+    assert_eq!(a.clone().into_ntt().coeffs(), expected.coeffs());
+
+    assert_eq!(evals.to_intt().coeffs(), a.coeffs());
+    assert_eq!(evals.coeffs(), expected.coeffs());
+    assert_eq!(evals.into_intt().coeffs(), a.coeffs());
+}
+
+// Property: Harvey's lazy forward NTT matches the fully reduced one, up to
+// the largest modulus it supports and past it through the fallback
+proptest! {
+    #[test]
+    fn lazy_ntt_matches_shoup(
+        coeffs in prop::array::uniform16(any::<u64>()),
+        q in prop::sample::select(vec![
+            find_first_prime_up(20, 16),
+            find_first_prime_up(50, 16),
+            rust_ntt::find_first_prime_down(62, 16),
+            find_first_prime_up(62, 16),
+        ]),
+    ) {
+        let ctx = NttContext::<16>::new(q);
+        let a = NttPolynomial::from_unreduced(&coeffs, ctx);
+
+        let mut lazy = a.clone();
+        lazy.ntt_forward_lazy();
+        let mut expected = a.clone();
+        expected.ntt_forward_shoup();
+        prop_assert_eq!(*lazy.coeffs(), *expected.coeffs());
+    }
+}
+
+// Property: cyclic convolution matches the schoolbook product modulo
+// x^N - 1, and the cyclic transforms invert each other
+proptest! {
+    #[test]
+    fn cyclic_convolution_matches_schoolbook(
+        a in prop::array::uniform16(any::<u64>()),
+        b in prop::array::uniform16(any::<u64>()),
+    ) {
+        let ctx = NttContext::<16>::new(find_first_prime_up(40, 16));
+        let class = *ctx.class();
+        let x = NttPolynomial::from_unreduced(&a, Arc::clone(&ctx));
+        let y = NttPolynomial::from_unreduced(&b, ctx);
+
+        let mut expected = [0u64; 16];
+        for (i, &u) in x.coeffs().iter().enumerate() {
+            for (j, &v) in y.coeffs().iter().enumerate() {
+                let k = (i + j) % 16;
+                expected[k] = class.modadd(expected[k], class.modmul(u, v));
+            }
+        }
+        let product = x.cyclic_convolution(&y);
+        prop_assert_eq!(*product.coeffs(), expected);
+
+        let mut evals = x.clone();
+        evals.ntt_forward_cyclic();
+        let mut other = y.clone();
+        other.ntt_forward_cyclic();
+        let mut slotwise = evals.pointwise_mul(&other);
+        slotwise.ntt_inverse_cyclic();
+        prop_assert_eq!(*slotwise.coeffs(), expected);
+
+        evals.ntt_inverse_cyclic();
+        prop_assert_eq!(evals.coeffs(), x.coeffs());
+    }
+}
+
+#[test]
+fn test_evaluate_reduces_the_point() {
+    let ctx = NttContext::<4>::new(find_first_prime_up(20, 4));
+    let q = ctx.modulus();
+    let a = NttPolynomial::from_coeffs([1, 2, 3, 4], ctx);
+
+    for x in [q, q + 5, 3 * q - 1, u64::MAX] {
+        assert_eq!(a.evaluate(x), a.evaluate(x % q));
+    }
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "async")]
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::offload::WorkerPool;
+use rust_ntt::*;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+/// Minimal single-future executor that parks until woken
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct Unpark(Thread);
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let mut future = std::pin::pin!(future);
+    let waker = Waker::from(Arc::new(Unpark(thread::current())));
+    loop {
+        match future.as_mut().poll(&mut Context::from_waker(&waker)) {
+            Poll::Ready(value) => return value,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+const N: usize = 1024;
+
+#[test]
+fn test_convolve_async_matches_sync() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let ctx = NttContext::<N>::new(find_first_prime_up(50, N));
+    let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+    let b = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+    let product = block_on(a.convolve_async(&b));
+    assert_eq!(
+        product.coeffs(),
+        a.negacyclic_convolution_shoup(&b).coeffs()
+    );
+}
+
+#[test]
+fn test_transforms_async_round_trip() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let ctx = NttContext::<N>::new(find_first_prime_up(50, N));
+    let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+    let evals = block_on(a.clone().ntt_forward_async());
+    let mut expected = a.clone();
+    expected.ntt_forward_shoup();
+    assert_eq!(evals.coeffs(), expected.coeffs());
+
+    let back = block_on(evals.ntt_inverse_async());
+    assert!(back.ct_eq(&a));
+}
+
+#[test]
+fn test_many_jobs_on_small_pool() {
+    let pool = WorkerPool::new(2);
+    assert_eq!(pool.threads(), 2);
+    let ctx = NttContext::<16>::new(find_first_prime_up(30, 16));
+
+    let polys: Vec<_> = (0..16u64)
+        .map(|i| NttPolynomial::from_coeffs([i; 16], Arc::clone(&ctx)))
+        .collect();
+    let futures: Vec<_> = polys
+        .iter()
+        .map(|p| p.convolve_async_on(p, &pool))
+        .collect();
+
+    for (p, future) in polys.iter().zip(futures) {
+        assert!(block_on(future).ct_eq(&(p * p)));
+    }
+}
+
+#[test]
+#[should_panic(expected = "job failed")]
+fn test_job_panic_surfaces_on_poll() {
+    let pool = WorkerPool::new(1);
+    block_on(pool.spawn(|| -> u64 { panic!("job failed") }));
+}
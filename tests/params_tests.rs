@@ -0,0 +1,51 @@
+use rust_ntt::params::{DILITHIUM, FALCON_512, FHE_4096};
+use rust_ntt::{NttPolynomial, find_first_prime_down};
+use std::sync::Arc;
+
+#[test]
+fn test_single_modulus_presets() {
+    let ctx = DILITHIUM.context();
+    assert_eq!(ctx.modulus(), DILITHIUM.q);
+    assert_eq!(ctx.degree(), 256);
+
+    let ctx = FALCON_512.context();
+    assert_eq!(ctx.modulus(), 12289);
+    assert_eq!(ctx.degree(), FALCON_512.degree());
+}
+
+#[test]
+fn test_fhe_chain_is_distinct_and_ntt_friendly() {
+    let moduli = FHE_4096.moduli();
+    assert_eq!(moduli.len(), FHE_4096.prime_bits.len());
+
+    // First two primes share a bit size, so the second must be the next one down
+    assert_eq!(moduli[0], find_first_prime_down(36, 4096));
+    assert!(moduli[1] < moduli[0]);
+
+    for (i, &q) in moduli.iter().enumerate() {
+        assert_eq!((q - 1) % (2 * 4096), 0);
+        assert!(!moduli[..i].contains(&q));
+    }
+
+    assert!(FHE_4096.log_modulus() > 108.0);
+}
+
+#[test]
+fn test_fhe_contexts_multiply() {
+    let chain = FHE_4096.contexts();
+
+    for ctx in chain {
+        let x = NttPolynomial::from_coeffs(
+            std::array::from_fn(|i| if i == 1 { 1 } else { 0 }),
+            Arc::clone(&ctx),
+        );
+        let x_to_n_minus_1 = NttPolynomial::from_coeffs(
+            std::array::from_fn(|i| if i == 4095 { 1 } else { 0 }),
+            Arc::clone(&ctx),
+        );
+
+        // x * x^(N-1) = x^N = -1
+        let product = &x * &x_to_n_minus_1;
+        assert_eq!(product.coeffs()[0], ctx.modulus() - 1);
+    }
+}
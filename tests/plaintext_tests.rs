@@ -0,0 +1,72 @@
+use proptest::prelude::*;
+use rust_ntt::plaintext::{ScalingError, max_message};
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 8;
+
+// Property: messages away from the wrap-around point survive encoding plus
+// any noise below Δ/2
+proptest! {
+    #[test]
+    fn decode_removes_noise_below_half_delta(
+        t in 2u64..1000,
+        message in prop::array::uniform8(any::<i64>()),
+        noise in prop::array::uniform8(any::<i64>()),
+    ) {
+        let ctx = NttContext::<N>::new(find_first_prime_up(40, N));
+        let q = ctx.modulus();
+        let delta = q / t;
+        let max = max_message(q, delta).unwrap();
+        let message = message.map(|m| m % max);
+        let half = (delta as i64 - 1) / 2;
+        let noise = noise.map(|e| e % (half + 1));
+
+        let m = NttPolynomial::encode_scaled(&message, delta, Arc::clone(&ctx)).unwrap();
+        let e = NttPolynomial::from_coeffs(
+            noise.map(|e| if e < 0 { q - e.unsigned_abs() } else { e as u64 }),
+            ctx,
+        );
+        prop_assert_eq!((&m + &e).decode_scaled(delta).unwrap(), message);
+    }
+}
+
+#[test]
+fn test_encode_rejects_overflow() {
+    let ctx = NttContext::<N>::new(97);
+    // (97 - 1) / 2 / 10 = 4
+    assert_eq!(max_message(97, 10), Ok(4));
+    assert!(NttPolynomial::encode_scaled(&[4, -4], 10, Arc::clone(&ctx)).is_ok());
+    assert_eq!(
+        NttPolynomial::encode_scaled(&[1, -5], 10, Arc::clone(&ctx)).unwrap_err(),
+        ScalingError::OutOfRange {
+            index: 1,
+            value: -5,
+            max: 4
+        }
+    );
+    assert_eq!(
+        NttPolynomial::encode_scaled(&[0], 0, ctx).unwrap_err(),
+        ScalingError::InvalidDelta {
+            delta: 0,
+            modulus: 97
+        }
+    );
+}
+
+#[test]
+fn test_decode_detects_wrapped_coefficient() {
+    let ctx = NttContext::<N>::new(97);
+    // 48 is within q/2 but rounds to 5·10, past the largest message 4
+    let poly = NttPolynomial::from_short_coeffs(&[40, 48], ctx);
+    let err = poly.decode_scaled(10).unwrap_err();
+    assert_eq!(
+        err,
+        ScalingError::OutOfRange {
+            index: 1,
+            value: 5,
+            max: 4
+        }
+    );
+    assert_eq!(err.to_string(), "message 1 = 5 is outside ±4");
+}
@@ -0,0 +1,140 @@
+use proptest::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::plantard::{
+    CongruenceClass32, NttContext32, NttPolynomial32, PLANTARD_MAX_MODULUS,
+};
+use rust_ntt::*;
+use std::sync::Arc;
+
+const KYBER_Q: u32 = 3329;
+const DILITHIUM_Q: u32 = 8380417;
+
+/// Largest NTT-friendly prime for degree 16 that Plantard reduction supports
+fn largest_prime() -> u32 {
+    let mut q = PLANTARD_MAX_MODULUS as u64 / 32 * 32 + 1;
+    while q > PLANTARD_MAX_MODULUS as u64 || !primal::is_prime(q) {
+        q -= 32;
+    }
+    q as u32
+}
+
+// Property: Plantard products agree with Barrett ones, with canonical
+// results, from Kyber-sized moduli up to the largest supported one
+proptest! {
+    #[test]
+    fn plantard_arithmetic_matches_barrett(a in any::<u32>(), b in any::<u32>()) {
+        for q in [KYBER_Q, DILITHIUM_Q, largest_prime()] {
+            let plantard = CongruenceClass32::new(q);
+            let barrett = CongruenceClass::new(q as u64);
+            let (a, b) = (a % q, b % q);
+            let expected = barrett.modmul(a as u64, b as u64);
+
+            let b_prec = plantard.precompute_plantard(b);
+            prop_assert_eq!(plantard.modmul_plantard(a, b_prec) as u64, expected);
+            prop_assert_eq!(plantard.modmul(a, b) as u64, expected);
+            // a = q is allowed as well and acts as zero
+            prop_assert_eq!(plantard.modmul_plantard(q, b_prec), 0);
+            prop_assert_eq!(plantard.modadd(a, b) as u64, barrett.modadd(a as u64, b as u64));
+            prop_assert_eq!(plantard.modsub(a, b) as u64, barrett.modsub(a as u64, b as u64));
+        }
+    }
+}
+
+// Property: the Plantard NTT gives the evaluations of the Barrett NTT,
+// round-trips, and multiplies like the reference convolution
+proptest! {
+    #[test]
+    fn plantard_ntt_matches_barrett(
+        a in prop::array::uniform16(any::<u32>()),
+        b in prop::array::uniform16(any::<u32>()),
+    ) {
+        for q in [KYBER_Q, DILITHIUM_Q, largest_prime()] {
+            let a = a.map(|c| c % q);
+            let b = b.map(|c| c % q);
+            let ctx = NttContext32::<16>::new(q);
+            let reference = NttContext::<16>::new(q as u64);
+
+            let mut evals = a;
+            ctx.ntt_forward(&mut evals);
+            let mut expected =
+                NttPolynomial::from_coeffs(a.map(u64::from), Arc::clone(&reference));
+            expected.ntt_forward();
+            prop_assert_eq!(evals.map(u64::from), *expected.coeffs());
+
+            ctx.ntt_inverse(&mut evals);
+            prop_assert_eq!(evals, a);
+
+            let x = NttPolynomial::from_coeffs(a.map(u64::from), Arc::clone(&reference));
+            let y = NttPolynomial::from_coeffs(b.map(u64::from), reference);
+            let product = x.naive_negacyclic_convolution(&y);
+            prop_assert_eq!(ctx.negacyclic_convolution(&a, &b).map(u64::from), *product.coeffs());
+        }
+    }
+}
+
+#[test]
+fn test_scheme_sized_transforms() {
+    // Kyber's q only has 256th roots of unity, so the full negacyclic NTT
+    // stops at degree 128
+    let kyber = NttContext32::<128>::new(KYBER_Q);
+    let dilithium = NttContext32::<256>::new(DILITHIUM_Q);
+
+    let a: [u32; 128] = std::array::from_fn(|i| (i as u32 * 31) % KYBER_Q);
+    let mut one = [0u32; 128];
+    one[0] = 1;
+    assert_eq!(kyber.negacyclic_convolution(&a, &one), a);
+
+    // x^255 · x = x^256 = -1
+    let (mut x, mut top) = ([0u32; 256], [0u32; 256]);
+    x[1] = 1;
+    top[255] = 1;
+    let mut minus_one = [0u32; 256];
+    minus_one[0] = DILITHIUM_Q - 1;
+    assert_eq!(dilithium.negacyclic_convolution(&x, &top), minus_one);
+}
+
+#[test]
+fn test_packed_polynomials_match_u64_ring() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let q = DILITHIUM_Q;
+    let wide = NttContext::<256>::new(q as u64);
+    let packed = NttContext32::<256>::new(q);
+
+    let a = NttPolynomial::sample_random(Arc::clone(&wide), &mut rng);
+    let b = NttPolynomial::sample_random(Arc::clone(&wide), &mut rng);
+    let narrow = |p: &NttPolynomial<256>| {
+        NttPolynomial32::from_coeffs(
+            p.coeffs().map(|c| c as u32),
+            Arc::clone(&packed),
+        )
+    };
+    let (a32, b32) = (narrow(&a), narrow(&b));
+    assert_eq!(size_of_val(a32.coeffs()), 4 * 256);
+
+    let widen = |p: NttPolynomial32<256>| p.coeffs().map(u64::from);
+    assert_eq!(widen(&a32 + &b32), *(&a + &b).coeffs());
+    assert_eq!(widen(&a32 - &b32), *(&a - &b).coeffs());
+    assert_eq!(widen(&a32 * &b32), *(&a * &b).coeffs());
+
+    let (mut x, mut y) = (a32.clone(), b32.clone());
+    x.ntt_forward();
+    y.ntt_forward();
+    let mut product = x.pointwise_mul(&y);
+    product.ntt_inverse();
+    assert_eq!(widen(product), *(&a * &b).coeffs());
+    assert_eq!(widen(NttPolynomial32::zero(packed)), [0; 256]);
+}
+
+#[test]
+#[should_panic(expected = "Cannot add polynomials: moduli differ")]
+fn test_packed_mismatched_moduli_panic() {
+    let a = NttPolynomial32::<16>::zero(NttContext32::new(KYBER_Q));
+    let b = NttPolynomial32::<16>::zero(NttContext32::new(DILITHIUM_Q));
+    let _ = &a + &b;
+}
+
+#[test]
+#[should_panic(expected = "modulus must be < 2^32/φ")]
+fn test_oversized_modulus_panics() {
+    CongruenceClass32::new(PLANTARD_MAX_MODULUS + 2);
+}
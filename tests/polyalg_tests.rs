@@ -0,0 +1,374 @@
+use proptest::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::polyalg::{
+    add, berlekamp_massey, compose_mod, degree, divrem, divrem_schoolbook,
+    evaluate, evaluate_at_powers, evaluate_multipoint, factor, gcd, interpolate,
+    interpolate_naive, inverse_series, is_irreducible, middle_product,
+    minimal_polynomial, mul, mulmod, random_irreducible,
+};
+use rust_ntt::*;
+
+fn naive_mul(a: &[u64], b: &[u64], class: &CongruenceClass) -> Vec<u64> {
+    let mut product = vec![0u64; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            product[i + j] = (product[i + j] + class.modmul(x, y)) % class.q();
+        }
+    }
+    product
+}
+
+fn moduli() -> [u64; 2] {
+    [
+        // NTT-friendly for every length used below
+        find_first_prime_up(40, 1 << 10),
+        // q - 1 = 2·odd, forcing the schoolbook fallback
+        1_000_003,
+    ]
+}
+
+// Property: Fast multiplication agrees with schoolbook
+proptest! {
+    #[test]
+    fn mul_matches_naive(
+        a in prop::collection::vec(any::<u64>(), 1..150),
+        b in prop::collection::vec(any::<u64>(), 1..150),
+    ) {
+        for q in moduli() {
+            let class = CongruenceClass::new(q);
+            let a: Vec<u64> = a.iter().map(|c| c % q).collect();
+            let b: Vec<u64> = b.iter().map(|c| c % q).collect();
+
+            prop_assert_eq!(mul(&a, &b, &class), naive_mul(&a, &b, &class));
+        }
+    }
+}
+
+// Property: Middle product is the middle slice of the full product
+proptest! {
+    #[test]
+    fn middle_product_is_middle_of_full_product(
+        b in prop::collection::vec(any::<u64>(), 1..100),
+        extra in 0usize..100,
+        seed in prop::collection::vec(any::<u64>(), 200),
+    ) {
+        let n = b.len();
+        for q in moduli() {
+            let class = CongruenceClass::new(q);
+            let a: Vec<u64> = seed[..n + extra].iter().map(|c| c % q).collect();
+            let b: Vec<u64> = b.iter().map(|c| c % q).collect();
+
+            let full = naive_mul(&a, &b, &class);
+            let middle = middle_product(&a, &b, &class);
+            prop_assert_eq!(&middle[..], &full[n - 1..a.len()]);
+        }
+    }
+}
+
+#[test]
+fn test_mul_modulo_prime_power_falls_back_to_schoolbook() {
+    // q - 1 = (p - 1)(p + 1) has the factors of two for a transform, but
+    // p^2 has no quadratic non-residue x with x^((q-1)/2) = -1
+    let p = find_first_prime_up(28, 1 << 8);
+    let class = CongruenceClass::new(p * p);
+    let a: Vec<u64> = (1..=64).map(|i| i * p + 7).collect();
+    let b: Vec<u64> = (1..=64).map(|i| i * i).collect();
+    assert_eq!(mul(&a, &b, &class), naive_mul(&a, &b, &class));
+}
+
+#[test]
+fn test_mul_with_empty_operand_is_empty() {
+    let class = CongruenceClass::new(97);
+    assert!(mul(&[], &[1, 2, 3], &class).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "must not be longer")]
+fn test_middle_product_rejects_long_second_operand() {
+    let class = CongruenceClass::new(97);
+    middle_product(&[1, 2], &[1, 2, 3], &class);
+}
+
+// Property: Fast and schoolbook division agree and satisfy a = q·b + r
+proptest! {
+    #[test]
+    fn divrem_matches_schoolbook(
+        a in prop::collection::vec(any::<u64>(), 0..400),
+        b in prop::collection::vec(any::<u64>(), 1..200),
+    ) {
+        for q in moduli() {
+            let class = CongruenceClass::new(q);
+            let a: Vec<u64> = a.iter().map(|c| c % q).collect();
+            let mut b: Vec<u64> = b.iter().map(|c| c % q).collect();
+            *b.last_mut().unwrap() = 1 + b.last().unwrap() % (q - 1);
+
+            let (quot, rem) = divrem(&a, &b, &class);
+            prop_assert_eq!(divrem_schoolbook(&a, &b, &class), (quot.clone(), rem.clone()));
+            prop_assert!(rem.len() < b.len());
+
+            // Reassemble a = quot·b + rem
+            let mut back = if quot.is_empty() { vec![] } else { naive_mul(&quot, &b, &class) };
+            back.resize(a.len().max(back.len()), 0);
+            for (x, &r) in back.iter_mut().zip(&rem) {
+                *x = (*x + r) % q;
+            }
+            back.truncate(degree(&back).map_or(0, |d| d + 1));
+            let mut a = a;
+            a.truncate(degree(&a).map_or(0, |d| d + 1));
+            prop_assert_eq!(back, a);
+        }
+    }
+}
+
+// Property: Newton inversion yields a·a^(-1) ≡ 1 (mod x^n)
+proptest! {
+    #[test]
+    fn inverse_series_is_inverse(
+        a in prop::collection::vec(any::<u64>(), 1..200),
+        n in 1usize..300,
+    ) {
+        let q = moduli()[0];
+        let class = CongruenceClass::new(q);
+        let mut a: Vec<u64> = a.iter().map(|c| c % q).collect();
+        a[0] = a[0].max(1);
+
+        let inv = inverse_series(&a, n, &class);
+        prop_assert_eq!(inv.len(), n);
+
+        let product = naive_mul(&a, &inv, &class);
+        prop_assert_eq!(product[0], 1);
+        prop_assert!(product[1..n].iter().all(|&c| c == 0));
+    }
+}
+
+#[test]
+#[should_panic(expected = "zero polynomial")]
+fn test_divrem_by_zero_panics() {
+    let class = CongruenceClass::new(97);
+    divrem(&[1, 2, 3], &[0, 0], &class);
+}
+
+// Property: Brent–Kung composition agrees with Horner's rule modulo h
+proptest! {
+    #[test]
+    fn compose_mod_matches_horner(
+        f in prop::collection::vec(any::<u64>(), 0..60),
+        g in prop::collection::vec(any::<u64>(), 0..40),
+        h in prop::collection::vec(any::<u64>(), 2..30),
+    ) {
+        let q = moduli()[0];
+        let class = CongruenceClass::new(q);
+        let f: Vec<u64> = f.iter().map(|c| c % q).collect();
+        let g: Vec<u64> = g.iter().map(|c| c % q).collect();
+        let mut h: Vec<u64> = h.iter().map(|c| c % q).collect();
+        *h.last_mut().unwrap() = 1;
+
+        // f(g) mod h = (...((f_d·g + f_{d-1})·g + ...) mod h
+        let mut expected = Vec::new();
+        for &c in f.iter().rev() {
+            expected = add(&mulmod(&expected, &g, &h, &class), &[c], &class);
+        }
+
+        prop_assert_eq!(compose_mod(&f, &g, &h, &class), expected);
+    }
+}
+
+// Property: Berlekamp–Massey recovers a random recurrence from 2L terms
+proptest! {
+    #[test]
+    fn berlekamp_massey_recovers_recurrence(
+        taps in prop::collection::vec(any::<u64>(), 1..20),
+        init in prop::collection::vec(any::<u64>(), 20),
+    ) {
+        let q = moduli()[1];
+        let class = CongruenceClass::new(q);
+        let l = taps.len();
+        let mut taps: Vec<u64> = taps.iter().map(|c| c % q).collect();
+        taps[l - 1] = taps[l - 1].max(1);
+
+        // s_n = Σ taps_i·s_{n-1-i}
+        let mut seq: Vec<u64> = init[..l].iter().map(|c| c % q).collect();
+        for n in l..3 * l {
+            let next = (0..l).fold(0, |acc, i| (acc + class.modmul(taps[i], seq[n - 1 - i])) % q);
+            seq.push(next);
+        }
+
+        let conn = berlekamp_massey(&seq, &class);
+        prop_assert!(conn.len() <= l + 1);
+        prop_assert_eq!(conn[0], 1);
+
+        // The recurrence holds along the whole sequence
+        let deg = conn.len() - 1;
+        for n in deg..seq.len() {
+            let sum = (0..=deg).fold(0, |acc, i| (acc + class.modmul(conn[i], seq[n - i])) % q);
+            prop_assert_eq!(sum, 0);
+        }
+
+        let min_poly = minimal_polynomial(&seq, &class);
+        prop_assert_eq!(min_poly.last(), Some(&1));
+    }
+}
+
+#[test]
+fn test_berlekamp_massey_zero_sequence() {
+    let class = CongruenceClass::new(97);
+    assert_eq!(berlekamp_massey(&[0, 0, 0, 0], &class), vec![1]);
+    assert_eq!(berlekamp_massey(&[], &class), vec![1]);
+}
+
+/// Brute-force irreducibility over a tiny field: no monic factor of degree
+/// at most half
+fn has_small_factor(f: &[u64], class: &CongruenceClass) -> bool {
+    let q = class.q();
+    let n = degree(f).unwrap();
+    (1..=n / 2).any(|d| {
+        (0..q.pow(d as u32)).any(|code| {
+            let mut g: Vec<u64> =
+                (0..d).map(|i| code / q.pow(i as u32) % q).collect();
+            g.push(1);
+            divrem(f, &g, class).1.is_empty()
+        })
+    })
+}
+
+// Property: Rabin's test agrees with exhaustive factor search over F_5
+proptest! {
+    #[test]
+    fn is_irreducible_matches_brute_force(
+        coeffs in prop::collection::vec(0u64..5, 2..7),
+    ) {
+        let class = CongruenceClass::new(5);
+        let mut f = coeffs;
+        f.push(1);
+
+        prop_assert_eq!(is_irreducible(&f, &class), !has_small_factor(&f, &class));
+    }
+}
+
+#[test]
+fn test_gcd_of_products() {
+    let class = CongruenceClass::new(97);
+    // (x + 1)(x + 2) and (x + 1)(x + 3) share x + 1
+    let a = mul(&[1, 1], &[2, 1], &class);
+    let b = mul(&[1, 1], &[3, 1], &class);
+    assert_eq!(gcd(&a, &b, &class), vec![1, 1]);
+    assert!(!is_irreducible(&[5], &class));
+    assert!(is_irreducible(&[5, 3], &class));
+}
+
+#[test]
+fn test_random_irreducible_has_no_small_factors() {
+    let class = CongruenceClass::new(5);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    for degree in 1..=6 {
+        let f = random_irreducible(degree, &class, &mut rng);
+        assert_eq!(f.len(), degree + 1);
+        assert_eq!(f[degree], 1);
+        assert!(!has_small_factor(&f, &class));
+    }
+}
+
+// Property: Factoring a random product recovers its irreducible factors
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+    #[test]
+    fn factor_recovers_planted_factors(
+        degrees in prop::collection::vec(1usize..4, 1..5),
+        exponents in prop::collection::vec(1usize..3, 4),
+        seed in any::<u64>(),
+    ) {
+        for q in [3u64, 5, 97] {
+            let class = CongruenceClass::new(q);
+            let mut rng = StdRng::seed_from_u64(seed);
+
+            let mut f = vec![2u64];
+            for (&d, &e) in degrees.iter().zip(&exponents) {
+                let g = random_irreducible(d, &class, &mut rng);
+                for _ in 0..e {
+                    f = mul(&f, &g, &class);
+                }
+            }
+
+            let (lead, factors) = factor(&f, &class, &mut rng);
+            prop_assert_eq!(lead, 2);
+
+            let mut product = vec![lead];
+            for (g, e) in &factors {
+                prop_assert!(is_irreducible(g, &class));
+                for _ in 0..*e {
+                    product = mul(&product, g, &class);
+                }
+            }
+            prop_assert_eq!(product, f);
+        }
+    }
+}
+
+#[test]
+fn test_factor_pth_power() {
+    // x^5 + 1 = (x + 1)^5 over F_5: the derivative vanishes
+    let class = CongruenceClass::new(5);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let (lead, factors) = factor(&[1, 0, 0, 0, 0, 1], &class, &mut rng);
+    assert_eq!(lead, 1);
+    assert_eq!(factors, vec![(vec![1, 1], 5)]);
+}
+
+// Property: Subproduct-tree evaluation and interpolation are mutually inverse
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+    #[test]
+    fn interpolate_inverts_multipoint_evaluation(
+        f in prop::collection::vec(any::<u64>(), 1..200),
+        offset in any::<u64>(),
+    ) {
+        for q in moduli() {
+            let class = CongruenceClass::new(q);
+            let n = f.len();
+            let f: Vec<u64> = f.iter().map(|c| c % q).collect();
+            let points: Vec<u64> = (0..n as u64).map(|i| (offset % q + i) % q).collect();
+
+            let values = evaluate_multipoint(&f, &points, &class);
+            let horner: Vec<u64> = points.iter().map(|&x| evaluate(&f, x, &class)).collect();
+            prop_assert_eq!(&values, &horner);
+
+            let fast = interpolate(&points, &values, &class);
+            prop_assert_eq!(&fast, &f);
+            prop_assert_eq!(interpolate_naive(&points, &values, &class), fast);
+        }
+    }
+}
+
+// Property: chirp evaluation at powers matches Horner at every power
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+    #[test]
+    fn evaluate_at_powers_matches_horner(
+        f in prop::collection::vec(any::<u64>(), 0..100),
+        c in prop_oneof![Just(0u64), Just(1), any::<u64>()],
+        count in 0usize..100,
+    ) {
+        for q in moduli() {
+            let class = CongruenceClass::new(q);
+            let f: Vec<u64> = f.iter().map(|x| x % q).collect();
+            let c = c % q;
+
+            let mut point = 1;
+            let mut horner = Vec::new();
+            for _ in 0..count {
+                horner.push(evaluate(&f, point, &class));
+                point = class.modmul(point, c);
+            }
+            prop_assert_eq!(evaluate_at_powers(&f, c, count, &class), horner);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "not invertible")]
+fn test_interpolate_duplicate_points_panics() {
+    let class = CongruenceClass::new(97);
+    interpolate(&[1, 2, 1], &[3, 4, 5], &class);
+}
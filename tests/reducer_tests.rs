@@ -0,0 +1,207 @@
+use proptest::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::kernel::ScalarKernel;
+use rust_ntt::reducer::{Barrett, Montgomery, Plantard, Reducer, Shoup};
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 16;
+
+/// Product, sum and difference under `R`, plus the product by a
+/// precomputed multiplicand
+fn ops<R: Reducer>(q: u64, a: u64, b: u64) -> [u64; 4] {
+    let r = R::new(q);
+    assert_eq!(r.q(), q);
+    [
+        r.modmul(a, b),
+        r.modmul_precomp(a, b, r.precompute(b)),
+        r.modadd(a, b),
+        r.modsub(a, b),
+    ]
+}
+
+// Property: every reducer agrees with CongruenceClass on canonical residues
+proptest! {
+    #[test]
+    fn reducers_agree_with_congruence_class(
+        bits in 5usize..=31,
+        a in any::<u64>(),
+        b in any::<u64>(),
+    ) {
+        let q = find_first_prime_up(bits, 1);
+        let class = CongruenceClass::new(q);
+        let (a, b) = (a % q, b % q);
+        let product = class.modmul(a, b);
+        let expected = [product, product, class.modadd(a, b), class.modsub(a, b)];
+
+        prop_assert_eq!(ops::<Barrett>(q, a, b), expected);
+        prop_assert_eq!(ops::<Shoup>(q, a, b), expected);
+        prop_assert_eq!(ops::<Montgomery>(q, a, b), expected);
+        prop_assert_eq!(ops::<Plantard>(q, a, b), expected);
+    }
+}
+
+fn convolution_with<R: Reducer>(q: u64, a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+    let ctx = NttContext::<N>::with_reducer::<R>(q);
+    let a = NttPolynomial::from_coeffs(*a, Arc::clone(&ctx));
+    let b = NttPolynomial::from_coeffs(*b, ctx);
+    *a.negacyclic_convolution_shoup(&b).coeffs()
+}
+
+#[test]
+fn test_reducer_kernels_match_default() {
+    let mut rng = StdRng::seed_from_u64(42);
+
+    for bits in [20, 30, 50] {
+        let q = find_first_prime_up(bits, N);
+        let ctx = NttContext::<N>::new(q);
+        let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+        let b = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+        let expected = *a.negacyclic_convolution_shoup(&b).coeffs();
+
+        assert_eq!(
+            convolution_with::<Barrett>(q, a.coeffs(), b.coeffs()),
+            expected
+        );
+        assert_eq!(
+            convolution_with::<Shoup>(q, a.coeffs(), b.coeffs()),
+            expected
+        );
+        assert_eq!(
+            convolution_with::<Montgomery>(q, a.coeffs(), b.coeffs()),
+            expected
+        );
+        if bits <= 30 {
+            assert_eq!(
+                convolution_with::<Plantard>(q, a.coeffs(), b.coeffs()),
+                expected
+            );
+        }
+
+        // Evaluations keep the slot order of the default kernel
+        let mut evals = a.clone();
+        evals.ntt_forward_shoup();
+        let mut montgomery = NttPolynomial::from_coeffs(
+            *a.coeffs(),
+            NttContext::<N>::with_reducer::<Montgomery>(q),
+        );
+        montgomery.ntt_forward_shoup();
+        assert_eq!(montgomery.coeffs(), evals.coeffs());
+    }
+}
+
+/// Results of the operations documented to honour the reducer, over `ctx`
+fn honoured_ops<R: Reducer>(
+    ctx: &Arc<NttContext<N, R>>,
+    a: &[u64; N],
+    b: &[u64; N],
+) -> Vec<Vec<u64>> {
+    let a = NttPolynomial::from_coeffs(*a, Arc::clone(ctx));
+    let b = NttPolynomial::from_coeffs(*b, Arc::clone(ctx));
+
+    let mut evals = a.clone();
+    evals.ntt_forward();
+    let mut coeffs = evals.clone();
+    coeffs.ntt_inverse();
+    let mut padded =
+        NttPolynomial::from_short_coeffs(&a.coeffs()[..3], Arc::clone(ctx));
+    padded.ntt_forward_padded(3);
+    let mut lazy = a.clone();
+    lazy.ntt_forward_lazy();
+
+    [
+        &evals,
+        &coeffs,
+        &padded,
+        &lazy,
+        &(&a * &b),
+        &a.checked_mul(&b).unwrap(),
+        &a.naive_negacyclic_convolution(&b),
+        &a.pointwise_mul(&b),
+        &a.mul_scalar(12345),
+        &NttPolynomial::affine(3, &a, 7, &b),
+        &a.scale_argument(5),
+    ]
+    .iter()
+    .map(|p| p.coeffs().to_vec())
+    .chain([vec![a.evaluate(9)]])
+    .collect()
+}
+
+/// Whether `ctx` runs the honoured operations on `R` and agrees with the
+/// default context
+fn check_honoured<R: Reducer>(
+    ctx: &Arc<NttContext<N, R>>,
+    a: &[u64; N],
+    b: &[u64; N],
+    expected: &[Vec<u64>],
+) {
+    assert_eq!(ctx.reducer().q(), ctx.modulus());
+    assert_eq!(honoured_ops(ctx, a, b), expected);
+}
+
+#[test]
+fn test_reducer_runs_every_transform_and_product() {
+    let mut rng = StdRng::seed_from_u64(42);
+
+    for bits in [20, 30, 50] {
+        let q = find_first_prime_up(bits, N);
+        let ctx = NttContext::<N>::new(q);
+        let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+        let b = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+        let (a, b) = (a.coeffs(), b.coeffs());
+        let expected = honoured_ops(&ctx, a, b);
+
+        check_honoured(&NttContext::with_reducer::<Barrett>(q), a, b, &expected);
+        check_honoured(&NttContext::with_reducer::<Shoup>(q), a, b, &expected);
+        check_honoured(&NttContext::with_reducer::<Montgomery>(q), a, b, &expected);
+        if bits <= 30 {
+            check_honoured(
+                &NttContext::with_reducer::<Plantard>(q),
+                a,
+                b,
+                &expected,
+            );
+        }
+    }
+}
+
+#[test]
+fn test_reducer_composes_with_other_constructors() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let q = find_first_prime_up(30, N);
+
+    // Strict contexts stay strict
+    let strict = NttContext::<N>::new_strict(q);
+    let a = NttPolynomial::sample_random(Arc::clone(&strict), &mut rng);
+    let b = NttPolynomial::sample_random(Arc::clone(&strict), &mut rng);
+    let (a, b) = (a.coeffs(), b.coeffs());
+    let expected = honoured_ops(&strict, a, b);
+    let montgomery = Arc::clone(&strict).into_reducer::<Montgomery>();
+    assert!(montgomery.is_strict());
+    check_honoured(&montgomery, a, b, &expected);
+
+    // Tables and kernels carry over
+    let tables = NttContext::<N>::with_tables(q, strict.tf(), strict.itf());
+    check_honoured(&tables.into_reducer::<Barrett>(), a, b, &expected);
+    let kernel = montgomery.into_kernel(Arc::new(ScalarKernel));
+    check_honoured(&kernel, a, b, &expected);
+    let half = kernel.derive_half::<{ N / 2 }>();
+    assert_eq!(half.reducer().q(), q);
+    assert!(half.is_strict());
+
+    // Prime-power moduli
+    let p = find_first_prime_up(12, N);
+    let power = NttContext::<N>::new_prime_power(p, 2);
+    let a = NttPolynomial::sample_random(Arc::clone(&power), &mut rng);
+    let b = NttPolynomial::sample_random(Arc::clone(&power), &mut rng);
+    let (a, b) = (a.coeffs(), b.coeffs());
+    let expected = honoured_ops(&power, a, b);
+    check_honoured(&power.into_reducer::<Plantard>(), a, b, &expected);
+}
+
+#[test]
+#[should_panic(expected = "modulus must be < 2^32/φ")]
+fn test_plantard_rejects_large_moduli() {
+    NttContext::<N>::with_reducer::<Plantard>(find_first_prime_up(40, N));
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "reference")]
+
+use num_bigint::BigUint;
+use proptest::prelude::*;
+use rust_ntt::reference::ReferenceRing;
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 32;
+
+fn context() -> Arc<NttContext<N>> {
+    NttContext::<N>::new(find_first_prime_up(55, N))
+}
+
+#[test]
+fn test_big_modulus_negacyclic_wrap() {
+    // 2^127 - 1 is far beyond anything the word-sized code handles
+    let q = (BigUint::from(1u8) << 127u32) - 1u8;
+    let ring = ReferenceRing::new(q.clone(), 4);
+
+    // x^3 · x = x^4 = -1
+    let x3 = ring.lift(&[0, 0, 0, 1]);
+    let x = ring.lift(&[0, 1, 0, 0]);
+    let minus_one = vec![&q - 1u8, BigUint::ZERO, BigUint::ZERO, BigUint::ZERO];
+    assert_eq!(ring.mul(&x3, &x), minus_one);
+}
+
+#[test]
+fn test_inverse_with_composite_modulus() {
+    let ring = ReferenceRing::new(BigUint::from(100u8), 1);
+    assert_eq!(
+        ring.inv_scalar(&BigUint::from(3u8)),
+        Some(BigUint::from(67u8))
+    );
+    assert_eq!(ring.inv_scalar(&BigUint::from(10u8)), None);
+}
+
+#[test]
+#[should_panic(expected = "coefficient 1 differs")]
+fn test_assert_matches_reports_first_mismatch() {
+    let ring = ReferenceRing::new(BigUint::from(17u8), 4);
+    let expected = ring.lift(&[1, 2, 3, 4]);
+    ring.assert_matches(&expected, &[18, 3, 3, 4]);
+}
+
+// Property: Fast negacyclic products agree with the exact reference
+proptest! {
+    #[test]
+    fn products_match_reference(
+        a in prop::array::uniform32(any::<u64>()),
+        b in prop::array::uniform32(any::<u64>()),
+    ) {
+        let ctx = context();
+        let q = ctx.modulus();
+        let ring = ReferenceRing::from_context(&ctx);
+        let a = NttPolynomial::from_coeffs(a.map(|c| c % q), Arc::clone(&ctx));
+        let b = NttPolynomial::from_coeffs(b.map(|c| c % q), Arc::clone(&ctx));
+
+        ring.check_product(&a, &b, &a.negacyclic_convolution(&b));
+        ring.check_product(&a, &b, &a.negacyclic_convolution_shoup(&b));
+        ring.check_product(&a, &b, &(&a * &b));
+    }
+}
+
+// Property: Additive operations, evaluation and automorphisms agree with the exact reference
+proptest! {
+    #[test]
+    fn ring_operations_match_reference(
+        a in prop::array::uniform32(any::<u64>()),
+        b in prop::array::uniform32(any::<u64>()),
+        x in any::<u64>(),
+        k in 0usize..N,
+    ) {
+        let ctx = context();
+        let q = ctx.modulus();
+        let ring = ReferenceRing::from_context(&ctx);
+        let a = NttPolynomial::from_coeffs(a.map(|c| c % q), Arc::clone(&ctx));
+        let b = NttPolynomial::from_coeffs(b.map(|c| c % q), Arc::clone(&ctx));
+        let (ra, rb) = (ring.lift(a.coeffs()), ring.lift(b.coeffs()));
+
+        ring.assert_matches(&ring.add(&ra, &rb), (&a + &b).coeffs());
+        ring.assert_matches(&ring.sub(&ra, &rb), (&a - &b).coeffs());
+
+        let k = 2 * k + 1;
+        ring.assert_matches(&ring.automorphism(&ra, k), a.automorphism(k).coeffs());
+
+        let x = x % q;
+        let expected = ring.evaluate(&ra, &BigUint::from(x));
+        prop_assert_eq!(expected, BigUint::from(a.evaluate(x) % q));
+    }
+}
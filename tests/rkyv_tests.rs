@@ -0,0 +1,86 @@
+#![cfg(feature = "rkyv")]
+
+use rand::{SeedableRng, rngs::StdRng};
+use rkyv::rancor::Error;
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 64;
+
+#[test]
+fn test_polynomial_round_trip() {
+    let ctx = NttContext::<N>::new(find_first_prime_down(50, N));
+    let mut rng = StdRng::seed_from_u64(42);
+    let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+    let bytes = rkyv::to_bytes::<Error>(&a).unwrap();
+    let restored: NttPolynomial<N> = rkyv::from_bytes::<_, Error>(&bytes).unwrap();
+
+    assert_eq!(restored.coeffs(), a.coeffs());
+    assert_eq!(restored.context().modulus(), ctx.modulus());
+    assert_eq!(restored.context().tf(), ctx.tf());
+
+    // The restored context is fully functional
+    let b = NttPolynomial::sample_random(Arc::clone(restored.context()), &mut rng);
+    assert_eq!(
+        restored.negacyclic_convolution(&b).coeffs(),
+        restored.naive_negacyclic_convolution(&b).coeffs()
+    );
+}
+
+#[test]
+fn test_archived_access_is_zero_copy() {
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+    let mut rng = StdRng::seed_from_u64(42);
+    let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+    let bytes = rkyv::to_bytes::<Error>(&a).unwrap();
+    let archived =
+        rkyv::access::<rkyv::Archived<NttPolynomial<N>>, Error>(&bytes).unwrap();
+
+    let coeffs: Vec<u64> =
+        archived.coeffs().iter().map(|c| c.to_native()).collect();
+    assert_eq!(coeffs, a.coeffs());
+    assert_eq!(archived.context().modulus(), ctx.modulus());
+
+    let tf: Vec<u64> = archived
+        .context()
+        .tf()
+        .iter()
+        .map(|t| t.to_native())
+        .collect();
+    assert_eq!(tf, ctx.tf());
+}
+
+#[test]
+fn test_shared_context_is_archived_once() {
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+    let polys: Vec<_> = (0..4)
+        .map(|i| NttPolynomial::from_coeffs([i; N], Arc::clone(&ctx)))
+        .collect();
+
+    let bytes = rkyv::to_bytes::<Error>(&polys).unwrap();
+    let restored: Vec<NttPolynomial<N>> =
+        rkyv::from_bytes::<_, Error>(&bytes).unwrap();
+
+    assert!(Arc::ptr_eq(restored[0].context(), restored[3].context()));
+}
+
+#[test]
+fn test_reducer_survives_round_trip() {
+    use rust_ntt::reducer::{Montgomery, Reducer};
+
+    let q = find_first_prime_up(40, N);
+    let ctx = NttContext::<N>::new_strict(q).into_reducer::<Montgomery>();
+    let mut rng = StdRng::seed_from_u64(42);
+    let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+    let bytes = rkyv::to_bytes::<Error>(&a).unwrap();
+    let restored: NttPolynomial<N, Heap, Montgomery> =
+        rkyv::from_bytes::<_, Error>(&bytes).unwrap();
+
+    // The reducer is part of the type and rebuilt from the modulus
+    assert_eq!(restored.context().reducer().q(), q);
+    assert!(restored.context().is_strict());
+    assert_eq!((&restored * &a).coeffs(), (&a * &a).coeffs());
+}
@@ -0,0 +1,70 @@
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use rust_ntt::params::FHE_4096;
+use rust_ntt::rns::RnsPolynomial;
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 4096;
+
+fn sample(rng: &mut StdRng, chain: &[Arc<NttContext<N>>]) -> RnsPolynomial<N> {
+    let coeffs = std::array::from_fn(|_| rng.random_range(-1000..1000));
+    RnsPolynomial::from_signed(&coeffs, chain)
+}
+
+#[test]
+fn test_operations_act_per_prime() {
+    let chain = FHE_4096.contexts();
+    let mut rng = StdRng::seed_from_u64(42);
+    let a = sample(&mut rng, &chain);
+    let b = sample(&mut rng, &chain);
+
+    let sum = &a + &b;
+    let diff = &a - &b;
+    let product = &a * &b;
+    assert_eq!(product.len(), chain.len());
+
+    for i in 0..chain.len() {
+        let (x, y) = (&a.residues()[i], &b.residues()[i]);
+        assert_eq!(sum.residues()[i].coeffs(), (x + y).coeffs());
+        assert_eq!(diff.residues()[i].coeffs(), (x - y).coeffs());
+        assert_eq!(product.residues()[i].coeffs(), (x * y).coeffs());
+    }
+
+    // Products through explicit transforms agree with the operator
+    let (mut x, mut y) = (a.clone(), b.clone());
+    x.ntt_forward();
+    y.ntt_forward();
+    x.pointwise_mul_assign(&y);
+    x.ntt_inverse();
+    for (r, expected) in x.residues().iter().zip(product.residues()) {
+        assert_eq!(r.coeffs(), expected.coeffs());
+    }
+}
+
+#[test]
+fn test_signed_residues() {
+    let chain = FHE_4096.contexts();
+    let mut coeffs = [0i64; N];
+    coeffs[0] = -5;
+    coeffs[1] = 7;
+
+    let a = RnsPolynomial::from_signed(&coeffs, &chain);
+    for (r, ctx) in a.residues().iter().zip(&chain) {
+        assert_eq!(r.coeffs()[..3], [ctx.modulus() - 5, 7, 0]);
+    }
+    assert!(
+        RnsPolynomial::zero(&chain)
+            .residues()
+            .iter()
+            .all(|r| r.coeffs() == &[0; N])
+    );
+}
+
+#[test]
+#[should_panic(expected = "RNS chains differ in length")]
+fn test_rejects_different_chains() {
+    let chain = FHE_4096.contexts();
+    let a = RnsPolynomial::zero(&chain);
+    let b = RnsPolynomial::zero(&chain[..2]);
+    let _ = &a + &b;
+}
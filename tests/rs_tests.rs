@@ -0,0 +1,69 @@
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::index::sample};
+use rust_ntt::rs::ReedSolomon;
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 32;
+
+#[test]
+fn test_forward_ntt_evaluates_at_domain_points() {
+    let q = find_first_prime_down(50, N);
+    let ctx = NttContext::<N>::new(q);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+    let mut a_hat = a.clone();
+    a_hat.ntt_forward();
+
+    for (slot, &x) in ctx.evaluation_points().iter().enumerate() {
+        assert_eq!(a_hat.coeffs()[slot], a.evaluate(x));
+    }
+}
+
+#[test]
+fn test_encode_is_systematic_and_low_degree() {
+    let q = find_first_prime_down(40, N);
+    let ctx = NttContext::<N>::new(q);
+    let code = ReedSolomon::new(Arc::clone(&ctx), 10);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let message: Vec<u64> = (0..10).map(|_| rng.random_range(0..q)).collect();
+    let codeword = code.encode(&message);
+    assert_eq!(&codeword[..10], &message[..]);
+
+    // Interpolating the codeword back gives a polynomial of degree < k
+    let mut f = NttPolynomial::from_coeffs(codeword, Arc::clone(&ctx));
+    f.ntt_inverse();
+    assert!(f.coeffs()[10..].iter().all(|&c| c == 0));
+}
+
+#[test]
+fn test_decode_random_erasures() {
+    let q = find_first_prime_down(40, N);
+    let ctx = NttContext::<N>::new(q);
+    let code = ReedSolomon::new(Arc::clone(&ctx), 12);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    for _ in 0..10 {
+        let message: Vec<u64> = (0..12).map(|_| rng.random_range(0..q)).collect();
+        let mut received = code.encode(&message).map(Some);
+
+        for i in sample(&mut rng, N, N - 12) {
+            received[i] = None;
+        }
+        assert_eq!(code.decode_erasures(&received), Some(message));
+    }
+}
+
+#[test]
+fn test_too_many_erasures() {
+    let q = find_first_prime_down(40, N);
+    let ctx = NttContext::<N>::new(q);
+    let code = ReedSolomon::new(ctx, 4);
+
+    let mut received = code.encode(&[1, 2, 3, 4]).map(Some);
+    for r in received.iter_mut().skip(3) {
+        *r = None;
+    }
+    assert_eq!(code.decode_erasures(&received), None);
+}
@@ -0,0 +1,134 @@
+use rand::RngCore;
+use rust_ntt::sampling::{self, DiscreteGaussian, Shake128Rng};
+use rust_ntt::*;
+
+const N: usize = 16;
+const SEED: &[u8] = b"rust-ntt sampling test vectors";
+
+fn centered(c: u64, q: u64) -> i64 {
+    rust_ntt::noise::centered(c, q)
+}
+
+/// Byte source that counts what it hands out
+struct Counting<R> {
+    inner: R,
+    bytes: usize,
+}
+
+impl<R: RngCore> RngCore for Counting<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.bytes += 4;
+        self.inner.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.bytes += 8;
+        self.inner.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.bytes += dst.len();
+        self.inner.fill_bytes(dst);
+    }
+}
+
+fn context() -> std::sync::Arc<NttContext<N>> {
+    // q = 1073741857
+    NttContext::<N>::new(find_first_prime_up(30, N))
+}
+
+// Locked outputs of sampling version 1; cross-checked against an
+// independent implementation over hashlib.shake_128
+#[test]
+fn test_version_1_vectors() {
+    assert_eq!(sampling::VERSION, 1);
+    let ctx = context();
+    let q = ctx.modulus();
+
+    assert_eq!(Shake128Rng::from_seed(SEED).next_u64(), 0x7c1276edff47f282);
+
+    let a = sampling::uniform(ctx.clone(), &mut Shake128Rng::from_seed(SEED));
+    assert_eq!(
+        a.coeffs(),
+        &[
+            1043584020, 896336151, 327053196, 903929870, 605528304, 277048495,
+            533431822, 673843956, 837479902, 973135327, 520357200, 756226839,
+            726625911, 482390235, 847833239, 429457671,
+        ]
+    );
+
+    let b = sampling::cbd(ctx.clone(), 2, &mut Shake128Rng::from_seed(SEED));
+    assert_eq!(
+        b.coeffs().map(|c| centered(c, q)),
+        [1, -1, 1, 0, 1, -1, 0, 0, -1, -1, 0, 1, 1, 1, -2, 1]
+    );
+
+    let gaussian = DiscreteGaussian::new(3.2);
+    let e = gaussian.sample(ctx, &mut Shake128Rng::from_seed(SEED));
+    assert_eq!(
+        e.coeffs().map(|c| centered(c, q)),
+        [7, 2, 2, -2, 4, -4, 6, 8, 1, 3, 1, 3, 4, 2, 2, -2]
+    );
+}
+
+#[test]
+fn test_byte_consumption_is_fixed() {
+    let ctx = context();
+    let mut rng = Counting {
+        inner: Shake128Rng::from_seed(SEED),
+        bytes: 0,
+    };
+
+    sampling::cbd(ctx.clone(), 3, &mut rng);
+    assert_eq!(rng.bytes, 2 * 3 * N / 8);
+
+    rng.bytes = 0;
+    DiscreteGaussian::new(3.2).sample(ctx.clone(), &mut rng);
+    assert_eq!(rng.bytes, 8 * N);
+
+    // 30-bit q: 4 bytes per attempt, rejections only add whole attempts
+    rng.bytes = 0;
+    sampling::uniform(ctx, &mut rng);
+    assert!(rng.bytes >= 4 * N);
+    assert_eq!(rng.bytes % 4, 0);
+}
+
+#[test]
+fn test_gaussian_moments() {
+    let gaussian = DiscreteGaussian::new(3.2);
+    let mut rng = Shake128Rng::from_seed(SEED);
+    let samples: Vec<i64> =
+        (0..20000).map(|_| gaussian.sample_i64(&mut rng)).collect();
+
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<i64>() as f64 / n;
+    let var = samples
+        .iter()
+        .map(|&x| (x as f64 - mean).powi(2))
+        .sum::<f64>()
+        / n;
+    assert!(mean.abs() < 0.1, "mean {mean}");
+    assert!((var.sqrt() - 3.2).abs() < 0.1, "sigma {}", var.sqrt());
+    assert!(samples.iter().all(|x| x.abs() <= 40));
+}
+
+#[test]
+fn test_cbd_range() {
+    let ctx = context();
+    let q = ctx.modulus();
+    let mut rng = Shake128Rng::from_seed(SEED);
+    for eta in [1, 2, 3, 16] {
+        let a = sampling::cbd(ctx.clone(), eta, &mut rng);
+        assert!(
+            a.coeffs()
+                .iter()
+                .all(|&c| centered(c, q).unsigned_abs() <= eta as u64)
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "eta must be in 1..=16")]
+fn test_cbd_rejects_large_eta() {
+    sampling::cbd(context(), 17, &mut Shake128Rng::from_seed(SEED));
+}
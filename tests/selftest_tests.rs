@@ -0,0 +1,20 @@
+use rust_ntt::selftest;
+
+#[test]
+fn test_selftest_passes() {
+    let report = selftest();
+    assert!(report.passed(), "{report}");
+    assert_eq!(report.failures().count(), 0);
+    assert!(report.checks().len() >= 7);
+}
+
+#[test]
+fn test_report_lists_every_check() {
+    let report = selftest();
+    let text = report.to_string();
+
+    for check in report.checks() {
+        assert!(text.contains(&format!("[ ok ] {}", check.name)));
+    }
+    assert!(text.ends_with(&format!("{} checks, 0 failed", report.checks().len())));
+}
@@ -0,0 +1,54 @@
+use rand::{SeedableRng, rngs::StdRng, seq::SliceRandom};
+use rust_ntt::shamir::{reconstruct, split, split_batched};
+use rust_ntt::*;
+
+#[test]
+fn test_any_threshold_subset_reconstructs() {
+    let q = find_first_prime_down(61, 1);
+    let class = CongruenceClass::new(q);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let secret = q - 12345;
+    let mut shares = split(secret, 4, 9, &class, &mut rng);
+
+    for _ in 0..10 {
+        shares.shuffle(&mut rng);
+        assert_eq!(reconstruct(&shares[..4], &class), secret);
+    }
+
+    // Using more shares than necessary is fine
+    assert_eq!(reconstruct(&shares, &class), secret);
+}
+
+#[test]
+fn test_below_threshold_is_not_the_secret() {
+    let class = CongruenceClass::new(find_first_prime_down(61, 1));
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let shares = split(42, 3, 5, &class, &mut rng);
+    assert_ne!(reconstruct(&shares[..2], &class), 42);
+}
+
+#[test]
+fn test_batched_shares_match_evaluation() {
+    const N: usize = 16;
+    let q = find_first_prime_down(40, N);
+    let ctx = NttContext::<N>::new(q);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let shares = split_batched(777, 5, &ctx, &mut rng);
+    assert_eq!(shares.len(), N);
+
+    assert_eq!(reconstruct(&shares[3..8], ctx.class()), 777);
+    assert_eq!(reconstruct(&shares[11..], ctx.class()), 777);
+}
+
+#[test]
+#[should_panic(expected = "duplicate share point")]
+fn test_duplicate_shares_rejected() {
+    let class = CongruenceClass::new(97);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let shares = split(5, 2, 3, &class, &mut rng);
+    reconstruct(&[shares[0], shares[0]], &class);
+}
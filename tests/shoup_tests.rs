@@ -0,0 +1,58 @@
+use proptest::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::shoup::ShoupPolynomial;
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 16;
+
+fn context() -> Arc<NttContext<N>> {
+    NttContext::<N>::new(find_first_prime_up(50, N))
+}
+
+// Property: multiplying by a ShoupPolynomial matches the Barrett pointwise
+// product, including for unreduced multiplicands
+proptest! {
+    #[test]
+    fn shoup_product_matches_pointwise_mul(
+        a in prop::array::uniform16(any::<u64>()),
+        b in prop::array::uniform16(any::<u64>()),
+    ) {
+        let ctx = context();
+        let x = NttPolynomial::from_unreduced(&a, Arc::clone(&ctx));
+        let y = NttPolynomial::from_coeffs(b, Arc::clone(&ctx));
+        let fixed = ShoupPolynomial::new(&y);
+        prop_assert!(fixed.poly().is_canonical());
+
+        let reduced = NttPolynomial::from_unreduced(&b, ctx);
+        let expected = x.pointwise_mul(&reduced);
+        prop_assert_eq!(*x.pointwise_mul_shoup(&fixed).coeffs(), *expected.coeffs());
+
+        let mut assigned = x.clone();
+        assigned.pointwise_mul_shoup_assign(&fixed);
+        prop_assert_eq!(assigned.coeffs(), expected.coeffs());
+    }
+}
+
+#[test]
+fn test_shoup_constants() {
+    let ctx = context();
+    let q = ctx.modulus() as u128;
+    let mut rng = StdRng::seed_from_u64(42);
+    let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+    let fixed = ShoupPolynomial::from(&a);
+
+    for (&c, &s) in fixed.coeffs().iter().zip(fixed.shoup()) {
+        assert_eq!(s as u128, ((c as u128) << 64) / q);
+    }
+    assert_eq!(fixed.into_poly().coeffs(), a.coeffs());
+}
+
+#[test]
+#[should_panic(expected = "Cannot multiply polynomials: moduli differ")]
+fn test_mismatched_moduli_panic() {
+    let a = NttPolynomial::<N>::zero(context());
+    let other = NttContext::<N>::new(find_first_prime_up(30, N));
+    let fixed = ShoupPolynomial::new(&NttPolynomial::zero(other));
+    let _ = a.pointwise_mul_shoup(&fixed);
+}
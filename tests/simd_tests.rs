@@ -0,0 +1,70 @@
+#![cfg(feature = "portable-simd")]
+
+use proptest::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::simd::SimdKernel;
+use rust_ntt::*;
+use std::sync::Arc;
+
+/// Transforms of `a` under the default kernel and under `SimdKernel<L>`
+fn check_lanes<const N: usize, const L: usize>(q: u64, seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let reference = NttContext::<N>::new(q);
+    let simd = NttContext::<N>::with_kernel(q, Arc::new(SimdKernel::<L>));
+    let a = NttPolynomial::sample_random(reference, &mut rng);
+
+    let mut expected = a.clone();
+    expected.ntt_forward_shoup();
+    let mut evals = NttPolynomial::from_coeffs(*a.coeffs(), simd);
+    evals.ntt_forward_shoup();
+    assert_eq!(evals.coeffs(), expected.coeffs());
+
+    evals.ntt_inverse_shoup();
+    assert_eq!(evals.coeffs(), a.coeffs());
+}
+
+// Property: the std::simd kernel matches the scalar and intrinsics kernels
+// for lane counts below, at and above the smallest half-block
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+    #[test]
+    fn simd_kernel_matches_default(
+        bits in prop::sample::select(vec![20usize, 40, 62]),
+        seed in any::<u64>(),
+    ) {
+        let q = find_first_prime_up(bits, 256);
+        check_lanes::<256, 2>(q, seed);
+        check_lanes::<256, 4>(q, seed);
+        check_lanes::<256, 8>(q, seed);
+
+        let q = find_first_prime_up(bits, 8);
+        check_lanes::<8, 4>(q, seed);
+        check_lanes::<8, 8>(q, seed);
+    }
+}
+
+#[test]
+fn test_simd_kernel_convolution() {
+    const N: usize = 2048;
+    let q = find_first_prime_down(62, N);
+    let simd = NttContext::<N>::with_kernel(q, Arc::new(SimdKernel::<4>));
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let a = NttPolynomial::sample_random(Arc::clone(&simd), &mut rng);
+    let b = NttPolynomial::sample_random(simd, &mut rng);
+    let expected = a.negacyclic_convolution(&b);
+    assert_eq!(
+        a.negacyclic_convolution_shoup(&b).coeffs(),
+        expected.coeffs()
+    );
+}
+
+#[test]
+fn test_simd_kernel_degree_one() {
+    let q = find_first_prime_up(20, 1);
+    let simd = NttContext::<1>::with_kernel(q, Arc::new(SimdKernel::<4>));
+    let mut a = NttPolynomial::from_coeffs([7], simd);
+    a.ntt_forward_shoup();
+    a.ntt_inverse_shoup();
+    assert_eq!(a.coeffs(), &[7]);
+}
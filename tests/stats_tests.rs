@@ -0,0 +1,84 @@
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::sampling::{DiscreteGaussian, Shake128Rng};
+use rust_ntt::stats::{CoeffStats, Histogram};
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 256;
+
+#[test]
+fn test_stats_match_direct_computation() {
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+    let q = ctx.modulus();
+    let mut rng = StdRng::seed_from_u64(42);
+    let polys: Vec<_> = (0..8)
+        .map(|_| NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng))
+        .collect();
+
+    let values: Vec<f64> = polys
+        .iter()
+        .flat_map(|p| p.coeffs().map(|c| noise::centered(c, q) as f64))
+        .collect();
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>()
+        / values.len() as f64;
+
+    let stats = CoeffStats::of_stream(&polys);
+    assert_eq!(stats.count(), (8 * N) as u64);
+    assert!((stats.mean() - mean).abs() < 1e-3 * q as f64);
+    assert!((stats.variance() / variance - 1.0).abs() < 1e-9);
+    assert_eq!(
+        stats.max_abs(),
+        values.iter().map(|v| v.abs() as u64).max().unwrap()
+    );
+
+    // Merging per-polynomial summaries gives the same result
+    let mut merged = CoeffStats::new();
+    for p in &polys {
+        merged.merge(&CoeffStats::of(p));
+    }
+    assert_eq!(merged.count(), stats.count());
+    assert_eq!((merged.min(), merged.max()), (stats.min(), stats.max()));
+    assert!((merged.variance() / stats.variance() - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_gaussian_noise_summary() {
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+    let mut rng = Shake128Rng::from_seed(b"stats");
+    let gaussian = DiscreteGaussian::new(3.2);
+
+    let polys: Vec<_> = (0..40)
+        .map(|_| gaussian.sample(Arc::clone(&ctx), &mut rng))
+        .collect();
+    let stats = CoeffStats::of_stream(&polys);
+    let histogram = Histogram::of_stream(&polys);
+
+    assert!(stats.mean().abs() < 0.2);
+    assert!((stats.std_dev() - 3.2).abs() < 0.2);
+    assert_eq!(histogram.total(), stats.count());
+    assert_eq!(histogram.iter().next().unwrap().0, stats.min());
+    assert!(histogram.get(0) > histogram.get(5));
+}
+
+#[test]
+fn test_histogram_buckets_and_display() {
+    let mut histogram = Histogram::new();
+    for v in [-3, -1, -1, 0, 2, 7] {
+        histogram.add(v);
+    }
+
+    let buckets: Vec<_> = histogram.bucketed(4).into_iter().collect();
+    assert_eq!(buckets, vec![(-4, 3), (0, 2), (4, 1)]);
+
+    let text = histogram.to_string();
+    assert_eq!(text.lines().count(), 5);
+    assert!(text.lines().nth(1).unwrap().ends_with(&"#".repeat(40)));
+}
+
+#[test]
+fn test_empty_stats() {
+    let stats = CoeffStats::new();
+    assert_eq!((stats.count(), stats.variance()), (0, 0.0));
+    assert_eq!(Histogram::new().total(), 0);
+}
@@ -0,0 +1,62 @@
+use proptest::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 16;
+
+// Property: inline polynomials compute the same results as heap ones
+proptest! {
+    #[test]
+    fn inline_matches_heap(
+        a in prop::array::uniform16(0u64..1000),
+        b in prop::array::uniform16(0u64..1000),
+        c in any::<u64>(),
+    ) {
+        let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+        let (x, y) = (
+            NttPolynomial::from_coeffs(a, Arc::clone(&ctx)),
+            NttPolynomial::from_coeffs(b, Arc::clone(&ctx)),
+        );
+        let (u, v) = (
+            NttPolynomial::from_coeffs_in(a, Arc::clone(&ctx), Inline),
+            NttPolynomial::from_coeffs_in(b, ctx, Inline),
+        );
+
+        prop_assert_eq!(*(&u + &v).coeffs(), *(&x + &y).coeffs());
+        prop_assert_eq!(*(&u - &v).coeffs(), *(&x - &y).coeffs());
+        prop_assert_eq!(*(&u * &v).coeffs(), *(&x * &y).coeffs());
+        prop_assert_eq!(*(-&u).coeffs(), *(-&x).coeffs());
+        prop_assert_eq!(*(&u * c).coeffs(), *(&x * c).coeffs());
+        prop_assert_eq!(
+            *u.naive_negacyclic_convolution(&v).coeffs(),
+            *x.naive_negacyclic_convolution(&y).coeffs()
+        );
+        prop_assert_eq!(
+            *u.negacyclic_convolution_shoup(&v).coeffs(),
+            *x.negacyclic_convolution_shoup(&y).coeffs()
+        );
+        prop_assert_eq!(*u.automorphism(3).coeffs(), *x.automorphism(3).coeffs());
+        prop_assert_eq!(*u.to_ntt().coeffs(), *x.to_ntt().coeffs());
+    }
+}
+
+#[test]
+fn test_inline_round_trips_through_the_heap() {
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+    let mut rng = StdRng::seed_from_u64(42);
+    let a = NttPolynomial::sample_random_in(ctx, &mut rng, Inline);
+
+    let boxed = a.clone().into_boxed_coeffs();
+    let b = NttPolynomial::from_boxed_coeffs(boxed, Arc::clone(a.context()));
+    assert_eq!(b.coeffs(), a.coeffs());
+    let zero = NttPolynomial::zero_in(Arc::clone(b.context()), Inline);
+    assert_eq!(zero.coeffs(), &[0; N]);
+}
+
+#[test]
+fn test_inline_polynomial_holds_its_coefficients() {
+    let context = size_of::<Arc<NttContext<N>>>();
+    assert_eq!(size_of::<NttPolynomial<N, Inline>>(), context + 8 * N);
+    assert_eq!(size_of::<NttPolynomial<N, Heap>>(), 2 * context);
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "testing")]
+
+use proptest::prelude::*;
+use rust_ntt::testing::{
+    context_with_polynomials, contexts, ntt_primes, polynomials,
+};
+
+// Property: Generated primes are NTT-friendly and of the requested size
+proptest! {
+    #[test]
+    fn ntt_primes_are_valid(q in ntt_primes(1024, 20..=50)) {
+        prop_assert_eq!((q - 1) % 2048, 0);
+        prop_assert!(q > 1 << 20 && q < 1 << 51);
+        prop_assert!(primal::is_prime(q));
+    }
+}
+
+// Property: Generated polynomials are reduced modulo their context
+proptest! {
+    #[test]
+    fn polynomials_are_in_range(
+        p in contexts::<16>(10..=60).prop_flat_map(polynomials)
+    ) {
+        let q = p.context().modulus();
+        prop_assert!(p.coeffs().iter().all(|&c| c < q));
+    }
+}
+
+// Property: Polynomials drawn together share their context
+proptest! {
+    #[test]
+    fn polynomials_share_context((ctx, polys) in context_with_polynomials::<8>(17..=30, 3)) {
+        prop_assert_eq!(polys.len(), 3);
+        for p in &polys {
+            prop_assert!(std::sync::Arc::ptr_eq(p.context(), &ctx));
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "too small")]
+fn test_bits_too_small_for_degree_panics() {
+    let _ = ntt_primes(1024, 5..=20);
+}
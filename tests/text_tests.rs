@@ -0,0 +1,67 @@
+use proptest::prelude::*;
+use rust_ntt::text::ParseError;
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 4;
+
+#[test]
+fn test_formats_match_ntl_and_sage() {
+    let ctx = NttContext::<N>::new(1033);
+    let a = NttPolynomial::from_coeffs([544, 561, 0, 0], ctx);
+
+    assert_eq!(a.to_ntl_string(), "[544 561]");
+    assert_eq!(a.to_sage_string(), "[544, 561, 0, 0]");
+
+    let zero = NttPolynomial::zero(NttContext::<N>::new(1033));
+    assert_eq!(zero.to_ntl_string(), "[]");
+}
+
+#[test]
+fn test_parsing_reduces_and_pads() {
+    let ctx = NttContext::<N>::new(1033);
+
+    let a = NttPolynomial::from_sage_string("[ -1, 1034 ,2,]", Arc::clone(&ctx))
+        .unwrap();
+    assert_eq!(a.coeffs(), &[1032, 1, 2, 0]);
+
+    let b = NttPolynomial::from_ntl_string("  [7\n8]  ", Arc::clone(&ctx)).unwrap();
+    assert_eq!(b.coeffs(), &[7, 8, 0, 0]);
+
+    let empty = NttPolynomial::from_sage_string("[]", ctx).unwrap();
+    assert_eq!(empty.coeffs(), &[0; N]);
+}
+
+#[test]
+fn test_parse_errors() {
+    let ctx = NttContext::<N>::new(1033);
+    let parse = |s| NttPolynomial::from_ntl_string(s, Arc::clone(&ctx)).err();
+
+    assert_eq!(parse("1 2 3"), Some(ParseError::MissingBrackets));
+    assert_eq!(
+        parse("[1 x 3]"),
+        Some(ParseError::InvalidCoefficient("x".to_string()))
+    );
+    assert_eq!(
+        parse("[1 2 3 4 5]"),
+        Some(ParseError::TooManyCoefficients {
+            found: 5,
+            degree: N
+        })
+    );
+}
+
+// Property: Both text formats round-trip
+proptest! {
+    #[test]
+    fn text_formats_round_trip(coeffs in prop::array::uniform4(0u64..1033)) {
+        let ctx = NttContext::<N>::new(1033);
+        let a = NttPolynomial::from_coeffs(coeffs, Arc::clone(&ctx));
+
+        let ntl = NttPolynomial::from_ntl_string(&a.to_ntl_string(), Arc::clone(&ctx)).unwrap();
+        prop_assert_eq!(ntl.coeffs(), a.coeffs());
+
+        let sage = NttPolynomial::from_sage_string(&a.to_sage_string(), ctx).unwrap();
+        prop_assert_eq!(sage.coeffs(), a.coeffs());
+    }
+}
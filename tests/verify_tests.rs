@@ -0,0 +1,52 @@
+use rust_ntt::verify::{
+    VerifyOptions, ntt_primes_below, verify_primes_below, verify_ring,
+    verify_scalar,
+};
+
+#[test]
+fn test_ntt_primes_below() {
+    assert_eq!(ntt_primes_below::<4>(100), vec![17, 41, 73, 89, 97]);
+    assert_eq!(ntt_primes_below::<16>(200), vec![97, 193]);
+}
+
+#[test]
+fn test_small_rings_verify_exhaustively() {
+    // 17^2 = 289 polynomials, 289^2 pairs: every unary and binary check is
+    // enumerated in full
+    let options = VerifyOptions {
+        exhaustive_limit: 17u64.pow(4),
+        ..VerifyOptions::default()
+    };
+    let violations = verify_ring::<2>(17, &options);
+    assert!(violations.is_empty(), "{violations:?}");
+}
+
+#[test]
+fn test_all_small_primes_verify() {
+    let options = VerifyOptions {
+        samples: 200,
+        ..VerifyOptions::default()
+    };
+    for violations in [
+        verify_primes_below::<1>(200, &options),
+        verify_primes_below::<2>(200, &options),
+        verify_primes_below::<4>(300, &options),
+        verify_primes_below::<8>(300, &options),
+    ] {
+        assert!(violations.is_empty(), "{violations:?}");
+    }
+}
+
+#[test]
+fn test_strict_mode_finds_only_canonical_outputs() {
+    let strict = VerifyOptions {
+        strict: true,
+        ..VerifyOptions::default()
+    };
+    for q in [17, 97, 7681] {
+        let violations = verify_scalar(q, &strict);
+        assert!(violations.is_empty(), "{violations:?}");
+    }
+    let violations = verify_primes_below::<4>(200, &strict);
+    assert!(violations.is_empty(), "{violations:?}");
+}
@@ -0,0 +1,90 @@
+use proptest::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
+use rust_ntt::view::{PolyView, PolyViewMut};
+use rust_ntt::*;
+use std::sync::Arc;
+
+const N: usize = 16;
+
+fn canonical<const D: usize>(coeffs: &[u64; D], q: u64) -> [u64; D] {
+    coeffs.map(|c| c % q)
+}
+
+#[test]
+fn test_views_over_tower_match_owned_polynomials() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let ctx = NttContext::<N>::new(find_first_prime_up(40, N));
+    let q = ctx.modulus();
+    let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+    let b = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+    // Ciphertext-like layout: both components in one flat buffer
+    let mut buffer = [a.coeffs().as_slice(), b.coeffs()].concat();
+    let (lhs, rhs) = buffer.split_at_mut(N);
+    let rhs = PolyView::new(rhs, &ctx);
+
+    let mut view = PolyViewMut::new(lhs, &ctx);
+    view += rhs;
+    assert_eq!(
+        canonical(view.coeffs(), q),
+        canonical((&a + &b).coeffs(), q)
+    );
+    view -= rhs;
+    assert_eq!(canonical(view.coeffs(), q), canonical(a.coeffs(), q));
+    view *= rhs;
+    assert_eq!(
+        canonical(view.coeffs(), q),
+        canonical((&a * &b).coeffs(), q)
+    );
+    view.neg_assign();
+    assert_eq!(
+        canonical(view.coeffs(), q),
+        canonical((-&(&a * &b)).coeffs(), q)
+    );
+
+    // The writes landed in the caller's buffer, the second half is untouched
+    assert_eq!(buffer[N..], b.coeffs()[..]);
+}
+
+#[test]
+fn test_view_mut_of_owned_polynomial() {
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+    let q = ctx.modulus();
+    let a = NttPolynomial::from_coeffs(std::array::from_fn(|i| i as u64), ctx);
+    let mut evals = a.clone();
+    evals.ntt_forward_shoup();
+
+    // Square through the view, reusing the transform of a
+    let mut b = a.clone();
+    let mut view = b.view_mut();
+    view.ntt_forward();
+    view.pointwise_mul_assign(evals.view());
+    view.ntt_inverse();
+    assert_eq!(canonical(b.coeffs(), q), canonical((&a * &a).coeffs(), q));
+}
+
+#[test]
+#[should_panic(expected = "view needs 16 coefficients, got 8")]
+fn test_view_rejects_wrong_length() {
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+    PolyView::new(&[0; 8], &ctx);
+}
+
+// Property: Transforms through a view round-trip and match the owned ones
+proptest! {
+    #[test]
+    fn view_transforms_match_owned(coeffs in prop::array::uniform16(0u64..1 << 30)) {
+        let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+        let q = ctx.modulus();
+        let mut owned = NttPolynomial::from_coeffs(coeffs.map(|c| c % q), Arc::clone(&ctx));
+        let mut buffer = *owned.coeffs();
+
+        let mut view = PolyViewMut::new(&mut buffer, &ctx);
+        view.ntt_forward();
+        owned.ntt_forward_shoup();
+        prop_assert_eq!(view.coeffs(), owned.coeffs());
+
+        view.ntt_inverse();
+        prop_assert_eq!(canonical(view.coeffs(), q), coeffs.map(|c| c % q));
+    }
+}
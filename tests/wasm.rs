@@ -0,0 +1,33 @@
+//! Smoke tests for wasm32-unknown-unknown, run with wasm-bindgen-test.
+#![cfg(target_arch = "wasm32")]
+
+use rust_ntt::*;
+use std::sync::Arc;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_convolution_in_wasm() {
+    const N: usize = 256;
+    let ctx = NttContext::<N>::new(find_first_prime_up(30, N));
+
+    // Entropy comes from the host through getrandom's wasm_js backend
+    let mut rng = rand::rng();
+    let a = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+    let b = NttPolynomial::sample_random(Arc::clone(&ctx), &mut rng);
+
+    let fast = a.negacyclic_convolution_shoup(&b);
+    let naive = a.naive_negacyclic_convolution(&b);
+    assert_eq!(fast.coeffs(), naive.coeffs());
+}
+
+#[wasm_bindgen_test]
+fn test_round_trip_in_wasm() {
+    const N: usize = 64;
+    let ctx = NttContext::<N>::new(find_first_prime_down(50, N));
+
+    let coeffs = std::array::from_fn(|i| i as u64);
+    let mut a = NttPolynomial::from_coeffs(coeffs, ctx);
+    a.ntt_forward_shoup();
+    a.ntt_inverse_shoup();
+    assert_eq!(a.coeffs(), &coeffs);
+}
@@ -0,0 +1,91 @@
+use proptest::prelude::*;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use rust_ntt::wide::{
+    CongruenceClassWide, NttContextWide, find_first_prime_up_wide,
+};
+
+const N: usize = 16;
+
+/// `a·b mod q` by double-and-add, needing only additions below 2^128
+fn mulmod_slow(a: u128, b: u128, q: u128) -> u128 {
+    let (mut acc, mut a) = (0u128, a % q);
+    for bit in 0..128 {
+        if (b >> bit) & 1 == 1 {
+            acc = (acc + a) % q;
+        }
+        a = (a << 1) % q;
+    }
+    acc
+}
+
+fn moduli() -> [u128; 3] {
+    [
+        find_first_prime_up_wide(60, N),
+        find_first_prime_up_wide(100, N),
+        (1 << 127) - 1,
+    ]
+}
+
+// Property: Montgomery products of 256-bit width agree with double-and-add
+proptest! {
+    #[test]
+    fn wide_modmul_matches_slow(a in any::<u128>(), b in any::<u128>()) {
+        for q in moduli() {
+            let class = CongruenceClassWide::new(q);
+            let (a, b) = (a % q, b % q);
+
+            prop_assert_eq!(class.modmul(a, b), mulmod_slow(a, b, q));
+            prop_assert_eq!(class.from_mont(class.to_mont(a)), a);
+            prop_assert_eq!(class.modadd(a, b), ((a % q) + (b % q)) % q);
+            prop_assert_eq!(class.modadd(class.modsub(a, b), b), a);
+        }
+    }
+}
+
+#[test]
+fn test_prime_search() {
+    let q = find_first_prime_up_wide(100, N);
+    assert!(q > 1 << 100);
+    assert_eq!((q - 1) % (2 * N as u128), 0);
+    assert!(CongruenceClassWide::new(q).is_probable_prime());
+    // (2^61 - 1)·(2^31 - 1) is a product of two Mersenne primes
+    let composite = ((1u128 << 61) - 1) * ((1 << 31) - 1);
+    assert!(!CongruenceClassWide::new(composite).is_probable_prime());
+}
+
+#[test]
+fn test_wide_ntt_convolution_matches_naive() {
+    let mut rng = StdRng::seed_from_u64(42);
+
+    for q in moduli().into_iter().take(2) {
+        let ctx = NttContextWide::<N>::new(q);
+        let class = *ctx.class();
+        let a: [u128; N] = std::array::from_fn(|_| rng.random_range(0..q));
+        let b: [u128; N] = std::array::from_fn(|_| rng.random_range(0..q));
+
+        let mut evals = a;
+        ctx.ntt_forward(&mut evals);
+        ctx.ntt_inverse(&mut evals);
+        assert_eq!(evals, a);
+
+        let mut expected = [0u128; N];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                let p = class.modmul(x, y);
+                let k = (i + j) % N;
+                expected[k] = if i + j < N {
+                    class.modadd(expected[k], p)
+                } else {
+                    class.modsub(expected[k], p)
+                };
+            }
+        }
+        assert_eq!(ctx.negacyclic_convolution(&a, &b), expected);
+    }
+}
+
+#[test]
+#[should_panic(expected = "has no primitive 2N-th root of unity")]
+fn test_wide_context_rejects_wrong_congruence() {
+    NttContextWide::<N>::new((1 << 127) - 1);
+}